@@ -0,0 +1,190 @@
+//
+// File Name:    throughput.rs
+// Directory:    benches
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Throughput Benchmarks
+//!
+//! Measures the log entries per second through the logging hot path: the logger,
+//! the log entry, and the formatter. The handler formats each log entry, then
+//! throws it away, so that no I/O is measured.
+//!
+//! Run with:
+//! ```text
+//! cargo bench --bench throughput
+//! ```
+//! To compare, run it before and after a change, on the same machine.
+//!
+
+use flogging::*;
+use std::{
+    any::Any,
+    fmt,
+    hint::black_box,
+    io::Error,
+    time::{Duration, Instant},
+};
+
+///
+/// How long each benchmark is run for.
+///
+const RUN_TIME: Duration = Duration::from_secs(2);
+
+///
+/// Formats each log entry into a reused buffer, then discards it.
+///
+#[derive(Debug, Default)]
+struct SinkHandler {
+    formatter: Formatter,
+    filter: Option<Box<dyn FilterTrait>>,
+    buf: String,
+}
+
+impl fmt::Display for SinkHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SinkHandler : {}", self.formatter)
+    }
+}
+
+impl HandlerTrait for SinkHandler {
+    fn create(_name: &str) -> Result<Self, Error> {
+        Ok(SinkHandler {
+            formatter: FormatType::Simple.create(None),
+            ..Default::default()
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn close(&mut self) {}
+
+    fn flush(&mut self) {}
+
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
+    fn get_formatter(&self) -> Formatter {
+        self.formatter.clone()
+    }
+
+    fn get_log(&self) -> String {
+        String::new()
+    }
+
+    fn is_open(&self) -> bool {
+        true
+    }
+
+    fn publish(&mut self, log_entry: &LogEntry) {
+        self.buf.clear();
+        self.formatter.format_into(log_entry, &mut self.buf);
+        black_box(&self.buf);
+    }
+
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
+    }
+
+    fn set_formatter(&mut self, formatter: Formatter) {
+        self.formatter = formatter;
+    }
+
+    fn set_test_mode(&mut self, _state: bool) {}
+}
+
+///
+/// Returns a logger, at level `INFO`, with a `SinkHandler` using `format_type`.
+///
+fn logger(format_type: FormatType) -> Logger {
+    Logger::builder("bench")
+        .set_fn_name("run")
+        .add_custom_handler_with(
+            "Sink",
+            Box::new(SinkHandler::create("").unwrap()),
+            format_type,
+            None,
+        )
+        .build()
+}
+
+static_logger!({ logger(FormatType::Simple) });
+
+#[logger]
+fn info_macro(n: u64) {
+    info!("Processed item {n} of the batch.");
+}
+
+#[logger]
+fn finest_macro(n: u64) {
+    finest!("Processed item {n} of the batch.");
+}
+
+///
+/// Run `f`, with the loop count, for `RUN_TIME`, then print the log entries per second.
+///
+fn bench(name: &str, mut f: impl FnMut(u64)) {
+    let start = Instant::now();
+    let mut n = 0;
+
+    while start.elapsed() < RUN_TIME {
+        for _ in 0..1000 {
+            f(black_box(n));
+            n += 1;
+        }
+    }
+
+    let per_sec = n as f64 / start.elapsed().as_secs_f64();
+    println!("{name:<40} {per_sec:>14.0} entries/s");
+}
+
+fn main() {
+    let log = logger(FormatType::Simple);
+    bench("info(), SimpleFormatter", |_| {
+        log.info("Processed an item of the batch.")
+    });
+
+    let log = logger(FormatType::Iso8601);
+    bench("info(), Iso8601Formatter", |_| {
+        log.info("Processed an item of the batch.")
+    });
+
+    let log = logger(FormatType::Ndjson);
+    bench("info(), JsonFormatter (NDJSON)", |_| {
+        log.info("Processed an item of the batch.")
+    });
+
+    let log = logger(FormatType::Simple);
+    bench("info(), with fields", |n| {
+        log.with_fields([("item", n.to_string()), ("batch", "7".to_string())])
+            .info("Processed an item of the batch.")
+    });
+
+    bench("info!(), with an argument", info_macro);
+    bench("finest!(), disabled", finest_macro);
+}
@@ -26,73 +26,470 @@
 //! Format the Log function call (Rust code)
 //!
 
-use dyn_fmt::AsStrFormatExt;
 use proc_macro::TokenStream;
-use regex::RegexBuilder;
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::quote;
+use syn::{
+    Expr, ExprLit, Ident, Lit, Token,
+    ext::IdentExt,
+    parse::{Parse, ParseStream, Parser},
+    parse_quote,
+    punctuated::Punctuated,
+};
 
-pub(crate) fn format_impl(fmt_str: &str, msg: TokenStream) -> TokenStream {
-    // println!("msg: {}", &msg);
+///
+/// Generate the code to format `msg`, then log it with the call returned by `log`.
+///
+/// This is only done if the logger is enabled for `level`. Otherwise, the
+/// arguments are neither evaluated nor formatted. See also: [`max_level_impl()`].
+///
+pub(crate) fn format_impl(
+    level: &str,
+    log: impl Fn(TokenStream2) -> TokenStream2,
+    msg: TokenStream,
+) -> TokenStream {
+    let ident = Ident::new(level, Span::call_site());
+
+    match format_code(quote!(::flogging::Level::#ident), log, msg.into()) {
+        Ok(code) => max_level_impl(level, code).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+///
+/// Generate the code for `log!(level, msg)`.
+///
+/// The `level` expression is evaluated once, then the log method for that level
+/// is called. Levels excluded by the `max_level_*` features have no method call.
+///
+pub(crate) fn log_impl(msg: TokenStream) -> TokenStream {
+    let split = |input: ParseStream| {
+        let level = input.parse::<Expr>()?;
+        input.parse::<Token![,]>()?;
 
-    let fmt = "let __fmt = format!({});\n".format(&[
-        match process_msg(msg){
-            Some(r_msg) => r_msg,
-            None => return TokenStream::new(),
+        Ok((level, input.parse::<TokenStream2>()?))
+    };
+
+    let (level, msg) = match split.parse(msg) {
+        Ok((level, msg)) if !msg.is_empty() => (level, msg),
+        Ok(_) => {
+            return syn::Error::new(Span::call_site(), "expected: `log!(level, msg)`")
+                .to_compile_error()
+                .into();
         }
-        ]);
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let arms = LEVELS[..LEVELS.len() - 1]
+        .iter()
+        .filter_map(|level| {
+            max_level_attr(level).map(|attr| (attr, Ident::new(level, Span::call_site())))
+        })
+        .collect::<Vec<_>>();
 
-    let mut buf = String::new();
-    buf.push_str(&fmt);
-    buf.push_str(fmt_str);
+    let log = |log: TokenStream2| {
+        let arms = arms.iter().map(|(attr, level)| {
+            quote! {
+                #attr
+                ::flogging::Level::#level => #log.log_owned(::flogging::Level::#level, __fmt),
+            }
+        });
+
+        // A custom level is only checked against the logger's level.
+        quote! {
+            match __level {
+                #(#arms)*
+                ::flogging::Level::Custom { .. } => #log.log_owned(__level, __fmt),
+                _ => {}
+            }
+        }
+    };
 
-    // let rtn = buf.parse().unwrap_or_default();
-    // println!("{rtn}");
-    // rtn
-    buf.parse().unwrap_or_default()
+    match format_code(quote!(__level), log, msg) {
+        Ok(code) => quote!({
+            let __level: ::flogging::Level = #level;
+            #code
+        })
+        .into(),
+        Err(e) => e.to_compile_error().into(),
+    }
 }
+
 ///
-/// Check for `object` only `msg`.
+/// Wrap `code`, so that it is only run the first time it is reached, per process.
 ///
-/// If found, provide default format string.
+/// This is a block, so that it may also be used as an expression.
 ///
-fn process_msg(msg: TokenStream) -> Option<String> {
-    let text = msg.to_string();
+pub(crate) fn once_impl(code: TokenStream) -> TokenStream {
+    let code = TokenStream2::from(code);
 
-    if text.is_empty() {
-        return None;
-    }
+    quote!({
+        static __ONCE: ::std::sync::atomic::AtomicBool = ::std::sync::atomic::AtomicBool::new(false);
+
+        if !__ONCE.swap(true, ::std::sync::atomic::Ordering::Relaxed) {
+            #code
+        }
+    })
+    .into()
+}
+
+///
+/// Generate the code for `{name}!(every, msg)`: log `msg` at `level`, with the
+/// call returned by `log`, only every `n`th time the call site is reached, or at
+/// most once per interval.
+///
+/// The `every` expression is converted into `::flogging::Every`.
+///
+pub(crate) fn every_impl(
+    name: &str,
+    level: &str,
+    log: impl Fn(TokenStream2) -> TokenStream2,
+    msg: TokenStream,
+) -> TokenStream {
+    let split = |input: ParseStream| {
+        let every = input.parse::<Expr>()?;
+        input.parse::<Token![,]>()?;
+
+        Ok((every, input.parse::<TokenStream2>()?))
+    };
+
+    let (every, msg) = match split.parse(msg) {
+        Ok((every, msg)) if !msg.is_empty() => (every, TokenStream::from(msg)),
+        Ok(_) => {
+            return syn::Error::new(
+                Span::call_site(),
+                format!("expected: `{name}!(every, msg)`"),
+            )
+            .to_compile_error()
+            .into();
+        }
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let code = TokenStream2::from(format_impl(level, log, msg));
+
+    quote!({
+        static __EVERY: ::flogging::EveryState = ::flogging::EveryState::new();
 
-    if text.starts_with('\"') && text.ends_with('\"') {
-        return Some(text);
+        if __EVERY.tick(#every) {
+            #code
+        }
+    })
+    .into()
+}
+
+///
+/// Generate the code for `throwing!(err)`.
+///
+/// This is a block, so that it may also be used as an expression, such as the body of a closure.
+///
+pub(crate) fn throwing_impl(err: TokenStream) -> TokenStream {
+    let err = TokenStream2::from(err);
+    let code = max_level_impl("FINER", quote!(__log().throwing(&(#err));));
+
+    quote!({ #code }).into()
+}
+
+///
+/// Generate the code to format `msg`, then log it with the call returned by `log`,
+/// if the logger is enabled for the level given by the expression: `level`.
+///
+/// `log` is given the logger to call, with the fields of `msg` attached, if any.
+///
+/// Returns `Err` if `msg` is invalid, or empty.
+///
+fn format_code(
+    level: TokenStream2,
+    log: impl Fn(TokenStream2) -> TokenStream2,
+    msg: TokenStream2,
+) -> syn::Result<TokenStream2> {
+    let msg = syn::parse2::<Message>(msg)?;
+
+    if msg.args.is_empty() {
+        return Err(syn::Error::new(Span::call_site(), "expected a message"));
     }
 
-    let regex_str = "(?<fmt>\".*\\{.*\\}.*\")(,[\\s]*(?<attrs>.*)*)";
+    let args = msg.format_args();
+    let log = match msg.fields.is_empty() {
+        // Attach the fields to the log entry, via the logger's `with_fields()`.
+        false => {
+            let fields = msg.fields();
+
+            log(quote!(__log().with_fields([#(#fields),*])))
+        }
+        true => log(quote!(__log())),
+    };
 
-    let re = RegexBuilder::new(regex_str)
-        .dot_matches_new_line(true)
-        .build()
-        .unwrap();
+    Ok(quote! {
+        if __log().is_enabled(#level) {
+            let __fmt = ::std::format!(#args);
+            #log
+        }
+    })
+}
+
+///
+/// The levels, from lowest to highest, as named by the `max_level_*` features.
+///
+const LEVELS: [&str; 8] = [
+    "FINEST", "FINER", "FINE", "CONFIG", "INFO", "WARNING", "SEVERE", "OFF",
+];
+
+///
+/// Apply the compile-time maximum level to `code`, the log call for `level`.
+///
+/// Returns:
+/// - `code` guarded by `if false`, if `level` is below that set by a `max_level_*`
+///   feature.
+/// - `code` guarded by `if cfg!(debug_assertions)`, if `level` is below that set by
+///   a `release_max_level_*` feature.
+/// - Otherwise, `code` itself.
+///
+/// So an excluded log call is still type-checked, and its variables are still used,
+/// but the optimizer removes its code and strings.
+///
+pub(crate) fn max_level_impl(level: &str, code: TokenStream2) -> TokenStream2 {
+    match max_level_attr(level) {
+        Some(attr) if attr.is_empty() => code,
+        Some(_) => quote!(if ::std::cfg!(debug_assertions) { #code }),
+        None => quote!(if false { #code }),
+    }
+}
+
+///
+/// Returns the attribute to apply to the log call for `level`: `None` if it is
+/// excluded, `#[cfg(debug_assertions)]` if it is only for debug builds, else empty.
+///
+pub(crate) fn max_level_attr(level: &str) -> Option<TokenStream2> {
+    let rank = LEVELS.iter().position(|l| *l == level).unwrap_or_default();
 
-    if re.is_match(&text) {
-        Some(text)
+    if rank < max_level() {
+        None
+    } else if rank < release_max_level() {
+        Some(quote!(#[cfg(debug_assertions)]))
     } else {
-        let count = text.split(',').count();
+        Some(TokenStream2::new())
+    }
+}
+
+///
+/// The rank of the lowest level enabled by the `max_level_*` features.
+///
+/// If more than one is set, the most restrictive applies.
+///
+fn max_level() -> usize {
+    let features = [
+        cfg!(feature = "max_level_finest"),
+        cfg!(feature = "max_level_finer"),
+        cfg!(feature = "max_level_fine"),
+        cfg!(feature = "max_level_config"),
+        cfg!(feature = "max_level_info"),
+        cfg!(feature = "max_level_warning"),
+        cfg!(feature = "max_level_severe"),
+        cfg!(feature = "max_level_off"),
+    ];
+
+    features.iter().rposition(|f| *f).unwrap_or_default()
+}
 
-        let mut buf = String::new();
+///
+/// The rank of the lowest level enabled by the `release_max_level_*` features.
+///
+/// If more than one is set, the most restrictive applies.
+///
+fn release_max_level() -> usize {
+    let features = [
+        cfg!(feature = "release_max_level_finest"),
+        cfg!(feature = "release_max_level_finer"),
+        cfg!(feature = "release_max_level_fine"),
+        cfg!(feature = "release_max_level_config"),
+        cfg!(feature = "release_max_level_info"),
+        cfg!(feature = "release_max_level_warning"),
+        cfg!(feature = "release_max_level_severe"),
+        cfg!(feature = "release_max_level_off"),
+    ];
+
+    features.iter().rposition(|f| *f).unwrap_or_default()
+}
 
-        if count == 1 {
-            buf.push_str("\"{}\", ");
+///
+/// The parameters of the logging macros: `msg`, optionally followed by `;`
+/// and the fields.
+///
+struct Message {
+    args: Vec<Expr>,
+    fields: Vec<Field>,
+}
+
+impl Parse for Message {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = Vec::new();
+
+        while !input.is_empty() && !input.peek(Token![;]) {
+            args.push(input.parse::<Expr>()?);
+
+            if input.is_empty() || input.peek(Token![;]) {
+                break;
+            }
+
+            input.parse::<Token![,]>()?;
+        }
+
+        let fields = if input.parse::<Option<Token![;]>>()?.is_some() {
+            Punctuated::<Field, Token![,]>::parse_terminated(input)?
+                .into_iter()
+                .collect()
         } else {
-            buf.push_str("\"{}");
+            Vec::new()
+        };
 
-            for _i in 1..count {
-                buf.push_str(", {}");
+        Ok(Message { args, fields })
+    }
+}
+
+impl Message {
+    ///
+    /// The arguments for `format!()`.
+    ///
+    /// If `msg` is only one or more variables (or other expressions), a default
+    /// format string is provided: `"{}, {}, {}"`.
+    ///
+    fn format_args(&self) -> TokenStream2 {
+        let args = &self.args;
+
+        match args.first() {
+            Some(Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            })) if args.len() == 1 || lit.value().contains('{') => quote!(#(#args),*),
+            _ => {
+                let fmt = vec!["{}"; args.len()].join(", ");
+
+                quote!(#fmt, #(#args),*)
             }
+        }
+    }
+
+    ///
+    /// The `(key, value)` tuples, one for each field.
+    ///
+    fn fields(&self) -> Vec<TokenStream2> {
+        self.fields
+            .iter()
+            .map(|field| {
+                let key = &field.key;
+                let spec = if field.debug { "{:?}" } else { "{}" };
+                let value = &field.value;
 
-            buf.push_str("\", ");
+                quote!((#key, ::std::format!(#spec, (#value))))
+            })
+            .collect()
+    }
+}
+
+///
+/// A key-value field.
+///
+/// Each field is one of:
+/// - `key = expr` - The value is formatted with `Display`.
+/// - `key = %expr` - Ditto.
+/// - `key = ?expr` - The value is formatted with `Debug`.
+/// - `ident`, `%ident`, or `?ident` - Shorthand for: `ident = ident`, etc.
+///
+struct Field {
+    key: String,
+    debug: bool,
+    value: Expr,
+}
+
+impl Parse for Field {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // A lone `=`, rather than `==` or `=>`.
+        let at_eq = |input: ParseStream| {
+            input.peek(Token![=]) && !input.peek(Token![==]) && !input.peek(Token![=>])
+        };
+
+        // Everything up to the `=`, or the end of the field.
+        let mut key = TokenStream2::new();
+
+        while !(input.is_empty() || input.peek(Token![,]) || at_eq(input)) {
+            key.extend([input.parse::<TokenTree>()?]);
+        }
+
+        if input.parse::<Option<Token![=]>>()?.is_some() {
+            let key: String = key.into_iter().map(|tt| tt.to_string()).collect();
+
+            if key.is_empty() {
+                return Err(input.error("missing key in field"));
+            }
+
+            let debug = Self::parse_spec(input)?;
+
+            return Ok(Field {
+                key: key.trim_start_matches("r#").to_string(),
+                debug,
+                value: input.parse()?,
+            });
         }
 
-        buf.push_str(&text);
+        // Shorthand: `ident`, `%ident`, or `?ident`.
+        let shorthand = |input: ParseStream| {
+            let debug = Self::parse_spec(input)?;
+            let ident = input.call(Ident::parse_any)?;
+
+            Ok((debug, ident))
+        };
+
+        match shorthand.parse2(key.clone()) {
+            Ok((debug, ident)) => Ok(Field {
+                key: ident.to_string().trim_start_matches("r#").to_string(),
+                debug,
+                value: parse_quote!(#ident),
+            }),
+            Err(_) => Err(syn::Error::new_spanned(
+                &key,
+                format!("expected `key = value`, found: `{key}`"),
+            )),
+        }
+    }
+}
 
-        Some(buf)
+impl Field {
+    ///
+    /// Parse the optional `%` or `?` before a value.
+    ///
+    /// Returns `true` for `?`: format with `Debug`.
+    ///
+    fn parse_spec(input: ParseStream) -> syn::Result<bool> {
+        if input.parse::<Option<Token![%]>>()?.is_some() {
+            Ok(false)
+        } else {
+            Ok(input.parse::<Option<Token![?]>>()?.is_some())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_code() {
+        let log = |log: TokenStream2| quote!(#log.log_owned(::flogging::Level::INFO, __fmt););
+
+        let err = super::format_code(quote!(__level), log, TokenStream2::new())
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), "expected a message");
+
+        let code = super::format_code(quote!(__level), log, quote!(value, "text"; uid)).unwrap();
+        let expected = quote! {
+            if __log().is_enabled(__level) {
+                let __fmt = ::std::format!("{}, {}", value, "text");
+                __log()
+                    .with_fields([("uid", ::std::format!("{}", (uid)))])
+                    .log_owned(::flogging::Level::INFO, __fmt);
+            }
+        };
+        assert_eq!(code.to_string(), expected.to_string());
     }
 }
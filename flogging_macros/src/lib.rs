@@ -55,6 +55,14 @@
 //!     - The number of `"{}"` will depend on the number of parameters.
 //!     - Ideal for logging concrete instances that have very good `Display` implementations,
 //!       or you just need their data without further explanation.
+//! - Key-Value Fields
+//!     - Fields may be appended after a `;`: `("Order placed."; user_id = uid, order = %order)`
+//!     - `key = value` and `key = %value` use `Display`, `key = ?value` uses `Debug`.
+//!     - A plain variable is shorthand for a field of the same name: `("Done."; uid, ?items)`
+//!     - The fields are attached to the `LogEntry`, and shown by the formatters.
+//! - Lazy Evaluation
+//!     - The parameters are only evaluated and formatted if the logger is enabled for
+//!       the macro's level. So disabled `finest!()` calls cost next to nothing.
 //! - Special Cases
 //!     - [entering!] and [exiting!]
 //!     - These two macros have the same features as the others,
@@ -65,12 +73,16 @@
 mod format;
 mod logger;
 
-extern crate dyn_fmt;
 extern crate proc_macro;
 extern crate proc_macro_error;
 
-use crate::{format::format_impl, logger::logger_impl};
+use crate::{
+    format::{every_impl, format_impl, log_impl, max_level_impl, once_impl, throwing_impl},
+    logger::{logger_impl, set_level_impl},
+};
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
 // use proc_macro_error::proc_macro_error;
 
 ///
@@ -134,7 +146,46 @@ use proc_macro::TokenStream;
 // #[proc_macro_error]
 #[proc_macro]
 pub fn config(msg: TokenStream) -> TokenStream {
-    format_impl("__log.config({&__fmt});\n", msg)
+    format_impl(
+        "CONFIG",
+        |log| quote!(#log.log_owned(::flogging::Level::CONFIG, __fmt);),
+        msg,
+    )
+}
+
+///
+/// Log a CONFIG message, only every `n`th time this call site is reached, or at
+/// most once per interval.
+///
+/// See [info_every!] for the details, and [config!] for the `msg` syntax/usage.
+///
+#[proc_macro]
+pub fn config_every(msg: TokenStream) -> TokenStream {
+    every_impl(
+        "config_every",
+        "CONFIG",
+        |log| quote!(#log.log_owned(::flogging::Level::CONFIG, __fmt);),
+        msg,
+    )
+}
+
+///
+/// Log a FINE message, as `debug!`. An alias for [fine!].
+///
+/// Only available with the `aliases` feature.
+///
+/// ## Parameters
+/// - `msg` - See [Special Note](index.html#special-note)
+///
+/// ## Examples
+///
+/// See [config](macro.config.html#examples). The syntax/usage is the same.
+/// Just substitute `debug!` for `config!`.
+///
+#[cfg(feature = "aliases")]
+#[proc_macro]
+pub fn debug(msg: TokenStream) -> TokenStream {
+    fine(msg)
 }
 
 ///
@@ -173,12 +224,31 @@ pub fn config(msg: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn entering(_msg: TokenStream) -> TokenStream {
     if _msg.to_string().is_empty() {
-        "__log.entering();\n".parse().unwrap_or_default()
+        max_level_impl("FINER", quote!(__log().entering();)).into()
     } else {
-        format_impl("__log.entering_with({&__fmt});\n", _msg)
+        format_impl("FINER", |log| quote!(#log.entering_with(&__fmt);), _msg)
     }
 }
 
+///
+/// Log a SEVERE message, as `error!`. An alias for [severe!].
+///
+/// Only available with the `aliases` feature.
+///
+/// ## Parameters
+/// - `msg` - See [Special Note](index.html#special-note)
+///
+/// ## Examples
+///
+/// See [config](macro.config.html#examples). The syntax/usage is the same.
+/// Just substitute `error!` for `config!`.
+///
+#[cfg(feature = "aliases")]
+#[proc_macro]
+pub fn error(msg: TokenStream) -> TokenStream {
+    severe(msg)
+}
+
 ///
 /// Log return from a function/method.
 ///
@@ -190,6 +260,9 @@ pub fn entering(_msg: TokenStream) -> TokenStream {
 ///
 /// A `LogEntry` is created with a log level of FINER, that is then logged.
 ///
+/// To log the value returned on every `return`, use [`#[logger(capture_return)]`][macro@logger]
+/// instead.
+///
 /// ## Parameters
 /// - `msg` - (Optional) See [Special Note](index.html#special-note)
 ///
@@ -229,9 +302,9 @@ pub fn entering(_msg: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn exiting(_msg: TokenStream) -> TokenStream {
     if _msg.to_string().is_empty() {
-        "__log.exiting();\n".parse().unwrap_or_default()
+        max_level_impl("FINER", quote!(__log().exiting();)).into()
     } else {
-        format_impl("__log.exiting_with({&__fmt});\n", _msg)
+        format_impl("FINER", |log| quote!(#log.exiting_with(&__fmt);), _msg)
     }
 }
 
@@ -269,7 +342,27 @@ pub fn exiting(_msg: TokenStream) -> TokenStream {
 ///
 #[proc_macro]
 pub fn fine(msg: TokenStream) -> TokenStream {
-    format_impl("__log.fine({&__fmt});\n", msg)
+    format_impl(
+        "FINE",
+        |log| quote!(#log.log_owned(::flogging::Level::FINE, __fmt);),
+        msg,
+    )
+}
+
+///
+/// Log a FINE message, only every `n`th time this call site is reached, or at
+/// most once per interval.
+///
+/// See [info_every!] for the details, and [fine!] for the `msg` syntax/usage.
+///
+#[proc_macro]
+pub fn fine_every(msg: TokenStream) -> TokenStream {
+    every_impl(
+        "fine_every",
+        "FINE",
+        |log| quote!(#log.log_owned(::flogging::Level::FINE, __fmt);),
+        msg,
+    )
 }
 
 ///
@@ -294,7 +387,27 @@ pub fn fine(msg: TokenStream) -> TokenStream {
 ///
 #[proc_macro]
 pub fn finer(msg: TokenStream) -> TokenStream {
-    format_impl("__log.finer({&__fmt});\n", msg)
+    format_impl(
+        "FINER",
+        |log| quote!(#log.log_owned(::flogging::Level::FINER, __fmt);),
+        msg,
+    )
+}
+
+///
+/// Log a FINER message, only every `n`th time this call site is reached, or at
+/// most once per interval.
+///
+/// See [info_every!] for the details, and [finer!] for the `msg` syntax/usage.
+///
+#[proc_macro]
+pub fn finer_every(msg: TokenStream) -> TokenStream {
+    every_impl(
+        "finer_every",
+        "FINER",
+        |log| quote!(#log.log_owned(::flogging::Level::FINER, __fmt);),
+        msg,
+    )
 }
 
 ///
@@ -316,12 +429,62 @@ pub fn finer(msg: TokenStream) -> TokenStream {
 ///
 #[proc_macro]
 pub fn finest(msg: TokenStream) -> TokenStream {
-    format_impl("__log.finest({&__fmt});\n", msg)
+    format_impl(
+        "FINEST",
+        |log| quote!(#log.log_owned(::flogging::Level::FINEST, __fmt);),
+        msg,
+    )
+}
+
+///
+/// Log a FINEST message, only every `n`th time this call site is reached, or at
+/// most once per interval.
+///
+/// See [info_every!] for the details, and [finest!] for the `msg` syntax/usage.
+///
+#[proc_macro]
+pub fn finest_every(msg: TokenStream) -> TokenStream {
+    every_impl(
+        "finest_every",
+        "FINEST",
+        |log| quote!(#log.log_owned(::flogging::Level::FINEST, __fmt);),
+        msg,
+    )
+}
+
+///
+/// Flush each of the handlers, after publishing any pending log entries.
+///
+/// See [`Logger::flush()`](struct.Logger.html#method.flush) for the details.
+///
+/// ## Examples
+/// ```no_run
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// const_logger!({
+///     Logger::file_logger(module_path!(), "test_logs/flush.log")
+/// });
+///
+/// #[logger]
+/// fn main() {
+///     info!("Written to the file, before main() returns.");
+///     flush!();
+/// }
+/// ```
+///
+#[proc_macro]
+pub fn flush(_msg: TokenStream) -> TokenStream {
+    quote!(__log().flush();).into()
 }
 
 ///
 /// Get the required `Handler`.
 ///
+/// Returns Some `HandlerGuard`, or None. The guard holds the module level logger,
+/// so it may be kept in a variable. Until it is dropped, this thread's log entries
+/// are deferred, and other threads wait for the logger.
+///
 /// ## Examples
 /// ```no_run
 /// extern crate flogging;
@@ -341,7 +504,9 @@ pub fn finest(msg: TokenStream) -> TokenStream {
 ///     warning!("Rain is wet!");
 ///     severe!("Hurricanes are windy!");
 ///
-///     if let Some(h) = get_handler!(Handler::String) {
+///     let handler = get_handler!(Handler::String);
+///
+///     if let Some(h) = handler {
 ///         println!(
 ///             "\n(h.get_log())\n======v======\n{}\n======^======",
 ///             h.get_log()
@@ -364,9 +529,9 @@ pub fn finest(msg: TokenStream) -> TokenStream {
 ///
 #[proc_macro]
 pub fn get_handler(handler: TokenStream) -> TokenStream {
-    format!("__log.get_handler({handler})")
-        .parse()
-        .unwrap_or_default()
+    let handler = TokenStream2::from(handler);
+
+    quote!(__log().into_handler(#handler)).into()
 }
 
 ///
@@ -396,7 +561,67 @@ pub fn get_handler(handler: TokenStream) -> TokenStream {
 ///
 #[proc_macro]
 pub fn info(msg: TokenStream) -> TokenStream {
-    format_impl("__log.info({&__fmt});\n", msg)
+    format_impl(
+        "INFO",
+        |log| quote!(#log.log_owned(::flogging::Level::INFO, __fmt);),
+        msg,
+    )
+}
+
+///
+/// Log an INFO message, only every `n`th time this call site is reached, or at
+/// most once per interval.
+///
+/// Use it for progress messages in loops, that would otherwise flood the log.
+/// The first time the call site is reached is always logged. The call site is
+/// counted, even if the logger is not enabled for INFO at the time.
+///
+/// There is one of these for each level: [finest_every!], [finer_every!], [fine_every!],
+/// [config_every!], [info_every!], [warning_every!], and [severe_every!].
+///
+/// ## Parameters
+/// - `every` - Either an integer: `n`, or a `std::time::Duration`: the interval.
+///   It is evaluated each time the call site is reached.
+/// - `msg` - See [Special Note](index.html#special-note)
+///
+/// ## Examples
+/// ```no_run
+/// extern crate flogging;
+/// use flogging::*;
+/// use std::time::Duration;
+///
+/// const_logger!({
+///     Logger::console_logger(module_path!())
+/// });
+///
+/// #[logger]
+/// fn import(records: &[&str]) {
+///     for (count, record) in records.iter().enumerate() {
+///         info_every!(1000, "processed {count} records");
+///         warning_every!(Duration::from_secs(5), "slow record: {record}");
+///     }
+/// }
+///
+/// fn main() {
+///     import(&["a"; 2500]);
+/// }
+/// ```
+/// Output:
+/// ```text
+/// flogging->import [INFO   ] processed 0 records
+/// flogging->import [WARNING] slow record: a
+/// flogging->import [INFO   ] processed 1000 records
+/// flogging->import [INFO   ] processed 2000 records
+/// ```
+///
+#[proc_macro]
+pub fn info_every(msg: TokenStream) -> TokenStream {
+    every_impl(
+        "info_every",
+        "INFO",
+        |log| quote!(#log.log_owned(::flogging::Level::INFO, __fmt);),
+        msg,
+    )
 }
 
 ///
@@ -438,7 +663,91 @@ pub fn info(msg: TokenStream) -> TokenStream {
 ///
 #[proc_macro]
 pub fn is_logging(_msg: TokenStream) -> TokenStream {
-    "__log.is_logging()".to_string().parse().unwrap_or_default()
+    quote!(__log().is_logging()).into()
+}
+
+///
+/// Log a message at a level chosen at runtime.
+///
+/// This has the same features as the other logging macros, with the level
+/// as the first parameter. Levels `ALL` and `OFF` are not logged.
+///
+/// ## Parameters
+/// - `level` - An expression of type `Level`. It is evaluated once.
+/// - `msg` - See [Special Note](index.html#special-note)
+///
+/// ## Examples
+/// ```no_run
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// const_logger!({
+///     Logger::console_logger(module_path!())
+/// });
+///
+/// #[logger]
+/// fn check_disk(free_pct: u8) {
+///     let level = if free_pct < 10 { Level::WARNING } else { Level::INFO };
+///
+///     log!(level, "Free space: {free_pct}%"; free_pct);
+/// }
+///
+/// fn main() {
+///     check_disk(5);
+/// }
+/// ```
+/// Output:
+/// ```text
+/// flogging->check_disk [WARNING] Free space: 5% free_pct=5
+/// ```
+///
+#[proc_macro]
+pub fn log(msg: TokenStream) -> TokenStream {
+    log_impl(msg)
+}
+
+///
+/// Log a message at a level chosen at runtime, only the first time this call
+/// site is reached, per process.
+///
+/// Use it for deprecation notices and configuration warnings, that would otherwise
+/// be repeated thousands of times. The call site is used up the first time it is
+/// reached, even if the logger is not enabled for `level` at the time.
+///
+/// ## Parameters
+/// - `level` - An expression of type `Level`. It is evaluated once, if at all.
+/// - `msg` - See [Special Note](index.html#special-note)
+///
+/// ## Examples
+/// ```no_run
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// const_logger!({
+///     Logger::console_logger(module_path!())
+/// });
+///
+/// #[logger]
+/// fn read_setting(name: &str) {
+///     if name == "colour" {
+///         log_once!(Level::INFO, "'colour' is deprecated. Use 'color' instead.");
+///     }
+/// }
+///
+/// fn main() {
+///     for _ in 0..1000 {
+///         read_setting("colour");
+///     }
+/// }
+/// ```
+/// Output:
+/// ```text
+/// flogging->read_setting [INFO   ] 'colour' is deprecated. Use 'color' instead.
+/// ```
+///
+#[proc_macro]
+pub fn log_once(msg: TokenStream) -> TokenStream {
+    once_impl(log_impl(msg))
 }
 
 ///
@@ -448,6 +757,10 @@ pub fn is_logging(_msg: TokenStream) -> TokenStream {
 /// It sets up the local variable used by the other macros, and it also registers the function/method
 /// name used by the log entries (if included in the formatter's `fmt_string`).
 ///
+/// The module level logger is only locked for the duration of each macro's statement. So
+/// attributed functions can call each other, and the `FLOGGING_LOGGER` created with `static_logger!()`
+/// can be used from multiple threads.
+///
 /// ```no_run
 /// #[logger]
 /// pub fn my_func(msg: &str){
@@ -458,6 +771,130 @@ pub fn is_logging(_msg: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// It can also be applied to an `impl` block, to instrument every method in it.
+/// Each method's name is then registered as `Type::method`. A method with its own
+/// `#[logger(name)]` attribute uses that `name` instead. `const` methods are left as is.
+///
+/// ```no_run
+/// #[logger]
+/// impl Counter {
+///     pub fn add(&mut self, value: u32) {
+///         self.count += value;
+///         fine!("count: {}", self.count);
+///     }
+/// }
+/// ```
+/// Output:
+/// ```text
+/// my_crate->Counter::add [FINE   ] count: 3
+/// ```
+///
+/// ## Options
+/// The attribute accepts a comma separated list of options, along with the optional name.
+/// Alongside options, the name must be a string literal, such as `#[logger("reset", trace)]`,
+/// so that a misspelt option is reported as an error, rather than taken as the name:
+/// - `capture_return` - Logs the exit at FINER, with the `Debug` value returned by the
+///   function: `Return: (Ok(42))`. This covers the tail expression, and each `return`
+///   expression, but not an early return by the `?` operator. Combined with `trace`, the
+///   plain "Return" is only logged for the latter. Combined with `timed`, the time taken
+///   is appended.
+/// - `level = "FINEST"` - Uses this level for the log entries from within the function,
+///   overriding the module logger's levels, without changing them for the rest of the
+///   module. The [compile-time maximum level](../flogging/index.html#compile-time-maximum-level)
+///   still applies.
+/// - `off` - Removes the logging macros from the function, so a hot function can opt out
+///   of logging. Any other options are ignored. Each macro becomes `if false { ... }`, so it
+///   is still type-checked, and any variables that it alone uses are not reported as unused.
+/// - `panic` - Logs "Exit (panicked)" at SEVERE, if the function panics. This shows how far
+///   execution got before the panic, as the entry is logged while the stack unwinds.
+/// - `params` - Logs the function's entry at FINER, with the `Debug` value of each named
+///   parameter: `Entry: (user: "mary", attempt: 1)`. So each of them must implement `Debug`.
+///   Combined with `trace`, this replaces its plain "Entry".
+/// - `skip(password, token)` - With `params`, logs `***` instead of the values of these
+///   parameters. Alternatively, mark each of them with the `#[redact]` attribute.
+/// - `timed` - Logs the exit at FINER, with the time taken: `Return (took 12.4ms)`.
+///   Combined with `trace`, this replaces its plain "Return".
+/// - `trace` - Logs the function's entry, as with [`entering!()`], and its exit, as with
+///   [`exiting!()`]. The exit is logged however the function returns: by falling through
+///   the end, by an early `return`, or by the `?` operator.
+///
+/// ```no_run
+/// #[logger(trace)]
+/// fn read_config(path: &str) -> io::Result<String> {
+///     let text = fs::read_to_string(path)?;
+///     config!("Read: {path}");
+///     Ok(text)
+/// }
+/// ```
+/// Output (with a missing file):
+/// ```text
+/// my_crate->read_config [FINER  ] Entry
+/// my_crate->read_config [FINER  ] Return
+/// ```
+///
+/// ```no_run
+/// #[logger(trace, panic)]
+/// fn average(values: &[u32]) -> u32 {
+///     values.iter().sum::<u32>() / values.len() as u32
+/// }
+/// ```
+/// Output (with an empty slice):
+/// ```text
+/// my_crate->average [FINER  ] Entry
+/// my_crate->average [SEVERE ] Exit (panicked)
+/// ```
+///
+/// ```no_run
+/// #[logger(timed)]
+/// fn rebuild_index(db: &mut Db) {
+///     ...
+/// }
+/// ```
+/// Output:
+/// ```text
+/// my_crate->rebuild_index [FINER  ] Return (took 12.4ms)
+/// ```
+///
+/// ```no_run
+/// #[logger(capture_return)]
+/// fn checked_div(a: u32, b: u32) -> Option<u32> {
+///     if b == 0 {
+///         return None;
+///     }
+///
+///     Some(a / b)
+/// }
+/// ```
+/// Output (with `checked_div(6, 3)`):
+/// ```text
+/// my_crate->checked_div [FINER  ] Return: (Some(2))
+/// ```
+///
+/// ```no_run
+/// #[logger(params, skip(password))]
+/// fn login(user: &str, password: &str, #[redact] token: u64) -> bool {
+///     ...
+/// }
+/// ```
+/// Output:
+/// ```text
+/// my_crate->login [FINER  ] Entry: (user: "mary", password: ***, token: ***)
+/// ```
+///
+/// ```no_run
+/// #[logger(level = "FINEST")]
+/// fn parse_header(bytes: &[u8]) -> Header {
+///     finest!("bytes: {bytes:?}");
+///     ...
+/// }
+///
+/// #[logger(off)]
+/// fn checksum(bytes: &[u8]) -> u32 {
+///     finest!("Not logged, nor even evaluated.");
+///     ...
+/// }
+/// ```
+///
 #[proc_macro_attribute]
 pub fn logger(attr: TokenStream, item: TokenStream) -> TokenStream {
     logger_impl(attr, item)
@@ -468,8 +905,13 @@ pub fn logger(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// The default level is INFO.
 ///
+/// The level may be given by name, as a string literal, such as `"fine"`. The
+/// case is ignored, and an unknown name is a compile error. For a name only
+/// known at runtime, such as from a command line flag, parse it first:
+/// `set_level!(flag.parse::<Level>()?)`.
+///
 /// ## Parameters
-/// - `level` - The required logging level.
+/// - `level` - The required logging level, or its name.
 ///
 /// ## Examples
 /// ```
@@ -483,6 +925,8 @@ pub fn logger(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// fn main(){
 ///     set_level!(Level::FINER);
+///     // or
+///     set_level!("finer");
 ///
 ///     let msg = "Just some text to work with.";
 ///
@@ -498,9 +942,7 @@ pub fn logger(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 #[proc_macro]
 pub fn set_level(level: TokenStream) -> TokenStream {
-    format!("__log.set_level({level});\n")
-        .parse()
-        .unwrap_or_default()
+    set_level_impl(level)
 }
 
 ///
@@ -527,7 +969,90 @@ pub fn set_level(level: TokenStream) -> TokenStream {
 ///
 #[proc_macro]
 pub fn severe(msg: TokenStream) -> TokenStream {
-    format_impl("__log.severe({&__fmt});\n", msg)
+    format_impl(
+        "SEVERE",
+        |log| quote!(#log.log_owned(::flogging::Level::SEVERE, __fmt);),
+        msg,
+    )
+}
+
+///
+/// Log a SEVERE message, only every `n`th time this call site is reached, or at
+/// most once per interval.
+///
+/// See [info_every!] for the details, and [severe!] for the `msg` syntax/usage.
+///
+#[proc_macro]
+pub fn severe_every(msg: TokenStream) -> TokenStream {
+    every_impl(
+        "severe_every",
+        "SEVERE",
+        |log| quote!(#log.log_owned(::flogging::Level::SEVERE, __fmt);),
+        msg,
+    )
+}
+
+///
+/// Log throwing an error.
+///
+/// This is a convenience macro that can be used to log that a function/method is returning
+/// an error. The error's message, and those of its `source()` chain, are attached to the
+/// `LogEntry`, and shown by the formatters.
+///
+/// A `LogEntry` is created with a log level of FINER and the message "Throw", that is then logged.
+///
+/// ## Parameters
+/// - `err` - An expression for the error. It must implement `std::error::Error`.
+///
+/// ## Examples
+/// ```no_run
+/// extern crate flogging;
+/// use flogging::*;
+/// use std::num::ParseIntError;
+///
+/// const_logger!({
+///     Logger::builder(module_path!())
+///         .add_console_handler()
+///         .set_level(Level::FINER)
+///         .build()
+/// });
+///
+/// #[logger]
+/// fn parse_port(text: &str) -> Result<u16, ParseIntError> {
+///     text.parse().inspect_err(|e| throwing!(e))
+/// }
+///
+/// fn main() {
+///     let _ = parse_port("http");
+/// }
+/// ```
+/// Output:
+/// ```text
+/// flogging->parse_port [FINER  ] Throw error="invalid digit found in string"
+/// ```
+///
+#[proc_macro]
+pub fn throwing(err: TokenStream) -> TokenStream {
+    throwing_impl(err)
+}
+
+///
+/// Log a FINEST message, as `trace!`. An alias for [finest!].
+///
+/// Only available with the `aliases` feature.
+///
+/// ## Parameters
+/// - `msg` - See [Special Note](index.html#special-note)
+///
+/// ## Examples
+///
+/// See [config](macro.config.html#examples). The syntax/usage is the same.
+/// Just substitute `trace!` for `config!`.
+///
+#[cfg(feature = "aliases")]
+#[proc_macro]
+pub fn trace(msg: TokenStream) -> TokenStream {
+    finest(msg)
 }
 
 ///
@@ -553,5 +1078,62 @@ pub fn severe(msg: TokenStream) -> TokenStream {
 ///
 #[proc_macro]
 pub fn warning(msg: TokenStream) -> TokenStream {
-    format_impl("__log.warning({&__fmt});\n", msg)
+    format_impl(
+        "WARNING",
+        |log| quote!(#log.log_owned(::flogging::Level::WARNING, __fmt);),
+        msg,
+    )
+}
+
+///
+/// Log a WARNING message, only every `n`th time this call site is reached, or at
+/// most once per interval.
+///
+/// See [info_every!] for the details, and [warning!] for the `msg` syntax/usage.
+///
+#[proc_macro]
+pub fn warning_every(msg: TokenStream) -> TokenStream {
+    every_impl(
+        "warning_every",
+        "WARNING",
+        |log| quote!(#log.log_owned(::flogging::Level::WARNING, __fmt);),
+        msg,
+    )
+}
+
+///
+/// Log a WARNING message, only the first time this call site is reached, per process.
+///
+/// See [log_once!] for the details, and [warning!] for the syntax/usage.
+///
+/// ## Examples
+/// ```no_run
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// const_logger!({
+///     Logger::console_logger(module_path!())
+/// });
+///
+/// #[logger]
+/// fn connect(timeout_ms: u64) {
+///     if timeout_ms == 0 {
+///         warn_once!("A timeout of 0 is not supported. Using the default.");
+///     }
+/// }
+///
+/// fn main() {
+///     for _ in 0..1000 {
+///         connect(0);
+///     }
+/// }
+/// ```
+///
+#[proc_macro]
+pub fn warn_once(msg: TokenStream) -> TokenStream {
+    once_impl(format_impl(
+        "WARNING",
+        |log| quote!(#log.log_owned(::flogging::Level::WARNING, __fmt);),
+        msg,
+    ))
 }
@@ -24,37 +24,464 @@
 //! # Logger Macro Impl
 //!
 
+use crate::format::max_level_attr;
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{ItemFn, parse_macro_input};
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2, TokenTree};
+use quote::{ToTokens, quote};
+use syn::{
+    Attribute, Block, Expr, ExprLit, FnArg, ImplItem, Item, ItemFn, ItemImpl, Lit, LitStr, Macro,
+    MetaList, MetaNameValue, Pat, ReturnType, Signature, Stmt, Token, Type,
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote,
+    punctuated::Punctuated,
+    visit_mut::{self, VisitMut},
+};
+
+///
+/// The arguments to the `#[logger]` attribute.
+///
+/// A comma separated list of options, and an optional name to register instead
+/// of the function's identifier. On its own, anything that isn't an option is taken
+/// as the name. Alongside options, the name must be a string literal, so that a
+/// misspelt option is reported, rather than taken as the name:
+/// - `capture_return` - Log the exit, with the `Debug` value returned by the function.
+/// - `level = "FINEST"` - Use this level for the log entries from within the function.
+/// - `off` - Remove the logging macros from the function, and ignore the other options.
+/// - `panic` - Log the exit, if the function panics.
+/// - `params` - Log the function's entry, with the `Debug` value of each parameter.
+/// - `skip(password, token)` - With `params`, log `***` for these parameters. So are any
+///   parameters with the `#[redact]` attribute.
+/// - `timed` - Log the exit, with the time taken: `Return (took 12.4ms)`.
+/// - `trace` - Log the function's entry, and its exit however it returns.
+///
+#[derive(Clone, Default)]
+struct LoggerArgs {
+    name: Option<String>,
+    capture_return: bool,
+    level: Option<String>,
+    off: bool,
+    panic: bool,
+    params: bool,
+    skip: Vec<Ident>,
+    timed: bool,
+    trace: bool,
+}
+
+impl LoggerArgs {
+    ///
+    /// Combine the arguments of an `impl` block, with those of one of its methods.
+    ///
+    fn merge(&self, method: LoggerArgs) -> LoggerArgs {
+        LoggerArgs {
+            name: method.name,
+            capture_return: self.capture_return || method.capture_return,
+            level: method.level.or_else(|| self.level.clone()),
+            off: self.off || method.off,
+            panic: self.panic || method.panic,
+            params: self.params || method.params,
+            skip: self.skip.iter().cloned().chain(method.skip).collect(),
+            timed: self.timed || method.timed,
+            trace: self.trace || method.trace,
+        }
+    }
+}
+
+impl Parse for LoggerArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = LoggerArgs::default();
+        let mut count = 0;
+        // A name that isn't a string literal, and so may be a misspelt option.
+        let mut bare_name = None;
+
+        while !input.is_empty() {
+            count += 1;
+
+            // Everything up to the next comma.
+            let mut tokens = TokenStream2::new();
+
+            while !input.is_empty() && !input.peek(Token![,]) {
+                tokens.extend([input.parse::<TokenTree>()?]);
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+
+            if let Ok(lit) = syn::parse2::<LitStr>(tokens.clone()) {
+                args.name = Some(lit.value());
+            } else if let Ok(ident) = syn::parse2::<Ident>(tokens.clone()) {
+                match ident.to_string().as_str() {
+                    "capture_return" => args.capture_return = true,
+                    "off" => args.off = true,
+                    "panic" => args.panic = true,
+                    "params" => args.params = true,
+                    "timed" => args.timed = true,
+                    "trace" => args.trace = true,
+                    name => {
+                        args.name = Some(name.to_string());
+                        bare_name = Some(tokens);
+                    }
+                }
+            } else if let Some(level) = syn::parse2::<MetaNameValue>(tokens.clone())
+                .ok()
+                .filter(|nv| nv.path.is_ident("level"))
+            {
+                args.level = Some(parse_level(&level.value)?);
+            } else if let Some(skip) = syn::parse2::<MetaList>(tokens.clone())
+                .ok()
+                .filter(|list| list.path.is_ident("skip"))
+            {
+                args.skip.extend(
+                    skip.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?,
+                );
+            } else {
+                args.name = Some(tokens.to_string());
+                bare_name = Some(tokens);
+            }
+        }
+
+        if let Some(tokens) = bare_name.filter(|_| count > 1) {
+            return Err(syn::Error::new_spanned(
+                &tokens,
+                format!(
+                    "unknown option: `{tokens}`. Use a string literal for the name, such as: \"{tokens}\""
+                ),
+            ));
+        }
+
+        Ok(args)
+    }
+}
+
+///
+/// Returns the name of the `Level` in `value`: a string literal, such as `"FINEST"`.
+///
+/// The aliases `"TRACE"`, `"DEBUG"`, and `"ERROR"` name the `Level` constants.
+///
+fn parse_level(value: &Expr) -> syn::Result<String> {
+    const NAMES: [&str; 12] = [
+        "ALL", "FINEST", "FINER", "FINE", "CONFIG", "INFO", "WARNING", "SEVERE", "OFF", "TRACE",
+        "DEBUG", "ERROR",
+    ];
+
+    if let Expr::Lit(ExprLit {
+        lit: Lit::Str(lit), ..
+    }) = value
+    {
+        let name = lit.value().to_uppercase();
+
+        if NAMES.contains(&name.as_str()) {
+            return Ok(name);
+        }
+    }
+
+    Err(syn::Error::new_spanned(
+        value,
+        format!(
+            "expected one of: {}",
+            NAMES.map(|n| format!("\"{n}\"")).join(", ")
+        ),
+    ))
+}
+
+///
+/// Generate the code for `set_level!(level)`.
+///
+/// A string literal, such as `"fine"`, is checked against the level names here,
+/// at compile time. Any other expression is passed to `set_level()` as is.
+///
+pub(crate) fn set_level_impl(level: TokenStream) -> TokenStream {
+    let level = parse_macro_input!(level as Expr);
+
+    let level = match level {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(_), ..
+        }) => match parse_level(&level) {
+            Ok(name) => {
+                let name = Ident::new(&name, Span::call_site());
+                quote!(::flogging::Level::#name)
+            }
+            Err(e) => return e.to_compile_error().into(),
+        },
+        level => level.to_token_stream(),
+    };
+
+    quote!(__log().set_level(#level);).into()
+}
 
 pub(crate) fn logger_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
-    // println!("attr: (is_empty: {}) {attr}", attr.to_string().is_empty());
-
-    // Parse the input as `ItemFn` which is a type provided
-    // by `syn` to represent a function.
-    let input = parse_macro_input!(item as ItemFn);
-
-    let ItemFn {
-        // The function signature
-        sig,
-        // The visibility specifier of this function
-        vis,
-        // The function block or body
-        block,
-        // Other attributes applied to this function
-        attrs,
-    } = input;
+    let args = parse_macro_input!(attr as LoggerArgs);
 
-    // Extract statements in the body of the functions
-    let statements = block.stmts;
+    // Parse the input as an `Item`, which is a type provided
+    // by `syn` to represent any item: a function, `impl` block, etc.
+    let input = parse_macro_input!(item as Item);
+
+    match input {
+        Item::Fn(ItemFn {
+            // Other attributes applied to this function
+            attrs,
+            // The visibility specifier of this function
+            vis,
+            // The function signature
+            sig,
+            // The function block or body
+            block,
+        }) => {
+            if let Err(e) = check_skip(&sig, &args.skip) {
+                return e.to_compile_error().into();
+            }
+
+            // Store the function identifier for logging
+            let function_identifier = args.name.clone().unwrap_or_else(|| sig.ident.to_string());
+
+            instrument(
+                &attrs,
+                vis.to_token_stream(),
+                &sig,
+                &block,
+                &function_identifier,
+                &args,
+            )
+            .into()
+        }
+        Item::Impl(item_impl) => logger_impl_block(&args, item_impl).into(),
+        _ => quote!(::std::compile_error!(
+            "`#[logger]` may only be applied to a function, method, or `impl` block"
+        );)
+        .into(),
+    }
+}
 
-    // Store the function identifier for logging
-    let function_identifier = if attr.to_string().is_empty() {
-        sig.ident.clone().to_string()
+///
+/// Instrument each method of an `impl` block, with the `fn_name`: `Type::method`.
+///
+/// `Type` is the name in `args`, if supplied. Methods with their own `#[logger]`
+/// attribute keep any name supplied by it, and add its options to those of the block.
+/// `const` methods are left as is.
+///
+fn logger_impl_block(args: &LoggerArgs, mut item_impl: ItemImpl) -> TokenStream2 {
+    let prefix = args
+        .name
+        .clone()
+        .unwrap_or_else(|| type_name(&item_impl.self_ty));
+
+    for item in item_impl.items.iter_mut() {
+        let ImplItem::Fn(method) = item else {
+            continue;
+        };
+
+        if method.sig.constness.is_some() {
+            continue;
+        }
+
+        let mut method_args = LoggerArgs::default();
+
+        // Remove any `#[logger]` attribute, to avoid instrumenting the method twice.
+        let mut errors = Vec::new();
+
+        method.attrs.retain(|attr| {
+            if !is_logger(attr) {
+                return true;
+            }
+
+            if attr.meta.require_list().is_ok() {
+                match attr
+                    .parse_args::<LoggerArgs>()
+                    .and_then(|parsed| check_skip(&method.sig, &parsed.skip).map(|_| parsed))
+                {
+                    Ok(parsed) => method_args = parsed,
+                    Err(e) => errors.push(e.to_compile_error()),
+                }
+            }
+
+            false
+        });
+
+        if !errors.is_empty() {
+            return quote!(#(#errors)*);
+        }
+
+        let method_args = args.merge(method_args);
+        let name = method_args
+            .name
+            .clone()
+            .unwrap_or_else(|| format!("{prefix}::{}", method.sig.ident));
+
+        let vis = &method.vis;
+        let defaultness = &method.defaultness;
+        let code = instrument(
+            &method.attrs,
+            quote!(#vis #defaultness),
+            &method.sig,
+            &method.block,
+            &name,
+            &method_args,
+        );
+
+        *item = ImplItem::Verbatim(code);
+    }
+
+    item_impl.into_token_stream()
+}
+
+///
+/// Reconstruct the function, with the local `__log()` closure used by the other macros.
+///
+fn instrument(
+    attrs: &[Attribute],
+    vis: TokenStream2,
+    sig: &Signature,
+    block: &Block,
+    function_identifier: &str,
+    args: &LoggerArgs,
+) -> TokenStream2 {
+    let mut sig = sig.clone();
+    let mut block = block.clone();
+    let off_args;
+
+    // The parameters to log, and whether or not each is redacted.
+    let params = params(&mut sig, &args.skip);
+
+    // Remove the logging macros, and any other options.
+    let args = if args.off {
+        StripMacros.visit_block_mut(&mut block);
+        off_args = LoggerArgs {
+            level: Some("OFF".to_string()),
+            ..LoggerArgs::default()
+        };
+        &off_args
+    } else {
+        args
+    };
+
+    // The level for the log entries from within the function, if not the logger's.
+    let fn_level = match &args.level {
+        Some(level) => {
+            let level = Ident::new(level, Span::call_site());
+
+            quote!(::std::option::Option::Some(::flogging::Level::#level))
+        }
+        None => quote!(::std::option::Option::None),
+    };
+
+    // The statement to log at `level`, if it is enabled by `option`.
+    let log_stmt = |option: bool, level: &str, stmt: TokenStream2| {
+        max_level_attr(level)
+            .filter(|_| option)
+            .map(|attr| quote!(#attr #stmt))
+    };
+
+    let entry = if args.params {
+        let fmt = params
+            .iter()
+            .map(|(param, redacted)| {
+                if *redacted {
+                    format!("{param}: ***")
+                } else {
+                    format!("{param}: {{:?}}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let values = params
+            .iter()
+            .filter(|(_, redacted)| !redacted)
+            .map(|(param, _)| param);
+
+        log_stmt(
+            true,
+            "FINER",
+            quote!(if __log().is_enabled(::flogging::Level::FINER) {
+                let __fmt = ::std::format!(#fmt, #(#values),*);
+                __log().entering_with(&__fmt);
+            }),
+        )
+    } else {
+        log_stmt(args.trace, "FINER", quote!(__log().entering();))
+    };
+    let start = log_stmt(
+        args.timed,
+        "FINER",
+        quote!(let __start = ::std::time::Instant::now();),
+    );
+    let exit = if args.timed {
+        log_stmt(
+            true,
+            "FINER",
+            quote!(if __log().is_enabled(::flogging::Level::FINER) {
+                let __took = ::std::format!("Return (took {:.1?})", __start.elapsed());
+                __log().log_owned(::flogging::Level::FINER, __took);
+            }),
+        )
     } else {
-        attr.to_string()
+        log_stmt(args.trace, "FINER", quote!(__log().exiting();))
     };
+    let panicked = log_stmt(
+        args.panic,
+        "SEVERE",
+        quote!(__log().severe("Exit (panicked)");),
+    );
+
+    // Log the value returned by the tail expression, and each `return` expression.
+    let (exit, captured) = match max_level_attr("FINER").filter(|_| args.capture_return) {
+        Some(attr) => {
+            let (fmt, took) = match args.timed {
+                true => ("Return: ({:?}) (took {:.1?})", quote!(, __start.elapsed())),
+                false => ("Return: ({:?})", TokenStream2::new()),
+            };
+
+            // Stop the guard from logging the exit again.
+            let (exit, captured, set_captured) = match exit {
+                Some(exit) => (
+                    Some(quote!(if !__captured.get() { #exit })),
+                    quote!(let __captured = &::std::cell::Cell::new(false);),
+                    quote!(__captured.set(true);),
+                ),
+                None => (None, TokenStream2::new(), TokenStream2::new()),
+            };
+
+            let ty = match &sig.output {
+                ReturnType::Type(_, ty) if !matches!(ty.as_ref(), Type::ImplTrait(_)) => {
+                    quote!(: #ty)
+                }
+                _ => TokenStream2::new(),
+            };
+
+            CaptureReturn {
+                capture: quote!(
+                    #attr
+                    if __log().is_enabled(::flogging::Level::FINER) {
+                        let __fmt = ::std::format!(#fmt, &__rtn #took);
+                        __log().log_owned(::flogging::Level::FINER, __fmt);
+                    }
+                    #set_captured
+                ),
+                ty,
+            }
+            .capture_block(&mut block);
+
+            (exit, captured)
+        }
+        None => (exit, TokenStream2::new()),
+    };
+
+    // Log the entry now, and the exit when `__exit` is dropped.
+    let guard = if exit.is_some() || panicked.is_some() {
+        quote!(
+            let __exit = ::flogging::ExitGuard::new(move || {
+                if ::std::thread::panicking() {
+                    #panicked
+                } else {
+                    #exit
+                }
+            });
+        )
+    } else {
+        TokenStream2::new()
+    };
+
+    // Extract statements in the body of the functions
+    let statements = &block.stmts;
 
     // Reconstruct the function as output using parsed input
     quote!(
@@ -65,13 +492,301 @@ pub(crate) fn logger_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
         // Reconstruct the function declaration
         #vis #sig {
             // At the beginning of the function, borrow a reference to
-            // module level static logger.
-            let __binding = LOGGER;
-            let mut __log = __binding.borrow_mut();
-            __log.set_fn_name(#function_identifier);
+            // module level logger. Each of the logging macros then
+            // locks it, just for the duration of their own statement,
+            // within a scope carrying this function's name and level.
+            let __binding = &FLOGGING_LOGGER;
+            let __log = move || {
+                ::flogging::Scope::new(
+                    ::flogging::LockLogger::lock_logger(__binding),
+                    #function_identifier,
+                )
+                .set_fn_level(#fn_level)
+            };
+
+            #entry
+            #start
+            #captured
+            #guard
 
             #(#statements)*
         }
     )
-    .into()
+}
+
+///
+/// Returns the named parameters of `sig`, each with whether or not it is redacted:
+/// named in `skip`, or with the `#[redact]` attribute. That attribute is removed.
+///
+fn params(sig: &mut Signature, skip: &[Ident]) -> Vec<(Ident, bool)> {
+    let mut params = Vec::new();
+
+    for input in sig.inputs.iter_mut() {
+        let FnArg::Typed(pat_type) = input else {
+            continue;
+        };
+
+        let attrs = pat_type.attrs.len();
+        pat_type
+            .attrs
+            .retain(|attr| !attr.path().is_ident("redact"));
+        let redact = pat_type.attrs.len() != attrs;
+
+        if let Pat::Ident(pat_ident) = pat_type.pat.as_ref() {
+            let ident = pat_ident.ident.clone();
+            let redacted = redact || skip.contains(&ident);
+
+            params.push((ident, redacted));
+        }
+    }
+
+    params
+}
+
+///
+/// Check that each parameter named in `skip`, is a parameter of `sig`.
+///
+fn check_skip(sig: &Signature, skip: &[Ident]) -> syn::Result<()> {
+    let named = |ident: &Ident| {
+        sig.inputs.iter().any(|input| match input {
+            FnArg::Typed(pat_type) => {
+                matches!(pat_type.pat.as_ref(), Pat::Ident(pat_ident) if pat_ident.ident == *ident)
+            }
+            FnArg::Receiver(_) => false,
+        })
+    };
+
+    match skip.iter().find(|ident| !named(ident)) {
+        Some(ident) => Err(syn::Error::new_spanned(
+            ident,
+            format!("no parameter named `{ident}`"),
+        )),
+        None => Ok(()),
+    }
+}
+
+///
+/// Logs the value returned by a function, by rewriting its tail expression and each
+/// `return` expression, as: `{ let __rtn: Type = expr; #capture __rtn }`.
+///
+/// Closures, `async` blocks, and nested items are left as is, as their `return`
+/// expressions are not those of the function.
+///
+struct CaptureReturn {
+    capture: TokenStream2,
+    ty: TokenStream2,
+}
+
+impl CaptureReturn {
+    ///
+    /// Rewrite the tail expression, and each `return` expression, of `block`.
+    ///
+    fn capture_block(&mut self, block: &mut Block) {
+        self.visit_block_mut(block);
+
+        if let Some(Stmt::Expr(tail, None)) = block.stmts.last_mut() {
+            if !matches!(tail, Expr::Return(_)) {
+                *tail = self.capture(tail.clone());
+            }
+        }
+    }
+
+    ///
+    /// Returns the block that captures `value`.
+    ///
+    fn capture(&self, value: Expr) -> Expr {
+        let CaptureReturn { capture, ty } = self;
+
+        parse_quote!({
+            let __rtn #ty = #value;
+            #capture
+            __rtn
+        })
+    }
+}
+
+impl VisitMut for CaptureReturn {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Async(_) | Expr::Closure(_) => {}
+            Expr::Return(ret) => {
+                let mut value = ret.expr.take().map_or_else(|| parse_quote!(()), |e| *e);
+
+                self.visit_expr_mut(&mut value);
+                ret.expr = Some(Box::new(self.capture(value)));
+            }
+            _ => visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+
+    fn visit_item_mut(&mut self, _item: &mut Item) {}
+}
+
+///
+/// The logging macros removed by `#[logger(off)]`.
+///
+/// Every macro exported by this crate is either one of these, `is_logging`, or one of
+/// the macros that don't log. The `strip_macros` test checks this.
+///
+const LOG_MACROS: [&str; 23] = [
+    "config",
+    "config_every",
+    "debug",
+    "entering",
+    "error",
+    "exiting",
+    "fine",
+    "fine_every",
+    "finer",
+    "finer_every",
+    "finest",
+    "finest_every",
+    "info",
+    "info_every",
+    "log",
+    "log_once",
+    "severe",
+    "severe_every",
+    "throwing",
+    "trace",
+    "warn_once",
+    "warning",
+    "warning_every",
+];
+
+///
+/// Replaces the logging macros with `if false { .. }`, and `is_logging!()` with `false`.
+///
+/// The logging macros are kept inside the `if false`, so that they are still type-checked,
+/// and any variables that they alone use are not reported as unused.
+///
+struct StripMacros;
+
+impl StripMacros {
+    ///
+    /// Returns the replacement for `mac`, if it is one of the logging macros.
+    ///
+    fn replacement(mac: &Macro) -> Option<Expr> {
+        let segments = &mac.path.segments;
+
+        if segments.len() > 2 || (segments.len() == 2 && segments[0].ident != "flogging") {
+            return None;
+        }
+
+        let name = segments.last()?.ident.to_string();
+
+        if name == "is_logging" {
+            Some(parse_quote!(false))
+        } else if LOG_MACROS.contains(&name.as_str()) {
+            Some(parse_quote!(if false { #mac; }))
+        } else {
+            None
+        }
+    }
+}
+
+impl VisitMut for StripMacros {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        let replacement = match expr {
+            Expr::Macro(expr_macro) => Self::replacement(&expr_macro.mac),
+            _ => None,
+        };
+
+        match replacement {
+            Some(replacement) => *expr = replacement,
+            None => visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+
+    fn visit_stmt_mut(&mut self, stmt: &mut Stmt) {
+        let replacement = match stmt {
+            Stmt::Macro(stmt_macro) => Self::replacement(&stmt_macro.mac)
+                .map(|replacement| Stmt::Expr(replacement, stmt_macro.semi_token)),
+            _ => None,
+        };
+
+        match replacement {
+            Some(replacement) => *stmt = replacement,
+            None => visit_mut::visit_stmt_mut(self, stmt),
+        }
+    }
+}
+
+///
+/// Returns `true` if `attr` is `#[logger]`, or `#[flogging::logger]`, etc.
+///
+fn is_logger(attr: &Attribute) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "logger")
+}
+
+///
+/// Returns the name of the type: the last segment of its path, without any generics.
+///
+fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map_or_else(|| ty.to_token_stream().to_string(), |s| s.ident.to_string()),
+        _ => ty.to_token_stream().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn logger_args() {
+        let args = syn::parse_str::<LoggerArgs>("reset_counter").unwrap();
+        assert_eq!(args.name.as_deref(), Some("reset_counter"));
+
+        let args = syn::parse_str::<LoggerArgs>("\"reset_counter\", trace").unwrap();
+        assert_eq!(args.name.as_deref(), Some("reset_counter"));
+        assert!(args.trace);
+
+        let err = syn::parse_str::<LoggerArgs>("timd, trace").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "unknown option: `timd`. Use a string literal for the name, such as: \"timd\""
+        );
+    }
+
+    #[test]
+    fn strip_macros() {
+        // The exported macros that don't log, so are left in place.
+        const KEPT: [&str; 4] = ["flush", "get_handler", "is_logging", "set_level"];
+
+        let source = include_str!("lib.rs");
+        let mut exported: Vec<&str> = source
+            .split("#[proc_macro]")
+            .skip(1)
+            .filter_map(|item| item.trim_start().strip_prefix("pub fn "))
+            .filter_map(|item| item.split('(').next())
+            .filter(|name| !KEPT.contains(name))
+            .collect();
+        exported.sort();
+
+        assert_eq!(exported, LOG_MACROS);
+
+        let mut block: Block = parse_quote!({
+            info!("{value}");
+            let logging = is_logging!();
+            flush!();
+        });
+        StripMacros.visit_block_mut(&mut block);
+
+        let expected: Block = parse_quote!({
+            if false {
+                info!("{value}");
+            };
+            let logging = false;
+            flush!();
+        });
+        assert_eq!(block, expected);
+    }
 }
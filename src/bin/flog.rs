@@ -0,0 +1,428 @@
+//
+// File Name:    flog.rs
+// Directory:    src/bin
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # flog
+//!
+//! Views, filters, and converts the log files written by the `FileHandler`,
+//! and the `BinaryHandler`.
+//!
+//! The lines of a text log are read with [`Iso8601Formatter::parse()`], or failing
+//! that, [`SimpleFormatter::parse()`]. Any line that is neither, such as the rest
+//! of a message spanning several lines, goes with the log entry before it.
+//!
+
+use flogging::*;
+use std::{
+    collections::VecDeque,
+    env,
+    fs::File,
+    io::{self, BufRead, BufReader, Seek, SeekFrom, Write},
+    process,
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+
+const USAGE: &str = "\
+Usage: flog [OPTIONS] [FILE]
+
+Views, filters, and converts the log files written by flogging's FileHandler,
+and BinaryHandler. Reads FILE, or if none, or '-', stdin.
+
+Options:
+  -f, --follow         Keep reading FILE, as it grows
+  -n, --lines <N>      Show only the last N log entries, before any that follow
+  -l, --level <LEVEL>  Show only those at, or above, LEVEL
+  -m, --module <PATH>  Show only those whose module path starts with PATH
+      --fn <NAME>      Show only those from the function/method NAME
+      --to <FORMAT>    Convert to FORMAT: iso8601, simple, json, ndjson, or unix.
+                       Defaults to that of the log
+      --color <WHEN>   Color by level: auto (default), always, or never
+  -h, --help           Print this help
+";
+
+///
+/// How often a followed file is checked for more.
+///
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Default, PartialEq)]
+struct Options {
+    follow: bool,
+    lines: Option<usize>,
+    level: Option<Level>,
+    module: Option<String>,
+    fn_name: Option<String>,
+    to: Option<FormatType>,
+    color: ColorChoice,
+    file: Option<String>,
+}
+
+impl Options {
+    ///
+    /// Returns `true` if `log_entry` passes the filters.
+    ///
+    fn shows(&self, log_entry: &LogEntry) -> bool {
+        self.level.is_none_or(|level| log_entry.level() >= level)
+            && self
+                .module
+                .as_deref()
+                .is_none_or(|module| log_entry.mod_path().starts_with(module))
+            && self
+                .fn_name
+                .as_deref()
+                .is_none_or(|fn_name| log_entry.fn_name() == fn_name)
+    }
+}
+
+///
+/// Parse the command line arguments.
+///
+/// Returns `None` if help was asked for.
+///
+fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Option<Options>, String> {
+    let mut options = Options::default();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+
+        let mut value = || {
+            inline
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("'{name}' needs a value"))
+        };
+
+        match name {
+            "-f" | "--follow" => options.follow = true,
+            "-n" | "--lines" => {
+                let v = value()?;
+                options.lines = Some(
+                    v.parse::<usize>()
+                        .map_err(|_| format!("invalid number: {v}"))?,
+                );
+            }
+            "-l" | "--level" => {
+                options.level = Some(value()?.parse::<Level>().map_err(|e| e.to_string())?);
+            }
+            "-m" | "--module" => options.module = Some(value()?),
+            "--fn" => options.fn_name = Some(value()?),
+            "--to" => options.to = Some(format_type(&value()?)?),
+            "--color" => {
+                options.color = match value()?.as_str() {
+                    "auto" => ColorChoice::Auto,
+                    "always" => ColorChoice::Always,
+                    "never" => ColorChoice::Never,
+                    v => return Err(format!("invalid color choice: {v}")),
+                }
+            }
+            "-h" | "--help" => return Ok(None),
+            "-" => options.file = None,
+            _ if name.starts_with('-') => return Err(format!("unknown option: {name}")),
+            _ if options.file.is_some() => return Err("only one FILE may be given".to_string()),
+            _ => options.file = Some(arg.clone()),
+        }
+    }
+
+    Ok(Some(options))
+}
+
+///
+/// Returns the output format named `name`.
+///
+fn format_type(name: &str) -> Result<FormatType, String> {
+    match name {
+        "iso8601" => Ok(FormatType::Iso8601),
+        "simple" => Ok(FormatType::Simple),
+        "json" => Ok(FormatType::Json),
+        "ndjson" => Ok(FormatType::Ndjson),
+        "unix" => Ok(FormatType::UnixTimestamp),
+        _ => Err(format!("invalid format: {name}")),
+    }
+}
+
+///
+/// Write `line` to `stdout`. If it has gone, such as when piped into `head`, then
+/// there is nothing more to do.
+///
+fn write_line(line: &str) {
+    if writeln!(io::stdout().lock(), "{line}").is_err() {
+        process::exit(0);
+    }
+}
+
+///
+/// Publishes the log entries that pass the filters, with the lines that go with them,
+/// holding back all but the last `--lines` of them until [`release()`][Output::release].
+///
+struct Output<'a> {
+    options: &'a Options,
+    handler: ConsoleHandler,
+    formatted: bool,
+    tail: Option<VecDeque<(LogEntry, Vec<String>)>>,
+    shown: bool,
+}
+
+impl<'a> Output<'a> {
+    fn new(options: &'a Options) -> Result<Self, io::Error> {
+        let mut handler = ConsoleHandler::create(ConsoleType::StdOut.as_str())?;
+        handler.set_color(options.color);
+        handler.set_printer(Some(Arc::new(write_line)));
+
+        Ok(Output {
+            options,
+            handler,
+            formatted: false,
+            tail: options.lines.map(|_| VecDeque::new()),
+            shown: false,
+        })
+    }
+
+    ///
+    /// Add a log entry, read from a log in `format`.
+    ///
+    fn entry(&mut self, log_entry: LogEntry, format: FormatType) {
+        if !self.formatted {
+            let to = self.options.to.clone().unwrap_or(format);
+            self.handler.set_formatter(to.create(None));
+            self.formatted = true;
+        }
+
+        self.shown = self.options.shows(&log_entry);
+
+        if !self.shown {
+            return;
+        }
+
+        match (&mut self.tail, self.options.lines) {
+            (Some(_), Some(0)) => self.shown = false,
+            (Some(tail), Some(n)) => {
+                tail.push_back((log_entry, Vec::new()));
+
+                if tail.len() > n {
+                    tail.pop_front();
+                }
+            }
+            _ => self.handler.publish(&log_entry),
+        }
+    }
+
+    ///
+    /// Add a line that is not a log entry, going with the log entry before it.
+    ///
+    fn line(&mut self, line: &str) {
+        if !self.shown {
+            return;
+        }
+
+        match &mut self.tail {
+            Some(tail) => {
+                if let Some((_, lines)) = tail.back_mut() {
+                    lines.push(line.to_string());
+                }
+            }
+            None => write_line(line),
+        }
+    }
+
+    ///
+    /// Publish the log entries held back, and stop holding them back.
+    ///
+    fn release(&mut self) {
+        for (log_entry, lines) in self.tail.take().unwrap_or_default() {
+            self.handler.publish(&log_entry);
+
+            for line in lines {
+                write_line(&line);
+            }
+        }
+    }
+}
+
+///
+/// Read the text log from `reader`, line by line.
+///
+/// ## Parameters
+/// - `reader` - The source of the log.
+/// - `output` - Where the log entries go.
+/// - `at_end` - Called at the end of the input. Returns `true` to read on.
+///
+fn read_text<R, F>(mut reader: R, output: &mut Output<'_>, mut at_end: F) -> Result<(), io::Error>
+where
+    R: BufRead,
+    F: FnMut(&mut R) -> Result<bool, io::Error>,
+{
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+
+        if reader.read_line(&mut line)? == 0 {
+            output.release();
+
+            if at_end(&mut reader)? {
+                continue;
+            }
+
+            return Ok(());
+        }
+
+        let text = line.trim_end_matches(['\n', '\r']);
+
+        if let Some(le) = Iso8601Formatter::parse(text) {
+            output.entry(le, FormatType::Iso8601);
+        } else if let Some(le) = SimpleFormatter::parse(text) {
+            output.entry(le, FormatType::Simple);
+        } else {
+            output.line(text);
+        }
+    }
+}
+
+///
+/// Read the log, given by `options`, to the end, or if following, forever.
+///
+fn run(options: &Options) -> Result<(), io::Error> {
+    let mut output = Output::new(options)?;
+
+    let Some(path) = &options.file else {
+        let mut stdin = io::stdin().lock();
+
+        if stdin.fill_buf()?.starts_with(&BINARY_MAGIC) {
+            return read_binary(stdin, &mut output);
+        }
+
+        return read_text(stdin, &mut output, |_| Ok(false));
+    };
+
+    let mut reader = BufReader::new(File::open(path)?);
+
+    if reader.fill_buf()?.starts_with(&BINARY_MAGIC) {
+        return read_binary(reader, &mut output);
+    }
+
+    read_text(reader, &mut output, |reader| {
+        if !options.follow {
+            return Ok(false);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+
+        // Truncated, so start again from the top.
+        if reader.get_ref().metadata()?.len() < reader.stream_position()? {
+            reader.seek(SeekFrom::Start(0))?;
+        }
+
+        Ok(true)
+    })
+}
+
+///
+/// Read the binary log from `reader`, to the end.
+///
+fn read_binary<R: io::Read>(reader: R, output: &mut Output<'_>) -> Result<(), io::Error> {
+    for log_entry in LogReader::new(reader)? {
+        output.entry(log_entry?, FormatType::Iso8601);
+    }
+
+    output.release();
+    Ok(())
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            print!("{USAGE}");
+            return;
+        }
+        Err(e) => {
+            eprintln!("flog: {e}\n\n{USAGE}");
+            process::exit(2);
+        }
+    };
+
+    if let Err(e) = run(&options) {
+        eprintln!("flog: {e}");
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Result<Option<Options>, String> {
+        parse_args(line.split_whitespace().map(str::to_string))
+    }
+
+    #[test]
+    fn options() {
+        let options = args("-f -n 20 --level=warning -m app::db --fn connect --to json app.log")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            options,
+            Options {
+                follow: true,
+                lines: Some(20),
+                level: Some(Level::WARNING),
+                module: Some("app::db".to_string()),
+                fn_name: Some("connect".to_string()),
+                to: Some(FormatType::Json),
+                color: ColorChoice::Auto,
+                file: Some("app.log".to_string()),
+            }
+        );
+
+        assert_eq!(args("--color never -h").unwrap(), None);
+        assert_eq!(args("-").unwrap(), Some(Options::default()));
+        assert!(args("--lines").is_err());
+        assert!(args("--to xml").is_err());
+        assert!(args("--verbose").is_err());
+        assert!(args("a.log b.log").is_err());
+    }
+
+    #[test]
+    fn shows() {
+        let options = args("-l INFO -m app --fn main").unwrap().unwrap();
+        let entry = |level, mod_path, fn_name| {
+            LogEntry::builder()
+                .level(level)
+                .mod_path(mod_path)
+                .fn_name(fn_name)
+                .build()
+        };
+
+        assert!(options.shows(&entry(Level::SEVERE, "app::db", "main")));
+        assert!(!options.shows(&entry(Level::FINE, "app::db", "main")));
+        assert!(!options.shows(&entry(Level::INFO, "other", "main")));
+        assert!(!options.shows(&entry(Level::INFO, "app", "run")));
+    }
+}
@@ -0,0 +1,212 @@
+//
+// File Name:    bridge.rs
+// Directory:    src
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Bridge
+//!
+//! Routes the records logged through the [`log`] crate facade, typically by
+//! third-party crates, into a flogging [`Logger`].
+//!
+//! Requires the `log` feature:
+//! ```text
+//! [dependencies]
+//! flogging = { version = "0.6.0", features = ["log"] }
+//! ```
+//!
+//! The `log` levels are mapped to these [`Level`]s:
+//!
+//! | `log::Level` | `Level`   |
+//! |--------------|-----------|
+//! | `Error`      | `SEVERE`  |
+//! | `Warn`       | `WARNING` |
+//! | `Info`       | `INFO`    |
+//! | `Debug`      | `FINE`    |
+//! | `Trace`      | `FINEST`  |
+//!
+
+use crate::{Level, Logger};
+use log::{LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+///
+/// Install `logger` as the global `log` facade logger.
+///
+/// Every record logged via the `log` macros (`error!`, `warn!`, `info!`, `debug!`,
+/// and `trace!`) is then published by `logger`'s handlers. The module path of each
+/// log entry is that of the source of the record.
+///
+/// The `log` crate's maximum level is set in accordance with `logger`'s level, and
+/// any per-module levels (see [`Logger::set_module_level()`]).
+///
+/// ## Parameters
+/// - `logger` - The configured `Logger` to receive the records.
+///
+/// Returns an error if a `log` facade logger has already been installed.
+///
+/// ## Examples
+/// ```
+/// use flogging::*;
+///
+/// let logger = Logger::builder("deps")
+///     .add_console_handler()
+///     .set_level(Level::FINE)
+///     .build();
+///
+/// bridge::init_log_facade(logger).expect("already initialized");
+///
+/// log::debug!("This is now published by flogging.");
+/// ```
+/// Output:
+/// ```text
+/// rust_out-> [FINE   ] This is now published by flogging.
+/// ```
+///
+pub fn init_log_facade(logger: Logger) -> Result<(), SetLoggerError> {
+    let level = match logger.level_map().most_verbose() {
+        Some(level) if level < logger.level() => level,
+        _ => logger.level(),
+    };
+    let max_level = level_filter(&level);
+
+    log::set_boxed_logger(Box::new(LogBridge { logger }))?;
+    log::set_max_level(max_level);
+
+    Ok(())
+}
+
+///
+/// The `log::Log` implementation installed by [`init_log_facade()`].
+///
+/// The `Logger` is not behind a lock of its own. So a record logged by one of its
+/// handlers, while publishing, is queued by the logger, rather than deadlocking.
+///
+struct LogBridge {
+    logger: Logger,
+}
+
+impl Log for LogBridge {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        let level = Level::from(metadata.level());
+
+        self.logger.is_loggable_from(metadata.target(), &level)
+    }
+
+    fn log(&self, record: &Record) {
+        let level = Level::from(record.level());
+        let mod_path = record.module_path().unwrap_or(record.target());
+        let msg = record.args().to_string();
+        let location = record.file().zip(record.line());
+
+        self.logger.log_from(level, mod_path, &msg, location);
+    }
+
+    fn flush(&self) {
+        self.logger.flush();
+    }
+}
+
+impl From<log::Level> for Level {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Error => Level::SEVERE,
+            log::Level::Warn => Level::WARNING,
+            log::Level::Info => Level::INFO,
+            log::Level::Debug => Level::FINE,
+            log::Level::Trace => Level::FINEST,
+        }
+    }
+}
+
+///
+/// Find the most verbose `log` level that would be accepted at `level`.
+///
+fn level_filter(level: &Level) -> LevelFilter {
+    match level {
+        Level::ALL | Level::FINEST | Level::FINER => LevelFilter::Trace,
+        Level::FINE => LevelFilter::Debug,
+        Level::CONFIG | Level::INFO => LevelFilter::Info,
+        Level::WARNING => LevelFilter::Warn,
+        Level::SEVERE => LevelFilter::Error,
+        Level::OFF => LevelFilter::Off,
+        Level::Custom { .. } => level_filter(&level.base()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn levels() {
+        assert_eq!(Level::from(log::Level::Error), Level::SEVERE);
+        assert_eq!(Level::from(log::Level::Warn), Level::WARNING);
+        assert_eq!(Level::from(log::Level::Info), Level::INFO);
+        assert_eq!(Level::from(log::Level::Debug), Level::FINE);
+        assert_eq!(Level::from(log::Level::Trace), Level::FINEST);
+
+        assert_eq!(level_filter(&Level::INFO), LevelFilter::Info);
+        assert_eq!(level_filter(&Level::CONFIG), LevelFilter::Info);
+        assert_eq!(level_filter(&Level::FINER), LevelFilter::Trace);
+        assert_eq!(level_filter(&Level::OFF), LevelFilter::Off);
+    }
+
+    #[test]
+    fn bridge() {
+        let bridge = LogBridge {
+            logger: Logger::builder(module_path!())
+                .add_string_handler()
+                .set_level(Level::FINE)
+                .build(),
+        };
+
+        let metadata = Metadata::builder()
+            .level(log::Level::Trace)
+            .target("other_crate")
+            .build();
+        assert!(!bridge.enabled(&metadata));
+
+        bridge.log(
+            &Record::builder()
+                .args(format_args!("Dependency says hello."))
+                .level(log::Level::Warn)
+                .target("other_crate")
+                .module_path(Some("other_crate::net"))
+                .build(),
+        );
+        bridge.log(
+            &Record::builder()
+                .args(format_args!("Too much detail."))
+                .level(log::Level::Trace)
+                .target("other_crate")
+                .build(),
+        );
+
+        let mut logger = bridge.logger;
+        let buf = logger.get_handler(Handler::String).unwrap().get_log();
+
+        assert_eq!(
+            buf,
+            "other_crate::net-> [WARNING] Dependency says hello.\n".to_string()
+        );
+    }
+}
@@ -0,0 +1,301 @@
+//
+// File Name:    context.rs
+// Directory:    src
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Context
+//!
+//! A nested diagnostic context (NDC): a per-thread stack of labels, that are
+//! included in every log entry created on that thread while they are in scope.
+//!
+//! A label is pushed with [`push()`], and popped when the returned [`ContextGuard`]
+//! is dropped. The formatters show the labels joined with `/`, as: `[batch-42/item-7]`.
+//! See: [`FormatTrait::ft_fmt()`][crate::FormatTrait::ft_fmt].
+//!
+//! ## Examples
+//! ```
+//! use flogging::*;
+//!
+//! let mut log = Logger::string_logger("app");
+//! log.set_fn_name("main");
+//!
+//! {
+//!     let _batch = context::push("batch-42");
+//!
+//!     for item in 6..8 {
+//!         let _item = context::push(format!("item-{item}"));
+//!         log.info("Processed.");
+//!     }
+//! }
+//!
+//! log.info("Done.");
+//!
+//! let h = log.get_handler(Handler::String).unwrap();
+//! assert_eq!(
+//!     h.get_log(),
+//!     "app->main [INFO   ] Processed. [batch-42/item-6]\n\
+//!      app->main [INFO   ] Processed. [batch-42/item-7]\n\
+//!      app->main [INFO   ] Done.\n"
+//! );
+//! ```
+//!
+//! ## Correlation ID
+//!
+//! Each thread may also have a correlation ID, to stitch together the log entries of
+//! a multi-function flow, in aggregated logs. It is set with [`new_correlation_id()`]
+//! or [`set_correlation_id()`]. The formatters show it as: `correlation_id=req-7`,
+//! or where the variable `{correlation_id}` is placed in a custom format string.
+//!
+//! To propagate it to another thread, pass the ID to that thread, and set it there.
+//!
+//! ```
+//! use flogging::*;
+//! use std::thread;
+//!
+//! let mut log = Logger::string_logger("app");
+//! log.set_fn_name("main");
+//!
+//! let request = context::set_correlation_id("req-7");
+//! log.info("Started.");
+//!
+//! let id = request.id().to_string();
+//! thread::spawn(move || {
+//!     let _request = context::set_correlation_id(id);
+//!     assert_eq!(context::correlation_id().as_deref(), Some("req-7"));
+//! }).join().unwrap();
+//!
+//! drop(request);
+//! log.info("Idle.");
+//!
+//! let h = log.get_handler(Handler::String).unwrap();
+//! assert_eq!(
+//!     h.get_log(),
+//!     "app->main [INFO   ] Started. correlation_id=req-7\n\
+//!      app->main [INFO   ] Idle.\n"
+//! );
+//! ```
+//!
+
+use std::{
+    cell::RefCell,
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    marker::PhantomData,
+    process,
+    sync::atomic::{AtomicU64, Ordering},
+    time::SystemTime,
+};
+
+thread_local! {
+    static STACK: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    static CORRELATION_ID: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+///
+/// Restores the previous correlation ID, if any, when dropped.
+///
+/// Returned by [`new_correlation_id()`] and [`set_correlation_id()`].
+///
+#[derive(Debug)]
+#[must_use = "the correlation ID is reset as soon as the guard is dropped"]
+pub struct CorrelationGuard {
+    id: String,
+    previous: Option<String>,
+    // The correlation ID is per-thread, so the guard must not be sent to another thread.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl CorrelationGuard {
+    ///
+    /// Returns the correlation ID set by this guard.
+    ///
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Drop for CorrelationGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CORRELATION_ID.with_borrow_mut(|id| *id = previous);
+    }
+}
+
+///
+/// Pops its label, and any labels pushed after it, from the context when dropped.
+///
+/// Returned by [`push()`].
+///
+#[derive(Debug)]
+#[must_use = "the label is popped as soon as the guard is dropped"]
+pub struct ContextGuard {
+    depth: usize,
+    // The stack is per-thread, so the guard must not be sent to another thread.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        STACK.with_borrow_mut(|stack| stack.truncate(self.depth));
+    }
+}
+
+///
+/// Returns a copy of the current thread's context labels, outermost first.
+///
+pub fn current() -> Vec<String> {
+    STACK.with_borrow(|stack| stack.clone())
+}
+
+///
+/// Returns the current thread's correlation ID, if one is set.
+///
+pub fn correlation_id() -> Option<String> {
+    CORRELATION_ID.with_borrow(|id| id.clone())
+}
+
+///
+/// Set the current thread's correlation ID to a newly generated one: 16 hexadecimal digits.
+///
+/// The previous correlation ID, if any, is restored when the returned guard is dropped.
+///
+/// ## Examples
+/// ```
+/// use flogging::context;
+///
+/// let request = context::new_correlation_id();
+/// assert_eq!(request.id().len(), 16);
+/// assert_eq!(context::correlation_id().as_deref(), Some(request.id()));
+/// ```
+///
+pub fn new_correlation_id() -> CorrelationGuard {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let nanos = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos());
+
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(nanos);
+    hasher.write_u32(process::id());
+    hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+
+    set_correlation_id(format!("{:016x}", hasher.finish()))
+}
+
+///
+/// Push `label` onto the current thread's context.
+///
+/// It will be included in every log entry created on this thread, until
+/// the returned guard is dropped.
+///
+/// ## Parameters
+/// - `label` - The label to push.
+///
+/// ## Examples
+/// ```
+/// use flogging::context;
+///
+/// let guard = context::push("batch-42");
+/// assert_eq!(context::current(), vec!["batch-42".to_string()]);
+///
+/// drop(guard);
+/// assert!(context::current().is_empty());
+/// ```
+///
+pub fn push(label: impl Into<String>) -> ContextGuard {
+    STACK.with_borrow_mut(|stack| {
+        let depth = stack.len();
+        stack.push(label.into());
+
+        ContextGuard {
+            depth,
+            _not_send: PhantomData,
+        }
+    })
+}
+
+///
+/// Set the current thread's correlation ID to `id`.
+///
+/// The previous correlation ID, if any, is restored when the returned guard is dropped.
+///
+/// ## Parameters
+/// - `id` - The correlation ID. Such as one received with a request.
+///
+pub fn set_correlation_id(id: impl Into<String>) -> CorrelationGuard {
+    let id = id.into();
+    let previous = CORRELATION_ID.with_borrow_mut(|current| current.replace(id.clone()));
+
+    CorrelationGuard {
+        id,
+        previous,
+        _not_send: PhantomData,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn correlation() {
+        assert_eq!(correlation_id(), None);
+
+        let outer = set_correlation_id("outer");
+        {
+            let inner = new_correlation_id();
+            assert_ne!(inner.id(), new_correlation_id().id());
+            assert_eq!(correlation_id().as_deref(), Some(inner.id()));
+        }
+        assert_eq!(correlation_id().as_deref(), Some("outer"));
+
+        drop(outer);
+        assert_eq!(correlation_id(), None);
+    }
+
+    #[test]
+    fn nested() {
+        let outer = push("outer");
+        {
+            let _inner = push("inner");
+            assert_eq!(current(), vec!["outer", "inner"]);
+        }
+        assert_eq!(current(), vec!["outer"]);
+
+        let _inner = push("inner");
+        drop(outer);
+        assert!(current().is_empty());
+    }
+
+    #[test]
+    fn per_thread() {
+        let _g = push("main");
+
+        thread::spawn(|| assert!(current().is_empty()))
+            .join()
+            .unwrap();
+
+        assert_eq!(current(), vec!["main"]);
+    }
+}
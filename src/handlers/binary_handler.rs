@@ -0,0 +1,438 @@
+//
+// File Name:    binary_handler.rs
+// Directory:    src/handlers
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # BinaryHandler
+//!
+//! The file starts with the 8 byte [`BINARY_MAGIC`]. Then come the records, each
+//! a `u32` length, followed by that many bytes of:
+//!
+//! | Item           | Encoding                            |
+//! |----------------|-------------------------------------|
+//! | timestamp      | `i64` seconds, `u32` nanoseconds    |
+//! | level          | string                              |
+//! | mod_path       | string                              |
+//! | fn_name        | string                              |
+//! | message        | string                              |
+//! | fields         | `u32` count, then key/value strings |
+//! | context        | `u32` count, then strings           |
+//! | correlation_id | optional string                     |
+//! | thread_name    | optional string                     |
+//! | file           | string                              |
+//! | line           | `u32`                               |
+//! | column         | `u32`                               |
+//! | seq            | `u64`                               |
+//! | elapsed        | `u64` seconds, `u32` nanoseconds    |
+//! | error          | optional string                     |
+//!
+//! All integers are big-endian. A string is a `u32` length, followed by that many
+//! bytes of UTF-8. An optional string is a `0` byte for none, or a `1` byte followed
+//! by the string.
+//!
+
+pub mod log_reader;
+
+use std::{
+    any::Any,
+    fmt,
+    fs::File,
+    io::{BufWriter, Error, ErrorKind::InvalidInput, Write},
+};
+
+use crate::*;
+
+///
+/// The bytes at the start of each binary log file: `FLOGBIN` and the format version.
+///
+pub const BINARY_MAGIC: [u8; 8] = *b"FLOGBIN\x01";
+
+///
+/// Publishes log entries to a file, in a compact binary format, instead of as text.
+///
+/// Nothing is lost: each [`LogEntry`] is stored with all of its data, such as its
+/// fields, context, and source code location. Read them back with a [`LogReader`].
+/// That is both faster, and more reliable, than parsing text.
+///
+/// An existing file is appended to. As with the [`FileHandler`], an I/O error does
+/// not panic. Instead, the file is closed, and the error is recorded in the
+/// [stats][BinaryHandler::stats].
+///
+/// The formatter is only used in `test_mode`, as the log entries are otherwise
+/// stored unformatted.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+/// # let _ = std::fs::remove_file("test_logs/binary_handler_example.bin");
+///
+/// let mut log = Logger::builder("app")
+///     .add_binary_handler("test_logs/binary_handler_example.bin")
+///     .set_fn_name("main")
+///     .build();
+///
+/// log.with_fields([("disk", "sda1")])
+///     .warning("Low on disk space.");
+/// log.close();
+///
+/// let entries = LogReader::open("test_logs/binary_handler_example.bin")
+///     .unwrap()
+///     .collect::<Result<Vec<LogEntry>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(entries.len(), 1);
+/// assert_eq!(entries[0].level(), Level::WARNING);
+/// assert_eq!(entries[0].message(), "Low on disk space.");
+/// assert_eq!(entries[0].field("disk"), Some("sda1"));
+/// ```
+///
+#[derive(Debug)]
+pub struct BinaryHandler {
+    filename: String,
+    formatter: Formatter,
+    filter: Option<Box<dyn FilterTrait>>,
+    file: Option<BufWriter<File>>,
+    writer: Option<Vec<u8>>,
+    line: String,
+    stats: HandlerStats,
+}
+
+impl BinaryHandler {
+    fn _create(filename: &str) -> Result<Self, Error> {
+        if filename.is_empty() {
+            return Err(Error::new(InvalidInput, "'filename' must not be empty"));
+        }
+
+        let file = File::options().append(true).create(true).open(filename)?;
+        let mut file = BufWriter::new(file);
+
+        if file.get_ref().metadata()?.len() == 0 {
+            file.write_all(&BINARY_MAGIC)?;
+            file.flush()?;
+        }
+
+        Ok(BinaryHandler {
+            filename: filename.to_string(),
+            formatter: FormatType::Iso8601.create(None),
+            filter: None,
+            file: Some(file),
+            writer: None,
+            line: String::new(),
+            stats: HandlerStats::default(),
+        })
+    }
+
+    fn log(&self) -> String {
+        if let Some(w) = self.writer.to_owned() {
+            String::from_utf8(w).unwrap()
+        } else {
+            String::new()
+        }
+    }
+
+    ///
+    /// Close the file, discarding what is left in its buffer, and record the error.
+    ///
+    fn fail(&mut self, error: Error) {
+        if let Some(f) = self.file.take() {
+            let _ = f.into_parts();
+        }
+
+        self.stats.error(&error, 1);
+    }
+}
+
+///
+/// Serialize the `entry`, as a length prefixed record.
+///
+fn encode(entry: &LogEntry) -> Vec<u8> {
+    let mut buf = vec![0; 4];
+
+    buf.extend_from_slice(&entry.timestamp.timestamp().to_be_bytes());
+    put_u32(&mut buf, entry.timestamp.timestamp_subsec_nanos());
+    put_str(&mut buf, entry.level.as_str());
+    put_str(&mut buf, &entry.mod_path);
+    put_str(&mut buf, &entry.fn_name);
+    put_str(&mut buf, &entry.message);
+
+    put_u32(&mut buf, entry.fields.len() as u32);
+    for (key, value) in &entry.fields {
+        put_str(&mut buf, key);
+        put_str(&mut buf, value);
+    }
+
+    put_u32(&mut buf, entry.context.len() as u32);
+    for item in &entry.context {
+        put_str(&mut buf, item);
+    }
+
+    put_opt_str(&mut buf, entry.correlation_id.as_deref());
+    put_opt_str(&mut buf, entry.thread_name.as_deref());
+    put_str(&mut buf, &entry.file);
+    put_u32(&mut buf, entry.line);
+    put_u32(&mut buf, entry.column);
+    buf.extend_from_slice(&entry.seq.to_be_bytes());
+    buf.extend_from_slice(&entry.elapsed.as_secs().to_be_bytes());
+    put_u32(&mut buf, entry.elapsed.subsec_nanos());
+    put_opt_str(&mut buf, entry.error.as_deref());
+
+    let len = (buf.len() - 4) as u32;
+    buf[..4].copy_from_slice(&len.to_be_bytes());
+    buf
+}
+
+fn put_u32(buf: &mut Vec<u8>, n: u32) {
+    buf.extend_from_slice(&n.to_be_bytes());
+}
+
+fn put_str(buf: &mut Vec<u8>, s: &str) {
+    put_u32(buf, s.len() as u32);
+    buf.extend_from_slice(s.as_bytes());
+}
+
+fn put_opt_str(buf: &mut Vec<u8>, s: Option<&str>) {
+    match s {
+        Some(s) => {
+            buf.push(1);
+            put_str(buf, s);
+        }
+        None => buf.push(0),
+    }
+}
+
+impl Drop for BinaryHandler {
+    ///
+    /// Closes the file, if still open, so that the tail of the log is not lost.
+    ///
+    fn drop(&mut self) {
+        if self.is_open() {
+            self.close();
+        }
+    }
+}
+
+impl fmt::Display for BinaryHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} : binary", self.filename)
+    }
+}
+
+impl HandlerTrait for BinaryHandler {
+    ///
+    /// Create a new handler instance.
+    ///
+    /// ## Parameters
+    /// - `name` - This the `filename` of the log file.
+    ///
+    fn create(name: &str) -> Result<Self, Error> {
+        BinaryHandler::_create(name)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    ///
+    /// Flushes and closes the file.
+    ///
+    fn close(&mut self) {
+        self.flush();
+        self.file = None;
+    }
+
+    fn flush(&mut self) {
+        if let Some(Err(e)) = self.file.as_mut().map(|f| f.flush()) {
+            self.fail(e);
+        }
+    }
+
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
+    fn get_formatter(&self) -> Formatter {
+        self.formatter.clone()
+    }
+
+    fn get_log(&self) -> String {
+        self.log()
+    }
+
+    fn is_open(&self) -> bool {
+        self.file.is_some()
+    }
+
+    fn publish(&mut self, log_entry: &LogEntry) {
+        self.stats.published += 1;
+
+        if let Some(w) = self.writer.as_mut() {
+            let line = &mut self.line;
+            line.clear();
+            self.formatter.format_into(log_entry, line);
+            w.extend_from_slice(line.as_bytes());
+            w.push(b'\n');
+            self.stats.wrote(line.len() + 1);
+            return;
+        }
+
+        let Some(file) = self.file.as_mut() else {
+            self.stats.dropped += 1;
+            return;
+        };
+
+        let record = encode(log_entry);
+
+        match file.write_all(&record).and_then(|_| file.flush()) {
+            Ok(()) => self.stats.wrote(record.len()),
+            Err(e) => self.fail(e),
+        }
+    }
+
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
+    }
+
+    fn set_formatter(&mut self, formatter: Formatter) {
+        self.formatter = formatter;
+    }
+
+    ///
+    /// Sets the test mode to `state`.
+    ///
+    /// If set to `true`, the log entries are formatted, and stored in an internal
+    /// buffer, instead of the file. Use `get_log()` to obtain them.
+    ///
+    fn set_test_mode(&mut self, state: bool) {
+        if state {
+            self.writer = Some(Vec::new());
+        } else {
+            self.writer = None;
+        }
+    }
+
+    fn stats(&self) -> HandlerStats {
+        self.stats.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::{
+        fs,
+        io::{Cursor, ErrorKind},
+    };
+
+    #[test]
+    fn round_trip() {
+        let filename = "test_logs/binary_handler.bin";
+        let _ = fs::remove_file(filename);
+
+        let entry = LogEntry::builder()
+            .level(Level::SEVERE)
+            .mod_path(module_path!())
+            .fn_name("round_trip")
+            .message("ü\n\t")
+            .field("id", "7")
+            .correlation_id("req-1")
+            .error(&std::io::Error::other("gone"))
+            .build();
+
+        {
+            let mut h = BinaryHandler::create(filename).unwrap();
+            h.publish(&entry);
+            h.publish(&LogEntry::builder().message("second").build());
+            h.close();
+            assert!(!h.is_open());
+            assert_eq!(h.stats().published(), 2);
+        }
+
+        // Appended, without a second header.
+        let mut h = BinaryHandler::create(filename).unwrap();
+        h.publish(&LogEntry::builder().message("third").build());
+        h.close();
+
+        let entries = LogReader::open(filename)
+            .unwrap()
+            .collect::<Result<Vec<LogEntry>, _>>()
+            .unwrap();
+        let read = &entries[0];
+
+        assert_eq!(entries.len(), 3);
+        assert_eq!(read.timestamp(), entry.timestamp());
+        assert_eq!(read.level(), Level::SEVERE);
+        assert_eq!(read.mod_path(), module_path!());
+        assert_eq!(read.fn_name(), "round_trip");
+        assert_eq!(read.message(), "ü\n\t");
+        assert_eq!(read.fields(), entry.fields());
+        assert_eq!(read.context(), entry.context());
+        assert_eq!(read.correlation_id(), Some("req-1"));
+        assert_eq!(read.thread_name(), entry.thread_name());
+        assert_eq!(read.file(), entry.file());
+        assert_eq!((read.line(), read.column()), (entry.line(), entry.column()));
+        assert_eq!(read.seq(), entry.seq());
+        assert_eq!(read.elapsed(), entry.elapsed());
+        assert_eq!(read.error(), Some("gone"));
+        assert_eq!(entries[2].message(), "third");
+    }
+
+    #[test]
+    fn bad_data() {
+        let err = LogReader::new(Cursor::new(b"FLOGTXT\x01")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        // A record cut short.
+        let mut data = BINARY_MAGIC.to_vec();
+        data.extend_from_slice(&super::encode(&LogEntry::builder().build()));
+        data.truncate(data.len() - 3);
+
+        let mut reader = LogReader::new(Cursor::new(data)).unwrap();
+        assert_eq!(
+            reader.next().unwrap().unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+        assert!(reader.next().is_none());
+
+        assert!(BinaryHandler::create("").is_err());
+    }
+
+    #[test]
+    fn test_mode() {
+        let mut h = BinaryHandler::create("test_logs/binary_handler_test_mode.bin").unwrap();
+        h.set_test_mode(true);
+        h.set_formatter(FormatType::Simple.create(None));
+
+        h.publish(
+            &LogEntry::builder()
+                .fn_name("test_mode")
+                .message("text")
+                .build(),
+        );
+
+        assert_eq!(h.get_log(), "->test_mode [INFO   ] text\n");
+    }
+}
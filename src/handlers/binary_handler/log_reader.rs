@@ -0,0 +1,272 @@
+//
+// File Name:    log_reader.rs
+// Directory:    src/handlers/binary_handler
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # LogReader
+//!
+
+use super::BINARY_MAGIC;
+use crate::{Level, LogEntry};
+use chrono::{Local, TimeZone};
+use std::{
+    fs::File,
+    io::{
+        BufReader, Error,
+        ErrorKind::{InvalidData, UnexpectedEof},
+        Read,
+    },
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+///
+/// Reads back the log entries written by a [`BinaryHandler`][crate::BinaryHandler].
+///
+/// It is an iterator over the log entries, oldest first. If a record is cut short,
+/// such as when the file is still being written, or is corrupt, its error is
+/// returned, and the iteration ends.
+///
+/// The decoded log entries have the thread ID of the reading thread, as the
+/// original is not available. Their thread name is kept.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// # let _ = std::fs::remove_file("test_logs/log_reader_example.bin");
+/// let mut h = BinaryHandler::create("test_logs/log_reader_example.bin").unwrap();
+/// h.publish(&LogEntry::builder().level(Level::SEVERE).message("Failed.").build());
+/// h.publish(&LogEntry::builder().message("Retried.").build());
+/// h.close();
+///
+/// let severe: Vec<String> = LogReader::open("test_logs/log_reader_example.bin")
+///     .unwrap()
+///     .filter_map(Result::ok)
+///     .filter(|le| le.level() >= Level::WARNING)
+///     .map(|le| le.message().to_string())
+///     .collect();
+///
+/// assert_eq!(severe, ["Failed."]);
+/// ```
+///
+#[derive(Debug)]
+pub struct LogReader<R: Read> {
+    reader: R,
+    done: bool,
+}
+
+impl LogReader<BufReader<File>> {
+    ///
+    /// Open the binary log file at `path`.
+    ///
+    /// ## Parameters
+    /// - `path` - The path of the file.
+    ///
+    /// Returns an error if it cannot be opened, or is not a binary log file.
+    ///
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        LogReader::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read> LogReader<R> {
+    ///
+    /// Creates a new instance of `LogReader`, reading from `reader`.
+    ///
+    /// ## Parameters
+    /// - `reader` - The source of the binary log, starting with its header.
+    ///
+    /// Returns an error if the header is not [`BINARY_MAGIC`][crate::BINARY_MAGIC].
+    ///
+    pub fn new(mut reader: R) -> Result<Self, Error> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+
+        if magic != BINARY_MAGIC {
+            return Err(Error::new(InvalidData, "not a flogging binary log"));
+        }
+
+        Ok(LogReader {
+            reader,
+            done: false,
+        })
+    }
+
+    ///
+    /// Read the next record, or `None` at the end of the log.
+    ///
+    fn read_record(&mut self) -> Result<Option<Vec<u8>>, Error> {
+        let mut len = [0; 4];
+        let mut filled = 0;
+
+        while filled < len.len() {
+            match self.reader.read(&mut len[filled..])? {
+                0 if filled == 0 => return Ok(None),
+                0 => return Err(Error::new(UnexpectedEof, "record length cut short")),
+                n => filled += n,
+            }
+        }
+
+        let len = u32::from_be_bytes(len) as u64;
+        let mut record = Vec::new();
+        (&mut self.reader).take(len).read_to_end(&mut record)?;
+
+        if (record.len() as u64) < len {
+            return Err(Error::new(UnexpectedEof, "record cut short"));
+        }
+
+        Ok(Some(record))
+    }
+}
+
+impl<R: Read> Iterator for LogReader<R> {
+    type Item = Result<LogEntry, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self
+            .read_record()
+            .and_then(|record| record.map(|r| decode(&r)).transpose())
+            .transpose();
+
+        if !matches!(result, Some(Ok(_))) {
+            self.done = true;
+        }
+
+        result
+    }
+}
+
+///
+/// Deserialize a record that was serialized by `encode()`, without its length.
+///
+fn decode(record: &[u8]) -> Result<LogEntry, Error> {
+    let mut r = Record(record);
+
+    let (secs, nanos) = (r.i64()?, r.u32()?);
+    let timestamp = Local
+        .timestamp_opt(secs, nanos)
+        .single()
+        .ok_or_else(|| Error::new(InvalidData, "invalid timestamp"))?;
+    let level = r
+        .string()?
+        .parse::<Level>()
+        .map_err(|e| Error::new(InvalidData, e))?;
+    let mod_path = r.string()?;
+    let fn_name = r.string()?;
+    let message = r.string()?;
+
+    let fields = (0..r.u32()?)
+        .map(|_| -> Result<(String, String), Error> { Ok((r.string()?, r.string()?)) })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let context = (0..r.u32()?)
+        .map(|_| r.string())
+        .collect::<Result<Vec<String>, Error>>()?;
+
+    let correlation_id = r.opt_string()?;
+    let thread_name = r.opt_string()?;
+    let file = r.string()?;
+    let line = r.u32()?;
+    let column = r.u32()?;
+    let seq = r.u64()?;
+    let elapsed = Duration::new(r.u64()?, r.u32()?);
+    let error = r.opt_string()?;
+
+    if !r.0.is_empty() {
+        return Err(Error::new(InvalidData, "unexpected data at end of record"));
+    }
+
+    Ok(LogEntry {
+        timestamp,
+        mod_path,
+        fn_name,
+        level,
+        message,
+        fields,
+        context,
+        correlation_id,
+        thread_id: thread::current().id(),
+        thread_name,
+        file: file.into(),
+        line,
+        column,
+        seq,
+        elapsed,
+        error,
+    })
+}
+
+///
+/// The unread part of a record.
+///
+struct Record<'a>(&'a [u8]);
+
+impl Record<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let (bytes, rest) = self
+            .0
+            .split_first_chunk::<N>()
+            .ok_or_else(|| Error::new(UnexpectedEof, "record cut short"))?;
+
+        self.0 = rest;
+        Ok(*bytes)
+    }
+
+    fn i64(&mut self) -> Result<i64, Error> {
+        Ok(i64::from_be_bytes(self.take()?))
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_be_bytes(self.take()?))
+    }
+
+    fn u64(&mut self) -> Result<u64, Error> {
+        Ok(u64::from_be_bytes(self.take()?))
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        let len = self.u32()? as usize;
+
+        if self.0.len() < len {
+            return Err(Error::new(UnexpectedEof, "record cut short"));
+        }
+
+        let (s, rest) = self.0.split_at(len);
+        self.0 = rest;
+
+        String::from_utf8(s.to_vec()).map_err(|e| Error::new(InvalidData, e))
+    }
+
+    fn opt_string(&mut self) -> Result<Option<String>, Error> {
+        match self.take::<1>()? {
+            [0] => Ok(None),
+            [1] => self.string().map(Some),
+            _ => Err(Error::new(InvalidData, "invalid optional string")),
+        }
+    }
+}
@@ -27,13 +27,22 @@
 //! Publishes log entries to the console: `[std::io::stderr]`.
 //!
 
+pub mod color_choice;
 pub mod console_type;
+pub mod printer_trait;
+pub mod production_split;
 
+use super::formatters::ansi;
 use crate::*;
+use color_choice::ColorChoice;
 use console_type::ConsoleType;
+use printer_trait::PrinterTrait;
+use production_split::ProductionSplit;
 use std::{
+    any::Any,
     fmt,
-    io::{self, Error, Write},
+    io::{Error, Write},
+    sync::Arc,
 };
 
 ///
@@ -46,21 +55,66 @@ use std::{
 /// - `ConsoleType::Production`:\
 ///   If `log_entry.level` is `LeveL::INFO`, then\
 ///   prints unformatted `log_entry.msg` to `stdout`, else\
-///   prints formatted `log_entry.msg` to `stderr`.
+///   prints formatted `log_entry.msg` to `stderr`.\
+///   This split is configurable, see: [`set_production_split()`][ConsoleHandler::set_production_split].
+///
+/// The output can be colored by level, see: [`set_color()`][ConsoleHandler::set_color].
+///
+/// The output can be routed through a printer, such as a progress bar's, see:
+/// [`set_printer()`][ConsoleHandler::set_printer].
 ///
 #[derive(Debug, Default)]
 pub struct ConsoleHandler {
     console_type: ConsoleType,
+    split: ProductionSplit,
+    color: ColorChoice,
+    printer: Option<Arc<dyn PrinterTrait>>,
     formatter: Formatter,
+    filter: Option<Box<dyn FilterTrait>>,
     writer: Option<Vec<u8>>,
+    buf: String,
+    stats: HandlerStats,
 }
 
 impl ConsoleHandler {
     fn _create(console_type: ConsoleType) -> Self {
         ConsoleHandler {
             console_type,
+            split: ProductionSplit::default(),
+            color: ColorChoice::default(),
+            printer: None,
             formatter: FormatType::Simple.create(None),
+            filter: None,
             writer: None,
+            buf: String::new(),
+            stats: HandlerStats::default(),
+        }
+    }
+
+    ///
+    /// Put the line to output for `log_entry` into `buf`, colored as required.
+    ///
+    /// In test mode, [`ColorChoice::Auto`] never colors.
+    ///
+    fn line(&mut self, log_entry: &LogEntry, formatted: bool, to_stdout: bool) {
+        let buf = &mut self.buf;
+        buf.clear();
+
+        if formatted {
+            self.formatter.format_into(log_entry, buf);
+        } else {
+            buf.push_str(log_entry.message());
+        }
+
+        let colors = match self.color {
+            ColorChoice::Auto if self.writer.is_some() => false,
+            color => color.colors(to_stdout),
+        };
+
+        if colors {
+            *buf = ansi::paint(log_entry.level(), buf);
+        } else if buf.contains('\x1b') {
+            *buf = ansi::strip(buf).into_owned();
         }
     }
 
@@ -71,6 +125,65 @@ impl ConsoleHandler {
             String::new()
         }
     }
+
+    ///
+    /// Returns whether or not the output is colored by level.
+    ///
+    pub fn color(&self) -> ColorChoice {
+        self.color
+    }
+
+    ///
+    /// Returns the printer, if any, used in place of `println!()` and `eprintln!()`.
+    ///
+    pub fn printer(&self) -> Option<&dyn PrinterTrait> {
+        self.printer.as_deref()
+    }
+
+    ///
+    /// Returns how a `ConsoleType::Production` console splits its log entries
+    /// between `stdout` and `stderr`.
+    ///
+    pub fn production_split(&self) -> &ProductionSplit {
+        &self.split
+    }
+
+    ///
+    /// Sets whether or not the output is colored by level.
+    ///
+    /// ## Parameters
+    /// - `color` - The required color choice.
+    ///
+    pub fn set_color(&mut self, color: ColorChoice) {
+        self.color = color;
+    }
+
+    ///
+    /// Sets the printer used in place of `println!()` and `eprintln!()`, or if `None`,
+    /// reverts to them.
+    ///
+    /// All lines are passed to the printer, whether they would have gone to `stdout`
+    /// or `stderr`. See [`PrinterTrait`].
+    ///
+    /// ## Parameters
+    /// - `printer` - The optional shared printer.
+    ///
+    pub fn set_printer(&mut self, printer: Option<Arc<dyn PrinterTrait>>) {
+        self.printer = printer;
+    }
+
+    ///
+    /// Sets how a `ConsoleType::Production` console splits its log entries
+    /// between `stdout` and `stderr`.
+    ///
+    /// Has no effect on the other console types.
+    ///
+    /// ## Parameters
+    /// - `split` - The required split.
+    ///
+    pub fn set_production_split(&mut self, split: ProductionSplit) {
+        self.split = split;
+    }
 }
 
 impl fmt::Display for ConsoleHandler {
@@ -87,6 +200,14 @@ impl HandlerTrait for ConsoleHandler {
         Ok(ConsoleHandler::_create(console_type.parse().unwrap()))
     }
 
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
     ///
     /// Removes the internal buffer, if in `test_mode`.\
     /// Will therefore, no longer be *in* `test_mode`.
@@ -106,6 +227,10 @@ impl HandlerTrait for ConsoleHandler {
         };
     }
 
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
     fn get_formatter(&self) -> Formatter {
         self.formatter.clone()
     }
@@ -122,22 +247,43 @@ impl HandlerTrait for ConsoleHandler {
     }
 
     fn publish(&mut self, log_entry: &LogEntry) {
+        let to_stdout = match self.console_type {
+            ConsoleType::StdOut => {
+                self.line(log_entry, true, true);
+                true
+            }
+            ConsoleType::StdErr => {
+                self.line(log_entry, true, false);
+                false
+            }
+            ConsoleType::Production => {
+                let to_stdout = self.split.to_stdout(log_entry.level());
+                let formatted = !to_stdout || self.split.is_formatted();
+                self.line(log_entry, formatted, to_stdout);
+                to_stdout
+            }
+        };
+
+        let line = &self.buf;
+        self.stats.published += 1;
+        self.stats.wrote(line.len() + 1);
+
         match self.writer.as_mut() {
             Some(w) => {
-                let _ = match self.console_type {
-                    ConsoleType::StdOut => writeln!(w, "{}", self.formatter.format(log_entry)),
-                    ConsoleType::StdErr => writeln!(w, "{}", self.formatter.format(log_entry)),
-                    ConsoleType::Production => production_test(w, &self.formatter, log_entry),
-                };
+                let _ = writeln!(w, "{line}");
             }
-            None => match self.console_type {
-                ConsoleType::StdOut => println!("{}", self.formatter.format(log_entry)),
-                ConsoleType::StdErr => eprintln!("{}", self.formatter.format(log_entry)),
-                ConsoleType::Production => production(&self.formatter, log_entry),
+            None => match self.printer.as_ref() {
+                Some(printer) => printer.print(&line),
+                None if to_stdout => println!("{line}"),
+                None => eprintln!("{line}"),
             },
         }
     }
 
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
+    }
+
     fn set_formatter(&mut self, formatter: Formatter) {
         self.formatter = formatter;
     }
@@ -156,28 +302,10 @@ impl HandlerTrait for ConsoleHandler {
             self.writer = None;
         }
     }
-}
-
-fn production(formatter: &Formatter, log_entry: &LogEntry) {
-    if log_entry.level() == Level::INFO {
-        println!("{}", log_entry.message());
-    } else {
-        eprintln!("{}", formatter.format(log_entry));
-    }
-}
 
-fn production_test(
-    writer: &mut Vec<u8>,
-    formatter: &Formatter,
-    log_entry: &LogEntry,
-) -> io::Result<()> {
-    if log_entry.level() == Level::INFO {
-        writeln!(writer, "{}", log_entry.message())?;
-    } else {
-        writeln!(writer, "{}", formatter.format(log_entry))?;
+    fn stats(&self) -> HandlerStats {
+        self.stats.clone()
     }
-
-    Ok(())
 }
 
 #[cfg(test)]
@@ -343,4 +471,90 @@ flogging::handlers::console_handler::tests-> [WARNING] The sky is falling!\n"
         h.flush();
         h.close();
     }
+
+    #[test]
+    fn production_split_test_mode() {
+        let expected = "flogging::handlers::console_handler::tests-> [CONFIG ] Loaded.
+Ready.
+Disk nearly full.
+flogging::handlers::console_handler::tests-> [SEVERE ] Out of disk.\n"
+            .to_string();
+
+        let mut log = Logger::builder(module_path!())
+            .add_pconsole_handler_with_split(ProductionSplit::new(Level::INFO, Level::WARNING))
+            .set_level(Level::CONFIG)
+            .build();
+
+        let h = log.get_handler_as::<ConsoleHandler>().unwrap();
+        assert_eq!(
+            h.production_split().to_string(),
+            "stdout: INFO..=WARNING (unformatted)"
+        );
+        h.set_test_mode(true);
+
+        log.config("Loaded.");
+        log.info("Ready.");
+        log.warning("Disk nearly full.");
+        log.severe("Out of disk.");
+
+        let h = log.get_handler(crate::Handler::PConsole).unwrap();
+        assert_eq!(expected, h.get_log());
+    }
+
+    #[test]
+    fn colored_test_mode() {
+        let expected = "flogging::handlers::console_handler::tests-> [INFO   ] Plain.
+\x1b[33mflogging::handlers::console_handler::tests-> [WARNING] Yellow.\x1b[0m\n"
+            .to_string();
+
+        let mut log = Logger::builder(module_path!())
+            .add_console_handler()
+            .set_color(ColorChoice::Always)
+            .build();
+
+        let h = log.get_handler_as::<ConsoleHandler>().unwrap();
+        assert_eq!(h.color(), ColorChoice::Always);
+        h.set_test_mode(true);
+
+        log.info("Plain.");
+        log.warning("Yellow.");
+
+        let h = log.get_handler(crate::Handler::Console).unwrap();
+        assert_eq!(expected, h.get_log());
+    }
+
+    #[test]
+    fn printer() {
+        use std::sync::{Arc, Mutex};
+
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let printed = lines.clone();
+
+        let mut log = Logger::builder(module_path!())
+            .add_pconsole_handler()
+            .set_color(ColorChoice::Never)
+            .set_printer(Arc::new(move |line: &str| {
+                printed.lock().unwrap().push(line.to_string())
+            }))
+            .add_econsole_handler()
+            .build();
+
+        log.info("To stdout.");
+        log.warning("To stderr.");
+
+        assert_eq!(
+            *lines.lock().unwrap(),
+            [
+                "To stdout.",
+                "flogging::handlers::console_handler::tests-> [INFO   ] To stdout.",
+                "flogging::handlers::console_handler::tests-> [WARNING] To stderr.",
+                "flogging::handlers::console_handler::tests-> [WARNING] To stderr.",
+            ]
+        );
+
+        let h = log.get_handler_as::<ConsoleHandler>().unwrap();
+        assert!(h.printer().is_some());
+        h.set_printer(None);
+        assert!(h.printer().is_none());
+    }
 }
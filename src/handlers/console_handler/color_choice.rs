@@ -0,0 +1,152 @@
+//
+// File Name:    color_choice.rs
+// Directory:    src/handlers/console_handler
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # ColorChoice
+//!
+
+use std::{env, fmt, io::IsTerminal, sync::OnceLock};
+
+///
+/// The name of the environment variable that, if set to anything other than `0`,
+/// forces colored output with [`ColorChoice::Auto`].
+///
+pub const CLICOLOR_FORCE: &str = "CLICOLOR_FORCE";
+
+///
+/// The name of the environment variable that, if set to anything other than an
+/// empty string, prevents colored output with [`ColorChoice::Auto`].
+///
+/// See: <https://no-color.org>.
+///
+pub const NO_COLOR: &str = "NO_COLOR";
+
+///
+/// `ColorChoice` configures whether the `ConsoleHandler` colors its output by level.
+///
+/// When colored, SEVERE is red, WARNING yellow, CONFIG cyan, and FINE, FINER and FINEST dim.
+/// INFO is left as is.
+///
+/// When not colored, any ANSI escape codes in the formatted output, such as from the
+/// `{level_colored}` variable, are removed.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+    ///
+    /// Always color the output.
+    ///
+    Always,
+
+    ///
+    /// Color the output if it is going to a terminal, unless told otherwise by the
+    /// environment variables.
+    ///
+    /// If [`CLICOLOR_FORCE`] is set (not `0`), then color. Otherwise, if [`NO_COLOR`]
+    /// is set (not empty), then don't.
+    ///
+    /// The environment, and whether `stdout` and `stderr` are terminals, are only
+    /// checked once.
+    ///
+    #[default]
+    Auto,
+
+    ///
+    /// Never color the output.
+    ///
+    Never,
+}
+
+impl ColorChoice {
+    ///
+    /// Converts a color choice to its string version.
+    ///
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ColorChoice::Always => "always",
+            ColorChoice::Auto => "auto",
+            ColorChoice::Never => "never",
+        }
+    }
+
+    ///
+    /// Returns `true` if the output to `stdout`, or if not `to_stdout` to `stderr`,
+    /// is to be colored.
+    ///
+    pub(crate) fn colors(&self, to_stdout: bool) -> bool {
+        static STDOUT: OnceLock<bool> = OnceLock::new();
+        static STDERR: OnceLock<bool> = OnceLock::new();
+
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto if to_stdout => {
+                *STDOUT.get_or_init(|| from_env(std::io::stdout().is_terminal()))
+            }
+            ColorChoice::Auto => *STDERR.get_or_init(|| from_env(std::io::stderr().is_terminal())),
+        }
+    }
+}
+
+fn from_env(is_terminal: bool) -> bool {
+    auto(
+        env::var(CLICOLOR_FORCE).ok().as_deref(),
+        env::var(NO_COLOR).ok().as_deref(),
+        is_terminal,
+    )
+}
+
+///
+/// Resolve [`ColorChoice::Auto`] from the values of [`CLICOLOR_FORCE`] and [`NO_COLOR`],
+/// and whether or not the output is to a terminal.
+///
+fn auto(clicolor_force: Option<&str>, no_color: Option<&str>, is_terminal: bool) -> bool {
+    if clicolor_force.is_some_and(|v| v != "0") {
+        true
+    } else if no_color.is_some_and(|v| !v.is_empty()) {
+        false
+    } else {
+        is_terminal
+    }
+}
+
+impl fmt::Display for ColorChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_choice() {
+        assert!(auto(None, None, true));
+        assert!(!auto(None, None, false));
+        assert!(!auto(None, Some("1"), true));
+        assert!(auto(None, Some(""), true));
+        assert!(auto(Some("1"), None, false));
+        assert!(auto(Some("1"), Some("1"), false));
+        assert!(!auto(Some("0"), None, false));
+    }
+}
@@ -0,0 +1,87 @@
+//
+// File Name:    printer_trait.rs
+// Directory:    src/handlers/console_handler
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Printer Trait
+//!
+
+use std::fmt;
+
+///
+/// Prints the lines output by a [`ConsoleHandler`][crate::ConsoleHandler], in place of
+/// `println!()` and `eprintln!()`.
+///
+/// This allows the log entries to be routed through something else that owns the
+/// terminal, such as a progress bar, so they don't corrupt it. For example, with the
+/// `indicatif` crate: `ProgressBar::println()`.
+///
+/// A printer is attached to a handler with
+/// [`ConsoleHandler::set_printer()`][crate::ConsoleHandler::set_printer], or to all of the
+/// console handlers when building the logger with
+/// [`LoggerBuilder::set_printer()`][crate::LoggerBuilder::set_printer].
+///
+/// Any closure of the form `Fn(&str)` is a `PrinterTrait`.
+///
+/// ## Examples
+/// ```
+/// use flogging::*;
+/// use std::sync::{Arc, Mutex};
+///
+/// // Stands in for: `let bar = ProgressBar::new(100);`
+/// let lines = Arc::new(Mutex::new(Vec::new()));
+/// let bar = lines.clone();
+///
+/// let log = Logger::builder("app")
+///     .add_console_handler()
+///     .set_printer(Arc::new(move |line: &str| bar.lock().unwrap().push(line.to_string())))
+///     .build();
+///
+/// log.info("Downloading.");
+///
+/// assert_eq!(*lines.lock().unwrap(), ["app-> [INFO   ] Downloading."]);
+/// ```
+///
+pub trait PrinterTrait: Send + Sync {
+    ///
+    /// Print `line`, which has no trailing newline.
+    ///
+    /// ## Parameters
+    /// - `line` - The formatted (and possibly colored) line to print.
+    ///
+    fn print(&self, line: &str);
+}
+
+impl<F> PrinterTrait for F
+where
+    F: Fn(&str) + Send + Sync,
+{
+    fn print(&self, line: &str) {
+        self(line)
+    }
+}
+
+impl fmt::Debug for dyn PrinterTrait {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "PrinterTrait".fmt(f)
+    }
+}
@@ -0,0 +1,163 @@
+//
+// File Name:    production_split.rs
+// Directory:    src/handlers/console_handler
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # ProductionSplit
+//!
+
+use crate::Level;
+use std::fmt;
+
+///
+/// Configures how a [`ConsoleType::Production`][crate::ConsoleType::Production]
+/// console splits its log entries between `stdout` and `stderr`.
+///
+/// Those log entries with a level from `lowest` to `highest` (inclusive) are printed
+/// to `stdout`, unformatted unless [`formatted()`][ProductionSplit::formatted] is set.
+/// All others are printed, formatted, to `stderr`.
+///
+/// The default is the original behaviour: only `INFO` to `stdout`, unformatted.
+///
+/// To suppress the lower levels altogether, such as `CONFIG` and below, give the
+/// handler its own level with
+/// [`add_pconsole_handler_at()`][crate::LoggerBuilder::add_pconsole_handler_at].
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// // Both INFO and WARNING are for the user's eyes.
+/// let split = ProductionSplit::new(Level::INFO, Level::WARNING);
+///
+/// let mut log = Logger::builder(module_path!())
+///     .add_pconsole_handler_with_split(split)
+///     .build();
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductionSplit {
+    lowest: Level,
+    highest: Level,
+    formatted: bool,
+}
+
+impl ProductionSplit {
+    ///
+    /// Creates a new split, sending log entries from `lowest` to `highest`
+    /// (inclusive) to `stdout`, unformatted.
+    ///
+    /// ## Parameters
+    /// - `lowest` - The lowest level printed to `stdout`.
+    /// - `highest` - The highest level printed to `stdout`.
+    ///
+    pub fn new(lowest: Level, highest: Level) -> Self {
+        ProductionSplit {
+            lowest,
+            highest,
+            formatted: false,
+        }
+    }
+
+    ///
+    /// Sets whether or not the log entries printed to `stdout` are formatted.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn formatted(mut self, formatted: bool) -> Self {
+        self.formatted = formatted;
+        self
+    }
+
+    ///
+    /// Returns the highest level printed to `stdout`.
+    ///
+    pub fn highest(&self) -> Level {
+        self.highest
+    }
+
+    ///
+    /// Returns `true` if the log entries printed to `stdout` are formatted.
+    ///
+    pub fn is_formatted(&self) -> bool {
+        self.formatted
+    }
+
+    ///
+    /// Returns the lowest level printed to `stdout`.
+    ///
+    pub fn lowest(&self) -> Level {
+        self.lowest
+    }
+
+    ///
+    /// Returns `true` if a log entry of `level` is printed to `stdout`.
+    ///
+    pub(crate) fn to_stdout(&self, level: Level) -> bool {
+        level >= self.lowest && level <= self.highest
+    }
+}
+
+impl Default for ProductionSplit {
+    fn default() -> Self {
+        ProductionSplit::new(Level::INFO, Level::INFO)
+    }
+}
+
+impl fmt::Display for ProductionSplit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "stdout: {}..={} ({})",
+            self.lowest,
+            self.highest,
+            if self.formatted {
+                "formatted"
+            } else {
+                "unformatted"
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_stdout() {
+        let split = ProductionSplit::default();
+        assert!(split.to_stdout(Level::INFO));
+        assert!(!split.to_stdout(Level::WARNING));
+        assert!(!split.to_stdout(Level::CONFIG));
+        assert_eq!(split.to_string(), "stdout: INFO..=INFO (unformatted)");
+
+        let split = ProductionSplit::new(Level::CONFIG, Level::WARNING).formatted(true);
+        assert!(split.to_stdout(Level::CONFIG));
+        assert!(split.to_stdout(Level::WARNING));
+        assert!(!split.to_stdout(Level::SEVERE));
+        assert!(!split.to_stdout(Level::FINE));
+        assert!(split.is_formatted());
+        assert_eq!(split.to_string(), "stdout: CONFIG..=WARNING (formatted)");
+    }
+}
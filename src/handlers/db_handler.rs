@@ -0,0 +1,395 @@
+//
+// File Name:    db_handler.rs
+// Directory:    src/handlers
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # DbHandler
+//!
+//! Stores log entries in an embedded database.
+//!
+
+use crate::*;
+use chrono::{DateTime, Local};
+use std::{
+    any::Any,
+    fmt,
+    io::{Error, ErrorKind::InvalidInput},
+    thread,
+    time::Duration,
+};
+
+///
+/// Stores log entries in the embedded [`sled`] database whose directory
+/// name was provided during initialization.
+///
+/// Each entry is keyed by a monotonically increasing id, so the store is
+/// indexed in the order the entries were published. The entries persist across
+/// restarts, and can be retrieved with [`entries()`][DbHandler::entries],
+/// searched with [`query()`][DbHandler::query], and formatted with
+/// [`report()`][DbHandler::report]. [`clear()`][DbHandler::clear] empties the store.
+///
+/// Requires the `db` feature.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// let mut log = Logger::builder(module_path!())
+///     .add_db_handler("test_logs/db_handler_example")
+///     .build();
+///
+/// log.warning("Low on disk space.");
+/// log.info("Started.");
+///
+/// let report = log.get_handler(Handler::Db).unwrap().get_log();
+/// assert!(report.ends_with("[INFO   ] Started."));
+/// ```
+///
+#[derive(Debug)]
+pub struct DbHandler {
+    path: String,
+    formatter: Formatter,
+    filter: Option<Box<dyn FilterTrait>>,
+    db: Option<sled::Db>,
+    writer: Option<Vec<u8>>,
+    stats: HandlerStats,
+}
+
+impl DbHandler {
+    fn _create(path: &str) -> Result<Self, Error> {
+        if path.is_empty() {
+            return Err(Error::new(InvalidInput, "'path' must not be empty"));
+        }
+
+        Ok(DbHandler {
+            path: path.to_string(),
+            formatter: FormatType::Iso8601.create(None),
+            filter: None,
+            db: Some(sled::open(path).map_err(Error::other)?),
+            writer: None,
+            stats: HandlerStats::default(),
+        })
+    }
+
+    ///
+    /// Remove all log entries from the store.
+    ///
+    pub fn clear(&mut self) {
+        if let Some(db) = &self.db {
+            db.clear().expect("clear() failed");
+        }
+    }
+
+    ///
+    /// Returns all of the stored log entries, oldest first.
+    ///
+    pub fn entries(&self) -> Vec<LogEntry> {
+        self.query(|_| true)
+    }
+
+    ///
+    /// Returns `true` if there are no stored log entries.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///
+    /// Returns the number of stored log entries.
+    ///
+    pub fn len(&self) -> usize {
+        self.db.as_ref().map_or(0, |db| db.len())
+    }
+
+    ///
+    /// Returns the stored log entries that match the `filter`, oldest first.
+    ///
+    /// ## Parameters
+    /// - `filter` - Returns `true` for each log entry to be included.
+    ///
+    /// ## Examples
+    /// ```text
+    /// let problems = h.query(|e| e.level() >= Level::WARNING);
+    /// ```
+    ///
+    pub fn query<F>(&self, filter: F) -> Vec<LogEntry>
+    where
+        F: Fn(&LogEntry) -> bool,
+    {
+        let Some(db) = &self.db else {
+            return Vec::new();
+        };
+
+        db.iter()
+            .values()
+            .filter_map(|value| decode(&value.ok()?))
+            .filter(|entry| filter(entry))
+            .collect()
+    }
+
+    ///
+    /// Returns all of the stored log entries, formatted by this handler's
+    /// formatter, one per line.
+    ///
+    pub fn report(&self) -> String {
+        self.entries()
+            .iter()
+            .map(|entry| self.formatter.format(entry))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+///
+/// Serialize the `entry`, as length prefixed fields.
+///
+fn encode(entry: &LogEntry) -> Vec<u8> {
+    let timestamp = entry.timestamp.to_rfc3339();
+    let fields = [
+        timestamp.as_str(),
+        entry.level.as_str(),
+        &entry.mod_path,
+        &entry.fn_name,
+        &entry.message,
+    ];
+
+    let mut buf = Vec::new();
+
+    for field in fields {
+        buf.extend_from_slice(&(field.len() as u32).to_be_bytes());
+        buf.extend_from_slice(field.as_bytes());
+    }
+
+    buf
+}
+
+///
+/// Deserialize a log entry that was serialized by [`encode()`].
+///
+fn decode(mut buf: &[u8]) -> Option<LogEntry> {
+    let mut fields = Vec::with_capacity(5);
+
+    while !buf.is_empty() {
+        let (len, rest) = buf.split_first_chunk::<4>()?;
+        let len = u32::from_be_bytes(*len) as usize;
+        let field = rest.get(..len)?;
+
+        fields.push(String::from_utf8(field.to_vec()).ok()?);
+        buf = &rest[len..];
+    }
+
+    let [timestamp, level, mod_path, fn_name, message] = <[String; 5]>::try_from(fields).ok()?;
+
+    Some(LogEntry {
+        timestamp: DateTime::parse_from_rfc3339(&timestamp)
+            .ok()?
+            .with_timezone(&Local),
+        mod_path,
+        fn_name,
+        level: level.parse().ok()?,
+        message,
+        fields: Vec::new(),
+        context: Vec::new(),
+        correlation_id: None,
+        thread_id: thread::current().id(),
+        thread_name: None,
+        file: "".into(),
+        line: 0,
+        column: 0,
+        seq: 0,
+        elapsed: Duration::ZERO,
+        error: None,
+    })
+}
+
+impl fmt::Display for DbHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} : {}", self.path, self.formatter)
+    }
+}
+
+impl HandlerTrait for DbHandler {
+    ///
+    /// Create a new handler instance.
+    ///
+    /// ## Parameters
+    /// - `name` - The path of the database directory. Created if required.
+    ///
+    fn create(name: &str) -> Result<Self, Error> {
+        DbHandler::_create(name)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    ///
+    /// Flushes and closes the database.
+    ///
+    fn close(&mut self) {
+        self.flush();
+        self.db = None;
+    }
+
+    fn flush(&mut self) {
+        if let Some(db) = &self.db {
+            db.flush().expect("flush() failed");
+        }
+    }
+
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
+    fn get_formatter(&self) -> Formatter {
+        self.formatter.clone()
+    }
+
+    ///
+    /// Returns the [`report()`][DbHandler::report], or if in `test_mode`,
+    /// the contents of the internal buffer.
+    ///
+    fn get_log(&self) -> String {
+        match &self.writer {
+            Some(w) => String::from_utf8(w.clone()).unwrap(),
+            None => self.report(),
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        self.db.is_some()
+    }
+
+    fn publish(&mut self, log_entry: &LogEntry) {
+        self.stats.published += 1;
+
+        if let Some(w) = self.writer.as_mut() {
+            let line = self.formatter.format(log_entry);
+            w.extend_from_slice(line.as_bytes());
+            w.push(b'\n');
+            self.stats.wrote(line.len() + 1);
+        } else if let Some(db) = &self.db {
+            let id = db.generate_id().expect("generate_id() failed");
+            let value = encode(log_entry);
+            let len = value.len();
+            db.insert(id.to_be_bytes(), value).expect("insert() failed");
+            self.stats.wrote(len);
+        } else {
+            self.stats.dropped += 1;
+        }
+    }
+
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
+    }
+
+    fn set_formatter(&mut self, formatter: Formatter) {
+        self.formatter = formatter;
+    }
+
+    ///
+    /// Sets the test mode to `state`.
+    ///
+    /// If set to `true`, the log entries are stored in an internal buffer,
+    /// instead of the database. Use `get_log()` to obtain them.
+    ///
+    fn set_test_mode(&mut self, state: bool) {
+        if state {
+            self.writer = Some(Vec::new());
+        } else {
+            self.writer = None;
+        }
+    }
+
+    fn stats(&self) -> HandlerStats {
+        self.stats.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode};
+    use crate::*;
+
+    #[test]
+    fn round_trip() {
+        let mut le = LogEntry::create(Level::CONFIG, "round_trip".to_string(), "ü\n\t".to_string());
+        le.set_mod_path(module_path!().to_string());
+
+        let le2 = decode(&encode(&le)).unwrap();
+
+        assert_eq!(le2.timestamp(), le.timestamp());
+        assert_eq!(le2.level(), Level::CONFIG);
+        assert_eq!(le2.mod_path(), module_path!());
+        assert_eq!(le2.fn_name(), "round_trip");
+        assert_eq!(le2.message(), "ü\n\t");
+
+        assert!(decode(b"\0\0\0\x09short").is_none());
+    }
+
+    #[test]
+    fn db_handler() {
+        let path = "test_logs/db_handler";
+
+        {
+            let mut h = DbHandler::create(path).unwrap();
+            h.clear();
+            h.set_formatter(FormatType::Simple.create(None));
+
+            for (level, msg) in [
+                (Level::INFO, "one"),
+                (Level::SEVERE, "two"),
+                (Level::FINE, "three"),
+            ] {
+                h.publish(&LogEntry::create(level, "db_handler".to_string(), msg.to_string()));
+            }
+
+            h.close();
+            assert!(!h.is_open());
+        }
+
+        // Persisted.
+        let mut h = DbHandler::create(path).unwrap();
+        h.set_formatter(FormatType::Simple.create(None));
+
+        assert_eq!(h.len(), 3);
+        assert_eq!(
+            h.report(),
+            "->db_handler [INFO   ] one\n->db_handler [SEVERE ] two\n->db_handler [FINE   ] three"
+        );
+
+        let severe: Vec<String> = h
+            .query(|e| e.level() >= Level::WARNING)
+            .iter()
+            .map(|e| e.message().to_string())
+            .collect();
+        assert_eq!(severe, vec!["two"]);
+
+        h.clear();
+        assert!(h.is_empty());
+    }
+}
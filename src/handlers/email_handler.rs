@@ -0,0 +1,505 @@
+//
+// File Name:    email_handler.rs
+// Directory:    src/handlers
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # EmailHandler
+//!
+//! Emails digests of the serious log entries, via an SMTP relay.
+//!
+
+use crate::*;
+use lettre::{
+    Message, SmtpTransport, Transport,
+    message::{Mailbox, header::ContentType},
+    transport::smtp::authentication::Credentials,
+};
+use std::{
+    any::Any,
+    fmt,
+    io::{Error, ErrorKind::InvalidInput},
+    time::{Duration, Instant},
+};
+
+///
+/// Emails a digest of the log entries at, or above, the trigger level
+/// (default: `Level::WARNING`) to the recipients, via the SMTP relay whose
+/// `host[:port]` was provided during initialization. Log entries below the
+/// trigger level are ignored.
+///
+/// Log entries are collected into a digest. The digest is sent when:
+///
+/// - it holds `batch_size` entries (default: 50),
+/// - an entry is published after `flush_interval` (default: 15 minutes) has
+///   passed since the last digest was sent,
+/// - [`flush()`][EmailHandler::flush] or [`close()`][EmailHandler::close] is called.
+///
+/// The connection to the relay uses TLS. Without a port, the standard port
+/// for implicit TLS (`465`) is used.
+///
+/// As sending blocks the thread doing the logging, consider using
+/// [`LoggerBuilder::set_async()`] with this handler.
+///
+/// Requires the `email` feature.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// let mut h = EmailHandler::create("smtp.example.com").unwrap();
+/// h.set_from("app@example.com").unwrap();
+/// h.add_recipient("ops@example.com").unwrap();
+/// h.set_subject("app alerts");
+/// h.set_test_mode(true);
+///
+/// let mut log = Logger::builder("app")
+///     .add_custom_handler("Alerts", Box::new(h))
+///     .set_fn_name("main")
+///     .build();
+///
+/// log.info("Ignored.");
+/// log.warning("Disk 90% full.");
+/// log.severe("Disk full!");
+///
+/// let h = log.get_handler_as::<EmailHandler>().unwrap();
+/// h.flush();
+/// assert_eq!(
+///     h.get_log(),
+///     "Subject: app alerts (2 entries)\n\
+///      app->main [WARNING] Disk 90% full.\n\
+///      app->main [SEVERE ] Disk full!\n\n"
+/// );
+/// ```
+///
+#[derive(Debug)]
+pub struct EmailHandler {
+    host: String,
+    port: Option<u16>,
+    credentials: Option<(String, String)>,
+    from: Option<Mailbox>,
+    recipients: Vec<Mailbox>,
+    subject: String,
+    formatter: Formatter,
+    filter: Option<Box<dyn FilterTrait>>,
+    trigger: Level,
+    batch: Vec<String>,
+    batch_size: usize,
+    flush_interval: Duration,
+    last_flush: Instant,
+    open: bool,
+    writer: Option<Vec<u8>>,
+    stats: HandlerStats,
+}
+
+impl EmailHandler {
+    fn _create(relay: &str) -> Result<Self, Error> {
+        let (host, port) = match relay.rsplit_once(':') {
+            Some((host, port)) => {
+                let port = port.parse::<u16>().map_err(|_| {
+                    Error::new(
+                        InvalidInput,
+                        format!("'relay' has an invalid port: {relay}"),
+                    )
+                })?;
+
+                (host, Some(port))
+            }
+            None => (relay, None),
+        };
+
+        if host.is_empty() {
+            return Err(Error::new(
+                InvalidInput,
+                format!("'relay' must be 'host[:port]': {relay}"),
+            ));
+        }
+
+        Ok(EmailHandler {
+            host: host.to_string(),
+            port,
+            credentials: None,
+            from: None,
+            recipients: Vec::new(),
+            subject: "Log digest".to_string(),
+            formatter: FormatType::Simple.create(None),
+            filter: None,
+            trigger: Level::WARNING,
+            batch: Vec::new(),
+            batch_size: 50,
+            flush_interval: Duration::from_secs(15 * 60),
+            last_flush: Instant::now(),
+            open: true,
+            writer: None,
+            stats: HandlerStats::default(),
+        })
+    }
+
+    fn log(&self) -> String {
+        if let Some(w) = self.writer.to_owned() {
+            String::from_utf8(w).unwrap()
+        } else {
+            String::new()
+        }
+    }
+
+    ///
+    /// Add a recipient of the digests.
+    ///
+    /// ## Parameters
+    /// - `to` - The email address, such as: `ops@example.com`, or
+    ///   `Ops <ops@example.com>`.
+    ///
+    pub fn add_recipient(&mut self, to: &str) -> Result<(), Error> {
+        self.recipients.push(mailbox(to)?);
+        Ok(())
+    }
+
+    ///
+    /// Set the number of entries that triggers sending a digest.
+    ///
+    /// ## Parameters
+    /// - `batch_size` - The number of entries.
+    ///
+    pub fn set_batch_size(&mut self, batch_size: usize) {
+        self.batch_size = batch_size.max(1);
+    }
+
+    ///
+    /// Set the credentials used to log in to the relay.
+    ///
+    /// ## Parameters
+    /// - `username` - The user name.
+    /// - `password` - The password.
+    ///
+    pub fn set_credentials(&mut self, username: &str, password: &str) {
+        self.credentials = Some((username.to_string(), password.to_string()));
+    }
+
+    ///
+    /// Set the maximum time a digest is held, before it is sent on the next publish.
+    ///
+    /// ## Parameters
+    /// - `flush_interval` - The time between digests.
+    ///
+    pub fn set_flush_interval(&mut self, flush_interval: Duration) {
+        self.flush_interval = flush_interval;
+    }
+
+    ///
+    /// Set the sender of the digests.
+    ///
+    /// ## Parameters
+    /// - `from` - The email address, such as: `app@example.com`, or
+    ///   `My App <app@example.com>`.
+    ///
+    pub fn set_from(&mut self, from: &str) -> Result<(), Error> {
+        self.from = Some(mailbox(from)?);
+        Ok(())
+    }
+
+    ///
+    /// Set the subject of the digests. The number of entries is appended to it.
+    ///
+    /// ## Parameters
+    /// - `subject` - The subject.
+    ///
+    pub fn set_subject(&mut self, subject: &str) {
+        self.subject = subject.to_string();
+    }
+
+    ///
+    /// Set the trigger level. Only log entries at, or above, it are sent.
+    ///
+    /// ## Parameters
+    /// - `trigger` - The trigger level.
+    ///
+    pub fn set_trigger(&mut self, trigger: Level) {
+        self.trigger = trigger;
+    }
+
+    fn post(&self, subject: &str, body: String) -> Result<(), Box<dyn std::error::Error>> {
+        let from = self.from.clone().ok_or("no sender set")?;
+        let mut builder = Message::builder()
+            .from(from)
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN);
+
+        for to in &self.recipients {
+            builder = builder.to(to.clone());
+        }
+
+        let message = builder.body(body)?;
+        let mut transport = SmtpTransport::relay(&self.host)?;
+
+        if let Some(port) = self.port {
+            transport = transport.port(port);
+        }
+
+        if let Some((username, password)) = &self.credentials {
+            transport = transport.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        transport.build().send(&message)?;
+        Ok(())
+    }
+
+    ///
+    /// Send the current batch, as a digest.
+    ///
+    fn send(&mut self) {
+        self.last_flush = Instant::now();
+
+        if self.batch.is_empty() {
+            return;
+        }
+
+        let count = self.batch.len() as u64;
+        let subject = format!("{} ({count} entries)", self.subject);
+        let mut body = self.batch.join("\n");
+        body.push('\n');
+        self.batch.clear();
+
+        if let Some(w) = self.writer.as_mut() {
+            let text = format!("Subject: {subject}\n{body}\n");
+            w.extend_from_slice(text.as_bytes());
+            self.stats.wrote(text.len());
+            return;
+        }
+
+        let len = body.len();
+
+        match self.post(&subject, body) {
+            Ok(()) => self.stats.wrote(len),
+            Err(e) => self.stats.error(e, count),
+        }
+    }
+}
+
+///
+/// Parse an email address.
+///
+fn mailbox(address: &str) -> Result<Mailbox, Error> {
+    address.parse::<Mailbox>().map_err(|e| {
+        Error::new(
+            InvalidInput,
+            format!("invalid email address: {address}: {e}"),
+        )
+    })
+}
+
+impl fmt::Display for EmailHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let to: Vec<String> = self.recipients.iter().map(|m| m.to_string()).collect();
+
+        match self.port {
+            Some(port) => write!(f, "{}:{port}", self.host)?,
+            None => write!(f, "{}", self.host)?,
+        }
+
+        write!(
+            f,
+            " ({}, [{}]) : {}",
+            self.trigger,
+            to.join(", "),
+            self.formatter
+        )
+    }
+}
+
+impl HandlerTrait for EmailHandler {
+    ///
+    /// Create a new handler instance.
+    ///
+    /// ## Parameters
+    /// - `name` - The SMTP relay: `host[:port]`.
+    ///
+    fn create(name: &str) -> Result<Self, Error> {
+        EmailHandler::_create(name)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    ///
+    /// Sends any remaining entries, then closes the handler.
+    ///
+    fn close(&mut self) {
+        self.flush();
+        self.open = false;
+    }
+
+    ///
+    /// Sends the current digest.
+    ///
+    fn flush(&mut self) {
+        if self.open {
+            self.send();
+        }
+    }
+
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
+    fn get_formatter(&self) -> Formatter {
+        self.formatter.clone()
+    }
+
+    fn get_log(&self) -> String {
+        self.log()
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn publish(&mut self, log_entry: &LogEntry) {
+        if log_entry.level < self.trigger {
+            return;
+        }
+
+        self.stats.published += 1;
+
+        if !self.open {
+            self.stats.dropped += 1;
+            return;
+        }
+
+        self.batch.push(self.formatter.format(log_entry));
+
+        if self.batch.len() >= self.batch_size || self.last_flush.elapsed() >= self.flush_interval {
+            self.send();
+        }
+    }
+
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
+    }
+
+    fn set_formatter(&mut self, formatter: Formatter) {
+        self.formatter = formatter;
+    }
+
+    ///
+    /// Sets the test mode to `state`.
+    ///
+    /// If set to `true`, the subject and body of each digest are stored instead
+    /// of being sent. Use `get_log()` to obtain them.
+    ///
+    fn set_test_mode(&mut self, state: bool) {
+        if state {
+            self.writer = Some(Vec::new());
+        } else {
+            self.writer = None;
+        }
+    }
+
+    ///
+    /// Only the log entries at, or above, the trigger level are counted.
+    ///
+    fn stats(&self) -> HandlerStats {
+        self.stats.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn batch_size() {
+        let mut log = Logger::builder(module_path!())
+            .add_email_handler("127.0.0.1:2525", "app@example.com", &["ops@example.com"])
+            .set_fn_name("batch_size")
+            .build();
+
+        let h = log.get_handler_as::<EmailHandler>().unwrap();
+        h.set_test_mode(true);
+        h.set_batch_size(2);
+        h.set_subject("alerts");
+
+        assert_eq!(
+            h.to_string(),
+            "127.0.0.1:2525 (WARNING, [ops@example.com]) : dt_fmt: \"\" - fmt_string: \"{mod_path}->{fn_name} [{level:7}] {message}\""
+        );
+
+        log.info("Ignored.");
+        log.warning("one");
+        log.severe("two");
+        log.warning("three");
+
+        let h = log.get_handler(Handler::Email).unwrap();
+
+        assert_eq!(
+            h.get_log(),
+            "Subject: alerts (2 entries)
+flogging::handlers::email_handler::tests->batch_size [WARNING] one
+flogging::handlers::email_handler::tests->batch_size [SEVERE ] two\n\n"
+        );
+
+        let h = log.get_handler_as::<EmailHandler>().unwrap();
+        h.close();
+
+        assert!(h.get_log().ends_with(
+            "Subject: alerts (1 entries)
+flogging::handlers::email_handler::tests->batch_size [WARNING] three\n\n"
+        ));
+        assert_eq!(h.stats().published(), 3);
+    }
+
+    #[test]
+    fn no_sender() {
+        let mut h = EmailHandler::create("127.0.0.1:2525").unwrap();
+        h.add_recipient("ops@example.com").unwrap();
+        h.publish(&LogEntry::create(
+            Level::SEVERE,
+            "f".to_string(),
+            String::new(),
+        ));
+        h.flush();
+
+        assert_eq!(h.stats().dropped(), 1);
+        assert_eq!(h.stats().last_error(), Some("no sender set"));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid email address: not an address")]
+    fn bad_address() {
+        let _ = Logger::builder(module_path!())
+            .add_email_handler("smtp.example.com", "not an address", &[])
+            .build();
+    }
+
+    #[test]
+    #[should_panic(expected = "'relay' has an invalid port")]
+    fn bad_port() {
+        let _ = Logger::builder(module_path!())
+            .add_email_handler("smtp.example.com:smtp", "app@example.com", &[])
+            .build();
+    }
+}
@@ -26,27 +26,362 @@
 //!
 
 use std::{
+    any::Any,
     fmt,
-    fs::{File, exists},
-    io::{Error, ErrorKind::InvalidInput, Write},
+    fs::{self, File, Metadata, exists},
+    io::{BufWriter, Error, ErrorKind::InvalidInput, Write},
+    time::{Duration, Instant},
 };
 
+use super::formatters::ansi;
 use crate::*;
 
+///
+/// Configures when a [`FileHandler`] flushes its buffer to the file.
+///
+/// The buffer is flushed when any one of these is met:
+/// - `every` log entries have been written since the last flush,
+/// - an entry is written after `interval` has passed since the last flush,
+/// - an entry at, or above, `level` is written.
+///
+/// The default is to flush after every entry, so that the file is always up to date.
+/// During heavy tracing, flushing less often saves a lot of system calls.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+/// use std::time::Duration;
+///
+/// let policy = FlushPolicy::new(1000)
+///     .interval(Duration::from_millis(500))
+///     .level(Level::WARNING);
+///
+/// assert_eq!(policy.every(), 1000);
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlushPolicy {
+    every: usize,
+    interval: Option<Duration>,
+    level: Option<Level>,
+}
+
+impl FlushPolicy {
+    ///
+    /// Creates a new `FlushPolicy`.
+    ///
+    /// ## Parameters
+    /// - `every` - The number of log entries written between flushes. `0` is taken as `1`.
+    ///
+    pub fn new(every: usize) -> Self {
+        FlushPolicy {
+            every: every.max(1),
+            interval: None,
+            level: None,
+        }
+    }
+
+    ///
+    /// Also flush when an entry is written after `interval` has passed since the last flush.
+    ///
+    /// ## Parameters
+    /// - `interval` - The time between flushes.
+    ///
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    ///
+    /// Also flush when an entry at, or above, `level` is written.
+    ///
+    /// ## Parameters
+    /// - `level` - The lowest level that is flushed straight away.
+    ///
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    ///
+    /// Returns the number of log entries written between flushes.
+    ///
+    pub fn every(&self) -> usize {
+        self.every
+    }
+
+    ///
+    /// Returns `true` if the buffer is due to be flushed.
+    ///
+    /// ## Parameters
+    /// - `pending` - The number of log entries written since the last flush.
+    /// - `level` - The level of the log entry just written.
+    /// - `last_flush` - When the last flush was, if any.
+    ///
+    fn is_due(&self, pending: usize, level: Level, last_flush: Option<Instant>) -> bool {
+        pending >= self.every
+            || self.level.is_some_and(|l| level >= l)
+            || self
+                .interval
+                .is_some_and(|i| last_flush.is_none_or(|t| t.elapsed() >= i))
+    }
+}
+
+impl Default for FlushPolicy {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+///
+/// Configures when a [`FileHandler`] syncs its file to the disk, with `fsync()`.
+///
+/// Syncing is what makes the log entries durable, should the system crash. It is
+/// also very expensive. So this trades durability for throughput. Whatever the
+/// policy, the buffer is first flushed to the file, and the operating system
+/// writes it to the disk in its own time.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+/// use std::time::Duration;
+///
+/// let mut log = Logger::file_logger(module_path!(), "test_logs/sync_policy.log");
+///
+/// log.get_handler_as::<FileHandler>()
+///     .unwrap()
+///     .set_sync_policy(SyncPolicy::Interval(Duration::from_secs(5)));
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum SyncPolicy {
+    ///
+    /// Never sync. Leave it to the operating system.
+    ///
+    Never,
+    ///
+    /// Sync on each [`flush()`][FileHandler::flush], and on [`close()`][FileHandler::close].
+    /// This is the default.
+    ///
+    #[default]
+    OnFlush,
+    ///
+    /// Sync only on [`close()`][FileHandler::close].
+    ///
+    OnClose,
+    ///
+    /// Sync after writing each log entry at, or above, the level, such as `SEVERE`,
+    /// and on close.
+    ///
+    AtLevel(Level),
+    ///
+    /// Sync after writing a log entry, if the interval has passed since the last sync,
+    /// and on close.
+    ///
+    Interval(Duration),
+}
+
+impl SyncPolicy {
+    ///
+    /// Returns `true` if the file is due to be synced, after writing a log entry.
+    ///
+    /// ## Parameters
+    /// - `level` - The level of the log entry just written.
+    /// - `last_sync` - When the last sync was, if any.
+    ///
+    pub(crate) fn is_due(&self, level: Level, last_sync: Option<Instant>) -> bool {
+        match self {
+            SyncPolicy::AtLevel(l) => level >= *l,
+            SyncPolicy::Interval(i) => last_sync.is_none_or(|t| t.elapsed() >= *i),
+            _ => false,
+        }
+    }
+}
+
+///
+/// What a [`FileHandler`] does with an existing file, when it first opens it.
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OpenMode {
+    ///
+    /// Append to it. This is the default.
+    ///
+    #[default]
+    Append,
+    ///
+    /// Truncate it, so each run starts with a clean file.
+    ///
+    Truncate,
+    ///
+    /// Fail, with `ErrorKind::AlreadyExists`.
+    ///
+    CreateNew,
+}
+
+///
+/// Configures how a [`FileHandler`] opens its file.
+///
+/// The [`OpenMode`] decides what happens to an existing file. It only applies the
+/// first time the file is opened. When [reopened][FileHandler::reopen], the file
+/// is always appended to.
+///
+/// On Unix, the file's permissions and owner can be set. As logs often contain
+/// sensitive data, they need not be left to the `umask`. The permissions are set
+/// each time the file is opened, whether or not it already exists.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// let options = FileOptions::new().open_mode(OpenMode::Truncate);
+/// #[cfg(unix)]
+/// let options = options.mode(0o600);
+///
+/// let log = Logger::builder(module_path!())
+///     .set_file_options(options)
+///     .add_file_handler("test_logs/file_options.log")
+///     .build();
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileOptions {
+    open_mode: OpenMode,
+    #[cfg(unix)]
+    mode: Option<u32>,
+    #[cfg(unix)]
+    owner: Option<(Option<u32>, Option<u32>)>,
+}
+
+impl FileOptions {
+    ///
+    /// Creates a new `FileOptions`, with the defaults.
+    ///
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    ///
+    /// Set what to do with an existing file.
+    ///
+    /// ## Parameters
+    /// - `open_mode` - Append to it, truncate it, or fail.
+    ///
+    pub fn open_mode(mut self, open_mode: OpenMode) -> Self {
+        self.open_mode = open_mode;
+        self
+    }
+
+    ///
+    /// Set the permissions of the file, such as `0o600`, regardless of the `umask`.
+    ///
+    /// Only available on Unix.
+    ///
+    /// ## Parameters
+    /// - `mode` - The permission bits.
+    ///
+    #[cfg(unix)]
+    pub fn mode(mut self, mode: u32) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    ///
+    /// Set the owner and/or group of the file. This usually requires privileges.
+    ///
+    /// Only available on Unix.
+    ///
+    /// ## Parameters
+    /// - `uid` - The user ID, or `None` to leave it as is.
+    /// - `gid` - The group ID, or `None` to leave it as is.
+    ///
+    #[cfg(unix)]
+    pub fn owner(mut self, uid: Option<u32>, gid: Option<u32>) -> Self {
+        self.owner = Some((uid, gid));
+        self
+    }
+
+    ///
+    /// Returns what is done with an existing file.
+    ///
+    pub fn get_open_mode(&self) -> OpenMode {
+        self.open_mode
+    }
+
+    ///
+    /// Returns the permissions of the file, if set.
+    ///
+    #[cfg(unix)]
+    pub fn get_mode(&self) -> Option<u32> {
+        self.mode
+    }
+}
+
 ///
 /// Publishes log entries to the file whose name was provided during
 /// initialization.
 ///
-#[derive(Debug, Default)]
+/// The log entries are written through a buffer, which is flushed according to
+/// its [`FlushPolicy`], and by [`flush()`][FileHandler::flush] and [`close()`][FileHandler::close].
+///
+/// An I/O error, such as a full disk, does not panic. Instead, the file is closed,
+/// so [`is_open()`][FileHandler::is_open] returns `false`, and the error is recorded
+/// in the [stats][FileHandler::stats], along with the log entries lost. The error is
+/// also passed to the callback set with [`set_on_error()`][FileHandler::set_on_error],
+/// if any. The later log entries are dropped, until the file is [reopened][FileHandler::reopen].
+///
+/// If the file is rotated by another program, such as `logrotate`, call [`reopen()`][FileHandler::reopen]
+/// afterwards, perhaps on `SIGHUP`. Or [watch][FileHandler::set_watch] for it, so the file
+/// is reopened as needed, rather than writing to the moved, or deleted, file forever.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// let mut log = Logger::file_logger(module_path!(), "test_logs/file_handler_on_error.log");
+///
+/// log.get_handler_as::<FileHandler>()
+///     .unwrap()
+///     .set_on_error(|e| eprintln!("Logging to file failed: {e}"));
+///
+/// log.info("Written, or reported on stderr.");
+/// ```
+///
+#[derive(Default)]
 pub struct FileHandler {
     filename: String,
     formatter: Formatter,
-    file: Option<File>,
+    filter: Option<Box<dyn FilterTrait>>,
+    file: Option<BufWriter<File>>,
+    options: FileOptions,
+    flush_policy: FlushPolicy,
+    pending: usize,
+    last_flush: Option<Instant>,
+    sync_policy: SyncPolicy,
+    last_sync: Option<Instant>,
+    on_error: Option<Box<dyn FnMut(&Error) + Send + Sync>>,
+    watch: bool,
     writer: Option<Vec<u8>>,
+    line: String,
+    stats: HandlerStats,
 }
 
 impl FileHandler {
     fn _create(filename: &str) -> Result<Self, Error> {
+        FileHandler::with_options(filename, FileOptions::default())
+    }
+
+    ///
+    /// Create a new handler instance, opening the file with `options`.
+    ///
+    /// ## Parameters
+    /// - `filename` - The name of the log file.
+    /// - `options` - How to open it.
+    ///
+    pub fn with_options(filename: &str, options: FileOptions) -> Result<Self, Error> {
         if filename.is_empty() {
             return Err(Error::new(InvalidInput, "'filename' must not be empty"));
         }
@@ -54,11 +389,19 @@ impl FileHandler {
         let fh = FileHandler {
             filename: filename.to_string(),
             formatter: FormatType::Iso8601.create(None),
-            file: {
-                let f = File::options().append(true).create(true).open(filename)?;
-                Some(f)
-            },
+            filter: None,
+            file: Some(open(filename, &options)?),
+            options,
+            flush_policy: FlushPolicy::default(),
+            pending: 0,
+            last_flush: None,
+            sync_policy: SyncPolicy::default(),
+            last_sync: None,
+            on_error: None,
+            watch: false,
             writer: None,
+            line: String::new(),
+            stats: HandlerStats::default(),
         };
 
         Ok(fh)
@@ -71,6 +414,241 @@ impl FileHandler {
             String::new()
         }
     }
+
+    ///
+    /// Returns the flush policy.
+    ///
+    pub fn flush_policy(&self) -> &FlushPolicy {
+        &self.flush_policy
+    }
+
+    ///
+    /// Flush the buffer, then close and reopen the file, by name.
+    ///
+    /// Use it after the file has been rotated by another program. It also reopens
+    /// the file after an I/O error, or [`close()`][FileHandler::close].
+    ///
+    /// Returns an error if the file cannot be opened. The handler is then closed.
+    ///
+    pub fn reopen(&mut self) -> Result<(), Error> {
+        self.flush_buffer();
+        self.file = None;
+
+        let options = FileOptions {
+            open_mode: OpenMode::Append,
+            ..self.options.clone()
+        };
+
+        self.file = Some(open(&self.filename, &options)?);
+        Ok(())
+    }
+
+    ///
+    /// Set the callback to be told of each I/O error.
+    ///
+    /// ## Parameters
+    /// - `on_error` - Called with the error, after the file has been closed.
+    ///
+    pub fn set_on_error<F>(&mut self, on_error: F)
+    where
+        F: FnMut(&Error) + Send + Sync + 'static,
+    {
+        self.on_error = Some(Box::new(on_error));
+    }
+
+    ///
+    /// Returns the sync policy.
+    ///
+    pub fn sync_policy(&self) -> &SyncPolicy {
+        &self.sync_policy
+    }
+
+    ///
+    /// Set the flush policy.
+    ///
+    /// ## Parameters
+    /// - `flush_policy` - The policy.
+    ///
+    pub fn set_flush_policy(&mut self, flush_policy: FlushPolicy) {
+        self.flush_policy = flush_policy;
+    }
+
+    ///
+    /// Set the sync policy.
+    ///
+    /// ## Parameters
+    /// - `sync_policy` - The policy.
+    ///
+    pub fn set_sync_policy(&mut self, sync_policy: SyncPolicy) {
+        self.sync_policy = sync_policy;
+    }
+
+    ///
+    /// Set whether to watch for the file being moved, or deleted, by another program.
+    ///
+    /// If `true`, before each log entry is written, the file's name is checked. If it
+    /// is missing, or refers to a different file (on Unix, by device and inode), then
+    /// the file is [reopened][FileHandler::reopen]. This costs a `stat()` per log entry.
+    ///
+    /// ## Parameters
+    /// - `watch` - `true` to watch.
+    ///
+    pub fn set_watch(&mut self, watch: bool) {
+        self.watch = watch;
+    }
+
+    ///
+    /// Returns `true` if the file's name no longer refers to the open file.
+    ///
+    fn is_rotated(&self) -> bool {
+        let Some(file) = &self.file else {
+            return false;
+        };
+
+        match (fs::metadata(&self.filename), file.get_ref().metadata()) {
+            (Ok(named), Ok(open)) => !same_file(&named, &open),
+            (Err(_), _) => true,
+            _ => false,
+        }
+    }
+
+    ///
+    /// Flush the buffer to the file, without syncing it to the disk.
+    ///
+    fn flush_buffer(&mut self) {
+        if let Some(Err(e)) = self.file.as_mut().map(|f| f.flush()) {
+            self.fail(e, self.pending as u64);
+        }
+
+        self.pending = 0;
+        self.last_flush = Some(Instant::now());
+    }
+
+    ///
+    /// Flush the buffer, then sync the file to the disk.
+    ///
+    fn sync(&mut self) {
+        self.flush_buffer();
+
+        if let Some(Err(e)) = self.file.as_ref().map(|f| f.get_ref().sync_all()) {
+            self.fail(e, 0);
+        }
+
+        self.last_sync = Some(Instant::now());
+    }
+
+    ///
+    /// Close the file, discarding its buffer, then record and report `error`.
+    ///
+    /// ## Parameters
+    /// - `error` - The I/O error.
+    /// - `dropped` - The number of log entries lost.
+    ///
+    fn fail(&mut self, error: Error, dropped: u64) {
+        if let Some(f) = self.file.take() {
+            let _ = f.into_parts();
+        }
+
+        self.stats.error(&error, dropped);
+
+        if let Some(on_error) = self.on_error.as_mut() {
+            on_error(&error);
+        }
+    }
+}
+
+///
+/// Open `filename`, creating it if needed, as set by `options`.
+///
+fn open(filename: &str, options: &FileOptions) -> Result<BufWriter<File>, Error> {
+    let mut open_options = File::options();
+
+    match options.open_mode {
+        OpenMode::Append => open_options.append(true).create(true),
+        OpenMode::Truncate => open_options.write(true).truncate(true).create(true),
+        OpenMode::CreateNew => open_options.write(true).create_new(true),
+    };
+
+    // A new file is created with the mode, so it is never more open than that.
+    #[cfg(unix)]
+    if let Some(mode) = options.mode {
+        use std::os::unix::fs::OpenOptionsExt;
+
+        open_options.mode(mode);
+    }
+
+    let file = open_options.open(filename)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{PermissionsExt, fchown};
+
+        // An existing file keeps its own permissions, and a new one loses any bits
+        // masked by the `umask`. So they are set, if they differ.
+        if let Some(mode) = options.mode {
+            let mut permissions = file.metadata()?.permissions();
+
+            if permissions.mode() & 0o7777 != mode {
+                permissions.set_mode(mode);
+                file.set_permissions(permissions)?;
+            }
+        }
+
+        if let Some((uid, gid)) = options.owner {
+            fchown(&file, uid, gid)?;
+        }
+    }
+
+    Ok(BufWriter::new(file))
+}
+
+///
+/// Returns `true` if `a` and `b` are of the same file.
+///
+#[cfg(unix)]
+fn same_file(a: &Metadata, b: &Metadata) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    a.dev() == b.dev() && a.ino() == b.ino()
+}
+
+///
+/// Returns `true`, as the file's identity is not available. Only its absence is noticed.
+///
+#[cfg(not(unix))]
+fn same_file(_a: &Metadata, _b: &Metadata) -> bool {
+    true
+}
+
+impl fmt::Debug for FileHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileHandler")
+            .field("filename", &self.filename)
+            .field("formatter", &self.formatter)
+            .field("filter", &self.filter)
+            .field("file", &self.file)
+            .field("options", &self.options)
+            .field("flush_policy", &self.flush_policy)
+            .field("pending", &self.pending)
+            .field("last_flush", &self.last_flush)
+            .field("sync_policy", &self.sync_policy)
+            .field("last_sync", &self.last_sync)
+            .field("watch", &self.watch)
+            .field("writer", &self.writer)
+            .field("stats", &self.stats)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for FileHandler {
+    ///
+    /// Closes the file, if still open, so that the tail of the log is not lost.
+    ///
+    fn drop(&mut self) {
+        if self.is_open() {
+            self.close();
+        }
+    }
 }
 
 impl fmt::Display for FileHandler {
@@ -90,22 +668,44 @@ impl HandlerTrait for FileHandler {
         FileHandler::_create(name)
     }
 
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
     ///
     /// Flushes and closes the file.\
     /// Also, removes the internal buffer, if in `test_mode`.\
     /// Will therefore, no longer be *in* `test_mode`.
     ///
     fn close(&mut self) {
-        self.flush();
+        match self.sync_policy {
+            SyncPolicy::Never => self.flush_buffer(),
+            _ => self.sync(),
+        }
+
         self.file = None;
+        self.writer = None;
     }
 
+    ///
+    /// Flushes the buffer to the file, then syncs it to the disk, if the
+    /// [`SyncPolicy`] is `OnFlush`.
+    ///
     fn flush(&mut self) {
-        if let Some(f) = &self.file {
-            f.sync_all().expect("sync_all failed");
+        match self.sync_policy {
+            SyncPolicy::OnFlush => self.sync(),
+            _ => self.flush_buffer(),
         }
     }
 
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
     fn get_formatter(&self) -> Formatter {
         self.formatter.clone()
     }
@@ -119,20 +719,56 @@ impl HandlerTrait for FileHandler {
     }
 
     fn publish(&mut self, log_entry: &LogEntry) {
-        if self.is_open() {
-            let mut buf = self.formatter.format(log_entry);
-            buf.push('\n');
-
-            if let Some(w) = self.writer.as_mut() {
-                writeln!(w, "{}", self.formatter.format(log_entry)).expect("writeln!() failed");
-            } else {
-                self.file
-                    .as_mut()
-                    .unwrap()
-                    .write_all(buf.as_bytes())
-                    .expect("write_all() failed");
+        self.stats.published += 1;
+
+        if self.watch && self.writer.is_none() && self.is_rotated() {
+            if let Err(e) = self.reopen() {
+                self.fail(e, 0);
             }
         }
+
+        let Some(file) = self.file.as_mut() else {
+            self.stats.dropped += 1;
+            return;
+        };
+
+        let buf = &mut self.line;
+        buf.clear();
+        self.formatter.format_into(log_entry, buf);
+
+        if buf.contains('\x1b') {
+            *buf = ansi::strip(buf).into_owned();
+        }
+
+        buf.push('\n');
+        let len = buf.len();
+
+        if let Some(w) = self.writer.as_mut() {
+            w.extend_from_slice(buf.as_bytes());
+            self.stats.wrote(len);
+            return;
+        }
+
+        if let Err(e) = file.write_all(buf.as_bytes()) {
+            self.fail(e, self.pending as u64 + 1);
+            return;
+        }
+
+        self.stats.wrote(len);
+        self.pending += 1;
+
+        if self.sync_policy.is_due(log_entry.level, self.last_sync) {
+            self.sync();
+        } else if self
+            .flush_policy
+            .is_due(self.pending, log_entry.level, self.last_flush)
+        {
+            self.flush_buffer();
+        }
+    }
+
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
     }
 
     fn set_formatter(&mut self, formatter: Formatter) {
@@ -153,14 +789,21 @@ impl HandlerTrait for FileHandler {
             self.writer = None;
         }
     }
+
+    fn stats(&self) -> HandlerStats {
+        self.stats.clone()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::*;
     use std::{
-        fs::File,
-        io::{Error, Read, Result},
+        fmt,
+        fs::{self, File},
+        io::{Error, ErrorKind, Read, Result},
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
     };
 
     #[test]
@@ -188,6 +831,12 @@ mod tests {
         h.flush();
         h.close();
         log.exiting_with("This should get thrown away.");
+        log.warning("So should this.");
+
+        let stats = log.handler_stats(Handler::File).unwrap();
+        assert_eq!(stats.published(), 3);
+        assert_eq!(stats.dropped(), 1);
+        assert!(stats.bytes_written() > 0);
     }
 
     #[test]
@@ -273,6 +922,260 @@ flogging::handlers::file_handler::tests->file_handler_test_mode [WARNING] The sk
 
         h.flush();
         h.close();
+
+        assert!(!h.is_open());
+        assert!(h.get_log().is_empty());
+    }
+
+    #[test]
+    fn strips_ansi_codes() {
+        #[derive(Clone)]
+        struct ColoredFormatter;
+
+        impl fmt::Display for ColoredFormatter {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                "ColoredFormatter".fmt(f)
+            }
+        }
+
+        impl FormatTrait for ColoredFormatter {
+            fn format(&self, log_entry: &LogEntry) -> String {
+                self.ft_fmt(
+                    String::new(),
+                    "[{level_colored}] {message}".to_string(),
+                    log_entry,
+                )
+            }
+        }
+
+        let log = Logger::builder(module_path!())
+            .remove_file("test_logs/file_handler_strips_ansi_codes.log")
+            .add_file_handler_with(
+                "test_logs/file_handler_strips_ansi_codes.log",
+                FormatType::Custom,
+                Some(Box::new(ColoredFormatter)),
+            )
+            .build();
+
+        log.severe("No color here.");
+        drop(log);
+
+        assert_eq!(
+            fs::read_to_string("test_logs/file_handler_strips_ansi_codes.log").unwrap(),
+            "[SEVERE ] No color here.\n"
+        );
+    }
+
+    #[test]
+    fn flush_policy() {
+        let filename = "test_logs/file_handler_flush_policy.log";
+
+        let mut log = Logger::builder(module_path!())
+            .remove_file(filename)
+            .add_file_handler_with(filename, FormatType::Simple, None)
+            .set_fn_name("flush_policy")
+            .build();
+
+        let h = log.get_handler_as::<FileHandler>().unwrap();
+        assert_eq!(h.flush_policy(), &FlushPolicy::default());
+        h.set_flush_policy(FlushPolicy::new(3).level(Level::SEVERE));
+
+        log.info("one");
+        log.info("two");
+        assert_eq!(fs::read_to_string(filename).unwrap(), "");
+
+        log.info("three");
+        assert_eq!(fs::read_to_string(filename).unwrap().lines().count(), 3);
+
+        log.info("four");
+        log.severe("five");
+        assert_eq!(fs::read_to_string(filename).unwrap().lines().count(), 5);
+
+        log.info("six");
+        log.get_handler_as::<FileHandler>().unwrap().close();
+        assert_eq!(fs::read_to_string(filename).unwrap().lines().count(), 6);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn disk_full() {
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let reported = errors.clone();
+
+        let mut log = Logger::builder(module_path!())
+            .add_file_handler("/dev/full")
+            .build();
+
+        let h = log.get_handler_as::<FileHandler>().unwrap();
+        h.set_on_error(move |e| reported.lock().unwrap().push(e.kind()));
+
+        log.info("No space left.");
+        log.info("Dropped.");
+
+        let h = log.get_handler_as::<FileHandler>().unwrap();
+        assert!(!h.is_open());
+        assert_eq!(*errors.lock().unwrap(), [ErrorKind::StorageFull]);
+
+        let stats = h.stats();
+        assert_eq!(stats.published(), 2);
+        assert_eq!(stats.dropped(), 2);
+        assert!(stats.last_error().is_some());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn options() {
+        use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+        let filename = "test_logs/file_handler_options.log";
+        let mode = |name| fs::metadata(name).unwrap().permissions().mode() & 0o777;
+
+        let log = Logger::builder(module_path!())
+            .remove_file(filename)
+            .set_file_options(FileOptions::new().mode(0o600))
+            .add_file_handler(filename)
+            .build();
+        drop(log);
+
+        assert_eq!(mode(filename), 0o600);
+
+        fs::set_permissions(filename, fs::Permissions::from_mode(0o644)).unwrap();
+        let uid = fs::metadata(filename).unwrap().uid();
+        let options = FileOptions::new().mode(0o640).owner(Some(uid), None);
+        assert_eq!(options.get_mode(), Some(0o640));
+
+        let h = FileHandler::with_options(filename, options).unwrap();
+        assert!(h.is_open());
+        assert_eq!(mode(filename), 0o640);
+    }
+
+    #[test]
+    fn sync_policy() {
+        let policy = SyncPolicy::AtLevel(Level::SEVERE);
+        assert!(!policy.is_due(Level::WARNING, None));
+        assert!(policy.is_due(Level::SEVERE, None));
+
+        let policy = SyncPolicy::Interval(Duration::from_secs(60));
+        assert!(policy.is_due(Level::FINEST, None));
+        assert!(!policy.is_due(Level::FINEST, Some(Instant::now())));
+        assert!(!SyncPolicy::OnClose.is_due(Level::SEVERE, None));
+
+        let filename = "test_logs/file_handler_sync_policy.log";
+
+        let mut log = Logger::builder(module_path!())
+            .remove_file(filename)
+            .add_file_handler(filename)
+            .build();
+
+        let h = log.get_handler_as::<FileHandler>().unwrap();
+        assert_eq!(h.sync_policy(), &SyncPolicy::OnFlush);
+        h.set_sync_policy(SyncPolicy::Never);
+        h.set_flush_policy(FlushPolicy::new(10));
+
+        log.info("Buffered.");
+
+        let h = log.get_handler_as::<FileHandler>().unwrap();
+        h.flush();
+        assert_eq!(fs::read_to_string(filename).unwrap().lines().count(), 1);
+    }
+
+    #[test]
+    fn flush_on_drop() {
+        let filename = "test_logs/file_handler_flush_on_drop.log";
+
+        let mut log = Logger::builder(module_path!())
+            .remove_file(filename)
+            .add_file_handler(filename)
+            .build();
+
+        let h = log.get_handler_as::<FileHandler>().unwrap();
+        h.set_flush_policy(FlushPolicy::new(100));
+
+        log.info("one");
+        log.info("two");
+        assert_eq!(fs::read_to_string(filename).unwrap(), "");
+
+        drop(log);
+        assert_eq!(fs::read_to_string(filename).unwrap().lines().count(), 2);
+
+        let mut h = FileHandler::create(filename).unwrap();
+        h.set_flush_policy(FlushPolicy::new(100));
+        h.publish(&LogEntry::create(
+            Level::INFO,
+            "flush_on_drop".to_string(),
+            "three".to_string(),
+        ));
+
+        drop(h);
+        assert_eq!(fs::read_to_string(filename).unwrap().lines().count(), 3);
+    }
+
+    #[test]
+    fn open_mode() {
+        let filename = "test_logs/file_handler_open_mode.log";
+        fs::write(filename, "old run\n").unwrap();
+
+        let mut log = Logger::builder(module_path!())
+            .set_file_options(FileOptions::new().open_mode(OpenMode::Truncate))
+            .add_file_handler_with(filename, FormatType::Simple, None)
+            .set_fn_name("open_mode")
+            .build();
+
+        log.info("new run");
+
+        let h = log.get_handler_as::<FileHandler>().unwrap();
+        h.reopen().unwrap();
+        log.info("appended");
+        drop(log);
+
+        assert_eq!(
+            fs::read_to_string(filename).unwrap(),
+            "flogging::handlers::file_handler::tests->open_mode [INFO   ] new run
+flogging::handlers::file_handler::tests->open_mode [INFO   ] appended\n"
+        );
+
+        let err =
+            FileHandler::with_options(filename, FileOptions::new().open_mode(OpenMode::CreateNew))
+                .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rotated_externally() {
+        let filename = "test_logs/file_handler_rotated_externally.log";
+        let moved = "test_logs/file_handler_rotated_externally.log.1";
+        let _ = fs::remove_file(moved);
+
+        let mut log = Logger::builder(module_path!())
+            .remove_file(filename)
+            .add_file_handler_with(filename, FormatType::Simple, None)
+            .set_fn_name("rotated_externally")
+            .build();
+
+        log.get_handler_as::<FileHandler>().unwrap().set_watch(true);
+
+        log.info("one");
+        fs::rename(filename, moved).unwrap();
+        log.info("two");
+        fs::remove_file(filename).unwrap();
+        log.info("three");
+
+        let h = log.get_handler_as::<FileHandler>().unwrap();
+        h.set_watch(false);
+        fs::remove_file(filename).unwrap();
+        log.info("lost");
+
+        let h = log.get_handler_as::<FileHandler>().unwrap();
+        h.reopen().unwrap();
+        log.info("four");
+        drop(log);
+
+        assert!(fs::read_to_string(moved).unwrap().ends_with("] one\n"));
+        assert_eq!(
+            fs::read_to_string(filename).unwrap(),
+            "flogging::handlers::file_handler::tests->rotated_externally [INFO   ] four\n"
+        );
     }
 
     #[test]
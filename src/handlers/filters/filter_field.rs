@@ -0,0 +1,123 @@
+//
+// File Name:    filter_field.rs
+// Directory:    src/handlers/filters
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # FilterField
+//!
+
+use crate::LogEntry;
+use std::{borrow::Cow, fmt};
+
+///
+/// Identifies the part of a [`LogEntry`] that a [`RegexFilter`][crate::RegexFilter]
+/// pattern is matched against.
+///
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum FilterField {
+    ///
+    /// The whole entry, formatted as:\
+    /// `{timestamp} : {mod_path}->{fn_name} ({level}) {message}`
+    ///
+    Entry,
+
+    ///
+    /// The name of the function/method inside which the entry was generated.
+    ///
+    FnName,
+
+    ///
+    /// The level of the entry, such as: `WARNING`.
+    ///
+    Level,
+
+    ///
+    /// The message text.
+    ///
+    #[default]
+    Message,
+
+    ///
+    /// The module path of the source of the entry.
+    ///
+    ModPath,
+}
+
+impl FilterField {
+    ///
+    /// Extract the text of this field from `log_entry`.
+    ///
+    /// ## Parameters
+    /// - `log_entry` - The `LogEntry` to extract the text from.
+    ///
+    pub fn text<'a>(&self, log_entry: &'a LogEntry) -> Cow<'a, str> {
+        match self {
+            FilterField::Entry => Cow::Owned(log_entry.to_string()),
+            FilterField::FnName => Cow::Borrowed(&log_entry.fn_name),
+            FilterField::Level => Cow::Borrowed(log_entry.level.as_str()),
+            FilterField::Message => Cow::Borrowed(&log_entry.message),
+            FilterField::ModPath => Cow::Borrowed(&log_entry.mod_path),
+        }
+    }
+
+    ///
+    /// Converts a filter field to its string version.
+    ///
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            FilterField::Entry => "Entry",
+            FilterField::FnName => "FnName",
+            FilterField::Level => "Level",
+            FilterField::Message => "Message",
+            FilterField::ModPath => "ModPath",
+        }
+    }
+}
+
+impl fmt::Display for FilterField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn text() {
+        let mut le = LogEntry::create(Level::WARNING, "text".to_string(), "Hello".to_string());
+        le.set_mod_path("app::net".to_string());
+
+        assert_eq!(FilterField::default(), FilterField::Message);
+        assert_eq!(FilterField::FnName.text(&le), "text");
+        assert_eq!(FilterField::Level.text(&le), "WARNING");
+        assert_eq!(FilterField::Message.text(&le), "Hello");
+        assert_eq!(FilterField::ModPath.text(&le), "app::net");
+        assert!(
+            FilterField::Entry
+                .text(&le)
+                .ends_with(" : app::net->text (WARNING) Hello")
+        );
+        assert_eq!(FilterField::ModPath.to_string(), "ModPath");
+    }
+}
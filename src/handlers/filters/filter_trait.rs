@@ -0,0 +1,103 @@
+//
+// File Name:    filter_trait.rs
+// Directory:    src/handlers/filters
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Filter Trait
+//!
+
+use crate::LogEntry;
+use std::fmt;
+
+///
+/// Provides fine-grained control over which [`LogEntry`]s are published
+/// by a handler.
+///
+/// A filter is attached to a handler with
+/// [`set_filter()`][crate::HandlerTrait::set_filter()], or when building the
+/// logger with one of the `LoggerBuilder::add_*_handler_with_filter()` methods.
+///
+/// It is consulted after the logger's level check, so it only sees those
+/// entries that are loggable.
+///
+/// Any closure of the form `Fn(&LogEntry) -> bool` is a `FilterTrait`.
+///
+/// ## Examples
+/// ```
+/// use flogging::*;
+///
+/// let mut log = Logger::builder("app")
+///     .add_string_handler_with_filter(Box::new(|le: &LogEntry| le.fn_name() == "connect"))
+///     .build();
+///
+/// log.set_fn_name("connect");
+/// log.info("Connected.");
+///
+/// log.set_fn_name("main");
+/// log.info("Started.");
+///
+/// let buf = log.get_handler(Handler::String).unwrap().get_log();
+///
+/// assert_eq!(buf, "app->connect [INFO   ] Connected.\n");
+/// ```
+///
+pub trait FilterTrait: Send + Sync {
+    ///
+    /// Check whether `log_entry` should be published.
+    ///
+    /// ## Parameters
+    /// - `log_entry` - A reference to the `LogEntry` to be checked.
+    ///
+    fn should_publish(&self, log_entry: &LogEntry) -> bool;
+}
+
+impl<F> FilterTrait for F
+where
+    F: Fn(&LogEntry) -> bool + Send + Sync,
+{
+    fn should_publish(&self, log_entry: &LogEntry) -> bool {
+        self(log_entry)
+    }
+}
+
+impl fmt::Debug for dyn FilterTrait {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FilterTrait")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn closure() {
+        let filter: Box<dyn FilterTrait> = Box::new(|le: &LogEntry| le.level() == Level::SEVERE);
+
+        let severe = LogEntry::create(Level::SEVERE, "closure".to_string(), "Bad".to_string());
+        let info = LogEntry::create(Level::INFO, "closure".to_string(), "Good".to_string());
+
+        assert!(filter.should_publish(&severe));
+        assert!(!filter.should_publish(&info));
+        assert_eq!(format!("{filter:?}"), "FilterTrait");
+    }
+}
@@ -0,0 +1,36 @@
+//
+// File Name:    mod.rs
+// Directory:    src/handlers/filters
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Filters
+//!
+
+mod filter_field;
+mod filter_trait;
+mod regex_filter;
+mod sample_filter;
+
+pub use filter_field::FilterField;
+pub use filter_trait::FilterTrait;
+pub use regex_filter::RegexFilter;
+pub use sample_filter::SampleFilter;
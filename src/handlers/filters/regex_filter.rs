@@ -0,0 +1,187 @@
+//
+// File Name:    regex_filter.rs
+// Directory:    src/handlers/filters
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Regex Filter
+//!
+
+use super::{FilterField, FilterTrait};
+use crate::LogEntry;
+use regex::Regex;
+use std::fmt;
+
+///
+/// A ready-made filter, using regular expressions to include and/or exclude
+/// [`LogEntry`]s.
+///
+/// Each pattern is matched against a [`FilterField`] of the log entry. An entry is
+/// published if:
+/// - there are no include patterns, or at least one of them matches, and
+/// - none of the exclude patterns match.
+///
+/// The patterns use the syntax of the [`regex`] crate.
+///
+/// ## Examples
+/// Drop the noisy heartbeat messages, from the `app::net` module only:
+/// ```
+/// use flogging::*;
+///
+/// let filter = RegexFilter::new()
+///     .include(FilterField::ModPath, "^app::net")
+///     .unwrap()
+///     .exclude(FilterField::Message, "(?i)heartbeat")
+///     .unwrap();
+///
+/// let mut log = Logger::builder("app::net")
+///     .add_string_handler_with_filter(Box::new(filter))
+///     .build();
+///
+/// log.info("Heartbeat: ok");
+/// log.info("Connected.");
+///
+/// let buf = log.get_handler(Handler::String).unwrap().get_log();
+///
+/// assert_eq!(buf, "app::net-> [INFO   ] Connected.\n");
+/// ```
+///
+#[derive(Debug, Default, Clone)]
+pub struct RegexFilter {
+    includes: Vec<(FilterField, Regex)>,
+    excludes: Vec<(FilterField, Regex)>,
+}
+
+impl RegexFilter {
+    ///
+    /// Creates a new instance of `RegexFilter`, without any patterns.
+    ///
+    /// As is, it publishes everything.
+    ///
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    ///
+    /// Adds an exclude pattern.
+    ///
+    /// ## Parameters
+    /// - `field` - The field of the log entry to match against.
+    /// - `pattern` - The regular expression.
+    ///
+    /// Returns an error if `pattern` is invalid.
+    ///
+    pub fn exclude(mut self, field: FilterField, pattern: &str) -> Result<Self, regex::Error> {
+        self.excludes.push((field, Regex::new(pattern)?));
+        Ok(self)
+    }
+
+    ///
+    /// Adds an include pattern.
+    ///
+    /// ## Parameters
+    /// - `field` - The field of the log entry to match against.
+    /// - `pattern` - The regular expression.
+    ///
+    /// Returns an error if `pattern` is invalid.
+    ///
+    pub fn include(mut self, field: FilterField, pattern: &str) -> Result<Self, regex::Error> {
+        self.includes.push((field, Regex::new(pattern)?));
+        Ok(self)
+    }
+}
+
+impl fmt::Display for RegexFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |patterns: &Vec<(FilterField, Regex)>| {
+            patterns
+                .iter()
+                .map(|(field, re)| format!("{field}: \"{re}\""))
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+
+        write!(
+            f,
+            "include: [{}] - exclude: [{}]",
+            list(&self.includes),
+            list(&self.excludes)
+        )
+    }
+}
+
+impl FilterTrait for RegexFilter {
+    fn should_publish(&self, log_entry: &LogEntry) -> bool {
+        let is_match = |(field, re): &(FilterField, Regex)| re.is_match(&field.text(log_entry));
+
+        (self.includes.is_empty() || self.includes.iter().any(is_match))
+            && !self.excludes.iter().any(is_match)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn entry(fn_name: &str, message: &str) -> LogEntry {
+        let mut le = LogEntry::create(Level::INFO, fn_name.to_string(), message.to_string());
+        le.set_mod_path("app::net".to_string());
+        le
+    }
+
+    #[test]
+    fn include_exclude() {
+        let filter = RegexFilter::new();
+        assert!(filter.should_publish(&entry("main", "Anything")));
+
+        let filter = RegexFilter::new()
+            .include(FilterField::FnName, "^(connect|send)$")
+            .unwrap()
+            .include(FilterField::Level, "WARNING")
+            .unwrap()
+            .exclude(FilterField::Message, "retry")
+            .unwrap();
+
+        assert!(filter.should_publish(&entry("connect", "Connected.")));
+        assert!(filter.should_publish(&entry("send", "Sent 10 bytes.")));
+        assert!(!filter.should_publish(&entry("send", "Will retry.")));
+        assert!(!filter.should_publish(&entry("main", "Started.")));
+
+        assert_eq!(
+            filter.to_string(),
+            "include: [FnName: \"^(connect|send)$\", Level: \"WARNING\"] - exclude: [Message: \"retry\"]"
+        );
+    }
+
+    #[test]
+    fn entry_field() {
+        let filter = RegexFilter::new()
+            .exclude(FilterField::Entry, r"app::net->\w+ \(INFO\)")
+            .unwrap();
+
+        assert!(!filter.should_publish(&entry("main", "Started.")));
+        assert!(
+            RegexFilter::new()
+                .include(FilterField::Message, "(")
+                .is_err()
+        );
+    }
+}
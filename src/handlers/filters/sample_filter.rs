@@ -0,0 +1,165 @@
+//
+// File Name:    sample_filter.rs
+// Directory:    src/handlers/filters
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Sample Filter
+//!
+
+use super::FilterTrait;
+use crate::{Level, LogEntry};
+use std::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+///
+/// A ready-made filter, keeping only every `n`th [`LogEntry`] below a level
+/// (default: `Level::WARNING`). Those at, or above, it are always kept.
+///
+/// The first of each `n` is kept. So, with `n` of `10`, the 1st, 11th, 21st, ...
+/// are kept. They are counted together, whatever their level.
+///
+/// Use it on a handler, with one of the `LoggerBuilder::add_*_handler_with_filter()`
+/// methods, or on the whole logger, with [`LoggerBuilder::set_sampling()`][crate::LoggerBuilder::set_sampling]
+/// or [`Logger::set_sampling()`][crate::Logger::set_sampling]. On the logger, the
+/// sampled log entries are noted with the field: `sampled=1/n`.
+///
+/// ## Examples
+/// ```
+/// use flogging::*;
+///
+/// let mut log = Logger::builder("app")
+///     .add_string_handler_with_filter(Box::new(SampleFilter::new(3)))
+///     .set_level(Level::FINEST)
+///     .set_fn_name("main")
+///     .build();
+///
+/// for n in 1..=5 {
+///     log.finest(&format!("Step {n}"));
+/// }
+///
+/// log.warning("Always kept.");
+///
+/// let buf = log.get_handler(Handler::String).unwrap().get_log();
+///
+/// assert_eq!(
+///     buf,
+///     "app->main [FINEST ] Step 1\n\
+///      app->main [FINEST ] Step 4\n\
+///      app->main [WARNING] Always kept.\n"
+/// );
+/// ```
+///
+#[derive(Debug)]
+pub struct SampleFilter {
+    n: u64,
+    below: Level,
+    count: AtomicU64,
+}
+
+impl SampleFilter {
+    ///
+    /// Creates a new instance of `SampleFilter`.
+    ///
+    /// ## Parameters
+    /// - `n` - Keep one in every `n` log entries below the level. `0` is taken as `1`.
+    ///
+    pub fn new(n: u64) -> Self {
+        SampleFilter {
+            n: n.max(1),
+            below: Level::WARNING,
+            count: AtomicU64::new(0),
+        }
+    }
+
+    ///
+    /// Set the level below which log entries are sampled.
+    ///
+    /// ## Parameters
+    /// - `level` - The lowest level that is always kept.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn below(mut self, level: Level) -> Self {
+        self.below = level;
+        self
+    }
+
+    ///
+    /// Returns the `n` in: keep one in every `n`.
+    ///
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    ///
+    /// Returns the note added to the sampled log entries, if `level` is sampled.
+    ///
+    pub(crate) fn note(&self, level: Level) -> Option<String> {
+        (level < self.below).then(|| format!("1/{}", self.n))
+    }
+
+    ///
+    /// Returns `true` if a log entry at `level` is to be kept.
+    ///
+    pub(crate) fn keep(&self, level: Level) -> bool {
+        level >= self.below || self.count.fetch_add(1, Ordering::Relaxed) % self.n == 0
+    }
+}
+
+impl fmt::Display for SampleFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "1/{} below {}", self.n, self.below)
+    }
+}
+
+impl FilterTrait for SampleFilter {
+    fn should_publish(&self, log_entry: &LogEntry) -> bool {
+        self.keep(log_entry.level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn below() {
+        let filter = SampleFilter::new(0).below(Level::INFO);
+        assert_eq!(filter.n(), 1);
+        assert_eq!(filter.to_string(), "1/1 below INFO");
+
+        let filter = SampleFilter::new(2).below(Level::INFO);
+        let fine = LogEntry::create(Level::FINE, "below".to_string(), String::new());
+        let info = LogEntry::create(Level::INFO, "below".to_string(), String::new());
+
+        let kept: Vec<bool> = [&fine, &info, &fine, &info, &fine]
+            .iter()
+            .map(|le| filter.should_publish(le))
+            .collect();
+
+        assert_eq!(kept, [true, true, false, true, true]);
+        assert_eq!(filter.note(Level::FINE).as_deref(), Some("1/2"));
+        assert_eq!(filter.note(Level::INFO), None);
+    }
+}
@@ -0,0 +1,112 @@
+//
+// File Name:    ansi.rs
+// Directory:    src/handlers/formatters
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # ANSI Colors
+//!
+//! The escape codes used to color log entries, by level, on a terminal.
+//!
+
+use crate::Level;
+use std::borrow::Cow;
+
+///
+/// Resets all attributes.
+///
+const RESET: &str = "\x1b[0m";
+
+///
+/// Returns the escape code that starts the color for `level`, or `""` if it has none.
+///
+/// A custom level has the color of the built-in level below it.
+///
+pub(crate) fn color(level: Level) -> &'static str {
+    match level {
+        Level::SEVERE => "\x1b[31m",
+        Level::WARNING => "\x1b[33m",
+        Level::INFO => "",
+        Level::CONFIG => "\x1b[36m",
+        Level::FINE | Level::FINER | Level::FINEST => "\x1b[2m",
+        Level::ALL | Level::OFF => "",
+        Level::Custom { .. } => color(level.base()),
+    }
+}
+
+///
+/// Returns `text` colored for `level`.
+///
+pub(crate) fn paint(level: Level, text: &str) -> String {
+    match color(level) {
+        "" => text.to_string(),
+        start => format!("{start}{text}{RESET}"),
+    }
+}
+
+///
+/// Returns `text` with any ANSI escape sequences removed.
+///
+/// Only allocates if there is something to remove.
+///
+pub(crate) fn strip(text: &str) -> Cow<'_, str> {
+    if !text.contains('\x1b') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            out.push(c);
+        } else if chars.next() == Some('[') {
+            // Control Sequence: parameters, and intermediates, up to the final byte.
+            for c in chars.by_ref() {
+                if ('\x40'..='\x7e').contains(&c) {
+                    break;
+                }
+            }
+        }
+    }
+
+    Cow::Owned(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paint_levels() {
+        assert_eq!(paint(Level::SEVERE, "oops"), "\x1b[31moops\x1b[0m");
+        assert_eq!(paint(Level::WARNING, "hmm"), "\x1b[33mhmm\x1b[0m");
+        assert_eq!(paint(Level::INFO, "fyi"), "fyi");
+        assert_eq!(paint(Level::FINER, "detail"), "\x1b[2mdetail\x1b[0m");
+    }
+
+    #[test]
+    fn strip_codes() {
+        assert!(matches!(strip("plain"), Cow::Borrowed("plain")));
+        assert_eq!(strip(&paint(Level::SEVERE, "oops")), "oops");
+        assert_eq!(strip("a \x1b[1;31mb\x1b[0m c"), "a b c");
+    }
+}
@@ -25,12 +25,12 @@
 //! # Format Trait
 //!
 
+use super::template::render_cached;
 use crate::LogEntry;
 use dyn_clone::DynClone;
 use dyn_fmt::AsStrFormatExt;
 use regex::Regex;
-use std::{fmt, hash};
-use strfmt::strfmt;
+use std::{env, fmt, fs, hash, sync::OnceLock};
 
 ///
 /// Provides methods for formatting [`LogEntry`]s.
@@ -66,23 +66,62 @@ pub trait FormatTrait: fmt::Display + DynClone + Send + Sync {
     ///       was generated. Supplied by the [`#[logger]`][crate::logger] macro, or manually with the
     ///       [`set_fn_name()`][crate::Logger::set_fn_name] method.
     ///     - `level` - The log [level][crate::Level] for which the entry was created.
+    ///     - `level_colored` - As `level`, padded to the same width as `{level:7}`, and colored
+    ///       with ANSI escape codes for a terminal. See: [`ColorChoice`][crate::ColorChoice].
     ///     - `message` - The text of the log entry.
+    ///     - `fields` - The [fields][crate::LogEntry::fields] attached to the log entry,
+    ///       as: `key=value key2="value 2"`.
+    ///     - `context` - The [context][crate::context] labels in scope when the log entry
+    ///       was created, as: `batch-42/item-7`.
+    ///     - `correlation_id` - The [correlation ID][crate::context::correlation_id] set when
+    ///       the log entry was created, or empty if none.
+    ///     - `thread` - The name of the thread on which the log entry was created,
+    ///       or its `thread_id` if it is unnamed.
+    ///     - `thread_id` - The id of that thread, as a number.
+    ///     - `file` - The source file in which the log entry was created. See:
+    ///       [`LogEntry::file()`].
+    ///     - `line` - The line of the source file, or `0` if not known.
+    ///     - `column` - The column of the source file, or `0` if not known.
+    ///     - `elapsed` - The [time since the start][crate::LogEntry::elapsed] of logging,
+    ///       when the log entry was created, as: `+12.304s`.
+    ///     - `error` - The [error][crate::LogEntry::error] attached to the log entry, with its
+    ///       source chain, or empty if none.
+    ///     - `seq` - The [sequence number][crate::LogEntry::seq] of the log entry.
+    ///     - `pid` - The id of this process.
+    ///     - `hostname` - The name of this host. Taken from the `HOSTNAME` or `COMPUTERNAME`
+    ///       environment variable, else `/etc/hostname`, else: `localhost`.
+    ///
+    ///   If `fmt` does not include `context`, then any context is appended, as: ` [batch-42/item-7]`.\
+    ///   If `fmt` does not include `correlation_id`, then any correlation ID is appended, as:
+    ///   ` correlation_id=req-7`.\
+    ///   If `fmt` does not include `fields`, then any fields are appended, after a space.\
+    ///   If `fmt` does not include `error`, then any error is appended, as: ` error="bad port"`.
+    ///
+    /// The format strings are compiled the first time they are used, on each thread,
+    /// and not parsed again.
     ///
     fn ft_fmt(&self, dt_fmt: String, fmt: String, log_entry: &LogEntry) -> String {
-        let dt = log_entry.timestamp.format(&dt_fmt).to_string();
-
-        strfmt!(
-            &fmt,
-            dt,
-            message => log_entry.message.clone(),
-            mod_path =>  log_entry.mod_path.clone(),
-            fn_name => log_entry.fn_name.clone(),
-            level => log_entry.level.as_str()
-        )
-        .unwrap()
+        render_cached(dt_fmt, fmt, log_entry)
     }
 }
 
+///
+/// Returns the name of this host, looked up once.
+///
+pub(super) fn hostname() -> &'static str {
+    static HOSTNAME: OnceLock<String> = OnceLock::new();
+
+    HOSTNAME.get_or_init(|| {
+        ["HOSTNAME", "COMPUTERNAME"]
+            .into_iter()
+            .find_map(|key| env::var(key).ok())
+            .or_else(|| fs::read_to_string("/etc/hostname").ok())
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| "localhost".to_string())
+    })
+}
+
 dyn_clone::clone_trait_object!(FormatTrait);
 
 impl fmt::Debug for dyn FormatTrait {
@@ -124,6 +163,123 @@ mod tests {
     //     // let text =
     // }
 
+    #[test]
+    fn level_colored() {
+        #[derive(Clone)]
+        struct ColoredFormatter;
+
+        impl std::fmt::Display for ColoredFormatter {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                "ColoredFormatter".fmt(f)
+            }
+        }
+
+        impl FormatTrait for ColoredFormatter {
+            fn format(&self, log_entry: &LogEntry) -> String {
+                self.ft_fmt(
+                    String::new(),
+                    "[{level_colored}] {message}".to_string(),
+                    log_entry,
+                )
+            }
+        }
+
+        let mut log = Logger::builder(module_path!())
+            .add_string_handler_with(FormatType::Custom, Some(Box::new(ColoredFormatter)))
+            .build();
+
+        log.severe("Red.");
+        log.info("Plain.");
+
+        assert_eq!(
+            log.get_handler(Handler::String).unwrap().get_log(),
+            "[\x1b[31mSEVERE \x1b[0m] Red.\n[INFO   ] Plain.\n"
+        );
+    }
+
+    #[test]
+    fn thread() {
+        #[derive(Clone)]
+        struct ThreadFormatter;
+
+        impl std::fmt::Display for ThreadFormatter {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                "ThreadFormatter".fmt(f)
+            }
+        }
+
+        impl FormatTrait for ThreadFormatter {
+            fn format(&self, log_entry: &LogEntry) -> String {
+                self.ft_fmt(String::new(), "{thread}: {message}".to_string(), log_entry)
+            }
+        }
+
+        let log_entry = std::thread::Builder::new()
+            .name("worker".to_string())
+            .spawn(|| LogEntry::create(Level::INFO, "thread".to_string(), "Busy.".to_string()))
+            .unwrap()
+            .join()
+            .unwrap();
+
+        assert_eq!(ThreadFormatter.format(&log_entry), "worker: Busy.");
+    }
+
+    #[test]
+    fn location() {
+        #[derive(Clone)]
+        struct LocationFormatter;
+
+        impl std::fmt::Display for LocationFormatter {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                "LocationFormatter".fmt(f)
+            }
+        }
+
+        impl FormatTrait for LocationFormatter {
+            fn format(&self, log_entry: &LogEntry) -> String {
+                self.ft_fmt(
+                    String::new(),
+                    "{file}:{line}:{column} {message}".to_string(),
+                    log_entry,
+                )
+            }
+        }
+
+        let mut log = Logger::builder(module_path!())
+            .add_string_handler_with(FormatType::Custom, Some(Box::new(LocationFormatter)))
+            .build();
+
+        let line = line!();
+        log.info("Here.");
+
+        assert_eq!(
+            log.get_handler(Handler::String).unwrap().get_log(),
+            format!("{}:{}:13 Here.\n", file!(), line + 1)
+        );
+    }
+
+    #[test]
+    fn process_variables() {
+        let log_entry = LogEntry::create(Level::INFO, "pid".to_string(), "Up.".to_string());
+        let text = MockFormatter::new().ft_fmt(
+            String::new(),
+            "{seq} {elapsed} {hostname}[{pid}] {message}".to_string(),
+            &log_entry,
+        );
+
+        assert_eq!(
+            text,
+            format!(
+                "{} {} {}[{}] Up.",
+                log_entry.seq(),
+                log_entry.elapsed_text(),
+                super::hostname(),
+                std::process::id()
+            )
+        );
+        assert!(!super::hostname().is_empty());
+    }
+
     #[test]
     fn debug() {
         let fmt = FormatType::Custom.create(Some(Box::new(SimpleFormatter::new())));
@@ -38,6 +38,16 @@ pub enum FormatType {
     ///
     Iso8601,
 
+    ///
+    /// JSON format, pretty-printed.
+    ///
+    Json,
+
+    ///
+    /// JSON format, one object per line (NDJSON).
+    ///
+    Ndjson,
+
     ///
     ///  Simple format.
     ///
@@ -69,6 +79,8 @@ impl FormatType {
     pub fn create(&self, custom: Option<Box<dyn FormatTrait>>) -> Formatter {
         match &self {
             FormatType::Iso8601 => Formatter::Iso8601(Default::default()),
+            FormatType::Json => Formatter::Json(JsonFormatter::new()),
+            FormatType::Ndjson => Formatter::Json(JsonFormatter::ndjson()),
             FormatType::Simple => Formatter::Simple(Default::default()),
             FormatType::UnixTimestamp => Formatter::UnixTimestamp(Default::default()),
             FormatType::Custom => match custom {
@@ -83,6 +95,8 @@ impl fmt::Display for FormatType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let label = match self {
             FormatType::Iso8601 => "Iso8601",
+            FormatType::Json => "Json",
+            FormatType::Ndjson => "Ndjson",
             FormatType::Simple => "SimpleFormatter",
             FormatType::UnixTimestamp => "UnixTimestamp",
             FormatType::Custom => "Custom",
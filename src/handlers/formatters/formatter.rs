@@ -39,6 +39,11 @@ pub enum Formatter {
     ///
     Iso8601(Iso8601Formatter),
 
+    ///
+    /// JSON format, either pretty-printed or NDJSON.
+    ///
+    Json(JsonFormatter),
+
     ///
     ///  Simple format.
     ///
@@ -68,12 +73,50 @@ impl Formatter {
     pub fn format(&self, log_entry: &LogEntry) -> String {
         match self {
             Formatter::Iso8601(f) => f.format(log_entry),
+            Formatter::Json(f) => f.format(log_entry),
             Formatter::Simple(f) => f.format(log_entry),
             Formatter::UnixTimestamp(f) => f.format(log_entry),
             Formatter::Custom(f) => f.format(log_entry),
         }
     }
 
+    ///
+    /// Format the `log_entry`, as [`format()`][Formatter::format], appending the
+    /// text to `buf`.
+    ///
+    /// Reusing `buf` for each log entry saves allocating a new `String`.
+    ///
+    /// ## Parameters
+    /// - `log_entry` - The log entry to be formatted.
+    /// - `buf` - The buffer to append the text to.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let formatter = FormatType::Simple.create(None);
+    /// let mut buf = String::new();
+    ///
+    /// for message in ["One.", "Two."] {
+    ///     buf.clear();
+    ///     formatter.format_into(&LogEntry::builder().mod_path("app").message(message).build(), &mut buf);
+    ///     println!("{buf}");
+    /// }
+    ///
+    /// assert_eq!(buf, "app-> [INFO   ] Two.");
+    /// ```
+    ///
+    pub fn format_into(&self, log_entry: &LogEntry, buf: &mut String) {
+        match self {
+            Formatter::Iso8601(f) => f.format_into(log_entry, buf),
+            Formatter::Json(f) => buf.push_str(&f.format(log_entry)),
+            Formatter::Simple(f) => f.format_into(log_entry, buf),
+            Formatter::UnixTimestamp(f) => f.format_into(log_entry, buf),
+            Formatter::Custom(f) => buf.push_str(&f.format(log_entry)),
+        }
+    }
+
     #[allow(dead_code)]
     pub(crate) fn width(&self) -> usize {
         15
@@ -90,6 +133,7 @@ impl fmt::Display for Formatter {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Formatter::Iso8601(formatter) => formatter.fmt(f),
+            Formatter::Json(formatter) => formatter.fmt(f),
             Formatter::Simple(formatter) => formatter.fmt(f),
             Formatter::UnixTimestamp(formatter) => formatter.fmt(f),
             Formatter::Custom(formatter) => formatter.fmt(f),
@@ -26,7 +26,9 @@
 //!
 
 use std::fmt;
-use crate::FormatTrait;
+use chrono::{DateTime, Local};
+use crate::{FormatTrait, LogEntry};
+use super::{parse::parse_line, template::Template};
 
 ///
 /// ISO 8601 / RFC 3339 date & time format.
@@ -52,6 +54,7 @@ use crate::FormatTrait;
 pub struct Iso8601Formatter {
     dt_fmt: String,
     fmt_string: String,
+    template: Template,
 }
 
 impl Iso8601Formatter {
@@ -59,9 +62,13 @@ impl Iso8601Formatter {
     /// Creates a new instance of `Iso8601Formatter`.
     ///
     pub fn new() -> Self {
+        let dt_fmt = "%+";
+        let fmt_string = "{dt:35} {mod_path}->{fn_name} [{level:7}] {message}";
+
         Self {
-            dt_fmt: "%+".to_string(),
-            fmt_string: "{dt:35} {mod_path}->{fn_name} [{level:7}] {message}".to_string(),
+            dt_fmt: dt_fmt.to_string(),
+            fmt_string: fmt_string.to_string(),
+            template: Template::new(dt_fmt, fmt_string),
         }
     }
 
@@ -78,6 +85,49 @@ impl Iso8601Formatter {
     pub fn fmt_string(&self) -> String {
         self.fmt_string.clone()
     }
+
+    ///
+    /// Append the `log_entry`, formatted, to `buf`.
+    ///
+    pub(crate) fn format_into(&self, log_entry: &LogEntry, buf: &mut String) {
+        self.template.render_into(log_entry, buf);
+    }
+
+    ///
+    /// Parse a line of this formatter's output, back into a log entry.
+    ///
+    /// Any context, correlation ID, fields, and error appended to the message are
+    /// recovered, as by [`SimpleFormatter::parse()`][crate::SimpleFormatter::parse].
+    /// The timestamp is converted to the local time zone. The source code location,
+    /// thread, sequence number, and elapsed time are not known.
+    ///
+    /// ## Parameters
+    /// - `line` - The line, without its line ending.
+    ///
+    /// Returns `None` if `line` is not in this format.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let le = Iso8601Formatter::parse(
+    ///     "2025-07-18T14:01:01.051532664+08:00 app->main [INFO   ] Started. [batch-42]",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(le.timestamp().timestamp(), 1752818461);
+    /// assert_eq!(le.level(), Level::INFO);
+    /// assert_eq!(le.message(), "Started.");
+    /// assert_eq!(le.context(), ["batch-42"]);
+    /// ```
+    ///
+    pub fn parse(line: &str) -> Option<LogEntry> {
+        let (dt, rest) = line.split_once(' ')?;
+        let timestamp = DateTime::parse_from_rfc3339(dt).ok()?.with_timezone(&Local);
+
+        parse_line(rest.trim_start(), timestamp)
+    }
 }
 
 impl Default for Iso8601Formatter {
@@ -97,7 +147,7 @@ impl fmt::Display for Iso8601Formatter {
 }
 
 impl FormatTrait for Iso8601Formatter {
-    fn format(&self, log_entry: &crate::LogEntry) -> String {
-        self.ft_fmt(self.dt_fmt(), self.fmt_string(), log_entry)
+    fn format(&self, log_entry: &LogEntry) -> String {
+        self.template.render(log_entry)
     }
 }
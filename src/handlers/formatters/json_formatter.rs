@@ -0,0 +1,324 @@
+//
+// File Name:    json_formatter.rs
+// Directory:    src/handlers/formatters
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # JSON Formatter
+//!
+
+use crate::{FormatTrait, LogEntry};
+use std::fmt::{self, Write};
+
+///
+/// JSON format.
+///
+/// Each `LogEntry` is formatted as a single JSON object, with the members:
+/// `timestamp`, `mod_path`, `fn_name`, `level`, and `message`. Plus `context`, the
+/// labels joined with `/`, if the log entry has any [context][LogEntry::context].
+/// `correlation_id`, if it has a [correlation ID][LogEntry::correlation_id].
+/// `error`, if it has an [error][LogEntry::error] attached.
+/// And `fields`, an object of string values, if it has any [fields][LogEntry::fields].
+///
+/// The `timestamp` is in ISO 8601 / RFC 3339 format.
+///
+/// By default the object is pretty-printed across multiple lines. In NDJSON
+/// mode ([`JsonFormatter::ndjson()`]), it is kept on a single line, so that a
+/// log file holds one object per line, ready for ingestion by tools such as
+/// Logstash or Filebeat.
+///
+/// Sample output (NDJSON):
+/// ```text
+/// {"timestamp":"2025-07-18T14:01:01.051532664+08:00","mod_path":"flogging","fn_name":"main","level":"WARNING","message":"Rain is wet!"}
+/// ```
+/// Sample output (pretty):
+/// ```text
+/// {
+///   "timestamp": "2025-07-18T14:01:01.051532664+08:00",
+///   "mod_path": "flogging",
+///   "fn_name": "main",
+///   "level": "WARNING",
+///   "message": "Rain is wet!"
+/// }
+/// ```
+///
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct JsonFormatter {
+    dt_fmt: String,
+    ndjson: bool,
+}
+
+impl JsonFormatter {
+    ///
+    /// Creates a new instance of `JsonFormatter`, that pretty-prints each object.
+    ///
+    pub fn new() -> Self {
+        Self {
+            dt_fmt: "%+".to_string(),
+            ndjson: false,
+        }
+    }
+
+    ///
+    /// Creates a new instance of `JsonFormatter`, that outputs each object
+    /// on a single line (NDJSON).
+    ///
+    pub fn ndjson() -> Self {
+        Self {
+            ndjson: true,
+            ..Self::new()
+        }
+    }
+
+    ///
+    /// Returns the date/time format string.
+    ///
+    pub fn dt_fmt(&self) -> String {
+        self.dt_fmt.clone()
+    }
+
+    ///
+    /// Returns `true` if each object is output on a single line.
+    ///
+    pub fn is_ndjson(&self) -> bool {
+        self.ndjson
+    }
+}
+
+impl Default for JsonFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for JsonFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "dt_fmt: \"{}\" - ndjson: {}", self.dt_fmt, self.ndjson)
+    }
+}
+
+impl FormatTrait for JsonFormatter {
+    fn format(&self, log_entry: &LogEntry) -> String {
+        let timestamp = log_entry.timestamp.format(&self.dt_fmt).to_string();
+        let members = [
+            ("timestamp", timestamp.as_str()),
+            ("mod_path", log_entry.mod_path()),
+            ("fn_name", log_entry.fn_name()),
+            ("level", log_entry.level.as_str()),
+            ("message", log_entry.message()),
+        ];
+
+        let (open, sep, colon, close) = if self.ndjson {
+            ("{", ",", ":", "}")
+        } else {
+            ("{\n  ", ",\n  ", ": ", "\n}")
+        };
+
+        let mut buf = String::from(open);
+
+        for (i, (key, value)) in members.iter().enumerate() {
+            if i > 0 {
+                buf.push_str(sep);
+            }
+
+            buf.push_str(&escape(key));
+            buf.push_str(colon);
+            buf.push_str(&escape(value));
+        }
+
+        if !log_entry.context.is_empty() {
+            buf.push_str(sep);
+            buf.push_str(&escape("context"));
+            buf.push_str(colon);
+            buf.push_str(&escape(&log_entry.context_text()));
+        }
+
+        if let Some(id) = &log_entry.correlation_id {
+            buf.push_str(sep);
+            buf.push_str(&escape("correlation_id"));
+            buf.push_str(colon);
+            buf.push_str(&escape(id));
+        }
+
+        if let Some(error) = &log_entry.error {
+            buf.push_str(sep);
+            buf.push_str(&escape("error"));
+            buf.push_str(colon);
+            buf.push_str(&escape(error));
+        }
+
+        if !log_entry.fields.is_empty() {
+            let (f_open, f_sep, f_close) = if self.ndjson {
+                ("{", ",", "}")
+            } else {
+                ("{\n    ", ",\n    ", "\n  }")
+            };
+
+            buf.push_str(sep);
+            buf.push_str(&escape("fields"));
+            buf.push_str(colon);
+            buf.push_str(f_open);
+
+            for (i, (key, value)) in log_entry.fields.iter().enumerate() {
+                if i > 0 {
+                    buf.push_str(f_sep);
+                }
+
+                buf.push_str(&escape(key));
+                buf.push_str(colon);
+                buf.push_str(&escape(value));
+            }
+
+            buf.push_str(f_close);
+        }
+
+        buf.push_str(close);
+        buf
+    }
+}
+
+///
+/// Quote `text` as a JSON string.
+///
+pub(crate) fn escape(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len() + 2);
+    buf.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            c if c.is_control() => {
+                write!(buf, "\\u{:04x}", c as u32).expect("write!() failed");
+            }
+            c => buf.push(c),
+        }
+    }
+
+    buf.push('"');
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Level;
+
+    #[test]
+    fn escaping() {
+        assert_eq!(escape("plain"), "\"plain\"");
+        assert_eq!(
+            escape("say \"hi\"\n\tC:\\ \u{1}"),
+            "\"say \\\"hi\\\"\\n\\tC:\\\\ \\u0001\""
+        );
+    }
+
+    #[test]
+    fn ndjson() {
+        let mut le = LogEntry::create(
+            Level::WARNING,
+            "ndjson".to_string(),
+            "Line one\nline \"two\"".to_string(),
+        );
+        le.set_mod_path("flogging".to_string());
+
+        let f = JsonFormatter::ndjson();
+        let fs = f.format(&le);
+        let timestamp = le.timestamp.format("%+").to_string();
+
+        assert!(f.is_ndjson());
+        assert!(!fs.contains('\n'));
+        assert_eq!(
+            fs,
+            format!(
+                "{{\"timestamp\":\"{timestamp}\",\"mod_path\":\"flogging\",\"fn_name\":\"ndjson\",\
+                 \"level\":\"WARNING\",\"message\":\"Line one\\nline \\\"two\\\"\"}}"
+            )
+        );
+    }
+
+    #[test]
+    fn pretty() {
+        let le = LogEntry::create(Level::INFO, "pretty".to_string(), "Some text".to_string());
+
+        let f = JsonFormatter::new();
+        let fs = f.format(&le);
+        let timestamp = le.timestamp.format("%+").to_string();
+
+        assert_eq!(f.to_string(), "dt_fmt: \"%+\" - ndjson: false");
+        assert_eq!(
+            fs,
+            format!(
+                "{{
+  \"timestamp\": \"{timestamp}\",
+  \"mod_path\": \"\",
+  \"fn_name\": \"pretty\",
+  \"level\": \"INFO\",
+  \"message\": \"Some text\"
+}}"
+            )
+        );
+    }
+
+    #[test]
+    fn context() {
+        let _g = crate::context::push("batch-42");
+        let _r = crate::context::set_correlation_id("req-7");
+        let le = LogEntry::create(Level::INFO, "context".to_string(), "Done".to_string());
+
+        assert!(JsonFormatter::ndjson().format(&le).ends_with(
+            "\"message\":\"Done\",\"context\":\"batch-42\",\"correlation_id\":\"req-7\"}"
+        ));
+    }
+
+    #[test]
+    fn fields() {
+        let mut le = LogEntry::create(Level::INFO, "fields".to_string(), "Placed".to_string());
+        le.set_fields(vec![
+            ("user_id".to_string(), "42".to_string()),
+            ("order".to_string(), "A \"17\"".to_string()),
+        ]);
+        let timestamp = le.timestamp.format("%+").to_string();
+
+        assert!(JsonFormatter::ndjson().format(&le).ends_with(
+            "\"message\":\"Placed\",\"fields\":{\"user_id\":\"42\",\"order\":\"A \\\"17\\\"\"}}"
+        ));
+        assert_eq!(
+            JsonFormatter::new().format(&le),
+            format!(
+                "{{
+  \"timestamp\": \"{timestamp}\",
+  \"mod_path\": \"\",
+  \"fn_name\": \"fields\",
+  \"level\": \"INFO\",
+  \"message\": \"Placed\",
+  \"fields\": {{
+    \"user_id\": \"42\",
+    \"order\": \"A \\\"17\\\"\"
+  }}
+}}"
+            )
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//
+// File Name:    parse.rs
+// Directory:    src/handlers/formatters
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Parse
+//!
+//! Turns the lines produced by the built-in text formatters back into log entries.
+//!
+
+use crate::{Level, LogEntry};
+use chrono::{DateTime, Local};
+use std::{thread, time::Duration};
+
+///
+/// The parts that `FormatTrait::ft_fmt()` appends after the message.
+///
+#[derive(Debug, Default)]
+struct Suffix {
+    context: Vec<String>,
+    correlation_id: Option<String>,
+    fields: Vec<(String, String)>,
+    error: Option<String>,
+}
+
+///
+/// Parse `text`, formatted as: `{mod_path}->{fn_name} [{level:7}] {message}`, followed
+/// by any context, correlation ID, fields, and error.
+///
+/// The appended parts are taken to start at the first space after which the rest
+/// of the line is made up of them alone.
+///
+/// ## Parameters
+/// - `text` - The text, after any date/time.
+/// - `timestamp` - The timestamp of the log entry.
+///
+pub(crate) fn parse_line(text: &str, timestamp: DateTime<Local>) -> Option<LogEntry> {
+    let (mod_path, rest) = text.split_once("->")?;
+    let (fn_name, rest) = rest.split_once(" [")?;
+    let (level, rest) = rest.split_once(']')?;
+    let level = level.parse::<Level>().ok()?;
+    let rest = rest.strip_prefix(' ').unwrap_or(rest);
+
+    let (message, suffix) = rest
+        .match_indices(' ')
+        .find_map(|(i, _)| Some((&rest[..i], parse_suffix(&rest[i + 1..])?)))
+        .unwrap_or((rest, Suffix::default()));
+
+    Some(LogEntry {
+        timestamp,
+        mod_path: mod_path.to_string(),
+        fn_name: fn_name.to_string(),
+        level,
+        message: message.to_string(),
+        fields: suffix.fields,
+        context: suffix.context,
+        correlation_id: suffix.correlation_id,
+        thread_id: thread::current().id(),
+        thread_name: None,
+        file: "".into(),
+        line: 0,
+        column: 0,
+        seq: 0,
+        elapsed: Duration::ZERO,
+        error: suffix.error,
+    })
+}
+
+///
+/// Parse the whole of `text` as: ` [context]`, ` correlation_id=...`, fields, and
+/// ` error=...`, in that order, each being optional.
+///
+fn parse_suffix(mut text: &str) -> Option<Suffix> {
+    let mut suffix = Suffix::default();
+
+    if text.is_empty() {
+        return None;
+    }
+
+    if let Some(rest) = text.strip_prefix('[') {
+        let (context, rest) = rest.split_once(']')?;
+        suffix.context = context.split('/').map(str::to_string).collect();
+        text = rest;
+
+        if text.is_empty() {
+            return Some(suffix);
+        }
+
+        text = text.strip_prefix(' ')?;
+    }
+
+    loop {
+        let (key, rest) = text.split_once('=')?;
+
+        if key.is_empty() || key.contains([' ', '"', '[', ']']) {
+            return None;
+        }
+
+        let (value, rest) = parse_value(rest)?;
+
+        match key {
+            "correlation_id" if suffix.correlation_id.is_none() && suffix.fields.is_empty() => {
+                suffix.correlation_id = Some(value);
+            }
+            "error" if rest.is_empty() => suffix.error = Some(value),
+            _ => suffix.fields.push((key.to_string(), value)),
+        }
+
+        if rest.is_empty() {
+            return Some(suffix);
+        }
+
+        text = rest.strip_prefix(' ')?;
+    }
+}
+
+///
+/// Parse a `logfmt` value, as written by `quote()`, from the start of `text`.
+///
+/// Returns the value, and the rest of `text`.
+///
+fn parse_value(text: &str) -> Option<(String, &str)> {
+    let Some(quoted) = text.strip_prefix('"') else {
+        let end = text.find(' ').unwrap_or(text.len());
+        let value = &text[..end];
+
+        return (!value.is_empty() && !value.contains(['=', '"']))
+            .then(|| (value.to_string(), &text[end..]));
+    };
+
+    let mut value = String::new();
+    let mut chars = quoted.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, &quoted[i + 1..])),
+            '\\' => value.push(match chars.next()?.1 {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                '0' => '\0',
+                'u' => {
+                    let (start, _) = chars.next().filter(|(_, c)| *c == '{')?;
+                    let (end, _) = chars.find(|(_, c)| *c == '}')?;
+                    let code = u32::from_str_radix(&quoted[start + 1..end], 16).ok()?;
+                    char::from_u32(code)?
+                }
+                c => c,
+            }),
+            c => value.push(c),
+        }
+    }
+
+    None
+}
@@ -26,7 +26,9 @@
 //!
 
 use std::fmt;
-use crate::FormatTrait;
+use chrono::{DateTime, Local};
+use crate::{FormatTrait, LogEntry};
+use super::{parse::parse_line, template::Template};
 
 ///
 /// Simple format.
@@ -47,6 +49,7 @@ use crate::FormatTrait;
 pub struct SimpleFormatter {
     dt_fmt: String,
     fmt_string: String,
+    template: Template,
 }
 
 impl SimpleFormatter {
@@ -54,9 +57,13 @@ impl SimpleFormatter {
     /// Creates a new instance of `SimpleFormatter`.
     ///
     pub fn new() -> Self {
+        let dt_fmt = "";
+        let fmt_string = "{mod_path}->{fn_name} [{level:7}] {message}";
+
         Self {
-            dt_fmt: "".to_string(),
-            fmt_string: "{mod_path}->{fn_name} [{level:7}] {message}".to_string(),
+            dt_fmt: dt_fmt.to_string(),
+            fmt_string: fmt_string.to_string(),
+            template: Template::new(dt_fmt, fmt_string),
         }
     }
 
@@ -73,6 +80,47 @@ impl SimpleFormatter {
     pub fn fmt_string(&self) -> String {
         self.fmt_string.clone()
     }
+
+    ///
+    /// Append the `log_entry`, formatted, to `buf`.
+    ///
+    pub(crate) fn format_into(&self, log_entry: &LogEntry, buf: &mut String) {
+        self.template.render_into(log_entry, buf);
+    }
+
+    ///
+    /// Parse a line of this formatter's output, back into a log entry.
+    ///
+    /// Any context, correlation ID, fields, and error appended to the message are
+    /// recovered. They are taken to start at the first space after which the rest of
+    /// the line is made up of them alone. So a message ending in text such as `key=value`
+    /// comes back with that text as a field.
+    ///
+    /// As the line has no date/time, the timestamp is the Unix epoch. The source code
+    /// location, thread, sequence number, and elapsed time are not known either.
+    ///
+    /// ## Parameters
+    /// - `line` - The line, without its line ending.
+    ///
+    /// Returns `None` if `line` is not in this format.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let le = SimpleFormatter::parse("app->main [WARNING] Low on disk space. disk=sda1").unwrap();
+    ///
+    /// assert_eq!(le.mod_path(), "app");
+    /// assert_eq!(le.fn_name(), "main");
+    /// assert_eq!(le.level(), Level::WARNING);
+    /// assert_eq!(le.message(), "Low on disk space.");
+    /// assert_eq!(le.field("disk"), Some("sda1"));
+    /// ```
+    ///
+    pub fn parse(line: &str) -> Option<LogEntry> {
+        parse_line(line, DateTime::<Local>::default())
+    }
 }
 
 impl Default for SimpleFormatter {
@@ -92,7 +140,7 @@ impl fmt::Display for SimpleFormatter {
 }
 
 impl FormatTrait for SimpleFormatter {
-    fn format(&self, log_entry: &crate::LogEntry) -> String {
-        self.ft_fmt(self.dt_fmt(), self.fmt_string(), log_entry)
+    fn format(&self, log_entry: &LogEntry) -> String {
+        self.template.render(log_entry)
     }
 }
@@ -0,0 +1,625 @@
+//
+// File Name:    template.rs
+// Directory:    src/handlers/formatters
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Template
+//!
+//! The format strings of [`FormatTrait::ft_fmt()`][crate::FormatTrait::ft_fmt], compiled
+//! once into literal text and variables, so that each log entry is rendered without
+//! parsing them again.
+//!
+
+use super::{ansi, format_trait::hostname};
+use crate::{LogEntry, logger::quote};
+use chrono::format::{Item, StrftimeItems};
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    collections::HashMap,
+    fmt::Write,
+    hash::{Hash, Hasher},
+    iter::repeat_n,
+    process,
+};
+use strfmt::strfmt;
+
+///
+/// The most templates cached by [`render_cached()`], per thread.
+///
+const CACHE_SIZE: usize = 32;
+
+///
+/// A compiled pair of format strings.
+///
+#[derive(Debug, Clone)]
+pub(crate) struct Template {
+    dt_fmt: String,
+    fmt: String,
+    compiled: Option<Compiled>,
+    appends: Appends,
+}
+
+///
+/// The compiled form of a template.
+///
+/// A template that uses something not supported here, such as a `#` flag, is left
+/// to `strfmt`, as before.
+///
+#[derive(Debug, Clone)]
+struct Compiled {
+    dt: Vec<Item<'static>>,
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Literal(String),
+    Var(Var, Spec),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Var {
+    Dt,
+    Message,
+    ModPath,
+    FnName,
+    Level,
+    LevelColored,
+    Fields,
+    Context,
+    CorrelationId,
+    Thread,
+    ThreadId,
+    File,
+    Line,
+    Column,
+    Elapsed,
+    Error,
+    Seq,
+    Pid,
+    Hostname,
+}
+
+impl Var {
+    fn from_name(name: &str) -> Option<Var> {
+        Some(match name {
+            "dt" => Var::Dt,
+            "message" => Var::Message,
+            "mod_path" => Var::ModPath,
+            "fn_name" => Var::FnName,
+            "level" => Var::Level,
+            "level_colored" => Var::LevelColored,
+            "fields" => Var::Fields,
+            "context" => Var::Context,
+            "correlation_id" => Var::CorrelationId,
+            "thread" => Var::Thread,
+            "thread_id" => Var::ThreadId,
+            "file" => Var::File,
+            "line" => Var::Line,
+            "column" => Var::Column,
+            "elapsed" => Var::Elapsed,
+            "error" => Var::Error,
+            "seq" => Var::Seq,
+            "pid" => Var::Pid,
+            "hostname" => Var::Hostname,
+            _ => return None,
+        })
+    }
+
+    fn is_number(self) -> bool {
+        matches!(self, Var::Line | Var::Column | Var::Seq | Var::Pid)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+///
+/// A format spec, as: `[[fill]align][width][.precision]`.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Spec {
+    fill: char,
+    align: Option<Align>,
+    width: usize,
+    precision: Option<usize>,
+}
+
+impl Spec {
+    const NONE: Spec = Spec {
+        fill: ' ',
+        align: None,
+        width: 0,
+        precision: None,
+    };
+
+    ///
+    /// Parse `text`, or return `None` if it uses anything else.
+    ///
+    fn parse(text: &str) -> Option<Spec> {
+        let mut spec = Spec::NONE;
+        let align = |c| match c {
+            '<' => Some(Align::Left),
+            '^' => Some(Align::Center),
+            '>' => Some(Align::Right),
+            _ => None,
+        };
+
+        let mut chars = text.chars();
+        let rest = match (chars.next(), chars.next()) {
+            (Some(fill), Some(c)) if align(c).is_some() => {
+                spec.fill = fill;
+                spec.align = align(c);
+                chars.as_str()
+            }
+            (Some(c), _) if align(c).is_some() => {
+                spec.align = align(c);
+                &text[1..]
+            }
+            _ => text,
+        };
+
+        let (width, precision) = match rest.split_once('.') {
+            Some((width, precision)) => (width, Some(precision)),
+            None => (rest, None),
+        };
+
+        if width.starts_with('0') {
+            return None;
+        }
+
+        if !width.is_empty() {
+            spec.width = number(width)?;
+        }
+
+        if let Some(precision) = precision {
+            spec.precision = Some(number(precision)?);
+        }
+
+        Some(spec)
+    }
+}
+
+fn number(text: &str) -> Option<usize> {
+    if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    text.parse().ok()
+}
+
+///
+/// Which of the context, correlation ID, fields, and error are appended,
+/// because the template does not include them.
+///
+#[derive(Debug, Clone, Copy)]
+struct Appends {
+    context: bool,
+    correlation_id: bool,
+    fields: bool,
+    error: bool,
+}
+
+impl Appends {
+    fn new(fmt: &str) -> Self {
+        Appends {
+            context: !fmt.contains("{context"),
+            correlation_id: !fmt.contains("{correlation_id"),
+            fields: !fmt.contains("{fields"),
+            error: !fmt.contains("{error"),
+        }
+    }
+
+    fn render(&self, log_entry: &LogEntry, buf: &mut String) {
+        if self.context && !log_entry.context.is_empty() {
+            buf.push_str(" [");
+            push_context(log_entry, buf);
+            buf.push(']');
+        }
+
+        if let Some(id) = log_entry
+            .correlation_id()
+            .filter(|id| self.correlation_id && !id.is_empty())
+        {
+            buf.push_str(" correlation_id=");
+            buf.push_str(id);
+        }
+
+        if self.fields && !log_entry.fields.is_empty() {
+            buf.push(' ');
+            push_fields(log_entry, buf);
+        }
+
+        if let Some(error) = log_entry.error().filter(|_| self.error) {
+            buf.push_str(" error=");
+            buf.push_str(&quote(error));
+        }
+    }
+}
+
+impl Template {
+    ///
+    /// Compile `dt_fmt` and `fmt`.
+    ///
+    pub(crate) fn new(dt_fmt: &str, fmt: &str) -> Self {
+        Template {
+            dt_fmt: dt_fmt.to_string(),
+            fmt: fmt.to_string(),
+            compiled: compile(dt_fmt, fmt),
+            appends: Appends::new(fmt),
+        }
+    }
+
+    ///
+    /// Returns the `log_entry`, formatted.
+    ///
+    pub(crate) fn render(&self, log_entry: &LogEntry) -> String {
+        let mut buf = String::new();
+        self.render_into(log_entry, &mut buf);
+        buf
+    }
+
+    ///
+    /// Append the `log_entry`, formatted, to `buf`.
+    ///
+    pub(crate) fn render_into(&self, log_entry: &LogEntry, buf: &mut String) {
+        let Some(compiled) = &self.compiled else {
+            buf.push_str(&strfmt_render(&self.dt_fmt, &self.fmt, log_entry));
+            self.appends.render(log_entry, buf);
+            return;
+        };
+
+        for segment in &compiled.segments {
+            match segment {
+                Segment::Literal(text) => buf.push_str(text),
+                Segment::Var(var, spec) => compiled.render_var(*var, spec, log_entry, buf),
+            }
+        }
+
+        self.appends.render(log_entry, buf);
+    }
+}
+
+// Two templates are the same if their format strings are.
+impl PartialEq for Template {
+    fn eq(&self, other: &Self) -> bool {
+        (&self.dt_fmt, &self.fmt) == (&other.dt_fmt, &other.fmt)
+    }
+}
+
+impl Eq for Template {}
+
+impl Hash for Template {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.dt_fmt.hash(state);
+        self.fmt.hash(state);
+    }
+}
+
+impl Compiled {
+    fn render_var(&self, var: Var, spec: &Spec, log_entry: &LogEntry, buf: &mut String) {
+        let number = match var {
+            Var::Line => Some(log_entry.line as u64),
+            Var::Column => Some(log_entry.column as u64),
+            Var::Seq => Some(log_entry.seq),
+            Var::Pid => Some(process::id() as u64),
+            _ => None,
+        };
+
+        if let Some(n) = number {
+            if *spec == Spec::NONE {
+                let _ = write!(buf, "{n}");
+            } else {
+                pad(&n.to_string(), spec, Align::Right, buf);
+            }
+
+            return;
+        }
+
+        if *spec == Spec::NONE {
+            match var {
+                Var::Dt => {
+                    let _ = write!(
+                        buf,
+                        "{}",
+                        log_entry.timestamp.format_with_items(self.dt.iter())
+                    );
+                }
+                Var::Fields => push_fields(log_entry, buf),
+                Var::Context => push_context(log_entry, buf),
+                _ => buf.push_str(&text(var, log_entry)),
+            }
+
+            return;
+        }
+
+        let text: Cow<'_, str> = match var {
+            Var::Dt => log_entry
+                .timestamp
+                .format_with_items(self.dt.iter())
+                .to_string()
+                .into(),
+            _ => text(var, log_entry),
+        };
+
+        pad(&text, spec, Align::Left, buf);
+    }
+}
+
+///
+/// Returns the text of `var`, which is not a number.
+///
+fn text(var: Var, log_entry: &LogEntry) -> Cow<'_, str> {
+    match var {
+        Var::Message => (&log_entry.message).into(),
+        Var::ModPath => (&log_entry.mod_path).into(),
+        Var::FnName => (&log_entry.fn_name).into(),
+        Var::Level => log_entry.level.as_str().into(),
+        Var::LevelColored => {
+            ansi::paint(log_entry.level, &format!("{:7}", log_entry.level.as_str())).into()
+        }
+        Var::Fields => log_entry.fields_text().into(),
+        Var::Context => log_entry.context_text().into(),
+        Var::CorrelationId => log_entry.correlation_id().unwrap_or_default().into(),
+        Var::Thread => match log_entry.thread_name() {
+            Some(name) => name.into(),
+            None => log_entry.thread_id_text().into(),
+        },
+        Var::ThreadId => log_entry.thread_id_text().into(),
+        Var::File => log_entry.file().into(),
+        Var::Elapsed => log_entry.elapsed_text().into(),
+        Var::Error => log_entry.error().unwrap_or_default().into(),
+        Var::Hostname => hostname().into(),
+        Var::Dt | Var::Line | Var::Column | Var::Seq | Var::Pid => Cow::Borrowed(""),
+    }
+}
+
+///
+/// Append `text` to `buf`, cut to the precision, and padded to the width, of `spec`.
+///
+fn pad(text: &str, spec: &Spec, default: Align, buf: &mut String) {
+    let text = match spec.precision {
+        Some(precision) => text
+            .char_indices()
+            .nth(precision)
+            .map_or(text, |(i, _)| &text[..i]),
+        None => text,
+    };
+
+    let fill = spec.width.saturating_sub(text.chars().count());
+    let (before, after) = match spec.align.unwrap_or(default) {
+        Align::Left => (0, fill),
+        Align::Center => (fill / 2, fill - fill / 2),
+        Align::Right => (fill, 0),
+    };
+
+    buf.extend(repeat_n(spec.fill, before));
+    buf.push_str(text);
+    buf.extend(repeat_n(spec.fill, after));
+}
+
+fn push_context(log_entry: &LogEntry, buf: &mut String) {
+    for (i, label) in log_entry.context.iter().enumerate() {
+        if i > 0 {
+            buf.push('/');
+        }
+
+        buf.push_str(label);
+    }
+}
+
+fn push_fields(log_entry: &LogEntry, buf: &mut String) {
+    for (i, (key, value)) in log_entry.fields.iter().enumerate() {
+        if i > 0 {
+            buf.push(' ');
+        }
+
+        buf.push_str(key);
+        buf.push('=');
+        buf.push_str(&quote(value));
+    }
+}
+
+///
+/// Compile `dt_fmt` and `fmt`, or return `None` if either uses something not
+/// supported here.
+///
+fn compile(dt_fmt: &str, fmt: &str) -> Option<Compiled> {
+    let dt = StrftimeItems::new(dt_fmt).parse_to_owned().ok()?;
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut rest = fmt;
+
+    while let Some(i) = rest.find(['{', '}']) {
+        literal.push_str(&rest[..i]);
+        let brace = rest.as_bytes()[i];
+        rest = &rest[i + 1..];
+
+        if brace == b'}' {
+            rest = rest.strip_prefix('}')?;
+            literal.push('}');
+            continue;
+        }
+
+        if let Some(after) = rest.strip_prefix('{') {
+            literal.push('{');
+            rest = after;
+            continue;
+        }
+
+        let (inner, after) = rest.split_once('}')?;
+        rest = after;
+
+        let (name, spec) = match inner.split_once(':') {
+            Some((name, spec)) => (name, Spec::parse(spec)?),
+            None => (inner, Spec::NONE),
+        };
+        let var = Var::from_name(name)?;
+
+        if var.is_number() && spec.precision.is_some() {
+            return None;
+        }
+
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(std::mem::take(&mut literal)));
+        }
+
+        segments.push(Segment::Var(var, spec));
+    }
+
+    literal.push_str(rest);
+
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+
+    Some(Compiled { dt, segments })
+}
+
+///
+/// Render `fmt` with `strfmt`, for those templates that are not compiled.
+///
+fn strfmt_render(dt_fmt: &str, fmt: &str, log_entry: &LogEntry) -> String {
+    let dt = log_entry.timestamp.format(dt_fmt).to_string();
+    let thread_id = log_entry.thread_id_text();
+    let thread = log_entry
+        .thread_name()
+        .map_or_else(|| thread_id.clone(), str::to_string);
+
+    strfmt!(
+        fmt,
+        dt,
+        message => log_entry.message.clone(),
+        mod_path =>  log_entry.mod_path.clone(),
+        fn_name => log_entry.fn_name.clone(),
+        level => log_entry.level.as_str(),
+        level_colored => ansi::paint(log_entry.level, &format!("{:7}", log_entry.level.as_str())),
+        fields => log_entry.fields_text(),
+        context => log_entry.context_text(),
+        correlation_id => log_entry.correlation_id().unwrap_or_default().to_string(),
+        thread,
+        thread_id,
+        file => log_entry.file().to_string(),
+        line => log_entry.line,
+        column => log_entry.column,
+        elapsed => log_entry.elapsed_text(),
+        error => log_entry.error().unwrap_or_default().to_string(),
+        seq => log_entry.seq,
+        pid => process::id(),
+        hostname => hostname()
+    )
+    .unwrap()
+}
+
+///
+/// Render `log_entry` with the template for `dt_fmt` and `fmt`, compiling it
+/// the first time it is used on this thread.
+///
+pub(crate) fn render_cached(dt_fmt: String, fmt: String, log_entry: &LogEntry) -> String {
+    thread_local! {
+        static TEMPLATES: RefCell<HashMap<(String, String), Template>> =
+            RefCell::new(HashMap::new());
+    }
+
+    TEMPLATES.with_borrow_mut(|templates| {
+        let key = (dt_fmt, fmt);
+
+        if templates.len() >= CACHE_SIZE && !templates.contains_key(&key) {
+            templates.clear();
+        }
+
+        templates
+            .entry(key)
+            .or_insert_with_key(|(dt_fmt, fmt)| Template::new(dt_fmt, fmt))
+            .render(log_entry)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Level;
+    use chrono::{Local, TimeZone};
+    use std::io::{Error, ErrorKind};
+
+    fn entry() -> LogEntry {
+        LogEntry::builder()
+            .timestamp(Local.with_ymd_and_hms(2025, 7, 1, 9, 30, 0).unwrap())
+            .level(Level::WARNING)
+            .mod_path("app::db")
+            .fn_name("connect")
+            .message("Retrying.")
+            .location("src/db.rs", 42, 9)
+            .field("attempt", "2")
+            .field("host", "db 1")
+            .correlation_id("req-7")
+            .error(&Error::new(ErrorKind::TimedOut, "timed out"))
+            .build()
+    }
+
+    #[test]
+    fn compiled() {
+        let log_entry = entry();
+
+        for (dt_fmt, fmt) in [
+            ("", "{mod_path}->{fn_name} [{level:7}] {message}"),
+            ("%+", "{dt:35} {mod_path}->{fn_name} [{level:7}] {message}"),
+            ("%s%.f", "{dt} {mod_path}->{fn_name} [{level:>7}] {message}"),
+            ("", "{file}:{line:5}:{column:<3}|{seq:*^12}|{pid}"),
+            (
+                "",
+                "{{literal}} {message:.5}|{fn_name:-^11.4}|{level_colored}",
+            ),
+            ("", "{fields} {error} {correlation_id} {context}"),
+            ("", "{thread} {thread_id} {elapsed} {hostname}"),
+        ] {
+            let template = Template::new(dt_fmt, fmt);
+            assert!(template.compiled.is_some(), "{fmt}");
+
+            let mut expected = strfmt_render(dt_fmt, fmt, &log_entry);
+            template.appends.render(&log_entry, &mut expected);
+
+            assert_eq!(template.render(&log_entry), expected, "{fmt}");
+        }
+    }
+
+    #[test]
+    fn not_compiled() {
+        for fmt in [
+            "{line:05}",
+            "{line:.2}",
+            "{message:#?}",
+            "{unknown}",
+            "{message",
+            "}",
+        ] {
+            assert!(Template::new("", fmt).compiled.is_none(), "{fmt}");
+        }
+
+        assert!(Template::new("%Q", "{dt}").compiled.is_none());
+    }
+}
@@ -26,7 +26,8 @@
 //!
 
 use std::fmt;
-use crate::FormatTrait;
+use crate::{FormatTrait, LogEntry};
+use super::template::Template;
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 
@@ -59,6 +60,7 @@ use crate::FormatTrait;
 pub struct UnixTimestampFormatter {
     dt_fmt: String,
     fmt_string: String,
+    template: Template,
 }
 
 impl UnixTimestampFormatter {
@@ -66,9 +68,13 @@ impl UnixTimestampFormatter {
     /// Creates a new instance of `UnixTimestampFormatter`.
     ///
     pub fn new() -> Self {
+        let dt_fmt = "%s.%f";
+        let fmt_string = "{dt} {mod_path}->{fn_name} [{level:7}] {message}";
+
         Self {
-            dt_fmt: "%s.%f".to_string(),
-            fmt_string: "{dt} {mod_path}->{fn_name} [{level:7}] {message}".to_string(),
+            dt_fmt: dt_fmt.to_string(),
+            fmt_string: fmt_string.to_string(),
+            template: Template::new(dt_fmt, fmt_string),
         }
     }
 
@@ -85,6 +91,13 @@ impl UnixTimestampFormatter {
     pub fn fmt_string(&self) -> String {
         self.fmt_string.clone()
     }
+
+    ///
+    /// Append the `log_entry`, formatted, to `buf`.
+    ///
+    pub(crate) fn format_into(&self, log_entry: &LogEntry, buf: &mut String) {
+        self.template.render_into(log_entry, buf);
+    }
 }
 
 impl Default for UnixTimestampFormatter {
@@ -104,7 +117,7 @@ impl fmt::Display for UnixTimestampFormatter {
 }
 
 impl FormatTrait for UnixTimestampFormatter {
-    fn format(&self, log_entry: &crate::LogEntry) -> String {
-        self.ft_fmt(self.dt_fmt(), self.fmt_string(), log_entry)
+    fn format(&self, log_entry: &LogEntry) -> String {
+        self.template.render(log_entry)
     }
 }
@@ -26,9 +26,11 @@
 //!
 
 // #![allow(unused)]
+pub mod handler_stats;
 pub mod handler_trait;
 
 use std::{fmt, hash::Hash, io::Error};
+pub use handler_stats::HandlerStats;
 pub use handler_trait::HandlerTrait;
 
 ///
@@ -36,28 +38,76 @@ pub use handler_trait::HandlerTrait;
 ///
 #[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
 pub enum Handler {
+    ///
+    /// Refers to the `BinaryHandler`.
+    ///
+    Binary,
     ///
     /// Refers to the `ConsoleHandler` => `ConsoleType::StdOut`.
     ///
     #[default]
     Console,
     ///
+    /// Refers to the `DbHandler`.
+    ///
+    #[cfg(feature = "db")]
+    Db,
+    ///
     /// Refers to the `ConsoleHandler` => `ConsoleType::StdErr`.
     ///
     EConsole,
     ///
+    /// Refers to the `EmailHandler`.
+    ///
+    #[cfg(feature = "email")]
+    Email,
+    ///
     /// Refers to the `FileHandler`.
     ///
     File,
     ///
+    /// Refers to the `HttpHandler`.
+    ///
+    #[cfg(feature = "http")]
+    Http,
+    ///
     /// Refers to the `ConsoleHandler` => `ConsoleType::Production`.
     ///
     PConsole,
     ///
+    /// Refers to the `MemoryHandler`.
+    ///
+    Memory,
+    ///
+    /// Refers to the `RotatingFileHandler`.
+    ///
+    RotatingFile,
+    ///
     /// Refers to the `StringHandler`.
     ///
     String,
     ///
+    /// Refers to the `SyslogHandler`.
+    ///
+    Syslog,
+    ///
+    /// Refers to the `TcpHandler`.
+    ///
+    Tcp,
+    ///
+    /// Refers to the `ThrottleHandler`.
+    ///
+    Throttle,
+    ///
+    /// Refers to the `UdpHandler`.
+    ///
+    Udp,
+    ///
+    /// Refers to the `WebhookHandler`.
+    ///
+    #[cfg(feature = "http")]
+    Webhook,
+    ///
     /// Refers to a custom handler; by default: `MockHandler`.
     ///
     Custom(String),
@@ -66,11 +116,26 @@ pub enum Handler {
 impl fmt::Display for Handler {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let text = match &self {
+            Handler::Binary => "Binary",
             Handler::Console => "Console",
+            #[cfg(feature = "db")]
+            Handler::Db => "Db",
             Handler::EConsole => "EConsole",
+            #[cfg(feature = "email")]
+            Handler::Email => "Email",
             Handler::File => "File",
+            #[cfg(feature = "http")]
+            Handler::Http => "Http",
             Handler::PConsole => "PConsole",
+            Handler::Memory => "Memory",
+            Handler::RotatingFile => "RotatingFile",
             Handler::String => "String",
+            Handler::Syslog => "Syslog",
+            Handler::Tcp => "Tcp",
+            Handler::Throttle => "Throttle",
+            Handler::Udp => "Udp",
+            #[cfg(feature = "http")]
+            Handler::Webhook => "Webhook",
             Handler::Custom(label) => &format!("Custom({label})"),
         };
 
@@ -88,14 +153,27 @@ mod test {
         let econsole = Handler::EConsole;
         let pconsole = Handler::PConsole;
         let file = Handler::File;
+        let memory = Handler::Memory;
+        let rotating_file = Handler::RotatingFile;
         let string = Handler::String;
+        let syslog = Handler::Syslog;
+        let tcp = Handler::Tcp;
+        let udp = Handler::Udp;
         let custom = Handler::Custom("MyCustom".to_string());
 
         assert_eq!(console.to_string(), "Handler::Console".to_string());
         assert_eq!(econsole.to_string(), "Handler::EConsole".to_string());
         assert_eq!(pconsole.to_string(), "Handler::PConsole".to_string());
         assert_eq!(file.to_string(), "Handler::File".to_string());
+        assert_eq!(memory.to_string(), "Handler::Memory".to_string());
+        assert_eq!(
+            rotating_file.to_string(),
+            "Handler::RotatingFile".to_string()
+        );
         assert_eq!(string.to_string(), "Handler::String".to_string());
+        assert_eq!(syslog.to_string(), "Handler::Syslog".to_string());
+        assert_eq!(tcp.to_string(), "Handler::Tcp".to_string());
+        assert_eq!(udp.to_string(), "Handler::Udp".to_string());
         assert_eq!(custom.to_string(), "Handler::Custom(MyCustom)".to_string());
     }
 }
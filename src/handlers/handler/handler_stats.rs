@@ -0,0 +1,122 @@
+//
+// File Name:    handler_stats.rs
+// Directory:    src/handlers/handler
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # HandlerStats
+//!
+
+///
+/// Counters kept by a handler, about what it has done with the log entries
+/// it has been given.
+///
+/// Returned by [`HandlerTrait::stats()`][crate::HandlerTrait::stats], and by
+/// [`Logger::handler_stats()`][crate::Logger::handler_stats], which also fills
+/// in the number of entries [`filtered()`][HandlerStats::filtered].
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// let log = Logger::builder("app")
+///     .add_string_handler_at(Level::WARNING)
+///     .build();
+///
+/// log.info("Not for this handler.");
+/// log.warning("Running low on disk space.");
+///
+/// let stats = log.handler_stats(Handler::String).unwrap();
+///
+/// assert_eq!(stats.published(), 1);
+/// assert_eq!(stats.filtered(), 1);
+/// assert_eq!(stats.dropped(), 0);
+/// assert!(stats.bytes_written() > 0);
+/// assert_eq!(stats.last_error(), None);
+/// ```
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct HandlerStats {
+    pub(crate) published: u64,
+    pub(crate) filtered: u64,
+    pub(crate) dropped: u64,
+    pub(crate) bytes_written: u64,
+    pub(crate) last_error: Option<String>,
+}
+
+impl HandlerStats {
+    ///
+    /// Returns the number of bytes written to this handler's destination.
+    ///
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written
+    }
+
+    ///
+    /// Returns the number of log entries accepted for publishing, that were
+    /// then lost. For example, because the handler was closed, its queue or
+    /// buffer was full, or the write failed.
+    ///
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    ///
+    /// Returns the number of log entries that never reached this handler,
+    /// because they were rejected by its own level, or its filter.
+    ///
+    /// Only known to the logger. So this is always `0`, unless obtained via
+    /// [`Logger::handler_stats()`][crate::Logger::handler_stats].
+    ///
+    pub fn filtered(&self) -> u64 {
+        self.filtered
+    }
+
+    ///
+    /// Returns the message of the most recent error, if any.
+    ///
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    ///
+    /// Returns the number of log entries accepted for publishing.
+    ///
+    pub fn published(&self) -> u64 {
+        self.published
+    }
+
+    ///
+    /// Record an error, along with the log entries lost because of it.
+    ///
+    pub(crate) fn error(&mut self, error: impl ToString, dropped: u64) {
+        self.last_error = Some(error.to_string());
+        self.dropped += dropped;
+    }
+
+    ///
+    /// Record a successful write of `bytes`.
+    ///
+    pub(crate) fn wrote(&mut self, bytes: usize) {
+        self.bytes_written += bytes as u64;
+    }
+}
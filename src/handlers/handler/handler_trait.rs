@@ -25,9 +25,9 @@
 //! # HandlerTrait
 //!
 
-use std::{fmt, io::Error};
+use std::{any::Any, fmt, io::Error};
 
-use crate::{Formatter, LogEntry};
+use crate::{FilterTrait, Formatter, HandlerStats, LogEntry};
 
 ///
 /// Provides common methods required for all handlers.
@@ -43,6 +43,25 @@ pub trait HandlerTrait: fmt::Display + Send + Sync {
     where
         Self: Sized;
 
+    ///
+    /// Return this handler as `Any`, to allow downcasting to its concrete type.
+    ///
+    /// The implementation is simply:
+    /// ```text
+    /// fn as_any(&self) -> &dyn Any {
+    ///     self
+    /// }
+    /// ```
+    ///
+    fn as_any(&self) -> &dyn Any;
+
+    ///
+    /// Return this handler as mutable `Any`, to allow downcasting to its concrete type.
+    ///
+    /// See [`Logger::get_handler_as()`][crate::Logger::get_handler_as].
+    ///
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
     ///
     /// Close the Handler and free all associated resources.
     ///
@@ -57,6 +76,11 @@ pub trait HandlerTrait: fmt::Display + Send + Sync {
     ///
     fn flush(&mut self);
 
+    ///
+    /// Return the Filter for this Handler, if one has been set.
+    ///
+    fn get_filter(&self) -> Option<&dyn FilterTrait>;
+
     ///
     /// Return a copy of the internal buffer as a `String`.
     ///
@@ -85,6 +109,18 @@ pub trait HandlerTrait: fmt::Display + Send + Sync {
     ///
     fn publish(&mut self, log_entry: &LogEntry);
 
+    ///
+    /// Set a Filter.
+    ///
+    /// When set, only those `LogEntry`s for which the filter's
+    /// [`should_publish()`][FilterTrait::should_publish()] returns `true`,
+    /// are published by this Handler.
+    ///
+    /// ## Parameters
+    /// - `filter` - The `FilterTrait` to use, or `None` to remove the current one.
+    ///
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>);
+
     ///
     /// Set a Formatter.
     ///
@@ -105,4 +141,17 @@ pub trait HandlerTrait: fmt::Display + Send + Sync {
     /// - `state` - If `true`, then a new vector will be set. Otherwise `None` will be set.
     ///
     fn set_test_mode(&mut self, state: bool);
+
+    ///
+    /// Return the counters kept by this handler.
+    ///
+    /// The default implementation keeps none, so all counters are `0`. Custom
+    /// handlers can override it, to report their own.
+    ///
+    /// See [`Logger::handler_stats()`][crate::Logger::handler_stats], which also
+    /// includes the number of entries filtered out by the logger.
+    ///
+    fn stats(&self) -> HandlerStats {
+        HandlerStats::default()
+    }
 }
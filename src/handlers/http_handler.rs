@@ -0,0 +1,468 @@
+//
+// File Name:    http_handler.rs
+// Directory:    src/handlers
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # HttpHandler
+//!
+//! POSTs batches of log entries to an HTTP endpoint.
+//!
+
+use super::formatters::json_escape;
+use crate::*;
+use std::{
+    any::Any,
+    fmt,
+    io::{Error, ErrorKind::InvalidInput},
+    thread,
+    time::{Duration, Instant},
+};
+use ureq::Agent;
+
+///
+/// POSTs batches of log entries, as a JSON array, to the endpoint whose URL
+/// was provided during initialization.
+///
+/// Published entries are collected into a batch. The batch is sent when:
+///
+/// - it holds `batch_size` entries (default: 100),
+/// - an entry is published after `flush_interval` (default: 5 seconds) has
+///   passed since the last batch was sent,
+/// - [`flush()`][HttpHandler::flush] or [`close()`][HttpHandler::close] is called.
+///
+/// If a POST fails (including a non-`2xx` response), it is retried up to
+/// `retries` times (default: 3), with the delay between attempts starting at
+/// `retry_delay` (default: 200 ms) and doubling each time. After that, the
+/// batch is dropped.
+///
+/// As sending blocks the thread doing the logging, consider using
+/// [`LoggerBuilder::set_async()`] with this handler.
+///
+/// The default formatter is the NDJSON [`JsonFormatter`], whose objects
+/// become the elements of the array. The output of any other formatter is
+/// sent as an array of JSON strings.
+///
+/// Requires the `http` feature.
+///
+#[derive(Debug)]
+pub struct HttpHandler {
+    url: String,
+    formatter: Formatter,
+    filter: Option<Box<dyn FilterTrait>>,
+    agent: Agent,
+    headers: Vec<(String, String)>,
+    batch: Vec<String>,
+    batch_size: usize,
+    flush_interval: Duration,
+    last_flush: Instant,
+    retries: u32,
+    retry_delay: Duration,
+    open: bool,
+    writer: Option<Vec<u8>>,
+    stats: HandlerStats,
+}
+
+impl HttpHandler {
+    fn _create(url: &str) -> Result<Self, Error> {
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err(Error::new(
+                InvalidInput,
+                format!("'url' must be 'http://' or 'https://': {url}"),
+            ));
+        }
+
+        let agent = Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(10)))
+            .build()
+            .into();
+
+        Ok(HttpHandler {
+            url: url.to_string(),
+            formatter: FormatType::Ndjson.create(None),
+            filter: None,
+            agent,
+            headers: Vec::new(),
+            batch: Vec::new(),
+            batch_size: 100,
+            flush_interval: Duration::from_secs(5),
+            last_flush: Instant::now(),
+            retries: 3,
+            retry_delay: Duration::from_millis(200),
+            open: true,
+            writer: None,
+            stats: HandlerStats::default(),
+        })
+    }
+
+    fn log(&self) -> String {
+        if let Some(w) = self.writer.to_owned() {
+            String::from_utf8(w).unwrap()
+        } else {
+            String::new()
+        }
+    }
+
+    ///
+    /// Add a header to be sent with each request.
+    ///
+    /// For example, for authorization.
+    ///
+    /// ## Parameters
+    /// - `name` - The header's name.
+    /// - `value` - The header's value.
+    ///
+    pub fn add_header(&mut self, name: &str, value: &str) {
+        self.headers.push((name.to_string(), value.to_string()));
+    }
+
+    ///
+    /// Set the number of entries that triggers sending a batch.
+    ///
+    /// ## Parameters
+    /// - `batch_size` - The number of entries.
+    ///
+    pub fn set_batch_size(&mut self, batch_size: usize) {
+        self.batch_size = batch_size.max(1);
+    }
+
+    ///
+    /// Set the maximum time a batch is held, before it is sent on the next publish.
+    ///
+    /// ## Parameters
+    /// - `flush_interval` - The time between batches.
+    ///
+    pub fn set_flush_interval(&mut self, flush_interval: Duration) {
+        self.flush_interval = flush_interval;
+    }
+
+    ///
+    /// Set the retry policy for failed requests.
+    ///
+    /// ## Parameters
+    /// - `retries` - The number of times a failed request is retried.
+    /// - `retry_delay` - The delay before the first retry. Doubled for each subsequent retry.
+    ///
+    pub fn set_retries(&mut self, retries: u32, retry_delay: Duration) {
+        self.retries = retries;
+        self.retry_delay = retry_delay;
+    }
+
+    ///
+    /// Produce the JSON array for the current batch.
+    ///
+    fn body(&self) -> String {
+        let is_json = matches!(self.formatter, Formatter::Json(_));
+        let mut buf = String::from("[");
+
+        for (i, entry) in self.batch.iter().enumerate() {
+            if i > 0 {
+                buf.push(',');
+            }
+
+            if is_json {
+                buf.push_str(entry);
+            } else {
+                buf.push_str(&json_escape(entry));
+            }
+        }
+
+        buf.push(']');
+        buf
+    }
+
+    fn post(&self, body: &str) -> Result<(), ureq::Error> {
+        let mut request = self
+            .agent
+            .post(&self.url)
+            .header("Content-Type", "application/json");
+
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+
+        request.send(body).map(|_| ())
+    }
+
+    ///
+    /// Send the current batch, retrying as required.
+    ///
+    fn send(&mut self) {
+        self.last_flush = Instant::now();
+
+        if self.batch.is_empty() {
+            return;
+        }
+
+        let count = self.batch.len() as u64;
+        let body = self.body();
+        self.batch.clear();
+
+        if let Some(w) = self.writer.as_mut() {
+            w.extend_from_slice(body.as_bytes());
+            w.push(b'\n');
+            self.stats.wrote(body.len() + 1);
+            return;
+        }
+
+        let mut delay = self.retry_delay;
+
+        for attempt in 0..=self.retries {
+            if attempt > 0 {
+                thread::sleep(delay);
+                delay *= 2;
+            }
+
+            match self.post(&body) {
+                Ok(()) => {
+                    self.stats.wrote(body.len());
+                    return;
+                }
+                Err(e) if attempt == self.retries => self.stats.error(e, count),
+                Err(_) => {}
+            }
+        }
+    }
+}
+
+impl fmt::Display for HttpHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} : {}", self.url, self.formatter)
+    }
+}
+
+impl HandlerTrait for HttpHandler {
+    ///
+    /// Create a new handler instance.
+    ///
+    /// ## Parameters
+    /// - `name` - The URL of the endpoint.
+    ///
+    fn create(name: &str) -> Result<Self, Error> {
+        HttpHandler::_create(name)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    ///
+    /// Sends any remaining entries, then closes the handler.
+    ///
+    fn close(&mut self) {
+        self.flush();
+        self.open = false;
+    }
+
+    ///
+    /// Sends the current batch.
+    ///
+    fn flush(&mut self) {
+        if self.open {
+            self.send();
+        }
+    }
+
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
+    fn get_formatter(&self) -> Formatter {
+        self.formatter.clone()
+    }
+
+    fn get_log(&self) -> String {
+        self.log()
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn publish(&mut self, log_entry: &LogEntry) {
+        self.stats.published += 1;
+
+        if !self.open {
+            self.stats.dropped += 1;
+            return;
+        }
+
+        self.batch.push(self.formatter.format(log_entry));
+
+        if self.batch.len() >= self.batch_size || self.last_flush.elapsed() >= self.flush_interval
+        {
+            self.send();
+        }
+    }
+
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
+    }
+
+    fn set_formatter(&mut self, formatter: Formatter) {
+        self.formatter = formatter;
+    }
+
+    ///
+    /// Sets the test mode to `state`.
+    ///
+    /// If set to `true`, the body of each request is stored instead of
+    /// being sent. Use `get_log()` to obtain them.
+    ///
+    fn set_test_mode(&mut self, state: bool) {
+        if state {
+            self.writer = Some(Vec::new());
+        } else {
+            self.writer = None;
+        }
+    }
+
+    fn stats(&self) -> HandlerStats {
+        self.stats.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+        time::Duration,
+    };
+
+    ///
+    /// Serve one request per status, returning the request bodies received.
+    ///
+    fn server(statuses: &'static [u16]) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/ingest", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut bodies = Vec::new();
+
+            for status in statuses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut len = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    if line == "\r\n" {
+                        break;
+                    }
+
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            len = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+
+                let mut body = vec![0; len];
+                reader.read_exact(&mut body).unwrap();
+                bodies.push(String::from_utf8(body).unwrap());
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} X\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                )
+                .unwrap();
+            }
+
+            bodies
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn batching() {
+        let (url, server) = server(&[200, 500, 200]);
+
+        let mut h = HttpHandler::create(&url).unwrap();
+        h.set_formatter(FormatType::Simple.create(None));
+        h.set_batch_size(2);
+        h.set_retries(1, Duration::ZERO);
+
+        let mut le = LogEntry::create(Level::INFO, "batching".to_string(), String::new());
+
+        for msg in ["one", "two \"2\"", "three"] {
+            le.message = msg.to_string();
+            h.publish(&le);
+        }
+
+        h.close();
+
+        assert_eq!(
+            server.join().unwrap(),
+            vec![
+                "[\"->batching [INFO   ] one\",\"->batching [INFO   ] two \\\"2\\\"\"]",
+                "[\"->batching [INFO   ] three\"]",
+                "[\"->batching [INFO   ] three\"]",
+            ]
+        );
+    }
+
+    #[test]
+    fn http_handler_test_mode() {
+        let mut log = Logger::builder(module_path!())
+            .add_http_handler("http://127.0.0.1:9/ingest")
+            .set_fn_name("http_handler_test_mode")
+            .build();
+
+        let h = log.get_handler(Handler::Http).unwrap();
+        h.set_test_mode(true);
+
+        assert!(h.is_open());
+        assert_eq!(
+            h.to_string(),
+            "http://127.0.0.1:9/ingest : dt_fmt: \"%+\" - ndjson: true"
+        );
+
+        log.info("one");
+        log.warning("two");
+
+        let h = log.get_handler(Handler::Http).unwrap();
+        h.flush();
+
+        let body = h.get_log();
+
+        assert!(body.starts_with("[{\"timestamp\":"));
+        assert!(body.contains("\"level\":\"INFO\",\"message\":\"one\"},{"));
+        assert!(body.ends_with("\"level\":\"WARNING\",\"message\":\"two\"}]\n"));
+    }
+
+    #[test]
+    #[should_panic(expected = "'url' must be 'http://' or 'https://'")]
+    fn bad_url() {
+        let _ = Logger::builder(module_path!())
+            .add_http_handler("localhost:8080")
+            .build();
+    }
+}
@@ -0,0 +1,425 @@
+//
+// File Name:    memory_handler.rs
+// Directory:    src/handlers
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # MemoryHandler
+//!
+
+use crate::*;
+use chrono::{DateTime, Local};
+use std::{any::Any, collections::VecDeque, fmt, io::Error};
+
+///
+/// The default number of log entries buffered.
+///
+pub const DEFAULT_CAPACITY: usize = 1000;
+
+///
+/// Buffers log entries in a circular buffer, only pushing them to a target
+/// handler when a log entry at, or above, the push level is published.
+///
+/// This is a cheap "flight recorder". For example, with the logger set to
+/// `Level::FINEST` and the push level to `Level::SEVERE`, nothing is written
+/// until something goes wrong. Then the last `capacity` log entries, leading
+/// up to and including the `SEVERE` one, are written by the target handler.
+///
+/// The formatter, test mode, and log are all those of the target handler.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// let target = StringHandler::create("").unwrap();
+///
+/// let mut log = Logger::builder("app")
+///     .add_memory_handler(Box::new(target), 2, Level::SEVERE)
+///     .set_level(Level::FINEST)
+///     .set_fn_name("main")
+///     .build();
+///
+/// log.finest("one");
+/// log.finest("two");
+/// log.finest("three");
+///
+/// let h = log.get_handler(Handler::Memory).unwrap();
+/// assert!(h.get_log().is_empty());
+///
+/// log.severe("Crash!");
+///
+/// let h = log.get_handler(Handler::Memory).unwrap();
+/// assert_eq!(
+///     h.get_log(),
+///     "app->main [FINEST ] three\napp->main [SEVERE ] Crash!\n"
+/// );
+/// ```
+///
+pub struct MemoryHandler {
+    target: Box<dyn HandlerTrait>,
+    buffer: VecDeque<LogEntry>,
+    capacity: usize,
+    push_level: Level,
+    filter: Option<Box<dyn FilterTrait>>,
+    stats: HandlerStats,
+}
+
+impl MemoryHandler {
+    ///
+    /// Creates a new `MemoryHandler`.
+    ///
+    /// ## Parameters
+    /// - `target` - The handler to push the buffered log entries to.
+    /// - `capacity` - The maximum number of log entries buffered.
+    /// - `push_level` - The level that triggers a [`push()`][MemoryHandler::push].
+    ///
+    pub fn new(target: Box<dyn HandlerTrait>, capacity: usize, push_level: Level) -> Self {
+        MemoryHandler {
+            target,
+            buffer: VecDeque::with_capacity(capacity),
+            capacity,
+            push_level,
+            filter: None,
+            stats: HandlerStats::default(),
+        }
+    }
+
+    ///
+    /// Returns the maximum number of log entries buffered.
+    ///
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    ///
+    /// Returns the buffered log entries, oldest first.
+    ///
+    pub fn entries(&self) -> &VecDeque<LogEntry> {
+        &self.buffer
+    }
+
+    ///
+    /// Returns the buffered log entries at `level`, oldest first.
+    ///
+    /// ## Parameters
+    /// - `level` - The level to select.
+    ///
+    pub fn entries_at(&self, level: Level) -> Vec<&LogEntry> {
+        self.entries_matching(|e| e.level == level)
+    }
+
+    ///
+    /// Returns the buffered log entries that match the `predicate`, oldest first.
+    ///
+    /// ## Parameters
+    /// - `predicate` - Returns `true` for each log entry to be included.
+    ///
+    /// ## Examples
+    /// ```text
+    /// let problems = h.entries_matching(|e| e.level() >= Level::WARNING);
+    /// ```
+    ///
+    pub fn entries_matching<F>(&self, predicate: F) -> Vec<&LogEntry>
+    where
+        F: Fn(&LogEntry) -> bool,
+    {
+        self.buffer.iter().filter(|e| predicate(e)).collect()
+    }
+
+    ///
+    /// Returns the buffered log entries created at, or after, `since`, oldest first.
+    ///
+    /// ## Parameters
+    /// - `since` - The earliest timestamp to include. A [`SystemTime`][std::time::SystemTime]
+    ///   will also do.
+    ///
+    pub fn entries_since(&self, since: impl Into<DateTime<Local>>) -> Vec<&LogEntry> {
+        let since = since.into();
+        self.entries_matching(|e| e.timestamp >= since)
+    }
+
+    ///
+    /// Publish all of the buffered log entries to the target handler,
+    /// then clear the buffer.
+    ///
+    /// Any filter set on the target handler is applied to each entry.
+    ///
+    pub fn push(&mut self) {
+        for entry in self.buffer.drain(..) {
+            if self
+                .target
+                .get_filter()
+                .is_none_or(|filter| filter.should_publish(&entry))
+            {
+                self.target.publish(&entry);
+            }
+        }
+    }
+
+    ///
+    /// Returns the level that triggers a push.
+    ///
+    pub fn push_level(&self) -> Level {
+        self.push_level
+    }
+
+    ///
+    /// Set the level that triggers a push.
+    ///
+    /// ## Parameters
+    /// - `push_level` - The new push level.
+    ///
+    pub fn set_push_level(&mut self, push_level: Level) {
+        self.push_level = push_level;
+    }
+}
+
+impl fmt::Debug for MemoryHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryHandler")
+            .field("target", &self.target.to_string())
+            .field("buffer", &self.buffer)
+            .field("capacity", &self.capacity)
+            .field("push_level", &self.push_level)
+            .field("filter", &self.filter)
+            .field("stats", &self.stats)
+            .finish()
+    }
+}
+
+impl fmt::Display for MemoryHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "MemoryHandler({}, {}) -> {}",
+            self.capacity, self.push_level, self.target
+        )
+    }
+}
+
+impl HandlerTrait for MemoryHandler {
+    ///
+    /// Create a new handler instance, with a [`StringHandler`] as its target,
+    /// a capacity of [`DEFAULT_CAPACITY`], and a push level of `Level::SEVERE`.
+    ///
+    /// ## Parameters
+    /// - `name` - Passed on to create the target.
+    ///
+    fn create(name: &str) -> Result<Self, Error> {
+        Ok(MemoryHandler::new(
+            Box::new(StringHandler::create(name)?),
+            DEFAULT_CAPACITY,
+            Level::SEVERE,
+        ))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    ///
+    /// Discards the buffer, then closes the target handler.
+    ///
+    fn close(&mut self) {
+        self.buffer.clear();
+        self.target.close();
+    }
+
+    ///
+    /// Flushes the target handler. Does *not* push the buffer.
+    ///
+    fn flush(&mut self) {
+        self.target.flush();
+    }
+
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
+    fn get_formatter(&self) -> Formatter {
+        self.target.get_formatter()
+    }
+
+    fn get_log(&self) -> String {
+        self.target.get_log()
+    }
+
+    fn is_open(&self) -> bool {
+        self.target.is_open()
+    }
+
+    fn publish(&mut self, log_entry: &LogEntry) {
+        self.stats.published += 1;
+
+        if self.capacity == 0 {
+            self.stats.dropped += 1;
+            return;
+        }
+
+        if self.buffer.len() == self.capacity {
+            self.buffer.pop_front();
+            self.stats.dropped += 1;
+        }
+
+        self.buffer.push_back(log_entry.clone());
+
+        if log_entry.level >= self.push_level {
+            self.push();
+        }
+    }
+
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
+    }
+
+    fn set_formatter(&mut self, formatter: Formatter) {
+        self.target.set_formatter(formatter);
+    }
+
+    fn set_test_mode(&mut self, state: bool) {
+        self.target.set_test_mode(state);
+    }
+
+    ///
+    /// Returns this handler's own counters. The buffered log entries that are
+    /// discarded, to make room for new ones, are counted as dropped. Nothing is
+    /// written by this handler, see the target handler for that.
+    ///
+    fn stats(&self) -> HandlerStats {
+        self.stats.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn memory_handler() {
+        let mut h = MemoryHandler::create("").unwrap();
+        h.set_push_level(Level::WARNING);
+
+        assert_eq!(h.capacity(), DEFAULT_CAPACITY);
+        assert_eq!(h.push_level(), Level::WARNING);
+
+        let mut le = LogEntry::create(Level::INFO, "memory_handler".to_string(), String::new());
+
+        for n in 1..=3 {
+            le.message = n.to_string();
+            h.publish(&le);
+        }
+
+        assert_eq!(h.get_log(), "");
+
+        le.level = Level::WARNING;
+        le.message = "4".to_string();
+        h.publish(&le);
+
+        le.level = Level::INFO;
+        le.message = "5".to_string();
+        h.publish(&le);
+
+        assert_eq!(
+            h.get_log(),
+            "->memory_handler [INFO   ] 1
+->memory_handler [INFO   ] 2
+->memory_handler [INFO   ] 3
+->memory_handler [WARNING] 4\n"
+        );
+
+        h.push();
+
+        assert!(
+            h.get_log()
+                .ends_with("[WARNING] 4\n->memory_handler [INFO   ] 5\n")
+        );
+    }
+
+    #[test]
+    fn add_memory_handler_with() {
+        let mut log = Logger::builder(module_path!())
+            .add_memory_handler_with(
+                Box::new(StringHandler::create("").unwrap()),
+                2,
+                Level::SEVERE,
+                FormatType::Custom,
+                Some(Box::new(MockFormatter::new())),
+            )
+            .set_fn_name("add_memory_handler_with")
+            .build();
+
+        log.info("one");
+        log.info("two");
+        log.severe("three");
+
+        let h = log.get_handler(Handler::Memory).unwrap();
+
+        assert_eq!(h.get_log(), "MockFormatter\nMockFormatter\n");
+        assert_eq!(
+            h.to_string().lines().next().unwrap(),
+            "MemoryHandler(2, SEVERE) -> MockFormatter"
+        );
+    }
+
+    #[test]
+    fn query() {
+        let mut h = MemoryHandler::create("").unwrap();
+
+        for (level, message) in [
+            (Level::INFO, "one"),
+            (Level::WARNING, "two"),
+            (Level::FINE, "three"),
+            (Level::WARNING, "four"),
+        ] {
+            h.publish(&LogEntry::create(
+                level,
+                "query".to_string(),
+                message.to_string(),
+            ));
+        }
+
+        let messages = |entries: Vec<&LogEntry>| -> Vec<String> {
+            entries.iter().map(|e| e.message().to_string()).collect()
+        };
+
+        assert_eq!(h.entries().len(), 4);
+        assert_eq!(messages(h.entries_at(Level::WARNING)), ["two", "four"]);
+        assert_eq!(
+            messages(h.entries_matching(|e| e.message().starts_with('t'))),
+            ["two", "three"]
+        );
+        assert_eq!(
+            messages(h.entries_since(h.entries()[0].timestamp())),
+            ["one", "two", "three", "four"]
+        );
+
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        assert!(h.entries_since(std::time::SystemTime::now()).is_empty());
+
+        h.push();
+        assert!(h.entries_at(Level::WARNING).is_empty());
+    }
+}
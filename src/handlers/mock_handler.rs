@@ -25,9 +25,12 @@
 //!
 //! Returned as Default for Custom handler that is missing.
 //!
+//! Also useful in tests, as it records every log entry it is given to publish.
+//!
 
 use crate::*;
 use std::{
+    any::Any,
     fmt,
     io::{self, Error},
 };
@@ -38,8 +41,68 @@ use std::{
 /// It is a filler for `Handler::Custom(label).create()`. It is also used
 /// in examples for custom handlers.
 ///
+/// Nothing is written anywhere, however, every log entry published is recorded.
+/// This allows tests to check what was actually sent to the handler, via
+/// [`published()`][MockHandler::published], and [`count_at()`][MockHandler::count_at].
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// let mut log = Logger::custom_logger(
+///     "app",
+///     "Mock",
+///     Box::new(MockHandler::create("").unwrap()),
+/// );
+/// log.set_fn_name("main");
+///
+/// log.info("one");
+/// log.warning("two");
+/// log.info("three");
+///
+/// let mock = log.get_handler_as::<MockHandler>().unwrap();
+///
+/// assert_eq!(mock.published().len(), 3);
+/// assert_eq!(mock.count_at(Level::INFO), 2);
+/// assert_eq!(mock.published()[1].fn_name(), "main");
+/// assert_eq!(mock.published()[1].message(), "two");
+///
+/// mock.clear();
+/// assert!(mock.published().is_empty());
+/// ```
+///
 #[derive(Debug, Default)]
-pub struct MockHandler {}
+pub struct MockHandler {
+    filter: Option<Box<dyn FilterTrait>>,
+    published: Vec<LogEntry>,
+}
+
+impl MockHandler {
+    ///
+    /// Discard all of the recorded log entries.
+    ///
+    pub fn clear(&mut self) {
+        self.published.clear();
+    }
+
+    ///
+    /// Returns the number of recorded log entries at `level`.
+    ///
+    /// ## Parameters
+    /// - `level` - The level to count.
+    ///
+    pub fn count_at(&self, level: Level) -> usize {
+        self.published.iter().filter(|e| e.level == level).count()
+    }
+
+    ///
+    /// Returns the recorded log entries, in the order they were published.
+    ///
+    pub fn published(&self) -> &[LogEntry] {
+        &self.published
+    }
+}
 
 impl fmt::Display for MockHandler {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -55,6 +118,14 @@ impl HandlerTrait for MockHandler {
         Ok(Default::default())
     }
 
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
     ///
     /// This is a 'NoOp' fn.
     ///
@@ -65,6 +136,10 @@ impl HandlerTrait for MockHandler {
     ///
     fn flush(&mut self) {}
 
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
     fn get_formatter(&self) -> Formatter {
         Default::default()
     }
@@ -80,8 +155,16 @@ impl HandlerTrait for MockHandler {
         false
     }
 
-    #[allow(private_interfaces)]
-    fn publish(&mut self, _log_entry: &LogEntry) {}
+    ///
+    /// Records the log entry. Nothing is written.
+    ///
+    fn publish(&mut self, log_entry: &LogEntry) {
+        self.published.push(log_entry.clone());
+    }
+
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
+    }
 
     fn set_formatter(&mut self, _formatter: Formatter) {}
 
@@ -89,6 +172,16 @@ impl HandlerTrait for MockHandler {
     /// This is a 'NoOp' fn.
     ///
     fn set_test_mode(&mut self, _state: bool) {}
+
+    ///
+    /// Only [`published()`][HandlerStats::published] is counted.
+    ///
+    fn stats(&self) -> HandlerStats {
+        HandlerStats {
+            published: self.published.len() as u64,
+            ..Default::default()
+        }
+    }
 }
 
 #[cfg(test)]
@@ -132,4 +225,51 @@ mod tests {
         assert_eq!(handler.get_log(), "".to_string());
         handler.close();
     }
+
+    #[test]
+    fn published() {
+        let mut log = Logger::custom_logger(
+            module_path!(),
+            "Mock",
+            Box::new(MockHandler::create(module_path!()).unwrap()),
+        );
+        log.set_level(Level::ALL);
+        log.set_fn_name("published");
+
+        log.fine("one");
+        log.info("two");
+        log.warning("three");
+        log.info("four");
+
+        let mock = log.get_handler_as::<MockHandler>().unwrap();
+
+        let entries: Vec<_> = mock
+            .published()
+            .iter()
+            .map(|e| (e.level(), e.fn_name(), e.message()))
+            .collect();
+
+        assert_eq!(
+            entries,
+            vec![
+                (Level::FINE, "published", "one"),
+                (Level::INFO, "published", "two"),
+                (Level::WARNING, "published", "three"),
+                (Level::INFO, "published", "four"),
+            ]
+        );
+        assert_eq!(mock.count_at(Level::INFO), 2);
+        assert_eq!(mock.count_at(Level::WARNING), 1);
+        assert_eq!(mock.count_at(Level::SEVERE), 0);
+
+        mock.clear();
+        assert!(mock.published().is_empty());
+        assert_eq!(mock.count_at(Level::INFO), 0);
+
+        log.severe("five");
+
+        let mock = log.get_handler_as::<MockHandler>().unwrap();
+        assert_eq!(mock.published().len(), 1);
+        assert_eq!(mock.published()[0].level(), Level::SEVERE);
+    }
 }
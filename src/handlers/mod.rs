@@ -24,16 +24,50 @@
 //! # Handlers
 //!
 
+mod binary_handler;
 mod console_handler;
+#[cfg(feature = "db")]
+mod db_handler;
+#[cfg(feature = "email")]
+mod email_handler;
 mod file_handler;
+mod filters;
 mod formatters;
 mod handler;
+#[cfg(feature = "http")]
+mod http_handler;
+mod memory_handler;
 mod mock_handler;
+mod rotating_file_handler;
 mod string_handler;
+mod syslog_handler;
+mod tcp_handler;
+mod throttle_handler;
+mod udp_handler;
+#[cfg(feature = "http")]
+mod webhook_handler;
 
-pub use console_handler::{ConsoleHandler, console_type::*};
-pub use file_handler::FileHandler;
+pub use binary_handler::{BINARY_MAGIC, BinaryHandler, log_reader::LogReader};
+pub use console_handler::{
+    ConsoleHandler, color_choice::*, console_type::*, printer_trait::*, production_split::*,
+};
+#[cfg(feature = "db")]
+pub use db_handler::DbHandler;
+#[cfg(feature = "email")]
+pub use email_handler::EmailHandler;
+pub use file_handler::{FileHandler, FileOptions, FlushPolicy, OpenMode, SyncPolicy};
+pub use filters::*;
 pub use formatters::*;
-pub use handler::{Handler, handler_trait::*};
+pub use handler::{Handler, handler_stats::*, handler_trait::*};
+#[cfg(feature = "http")]
+pub use http_handler::HttpHandler;
+pub use memory_handler::{DEFAULT_CAPACITY, MemoryHandler};
 pub use mock_handler::MockHandler;
+pub use rotating_file_handler::{DEFAULT_MAX_SIZE, RotatingFileHandler, Rotation};
 pub use string_handler::StringHandler;
+pub use syslog_handler::{SYSLOG_PORT, SYSLOG_SOCKET, SyslogHandler, facility::*};
+pub use tcp_handler::TcpHandler;
+pub use throttle_handler::ThrottleHandler;
+pub use udp_handler::{UDP_MAX_PACKET_SIZE, UdpHandler};
+#[cfg(feature = "http")]
+pub use webhook_handler::{WebhookHandler, WebhookService};
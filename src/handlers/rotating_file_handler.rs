@@ -0,0 +1,989 @@
+//
+// File Name:    rotating_file_handler.rs
+// Directory:    src/handlers
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # RotatingFileHandler
+//!
+
+use chrono::Local;
+use regex::Regex;
+use std::{
+    any::Any,
+    fmt,
+    fs::{self, File},
+    io::{Error, ErrorKind::InvalidInput, Write},
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+#[cfg(feature = "compression")]
+use flate2::{Compression, write::GzEncoder};
+#[cfg(feature = "compression")]
+use std::{
+    io,
+    thread::{self, JoinHandle},
+};
+
+use super::formatters::ansi;
+use crate::*;
+
+///
+/// The default maximum file size: 10 MiB.
+///
+pub const DEFAULT_MAX_SIZE: u64 = 10 * 1024 * 1024;
+
+///
+/// The extension added to compressed backups.
+///
+const GZ: &str = ".gz";
+
+///
+/// Configures when, and how, a [`RotatingFileHandler`] rotates its file.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// let rotation = Rotation::new(1024 * 1024)
+///     .max_backups(7)
+///     .pattern("test_logs/app.{date}.{index}.log");
+///
+/// assert_eq!(rotation.max_size(), 1024 * 1024);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rotation {
+    max_size: u64,
+    compress: bool,
+    max_backups: Option<usize>,
+    pattern: Option<String>,
+}
+
+impl Rotation {
+    ///
+    /// Creates a new `Rotation`.
+    ///
+    /// ## Parameters
+    /// - `max_size` - The size, in bytes, that the file must not grow beyond.
+    ///
+    pub fn new(max_size: u64) -> Self {
+        Rotation {
+            max_size,
+            compress: false,
+            max_backups: None,
+            pattern: None,
+        }
+    }
+
+    ///
+    /// Gzip each backup, on a background thread, as it is rotated out.
+    ///
+    /// The compressed backups are named: `name.1.gz`, `name.2.gz`, and so on.
+    ///
+    /// Requires the `compression` feature.
+    ///
+    /// ## Parameters
+    /// - `compress` - Compress if `true`.
+    ///
+    #[cfg(feature = "compression")]
+    pub fn compress(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
+    ///
+    /// Keep, at most, `max_backups` backups. The oldest are deleted.
+    ///
+    /// By default, all backups are kept.
+    ///
+    /// ## Parameters
+    /// - `max_backups` - The number of backups to keep.
+    ///
+    pub fn max_backups(mut self, max_backups: usize) -> Self {
+        self.max_backups = Some(max_backups);
+        self
+    }
+
+    ///
+    /// Name the backups according to `pattern`, instead of `name.1`, `name.2`, ...
+    ///
+    /// The `pattern` is the path of the backups, with these variables:
+    /// - `{date}` - The date of the rotation: `YYYY-MM-DD`.
+    /// - `{index}` - Starts at `1` and counts up, so the newest backup has the highest.
+    ///   If the `pattern` does not include `{index}`, then `.{index}` is appended.
+    ///
+    /// The variables may only be used in the file name part of the `pattern`.
+    ///
+    /// ## Parameters
+    /// - `pattern` - For example: `logs/app.{date}.{index}.log`.
+    ///
+    pub fn pattern(mut self, pattern: &str) -> Self {
+        let mut pattern = pattern.to_string();
+
+        if !pattern.contains("{index}") {
+            pattern.push_str(".{index}");
+        }
+
+        self.pattern = Some(pattern);
+        self
+    }
+
+    ///
+    /// Returns `true` if backups are compressed.
+    ///
+    pub fn is_compressed(&self) -> bool {
+        self.compress
+    }
+
+    ///
+    /// Returns the maximum file size, in bytes.
+    ///
+    pub fn max_size(&self) -> u64 {
+        self.max_size
+    }
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_SIZE)
+    }
+}
+
+///
+/// Publishes log entries to the file whose name was provided during
+/// initialization, rotating it when it would exceed its maximum size.
+///
+/// On rotation, the backups are each shifted up by one: `name.1` to `name.2`,
+/// and so on. Then the current file becomes `name.1`, and a new, empty
+/// file is started. Alternatively, the backups can be named by a
+/// [pattern][Rotation::pattern], such as `app.{date}.{index}.log`.
+///
+/// The number of backups kept can be limited with [`Rotation::max_backups()`].
+///
+/// The maximum size defaults to [`DEFAULT_MAX_SIZE`]. A single log entry that
+/// is larger than the maximum size is still written, to a file of its own.
+///
+/// See [`Rotation`] for the options.
+///
+/// Each log entry is written straight to the file. When the file is synced to the
+/// disk is set by its [`SyncPolicy`]. An I/O error closes the handler, and is
+/// recorded in its [`HandlerStats`], and reported to the callback set by
+/// [`set_on_error()`][RotatingFileHandler::set_on_error].
+///
+#[derive(Default)]
+pub struct RotatingFileHandler {
+    filename: String,
+    formatter: Formatter,
+    filter: Option<Box<dyn FilterTrait>>,
+    file: Option<File>,
+    size: u64,
+    rotation: Rotation,
+    sync_policy: SyncPolicy,
+    last_sync: Option<Instant>,
+    on_error: Option<Box<dyn FnMut(&Error) + Send + Sync>>,
+    #[cfg(feature = "compression")]
+    compressor: Option<JoinHandle<()>>,
+    writer: Option<Vec<u8>>,
+    line: String,
+    stats: HandlerStats,
+}
+
+impl RotatingFileHandler {
+    fn _create(filename: &str) -> Result<Self, Error> {
+        if filename.is_empty() {
+            return Err(Error::new(InvalidInput, "'filename' must not be empty"));
+        }
+
+        let file = File::options().append(true).create(true).open(filename)?;
+
+        Ok(RotatingFileHandler {
+            filename: filename.to_string(),
+            formatter: FormatType::Iso8601.create(None),
+            filter: None,
+            size: file.metadata()?.len(),
+            file: Some(file),
+            rotation: Rotation::default(),
+            sync_policy: SyncPolicy::default(),
+            last_sync: None,
+            on_error: None,
+            #[cfg(feature = "compression")]
+            compressor: None,
+            writer: None,
+            line: String::new(),
+            stats: HandlerStats::default(),
+        })
+    }
+
+    fn log(&self) -> String {
+        if let Some(w) = self.writer.to_owned() {
+            String::from_utf8(w).unwrap()
+        } else {
+            String::new()
+        }
+    }
+
+    ///
+    /// Returns the rotation options.
+    ///
+    pub fn rotation(&self) -> &Rotation {
+        &self.rotation
+    }
+
+    ///
+    /// Set the rotation options.
+    ///
+    /// ## Parameters
+    /// - `rotation` - The options.
+    ///
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.rotation = rotation;
+    }
+
+    ///
+    /// Set the callback to be told of each I/O error.
+    ///
+    /// ## Parameters
+    /// - `on_error` - Called with the error, after the file has been closed.
+    ///
+    pub fn set_on_error<F>(&mut self, on_error: F)
+    where
+        F: FnMut(&Error) + Send + Sync + 'static,
+    {
+        self.on_error = Some(Box::new(on_error));
+    }
+
+    ///
+    /// Returns the sync policy.
+    ///
+    pub fn sync_policy(&self) -> &SyncPolicy {
+        &self.sync_policy
+    }
+
+    ///
+    /// Set the sync policy.
+    ///
+    /// ## Parameters
+    /// - `sync_policy` - The policy.
+    ///
+    pub fn set_sync_policy(&mut self, sync_policy: SyncPolicy) {
+        self.sync_policy = sync_policy;
+    }
+
+    ///
+    /// Sync the file to the disk.
+    ///
+    fn sync(&mut self) {
+        if let Some(Err(e)) = self.file.as_ref().map(File::sync_all) {
+            self.fail(e, 0);
+        }
+
+        self.last_sync = Some(Instant::now());
+    }
+
+    ///
+    /// Close the file, then record and report `error`.
+    ///
+    /// ## Parameters
+    /// - `error` - The I/O error.
+    /// - `dropped` - The number of log entries lost.
+    ///
+    fn fail(&mut self, error: Error, dropped: u64) {
+        self.file = None;
+        self.stats.error(&error, dropped);
+
+        if let Some(on_error) = self.on_error.as_mut() {
+            on_error(&error);
+        }
+    }
+
+    ///
+    /// The name of the `index`th backup.
+    ///
+    fn backup_name(&self, index: usize) -> String {
+        format!("{}.{index}", self.filename)
+    }
+
+    ///
+    /// Check for the `index`th backup, compressed or not.
+    ///
+    fn backup_exists(&self, index: usize) -> bool {
+        let name = self.backup_name(index);
+        Path::new(&name).exists() || Path::new(&(name + GZ)).exists()
+    }
+
+    ///
+    /// Rename the `index`th backup, compressed or not, to the next index.
+    ///
+    fn shift(&self, index: usize) -> Result<(), Error> {
+        let from = self.backup_name(index);
+        let to = self.backup_name(index + 1);
+
+        for ext in ["", GZ] {
+            if Path::new(&(from.clone() + ext)).exists() {
+                fs::rename(from.clone() + ext, to.clone() + ext)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Wait for the previous backup to finish being compressed.
+    ///
+    fn wait_for_compressor(&mut self) {
+        #[cfg(feature = "compression")]
+        if let Some(handle) = self.compressor.take() {
+            let _ = handle.join();
+        }
+    }
+
+    ///
+    /// Shift the backups up by one, move the current file to `name.1`,
+    /// then start a new file.
+    ///
+    fn rotate(&mut self) -> Result<(), Error> {
+        if let Some(f) = self.file.take() {
+            if self.sync_policy != SyncPolicy::Never {
+                f.sync_all()?;
+            }
+        }
+
+        self.wait_for_compressor();
+
+        let backup = match self.rotation.pattern.clone() {
+            Some(pattern) => self.rotate_patterned(&pattern)?,
+            None => self.rotate_numbered()?,
+        };
+
+        self.file = Some(File::create(&self.filename)?);
+        self.size = 0;
+
+        #[cfg(feature = "compression")]
+        if self.rotation.compress && Path::new(&backup).exists() {
+            self.compressor = Some(thread::spawn(move || {
+                let _ = gzip(&backup);
+            }));
+        }
+
+        #[cfg(not(feature = "compression"))]
+        let _ = backup;
+
+        Ok(())
+    }
+
+    ///
+    /// Shift the backups up, then move the current file to `name.1`.
+    ///
+    /// Returns the name of the new backup.
+    ///
+    fn rotate_numbered(&self) -> Result<String, Error> {
+        let mut last = 1;
+
+        while self.backup_exists(last) {
+            last += 1;
+        }
+
+        for index in (1..last).rev() {
+            self.shift(index)?;
+        }
+
+        fs::rename(&self.filename, self.backup_name(1))?;
+
+        if let Some(max_backups) = self.rotation.max_backups {
+            for index in max_backups + 1..=last {
+                for ext in ["", GZ] {
+                    remove(&(self.backup_name(index) + ext))?;
+                }
+            }
+        }
+
+        Ok(self.backup_name(1))
+    }
+
+    ///
+    /// Move the current file to the next name from the `pattern`.
+    ///
+    /// Returns the name of the new backup.
+    ///
+    fn rotate_patterned(&self, pattern: &str) -> Result<String, Error> {
+        let date = if pattern.contains("{date}") {
+            Local::now().format("%Y-%m-%d").to_string()
+        } else {
+            String::new()
+        };
+        let mut backups = patterned_backups(pattern)?;
+
+        // Without a `{date}`, every backup has an empty date, so the index
+        // carries on from the highest one.
+        let index = backups
+            .iter()
+            .filter(|b| b.date == date)
+            .map(|b| b.index + 1)
+            .max()
+            .unwrap_or(1);
+
+        let backup = pattern
+            .replace("{date}", &date)
+            .replace("{index}", &index.to_string());
+
+        fs::rename(&self.filename, &backup)?;
+
+        if let Some(max_backups) = self.rotation.max_backups {
+            backups.push(Backup {
+                date,
+                index,
+                path: PathBuf::from(&backup),
+            });
+            backups.sort_by(|a, b| (&a.date, a.index).cmp(&(&b.date, b.index)));
+
+            let excess = backups.len().saturating_sub(max_backups);
+
+            for b in &backups[..excess] {
+                fs::remove_file(&b.path)?;
+            }
+        }
+
+        Ok(backup)
+    }
+}
+
+///
+/// An existing backup, named by a pattern.
+///
+struct Backup {
+    date: String,
+    index: usize,
+    path: PathBuf,
+}
+
+///
+/// Find all of the existing backups named by the `pattern`, compressed or not.
+///
+fn patterned_backups(pattern: &str) -> Result<Vec<Backup>, Error> {
+    let path = Path::new(pattern);
+    let dir = match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => Path::new("."),
+    };
+    let file_pattern = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| Error::new(InvalidInput, format!("invalid pattern: {pattern}")))?;
+
+    let re_str = regex::escape(file_pattern)
+        .replace(&regex::escape("{date}"), r"(?<date>\d{4}-\d{2}-\d{2})")
+        .replace(&regex::escape("{index}"), r"(?<index>\d+)");
+    let re = Regex::new(&format!("^{re_str}(?:{})?$", regex::escape(GZ)))
+        .map_err(|e| Error::new(InvalidInput, e))?;
+
+    let mut backups = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let Some(caps) = name.to_str().and_then(|n| re.captures(n)) else {
+            continue;
+        };
+
+        backups.push(Backup {
+            date: caps.name("date").map_or("", |m| m.as_str()).to_string(),
+            index: caps["index"].parse().unwrap_or(0),
+            path: entry.path(),
+        });
+    }
+
+    Ok(backups)
+}
+
+///
+/// Remove the file `name`, if it exists.
+///
+fn remove(name: &str) -> Result<(), Error> {
+    if Path::new(name).exists() {
+        fs::remove_file(name)?;
+    }
+
+    Ok(())
+}
+
+///
+/// Compress the file `name` to `name.gz`, then remove it.
+///
+#[cfg(feature = "compression")]
+fn gzip(name: &str) -> Result<(), Error> {
+    let mut input = File::open(name)?;
+    let mut encoder = GzEncoder::new(File::create(name.to_string() + GZ)?, Compression::default());
+
+    io::copy(&mut input, &mut encoder)?;
+    encoder.finish()?.sync_all()?;
+    fs::remove_file(name)
+}
+
+impl fmt::Debug for RotatingFileHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RotatingFileHandler")
+            .field("filename", &self.filename)
+            .field("formatter", &self.formatter)
+            .field("filter", &self.filter)
+            .field("file", &self.file)
+            .field("size", &self.size)
+            .field("rotation", &self.rotation)
+            .field("sync_policy", &self.sync_policy)
+            .field("last_sync", &self.last_sync)
+            .field("writer", &self.writer)
+            .field("stats", &self.stats)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for RotatingFileHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({} bytes) : {}",
+            self.filename, self.rotation.max_size, self.formatter
+        )
+    }
+}
+
+impl HandlerTrait for RotatingFileHandler {
+    ///
+    /// Create a new handler instance.
+    ///
+    /// ## Parameters
+    /// - `name` - This the `filename` of the log file.
+    ///
+    fn create(name: &str) -> Result<Self, Error> {
+        RotatingFileHandler::_create(name)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    ///
+    /// Syncs and closes the file, unless the [`SyncPolicy`] is `Never`.\
+    /// Also waits for any backup being compressed.
+    ///
+    fn close(&mut self) {
+        if self.sync_policy != SyncPolicy::Never {
+            self.sync();
+        }
+
+        self.file = None;
+        self.wait_for_compressor();
+    }
+
+    ///
+    /// Syncs the file to the disk, if the [`SyncPolicy`] is `OnFlush`.
+    ///
+    /// Each log entry is written straight to the file, so there is no buffer to flush.
+    ///
+    fn flush(&mut self) {
+        if self.sync_policy == SyncPolicy::OnFlush {
+            self.sync();
+        }
+    }
+
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
+    fn get_formatter(&self) -> Formatter {
+        self.formatter.clone()
+    }
+
+    fn get_log(&self) -> String {
+        self.log()
+    }
+
+    fn is_open(&self) -> bool {
+        self.file.is_some()
+    }
+
+    fn publish(&mut self, log_entry: &LogEntry) {
+        self.stats.published += 1;
+
+        if !self.is_open() {
+            self.stats.dropped += 1;
+            return;
+        }
+
+        let buf = &mut self.line;
+        buf.clear();
+        self.formatter.format_into(log_entry, buf);
+
+        if buf.contains('\x1b') {
+            *buf = ansi::strip(buf).into_owned();
+        }
+
+        buf.push('\n');
+
+        if let Some(w) = self.writer.as_mut() {
+            w.extend_from_slice(buf.as_bytes());
+            self.stats.wrote(buf.len());
+            return;
+        }
+
+        let len = buf.len() as u64;
+
+        if self.size > 0 && self.size + len > self.rotation.max_size {
+            if let Err(e) = self.rotate() {
+                self.fail(e, 1);
+                return;
+            }
+        }
+
+        let Some(file) = self.file.as_mut() else {
+            self.stats.dropped += 1;
+            return;
+        };
+
+        if let Err(e) = file.write_all(self.line.as_bytes()) {
+            self.fail(e, 1);
+            return;
+        }
+
+        self.size += len;
+        self.stats.wrote(self.line.len());
+
+        if self.sync_policy.is_due(log_entry.level, self.last_sync) {
+            self.sync();
+        }
+    }
+
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
+    }
+
+    fn set_formatter(&mut self, formatter: Formatter) {
+        self.formatter = formatter;
+    }
+
+    ///
+    /// Sets the test mode to `state`.
+    ///
+    /// If set to `true`, use `get_log()` to obtain the
+    /// log.
+    ///
+    fn set_test_mode(&mut self, state: bool) {
+        if state {
+            self.writer = Some(Vec::new());
+        } else {
+            self.writer = None;
+        }
+    }
+
+    fn stats(&self) -> HandlerStats {
+        self.stats.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use chrono::Local;
+    use std::fs;
+
+    fn remove_all(filename: &str) {
+        let _ = fs::remove_file(filename);
+
+        for index in 1..10 {
+            let _ = fs::remove_file(format!("{filename}.{index}"));
+            let _ = fs::remove_file(format!("{filename}.{index}.gz"));
+        }
+    }
+
+    #[test]
+    fn rotation() {
+        let filename = "test_logs/rotation.log";
+        remove_all(filename);
+
+        // Each entry is "->rotation [INFO   ] n\n": 23 bytes.
+        let mut log = Logger::builder("")
+            .add_rotating_file_handler_with(filename, Rotation::new(50), FormatType::Simple, None)
+            .set_fn_name("rotation")
+            .build();
+
+        for n in 1..=5 {
+            log.info(&n.to_string());
+        }
+
+        let h = log.get_handler(Handler::RotatingFile).unwrap();
+        h.close();
+
+        assert_eq!(
+            fs::read_to_string(filename).unwrap(),
+            "->rotation [INFO   ] 5\n"
+        );
+        assert_eq!(
+            fs::read_to_string(format!("{filename}.1")).unwrap(),
+            "->rotation [INFO   ] 3\n->rotation [INFO   ] 4\n"
+        );
+        assert_eq!(
+            fs::read_to_string(format!("{filename}.2")).unwrap(),
+            "->rotation [INFO   ] 1\n->rotation [INFO   ] 2\n"
+        );
+        assert!(fs::exists(format!("{filename}.3")).is_ok_and(|e| !e));
+    }
+
+    #[test]
+    fn rotating_file_handler_test_mode() {
+        let filename = "test_logs/rotating_file_handler_test_mode.log";
+        remove_all(filename);
+
+        let mut log = Logger::builder(module_path!())
+            .add_rotating_file_handler(filename, Rotation::new(1024))
+            .set_fn_name("rotating_file_handler_test_mode")
+            .build();
+
+        let h = log.get_handler(Handler::RotatingFile).unwrap();
+        h.set_test_mode(true);
+
+        assert!(h.is_open());
+        assert_eq!(
+            h.to_string(),
+            format!(
+                "{filename} (1024 bytes) : dt_fmt: \"%+\" - fmt_string: \"{{dt:35}} {{mod_path}}->{{fn_name}} [{{level:7}}] {{message}}\""
+            )
+        );
+
+        log.info("trait methods");
+
+        let h = log.get_handler(Handler::RotatingFile).unwrap();
+        assert!(h.get_log().ends_with(" [INFO   ] trait methods\n"));
+        assert_eq!(fs::read_to_string(filename).unwrap(), "");
+    }
+
+    #[test]
+    fn max_backups() {
+        let filename = "test_logs/max_backups.log";
+        remove_all(filename);
+
+        let log = Logger::builder("")
+            .add_rotating_file_handler_with(
+                filename,
+                Rotation::new(30).max_backups(2),
+                FormatType::Simple,
+                None,
+            )
+            .set_fn_name("max_backups")
+            .build();
+
+        for n in 1..=5 {
+            log.info(&n.to_string());
+        }
+
+        assert_eq!(
+            fs::read_to_string(format!("{filename}.1")).unwrap(),
+            "->max_backups [INFO   ] 4\n"
+        );
+        assert_eq!(
+            fs::read_to_string(format!("{filename}.2")).unwrap(),
+            "->max_backups [INFO   ] 3\n"
+        );
+        assert!(fs::exists(format!("{filename}.3")).is_ok_and(|e| !e));
+    }
+
+    #[test]
+    fn pattern() {
+        let dir = "test_logs/pattern";
+        let _ = fs::remove_dir_all(dir);
+        fs::create_dir_all(dir).unwrap();
+
+        let filename = format!("{dir}/app.log");
+        let date = Local::now().format("%Y-%m-%d").to_string();
+
+        let log = Logger::builder("")
+            .add_rotating_file_handler_with(
+                &filename,
+                Rotation::new(30)
+                    .max_backups(2)
+                    .pattern(&format!("{dir}/app.{{date}}.{{index}}.log")),
+                FormatType::Simple,
+                None,
+            )
+            .set_fn_name("pattern")
+            .build();
+
+        for n in 1..=4 {
+            log.info(&n.to_string());
+        }
+
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+
+        assert_eq!(
+            names,
+            vec![
+                format!("app.{date}.2.log"),
+                format!("app.{date}.3.log"),
+                "app.log".to_string(),
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(format!("{dir}/app.{date}.3.log")).unwrap(),
+            "->pattern [INFO   ] 3\n"
+        );
+    }
+
+    #[test]
+    fn pattern_without_date() {
+        let dir = "test_logs/pattern_without_date";
+        let _ = fs::remove_dir_all(dir);
+        fs::create_dir_all(dir).unwrap();
+
+        let filename = format!("{dir}/app.log");
+
+        // Each entry is "->pattern [INFO   ] n\n": 22 bytes, so every entry
+        // after the first rotates the file.
+        let log = Logger::builder("")
+            .add_rotating_file_handler_with(
+                &filename,
+                Rotation::new(30).pattern(&format!("{dir}/app.{{index}}.log")),
+                FormatType::Simple,
+                None,
+            )
+            .set_fn_name("pattern")
+            .build();
+
+        for n in 1..=4 {
+            log.info(&n.to_string());
+        }
+
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+
+        assert_eq!(
+            names,
+            vec!["app.1.log", "app.2.log", "app.3.log", "app.log"]
+        );
+
+        for n in 1..=3 {
+            assert_eq!(
+                fs::read_to_string(format!("{dir}/app.{n}.log")).unwrap(),
+                format!("->pattern [INFO   ] {n}\n")
+            );
+        }
+    }
+
+    #[test]
+    fn rotate_error() {
+        use std::sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        };
+
+        let filename = "test_logs/rotate_error.log";
+        remove_all(filename);
+
+        // The pattern's directory does not exist, so rotating fails.
+        let mut log = Logger::builder("")
+            .add_rotating_file_handler_with(
+                filename,
+                Rotation::new(30).pattern("test_logs/rotate_error/app.{index}.log"),
+                FormatType::Simple,
+                None,
+            )
+            .set_fn_name("rotate_error")
+            .build();
+
+        let errors = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&errors);
+        log.get_handler_as::<RotatingFileHandler>()
+            .unwrap()
+            .set_on_error(move |_| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            });
+
+        for n in 1..=3 {
+            log.info(&n.to_string());
+        }
+
+        let stats = log.handler_stats(Handler::RotatingFile).unwrap();
+        assert_eq!(errors.load(Ordering::Relaxed), 1);
+        assert_eq!(stats.dropped(), 2);
+        assert!(stats.last_error().is_some());
+        assert!(!log.get_handler(Handler::RotatingFile).unwrap().is_open());
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn compression() {
+        use flate2::read::GzDecoder;
+        use std::io::Read;
+
+        let filename = "test_logs/compression.log";
+        remove_all(filename);
+
+        let gunzip = |name: String| {
+            let mut buf = String::new();
+            GzDecoder::new(fs::File::open(name).unwrap())
+                .read_to_string(&mut buf)
+                .unwrap();
+            buf
+        };
+
+        let mut log = Logger::builder("")
+            .add_rotating_file_handler_with(
+                filename,
+                Rotation::new(30).compress(true),
+                FormatType::Simple,
+                None,
+            )
+            .set_fn_name("compression")
+            .build();
+
+        for n in 1..=3 {
+            log.info(&n.to_string());
+        }
+
+        let h = log.get_handler(Handler::RotatingFile).unwrap();
+        h.close();
+
+        assert_eq!(
+            fs::read_to_string(filename).unwrap(),
+            "->compression [INFO   ] 3\n"
+        );
+        assert!(fs::exists(format!("{filename}.1")).is_ok_and(|e| !e));
+        assert_eq!(
+            gunzip(format!("{filename}.1.gz")),
+            "->compression [INFO   ] 2\n"
+        );
+        assert_eq!(
+            gunzip(format!("{filename}.2.gz")),
+            "->compression [INFO   ] 1\n"
+        );
+    }
+}
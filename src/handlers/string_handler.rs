@@ -24,27 +24,253 @@
 //! # StringHandler
 //!
 use crate::*;
-use std::{fmt, io::Error};
+use chrono::{DateTime, Local};
+use std::{any::Any, collections::VecDeque, fmt, io::Error};
 
 ///
 /// Publishes log entries to an internal list.
 ///
-/// The list can then be accessed via: [get_log()][StringHandler::get_log()].
+/// The list can then be accessed via: [get_log()][StringHandler::get_log()],
+/// as one string. Or, one at a time, via: [entries()][StringHandler::entries()],
+/// and [lines()][StringHandler::lines()]. To consume the list incrementally,
+/// use [drain()][StringHandler::drain()].
+///
+/// The stored log entries can also be queried, without parsing the formatted
+/// text, via: [entries_at()][StringHandler::entries_at()],
+/// [entries_matching()][StringHandler::entries_matching()], and
+/// [entries_since()][StringHandler::entries_since()].
+///
+/// By default, the list grows without bound. When used as an in-memory tail,
+/// in a long-running process, set a limit with
+/// [set_max_entries()][StringHandler::set_max_entries()], and/or
+/// [set_max_bytes()][StringHandler::set_max_bytes()]. Once a limit is exceeded,
+/// the oldest entries are discarded.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// let mut log = Logger::string_logger("app");
+/// log.set_fn_name("main");
+///
+/// log.info("one");
+/// log.warning("two");
+///
+/// let h = log.get_handler_as::<StringHandler>().unwrap();
+/// assert_eq!(h.entries()[1].message(), "two");
+/// assert_eq!(h.lines()[0], "app->main [INFO   ] one");
+///
+/// let drained = h.drain();
+/// assert_eq!(drained.len(), 2);
+/// assert!(h.entries().is_empty());
+///
+/// log.info("three");
+///
+/// let h = log.get_handler_as::<StringHandler>().unwrap();
+/// assert_eq!(h.get_log(), "app->main [INFO   ] three\n");
+/// ```
 ///
 #[derive(Debug, Default)]
 pub struct StringHandler {
     formatter: Formatter,
-    log: Vec<String>,
+    filter: Option<Box<dyn FilterTrait>>,
+    entries: VecDeque<LogEntry>,
+    log: VecDeque<String>,
+    bytes: usize,
+    max_entries: Option<usize>,
+    max_bytes: Option<usize>,
+    stats: HandlerStats,
 }
 
 impl StringHandler {
     fn new() -> Self {
         StringHandler {
             formatter: FormatType::Simple.create(None),
-            log: Vec::new(),
+            filter: None,
+            entries: VecDeque::new(),
+            log: VecDeque::new(),
+            bytes: 0,
+            max_entries: None,
+            max_bytes: None,
+            stats: HandlerStats::default(),
         }
     }
 
+    ///
+    /// Discard the oldest entries, until both limits are met.
+    ///
+    fn bound(&mut self) {
+        while self.max_entries.is_some_and(|max| self.log.len() > max)
+            || self.max_bytes.is_some_and(|max| self.bytes > max)
+        {
+            self.entries.pop_front();
+
+            if let Some(line) = self.log.pop_front() {
+                self.bytes -= line.len();
+                self.stats.dropped += 1;
+            }
+        }
+    }
+
+    ///
+    /// Removes all of the stored log entries, returning them in the order
+    /// they were published.
+    ///
+    /// Any log entries published afterwards are kept, until the next `drain()`.
+    ///
+    pub fn drain(&mut self) -> Vec<LogEntry> {
+        self.log.clear();
+        self.bytes = 0;
+        self.entries.drain(..).collect()
+    }
+
+    ///
+    /// Returns the stored log entries, in the order they were published.
+    ///
+    pub fn entries(&self) -> &VecDeque<LogEntry> {
+        &self.entries
+    }
+
+    ///
+    /// Returns the stored log entries at `level`, oldest first.
+    ///
+    /// ## Parameters
+    /// - `level` - The level to select.
+    ///
+    pub fn entries_at(&self, level: Level) -> Vec<&LogEntry> {
+        self.entries_matching(|e| e.level == level)
+    }
+
+    ///
+    /// Returns the stored log entries that match the `predicate`, oldest first.
+    ///
+    /// ## Parameters
+    /// - `predicate` - Returns `true` for each log entry to be included.
+    ///
+    /// ## Examples
+    /// ```text
+    /// let problems = h.entries_matching(|e| e.level() >= Level::WARNING);
+    /// ```
+    ///
+    pub fn entries_matching<F>(&self, predicate: F) -> Vec<&LogEntry>
+    where
+        F: Fn(&LogEntry) -> bool,
+    {
+        self.entries.iter().filter(|e| predicate(e)).collect()
+    }
+
+    ///
+    /// Returns the stored log entries created at, or after, `since`, oldest first.
+    ///
+    /// ## Parameters
+    /// - `since` - The earliest timestamp to include. A [`SystemTime`][std::time::SystemTime]
+    ///   will also do.
+    ///
+    pub fn entries_since(&self, since: impl Into<DateTime<Local>>) -> Vec<&LogEntry> {
+        let since = since.into();
+        self.entries_matching(|e| e.timestamp >= since)
+    }
+
+    ///
+    /// Returns the stored log entries as formatted lines, in the order they
+    /// were published.
+    ///
+    /// Each line was formatted with the formatter that was set at the time.
+    ///
+    pub fn lines(&self) -> &VecDeque<String> {
+        &self.log
+    }
+
+    ///
+    /// Returns the maximum total size, in bytes, of the formatted lines stored,
+    /// if set.
+    ///
+    pub fn max_bytes(&self) -> Option<usize> {
+        self.max_bytes
+    }
+
+    ///
+    /// Returns the maximum number of log entries stored, if set.
+    ///
+    pub fn max_entries(&self) -> Option<usize> {
+        self.max_entries
+    }
+
+    ///
+    /// Set the maximum total size, in bytes, of the formatted lines stored.
+    ///
+    /// Once exceeded, the oldest entries are discarded. A single line larger
+    /// than `max_bytes` is not kept.
+    ///
+    /// ## Parameters
+    /// - `max_bytes` - The new limit, or `None` for no limit.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::string_logger("app");
+    /// log.set_fn_name("main");
+    ///
+    /// // Each line: "app->main [INFO   ] one" is 23 bytes.
+    /// log.get_handler_as::<StringHandler>()
+    ///     .unwrap()
+    ///     .set_max_bytes(Some(50));
+    ///
+    /// log.info("one");
+    /// log.info("two");
+    /// log.info("three");
+    ///
+    /// let h = log.get_handler_as::<StringHandler>().unwrap();
+    /// assert_eq!(
+    ///     *h.lines(),
+    ///     ["app->main [INFO   ] two", "app->main [INFO   ] three"]
+    /// );
+    /// ```
+    ///
+    pub fn set_max_bytes(&mut self, max_bytes: Option<usize>) {
+        self.max_bytes = max_bytes;
+        self.bound();
+    }
+
+    ///
+    /// Set the maximum number of log entries stored.
+    ///
+    /// Once exceeded, the oldest entries are discarded.
+    ///
+    /// ## Parameters
+    /// - `max_entries` - The new limit, or `None` for no limit.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::string_logger("app");
+    /// log.set_fn_name("main");
+    ///
+    /// log.get_handler_as::<StringHandler>()
+    ///     .unwrap()
+    ///     .set_max_entries(Some(2));
+    ///
+    /// log.info("one");
+    /// log.info("two");
+    /// log.info("three");
+    ///
+    /// let h = log.get_handler_as::<StringHandler>().unwrap();
+    /// assert_eq!(
+    ///     h.get_log(),
+    ///     "app->main [INFO   ] two\napp->main [INFO   ] three\n"
+    /// );
+    /// ```
+    ///
+    pub fn set_max_entries(&mut self, max_entries: Option<usize>) {
+        self.max_entries = max_entries;
+        self.bound();
+    }
+
     fn log(&self) -> String {
         let mut buf = String::new();
 
@@ -74,10 +300,24 @@ impl HandlerTrait for StringHandler {
         Ok(StringHandler::new())
     }
 
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
     fn close(&mut self) {}
 
     fn flush(&mut self) {
+        self.entries.clear();
         self.log.clear();
+        self.bytes = 0;
+    }
+
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
     }
 
     fn get_formatter(&self) -> Formatter {
@@ -92,9 +332,19 @@ impl HandlerTrait for StringHandler {
         true
     }
 
-    #[allow(private_interfaces)]
     fn publish(&mut self, log_entry: &LogEntry) {
-        self.log.push(self.formatter.format(log_entry));
+        let line = self.formatter.format(log_entry);
+
+        self.stats.published += 1;
+        self.stats.wrote(line.len());
+        self.bytes += line.len();
+        self.log.push_back(line);
+        self.entries.push_back(log_entry.clone());
+        self.bound();
+    }
+
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
     }
 
     fn set_formatter(&mut self, formatter: Formatter) {
@@ -106,6 +356,10 @@ impl HandlerTrait for StringHandler {
     /// has the required functionality.
     ///
     fn set_test_mode(&mut self, _state: bool) {}
+
+    fn stats(&self) -> HandlerStats {
+        self.stats.clone()
+    }
 }
 
 #[cfg(test)]
@@ -137,4 +391,132 @@ flogging::handlers::string_handler::tests-> [WARNING] The sky is falling!\n"
         assert_eq!(handler.get_log(), "".to_string());
         handler.close();
     }
+
+    #[test]
+    fn entries_and_drain() {
+        let mut log = Logger::string_logger(module_path!());
+        log.set_fn_name("entries_and_drain");
+
+        log.info("one");
+        log.warning("two");
+
+        let h = log.get_handler_as::<StringHandler>().unwrap();
+
+        let entries: Vec<_> = h
+            .entries()
+            .iter()
+            .map(|e| (e.level(), e.message()))
+            .collect();
+        assert_eq!(entries, vec![(Level::INFO, "one"), (Level::WARNING, "two")]);
+        assert_eq!(
+            *h.lines(),
+            [
+                "flogging::handlers::string_handler::tests->entries_and_drain [INFO   ] one",
+                "flogging::handlers::string_handler::tests->entries_and_drain [WARNING] two",
+            ]
+        );
+
+        let drained = h.drain();
+        assert_eq!(drained.len(), 2);
+        assert_eq!(drained[1].message(), "two");
+        assert!(h.entries().is_empty());
+        assert!(h.lines().is_empty());
+        assert_eq!(h.get_log(), "");
+
+        log.severe("three");
+
+        let h = log.get_handler_as::<StringHandler>().unwrap();
+        let drained = h.drain();
+        assert_eq!(drained.len(), 1);
+        assert_eq!(drained[0].level(), Level::SEVERE);
+        assert!(h.drain().is_empty());
+    }
+
+    #[test]
+    fn bounded() {
+        let mut h = StringHandler::create("").unwrap();
+        assert_eq!(h.max_entries(), None);
+        assert_eq!(h.max_bytes(), None);
+
+        let mut le = LogEntry::create(Level::INFO, "bounded".to_string(), String::new());
+
+        for n in 1..=5 {
+            le.message = n.to_string();
+            h.publish(&le);
+        }
+
+        h.set_max_entries(Some(3));
+        assert_eq!(h.max_entries(), Some(3));
+
+        let messages: Vec<_> = h.entries().iter().map(|e| e.message()).collect();
+        assert_eq!(messages, ["3", "4", "5"]);
+
+        le.message = "6".to_string();
+        h.publish(&le);
+
+        let messages: Vec<_> = h.entries().iter().map(|e| e.message()).collect();
+        assert_eq!(messages, ["4", "5", "6"]);
+        assert_eq!(h.lines().len(), 3);
+
+        // Each line: "->bounded [INFO   ] N" is 21 bytes.
+        h.set_max_bytes(Some(45));
+        assert_eq!(h.max_bytes(), Some(45));
+        assert_eq!(
+            h.get_log(),
+            "->bounded [INFO   ] 5\n->bounded [INFO   ] 6\n"
+        );
+
+        h.set_max_entries(None);
+        le.message = "7".to_string();
+        h.publish(&le);
+        assert_eq!(
+            h.get_log(),
+            "->bounded [INFO   ] 6\n->bounded [INFO   ] 7\n"
+        );
+
+        h.set_max_bytes(Some(10));
+        le.message = "8".to_string();
+        h.publish(&le);
+        assert!(h.entries().is_empty());
+        assert!(h.lines().is_empty());
+
+        h.set_max_bytes(None);
+        h.publish(&le);
+        assert_eq!(h.drain().len(), 1);
+    }
+
+    #[test]
+    fn query() {
+        let mut h = StringHandler::create("").unwrap();
+        let mut le = LogEntry::create(Level::INFO, "query".to_string(), "one".to_string());
+        h.publish(&le);
+
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let since = chrono::Local::now();
+
+        le = LogEntry::create(Level::WARNING, "query".to_string(), "two".to_string());
+        h.publish(&le);
+        le = LogEntry::create(Level::INFO, "query".to_string(), "three".to_string());
+        h.publish(&le);
+        le = LogEntry::create(Level::SEVERE, "query".to_string(), "four".to_string());
+        h.publish(&le);
+
+        let messages = |entries: Vec<&LogEntry>| -> Vec<String> {
+            entries.iter().map(|e| e.message().to_string()).collect()
+        };
+
+        assert_eq!(messages(h.entries_at(Level::INFO)), ["one", "three"]);
+        assert_eq!(messages(h.entries_at(Level::FINE)), Vec::<String>::new());
+        assert_eq!(
+            messages(h.entries_matching(|e| e.level() >= Level::WARNING)),
+            ["two", "four"]
+        );
+        assert_eq!(messages(h.entries_since(since)), ["two", "three", "four"]);
+
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        assert_eq!(
+            messages(h.entries_since(std::time::SystemTime::now())),
+            Vec::<String>::new()
+        );
+    }
 }
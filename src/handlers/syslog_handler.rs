@@ -0,0 +1,388 @@
+//
+// File Name:    syslog_handler.rs
+// Directory:    src/handlers
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # SyslogHandler
+//!
+//! Publishes log entries to a syslog daemon.
+//!
+
+pub mod facility;
+
+use super::udp_handler::connect_udp;
+use crate::*;
+use facility::Facility;
+use std::{
+    any::Any,
+    fmt,
+    io::{Error, ErrorKind::InvalidInput, Write},
+    net::{ToSocketAddrs, UdpSocket},
+};
+
+#[cfg(unix)]
+use std::os::unix::net::UnixDatagram;
+
+///
+/// The default local syslog socket.
+///
+pub const SYSLOG_SOCKET: &str = "/dev/log";
+
+///
+/// The default syslog UDP port.
+///
+pub const SYSLOG_PORT: u16 = 514;
+
+///
+/// Publishes log entries to a syslog daemon, one datagram per entry.
+///
+/// The destination is set by the `name` passed to [`create()`][SyslogHandler::create]:
+///
+/// - `""` - The local syslog socket: [`SYSLOG_SOCKET`] (unix only),
+/// - `"/path/to/socket"` - Any other local unix datagram socket (unix only),
+/// - `"host:port"` or `"host"` - UDP to a remote syslog server. Without a port,
+///   [`SYSLOG_PORT`] is used.
+///
+/// Each message is prefixed with its priority, `<PRI>`, made up of the
+/// [`Facility`] and the severity mapped from the entry's level:
+///
+/// | `Level`                           | Severity      |
+/// |-----------------------------------|---------------|
+/// | `SEVERE`                          | 3 (error)     |
+/// | `WARNING`                         | 4 (warning)   |
+/// | `CONFIG`                          | 5 (notice)    |
+/// | `INFO`                            | 6 (info)      |
+/// | `FINE`, `FINER`, `FINEST`, `ALL`  | 7 (debug)     |
+///
+/// The rest of the message is produced by the formatter, by default
+/// the [`SimpleFormatter`]. As the syslog daemon timestamps the messages,
+/// there is no need for a formatter that adds its own.
+///
+/// Syslog is a "best effort" service, so any error sending a message
+/// is silently ignored.
+///
+#[derive(Debug, Default)]
+pub struct SyslogHandler {
+    destination: String,
+    facility: Facility,
+    formatter: Formatter,
+    filter: Option<Box<dyn FilterTrait>>,
+    transport: Option<Transport>,
+    writer: Option<Vec<u8>>,
+    stats: HandlerStats,
+}
+
+///
+/// The socket the messages are sent through.
+///
+#[derive(Debug)]
+enum Transport {
+    Udp(UdpSocket),
+    #[cfg(unix)]
+    Unix(UnixDatagram),
+}
+
+impl Transport {
+    fn connect(destination: &str) -> Result<Self, Error> {
+        #[cfg(unix)]
+        if destination.starts_with('/') {
+            let socket = UnixDatagram::unbound()?;
+            socket.connect(destination)?;
+            return Ok(Transport::Unix(socket));
+        }
+
+        let addr = if destination.contains(':') {
+            destination.to_socket_addrs()
+        } else {
+            (destination, SYSLOG_PORT).to_socket_addrs()
+        }?
+        .next()
+        .ok_or_else(|| Error::new(InvalidInput, format!("unknown host: {destination}")))?;
+
+        Ok(Transport::Udp(connect_udp(addr)?))
+    }
+
+    fn send(&self, buf: &[u8]) -> Result<usize, Error> {
+        match self {
+            Transport::Udp(socket) => socket.send(buf),
+            #[cfg(unix)]
+            Transport::Unix(socket) => socket.send(buf),
+        }
+    }
+}
+
+impl SyslogHandler {
+    fn _create(name: &str) -> Result<Self, Error> {
+        let destination = if name.is_empty() {
+            SYSLOG_SOCKET.to_string()
+        } else {
+            name.to_string()
+        };
+
+        Ok(SyslogHandler {
+            transport: Some(Transport::connect(&destination)?),
+            destination,
+            facility: Facility::default(),
+            formatter: FormatType::Simple.create(None),
+            filter: None,
+            writer: None,
+            stats: HandlerStats::default(),
+        })
+    }
+
+    fn log(&self) -> String {
+        if let Some(w) = self.writer.to_owned() {
+            String::from_utf8(w).unwrap()
+        } else {
+            String::new()
+        }
+    }
+
+    ///
+    /// Returns the facility.
+    ///
+    pub fn facility(&self) -> Facility {
+        self.facility
+    }
+
+    ///
+    /// Set the facility to log under.
+    ///
+    /// ## Parameters
+    /// - `facility` - The syslog facility.
+    ///
+    pub fn set_facility(&mut self, facility: Facility) {
+        self.facility = facility;
+    }
+
+    fn message(&self, log_entry: &LogEntry) -> String {
+        let pri = self.facility.code() * 8 + severity(&log_entry.level);
+        format!("<{pri}>{}", self.formatter.format(log_entry))
+    }
+}
+
+///
+/// Map the `level` to a syslog severity.
+///
+fn severity(level: &Level) -> u8 {
+    match level {
+        Level::SEVERE | Level::OFF => 3,
+        Level::WARNING => 4,
+        Level::CONFIG => 5,
+        Level::INFO => 6,
+        Level::FINE | Level::FINER | Level::FINEST | Level::ALL => 7,
+        Level::Custom { .. } => severity(&level.base()),
+    }
+}
+
+impl fmt::Display for SyslogHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}) : {}",
+            self.destination, self.facility, self.formatter
+        )
+    }
+}
+
+impl HandlerTrait for SyslogHandler {
+    ///
+    /// Create a new handler instance.
+    ///
+    /// ## Parameters
+    /// - `name` - The destination: `""`, a socket path, or `host[:port]`.
+    ///
+    fn create(name: &str) -> Result<Self, Error> {
+        SyslogHandler::_create(name)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    ///
+    /// Closes the socket.
+    ///
+    fn close(&mut self) {
+        self.transport = None;
+    }
+
+    ///
+    /// Messages are sent as they are published, so there is nothing to flush.
+    ///
+    fn flush(&mut self) {}
+
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
+    fn get_formatter(&self) -> Formatter {
+        self.formatter.clone()
+    }
+
+    fn get_log(&self) -> String {
+        self.log()
+    }
+
+    fn is_open(&self) -> bool {
+        self.transport.is_some()
+    }
+
+    fn publish(&mut self, log_entry: &LogEntry) {
+        self.stats.published += 1;
+
+        if !self.is_open() {
+            self.stats.dropped += 1;
+            return;
+        }
+
+        let msg = self.message(log_entry);
+
+        if let Some(w) = self.writer.as_mut() {
+            writeln!(w, "{msg}").expect("writeln!() failed");
+            self.stats.wrote(msg.len() + 1);
+        } else if let Some(t) = &self.transport {
+            match t.send(msg.as_bytes()) {
+                Ok(n) => self.stats.wrote(n),
+                Err(e) => self.stats.error(e, 1),
+            }
+        }
+    }
+
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
+    }
+
+    fn set_formatter(&mut self, formatter: Formatter) {
+        self.formatter = formatter;
+    }
+
+    ///
+    /// Sets the test mode to `state`.
+    ///
+    /// If set to `true`, the messages are stored instead of being sent.
+    /// Use `get_log()` to obtain them.
+    ///
+    fn set_test_mode(&mut self, state: bool) {
+        if state {
+            self.writer = Some(Vec::new());
+        } else {
+            self.writer = None;
+        }
+    }
+
+    fn stats(&self) -> HandlerStats {
+        self.stats.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::{net::UdpSocket, time::Duration};
+
+    fn server() -> (UdpSocket, String) {
+        let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+        server
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let addr = server.local_addr().unwrap().to_string();
+
+        (server, addr)
+    }
+
+    fn recv(server: &UdpSocket) -> String {
+        let mut buf = [0u8; 1024];
+        let len = server.recv(&mut buf).unwrap();
+
+        String::from_utf8_lossy(&buf[..len]).to_string()
+    }
+
+    #[test]
+    fn syslog_handler_udp() {
+        let (server, addr) = server();
+
+        let log = Logger::builder(module_path!())
+            .add_syslog_handler(&addr, Facility::Local3)
+            .set_fn_name("syslog_handler_udp")
+            .build();
+
+        log.info("We begin!");
+        log.severe("Disk on fire.");
+
+        assert_eq!(
+            recv(&server),
+            "<158>flogging::handlers::syslog_handler::tests->syslog_handler_udp [INFO   ] We begin!"
+        );
+        assert_eq!(
+            recv(&server),
+            "<155>flogging::handlers::syslog_handler::tests->syslog_handler_udp [SEVERE ] Disk on fire."
+        );
+    }
+
+    #[test]
+    fn syslog_handler_test_mode() {
+        let (_server, addr) = server();
+
+        let mut log = Logger::builder(module_path!())
+            .add_syslog_handler_with(&addr, Facility::User, FormatType::Simple, None)
+            .set_level(Level::ALL)
+            .set_fn_name("syslog_handler_test_mode")
+            .build();
+
+        let h = log.get_handler(Handler::Syslog).unwrap();
+        h.set_test_mode(true);
+
+        assert!(h.is_open());
+        assert_eq!(
+            h.to_string(),
+            format!(
+                "{addr} (user) : dt_fmt: \"\" - fmt_string: \"{{mod_path}}->{{fn_name}} [{{level:7}}] {{message}}\""
+            )
+        );
+
+        log.config("config");
+        log.warning("warning");
+        log.finest("finest");
+
+        let h = log.get_handler(Handler::Syslog).unwrap();
+
+        assert_eq!(
+            h.get_log(),
+            "<13>flogging::handlers::syslog_handler::tests->syslog_handler_test_mode [CONFIG ] config
+<12>flogging::handlers::syslog_handler::tests->syslog_handler_test_mode [WARNING] warning
+<15>flogging::handlers::syslog_handler::tests->syslog_handler_test_mode [FINEST ] finest\n"
+        );
+
+        h.close();
+        assert!(!h.is_open());
+    }
+
+    #[test]
+    fn unknown_host() {
+        assert!(SyslogHandler::create("no.such.host.invalid").is_err());
+    }
+}
@@ -0,0 +1,231 @@
+//
+// File Name:    facility.rs
+// Directory:    src/handlers/syslog_handler
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Facility
+//!
+
+use std::{fmt, str::FromStr};
+use strum::{EnumIter, IntoEnumIterator};
+
+///
+/// The syslog facility that the `SyslogHandler` logs under.
+///
+/// As defined in [RFC 5424](https://www.rfc-editor.org/rfc/rfc5424#section-6.2.1).
+///
+#[derive(Debug, Default, Clone, Copy, EnumIter, PartialEq, Eq)]
+pub enum Facility {
+    ///
+    /// Kernel messages.
+    ///
+    Kern,
+    #[default]
+    ///
+    /// User-level messages.
+    ///
+    User,
+    ///
+    /// Mail system.
+    ///
+    Mail,
+    ///
+    /// System daemons.
+    ///
+    Daemon,
+    ///
+    /// Security/authorization messages.
+    ///
+    Auth,
+    ///
+    /// Messages generated internally by syslogd.
+    ///
+    Syslog,
+    ///
+    /// Line printer subsystem.
+    ///
+    Lpr,
+    ///
+    /// Network news subsystem.
+    ///
+    News,
+    ///
+    /// UUCP subsystem.
+    ///
+    Uucp,
+    ///
+    /// Clock daemon.
+    ///
+    Cron,
+    ///
+    /// Private security/authorization messages.
+    ///
+    AuthPriv,
+    ///
+    /// FTP daemon.
+    ///
+    Ftp,
+    ///
+    /// Local use 0.
+    ///
+    Local0,
+    ///
+    /// Local use 1.
+    ///
+    Local1,
+    ///
+    /// Local use 2.
+    ///
+    Local2,
+    ///
+    /// Local use 3.
+    ///
+    Local3,
+    ///
+    /// Local use 4.
+    ///
+    Local4,
+    ///
+    /// Local use 5.
+    ///
+    Local5,
+    ///
+    /// Local use 6.
+    ///
+    Local6,
+    ///
+    /// Local use 7.
+    ///
+    Local7,
+}
+
+impl Facility {
+    ///
+    /// Converts a facility to its string version.
+    ///
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Facility::Kern => "kern",
+            Facility::User => "user",
+            Facility::Mail => "mail",
+            Facility::Daemon => "daemon",
+            Facility::Auth => "auth",
+            Facility::Syslog => "syslog",
+            Facility::Lpr => "lpr",
+            Facility::News => "news",
+            Facility::Uucp => "uucp",
+            Facility::Cron => "cron",
+            Facility::AuthPriv => "authpriv",
+            Facility::Ftp => "ftp",
+            Facility::Local0 => "local0",
+            Facility::Local1 => "local1",
+            Facility::Local2 => "local2",
+            Facility::Local3 => "local3",
+            Facility::Local4 => "local4",
+            Facility::Local5 => "local5",
+            Facility::Local6 => "local6",
+            Facility::Local7 => "local7",
+        }
+    }
+
+    ///
+    /// The numerical code of this facility.
+    ///
+    pub const fn code(&self) -> u8 {
+        match self {
+            Facility::Kern => 0,
+            Facility::User => 1,
+            Facility::Mail => 2,
+            Facility::Daemon => 3,
+            Facility::Auth => 4,
+            Facility::Syslog => 5,
+            Facility::Lpr => 6,
+            Facility::News => 7,
+            Facility::Uucp => 8,
+            Facility::Cron => 9,
+            Facility::AuthPriv => 10,
+            Facility::Ftp => 11,
+            Facility::Local0 => 16,
+            Facility::Local1 => 17,
+            Facility::Local2 => 18,
+            Facility::Local3 => 19,
+            Facility::Local4 => 20,
+            Facility::Local5 => 21,
+            Facility::Local6 => 22,
+            Facility::Local7 => 23,
+        }
+    }
+}
+
+impl fmt::Display for Facility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+///
+/// Returned from `FromStr::from_str()` when an unknown string
+/// is passed-in.
+///
+#[derive(Debug)]
+pub struct FacilityError {
+    #[allow(dead_code)]
+    msg: String,
+}
+
+impl FromStr for Facility {
+    type Err = FacilityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Facility::iter()
+            .find(|f| f.as_str() == s)
+            .ok_or_else(|| FacilityError {
+                msg: format!("Unknown facility: {s}"),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_str_to_from_str() {
+        for facility in Facility::iter() {
+            let label = facility.as_str();
+            let facility2 = Facility::from_str(label).unwrap();
+            assert_eq!(facility, facility2);
+        }
+    }
+
+    #[test]
+    fn from_str_fail() {
+        assert!(Facility::from_str("local8").is_err());
+    }
+
+    #[test]
+    fn codes() {
+        assert_eq!(Facility::default().code(), 1);
+        assert_eq!(Facility::AuthPriv.code(), 10);
+        assert_eq!(Facility::Local7.code(), 23);
+    }
+}
@@ -0,0 +1,560 @@
+//
+// File Name:    tcp_handler.rs
+// Directory:    src/handlers
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # TcpHandler
+//!
+//! Streams log entries to a remote host over TCP.
+//!
+
+use crate::*;
+use std::{
+    any::Any,
+    collections::VecDeque,
+    fmt,
+    io::{Error, ErrorKind, ErrorKind::InvalidInput, Write},
+    net::{SocketAddr, TcpStream, ToSocketAddrs},
+    time::{Duration, Instant},
+};
+
+///
+/// The maximum time to wait for a connection to be established.
+///
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+
+///
+/// The default maximum time to wait for a write to the remote host.
+///
+const WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+///
+/// Streams log entries, one per line, to the remote `host:port`
+/// whose address was provided during initialization.
+///
+/// Entries are queued, then written whenever there is a connection. If the
+/// connection cannot be made, or is dropped, the handler keeps queuing entries
+/// and tries to reconnect with an exponential backoff: starting at 100 ms and
+/// doubling on each failed attempt, up to 30 seconds. Reconnection is only
+/// attempted when publishing or flushing, so no background thread is involved.
+///
+/// The queue holds at most 1,000 entries by default. Once full, the oldest
+/// entries are dropped to make room.
+///
+/// A write that takes longer than the write timeout, 1 second by default, is given
+/// up on, and resumed where it left off once the backoff has passed. So a stalled
+/// host does not block the logging threads. If the connection is lost part way
+/// through an entry, the rest of that entry is dropped, rather than sent again.
+///
+#[derive(Debug)]
+pub struct TcpHandler {
+    address: String,
+    addrs: Vec<SocketAddr>,
+    formatter: Formatter,
+    filter: Option<Box<dyn FilterTrait>>,
+    stream: Option<TcpStream>,
+    queue: VecDeque<String>,
+    written: usize,
+    max_queued: usize,
+    write_timeout: Duration,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    backoff: Duration,
+    next_attempt: Instant,
+    open: bool,
+    writer: Option<Vec<u8>>,
+    stats: HandlerStats,
+}
+
+impl TcpHandler {
+    fn _create(address: &str) -> Result<Self, Error> {
+        if address.is_empty() {
+            return Err(Error::new(InvalidInput, "'address' must not be empty"));
+        }
+
+        let addrs: Vec<SocketAddr> = address.to_socket_addrs()?.collect();
+        let initial_backoff = Duration::from_millis(100);
+
+        let mut th = TcpHandler {
+            address: address.to_string(),
+            addrs,
+            formatter: FormatType::Iso8601.create(None),
+            filter: None,
+            stream: None,
+            queue: VecDeque::new(),
+            written: 0,
+            max_queued: 1000,
+            write_timeout: WRITE_TIMEOUT,
+            initial_backoff,
+            max_backoff: Duration::from_secs(30),
+            backoff: initial_backoff,
+            next_attempt: Instant::now(),
+            open: true,
+            writer: None,
+            stats: HandlerStats::default(),
+        };
+
+        th.connect();
+        Ok(th)
+    }
+
+    fn log(&self) -> String {
+        if let Some(w) = self.writer.to_owned() {
+            String::from_utf8(w).unwrap()
+        } else {
+            String::new()
+        }
+    }
+
+    ///
+    /// Returns `true` if currently connected to the remote host.
+    ///
+    pub fn is_connected(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    ///
+    /// Returns the number of entries waiting to be sent.
+    ///
+    pub fn queued(&self) -> usize {
+        self.queue.len()
+    }
+
+    ///
+    /// Set the reconnection backoff.
+    ///
+    /// ## Parameters
+    /// - `initial` - The delay after the first failed attempt.
+    /// - `max` - The upper limit that the delay doubles up to.
+    ///
+    pub fn set_backoff(&mut self, initial: Duration, max: Duration) {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self.backoff = initial;
+        self.next_attempt = Instant::now();
+    }
+
+    ///
+    /// Set the maximum number of entries queued while disconnected.
+    ///
+    /// ## Parameters
+    /// - `max_queued` - The queue's capacity.
+    ///
+    pub fn set_max_queued(&mut self, max_queued: usize) {
+        self.max_queued = max_queued;
+
+        while self.queue.len() > max_queued {
+            self.queue.pop_front();
+        }
+    }
+
+    ///
+    /// Set the maximum time to wait for a write to the remote host.
+    ///
+    /// ## Parameters
+    /// - `timeout` - The write timeout. Must not be zero.
+    ///
+    pub fn set_write_timeout(&mut self, timeout: Duration) {
+        self.write_timeout = timeout;
+
+        if let Some(Err(e)) = self
+            .stream
+            .as_ref()
+            .map(|s| s.set_write_timeout(Some(timeout)))
+        {
+            self.stats.error(e, 0);
+            self.disconnected();
+        }
+    }
+
+    ///
+    /// Attempt to connect, if it is time to.
+    ///
+    fn connect(&mut self) {
+        if self.stream.is_some() || Instant::now() < self.next_attempt {
+            return;
+        }
+
+        let mut error = None;
+        let stream = self.addrs.iter().find_map(|addr| {
+            TcpStream::connect_timeout(addr, CONNECT_TIMEOUT)
+                .and_then(|s| s.set_write_timeout(Some(self.write_timeout)).map(|_| s))
+                .map_err(|e| error = Some(e))
+                .ok()
+        });
+
+        match stream {
+            Some(s) => {
+                self.stream = Some(s);
+                self.backoff = self.initial_backoff;
+            }
+            None => {
+                if let Some(e) = error {
+                    self.stats.error(e, 0);
+                }
+
+                self.disconnected();
+            }
+        }
+    }
+
+    ///
+    /// Drop the connection, and schedule the next attempt to reconnect.
+    ///
+    /// An entry that was only partly written is dropped with it.
+    ///
+    fn disconnected(&mut self) {
+        self.stream = None;
+
+        if self.written > 0 {
+            self.queue.pop_front();
+            self.stats.dropped += 1;
+            self.written = 0;
+        }
+
+        self.back_off();
+    }
+
+    ///
+    /// Schedule the next attempt to send, doubling the backoff.
+    ///
+    fn back_off(&mut self) {
+        self.next_attempt = Instant::now() + self.backoff;
+        self.backoff = (self.backoff * 2).min(self.max_backoff);
+    }
+
+    ///
+    /// Write out as much of the queue as possible.
+    ///
+    /// The bytes of the first entry already written are tracked, so that after a
+    /// timeout it is resumed, rather than sent again.
+    ///
+    fn send(&mut self) {
+        if Instant::now() < self.next_attempt {
+            return;
+        }
+
+        self.connect();
+
+        while let Some(stream) = self.stream.as_mut() {
+            let Some(line) = self.queue.front() else {
+                break;
+            };
+
+            match stream.write(&line.as_bytes()[self.written..]) {
+                Ok(0) => {
+                    self.stats.error(Error::from(ErrorKind::WriteZero), 0);
+                    self.disconnected();
+                    break;
+                }
+                Ok(n) => {
+                    self.written += n;
+
+                    if self.written == line.len() {
+                        self.stats.wrote(line.len());
+                        self.queue.pop_front();
+                        self.written = 0;
+                        self.backoff = self.initial_backoff;
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                    self.stats.error(e, 0);
+                    self.back_off();
+                    break;
+                }
+                Err(e) => {
+                    self.stats.error(e, 0);
+                    self.disconnected();
+                    break;
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for TcpHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} : {}", self.address, self.formatter)
+    }
+}
+
+impl HandlerTrait for TcpHandler {
+    ///
+    /// Create a new handler instance.
+    ///
+    /// ## Parameters
+    /// - `name` - The `host:port` address of the remote host.
+    ///
+    fn create(name: &str) -> Result<Self, Error> {
+        TcpHandler::_create(name)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    ///
+    /// Makes a final attempt to send any queued entries,
+    /// then closes the connection.
+    ///
+    fn close(&mut self) {
+        self.flush();
+        self.stream = None;
+        self.stats.dropped += self.queue.len() as u64;
+        self.queue.clear();
+        self.open = false;
+    }
+
+    fn flush(&mut self) {
+        if !self.open {
+            return;
+        }
+
+        self.send();
+
+        let failed = self.stream.as_mut().is_some_and(|s| s.flush().is_err());
+
+        if failed {
+            self.disconnected();
+        }
+    }
+
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
+    fn get_formatter(&self) -> Formatter {
+        self.formatter.clone()
+    }
+
+    fn get_log(&self) -> String {
+        self.log()
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn publish(&mut self, log_entry: &LogEntry) {
+        self.stats.published += 1;
+
+        if !self.open {
+            self.stats.dropped += 1;
+            return;
+        }
+
+        let mut buf = self.formatter.format(log_entry);
+        buf.push('\n');
+
+        if let Some(w) = self.writer.as_mut() {
+            w.extend_from_slice(buf.as_bytes());
+            self.stats.wrote(buf.len());
+            return;
+        }
+
+        if self.max_queued == 0 {
+            self.stats.dropped += 1;
+            return;
+        }
+
+        if self.queue.len() == self.max_queued {
+            self.queue.pop_front();
+            self.stats.dropped += 1;
+        }
+
+        self.queue.push_back(buf);
+        self.send();
+    }
+
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
+    }
+
+    fn set_formatter(&mut self, formatter: Formatter) {
+        self.formatter = formatter;
+    }
+
+    ///
+    /// Sets the test mode to `state`.
+    ///
+    /// If set to `true`, the entries are stored instead of being sent.
+    /// Use `get_log()` to obtain them.
+    ///
+    fn set_test_mode(&mut self, state: bool) {
+        if state {
+            self.writer = Some(Vec::new());
+        } else {
+            self.writer = None;
+        }
+    }
+
+    fn stats(&self) -> HandlerStats {
+        self.stats.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::{
+        io::{BufRead, BufReader},
+        net::TcpListener,
+        time::{Duration, Instant},
+    };
+
+    fn read_lines(listener: &TcpListener, count: usize) -> Vec<String> {
+        let (stream, _) = listener.accept().unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+
+        BufReader::new(stream)
+            .lines()
+            .take(count)
+            .map(|l| l.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn tcp_handler() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+
+        let log = Logger::builder(module_path!())
+            .add_tcp_handler_with(&addr, FormatType::Simple, None)
+            .set_fn_name("tcp_handler")
+            .build();
+
+        log.info("We begin!");
+        log.warning("Need more tests.");
+
+        assert_eq!(
+            read_lines(&listener, 2),
+            vec![
+                "flogging::handlers::tcp_handler::tests->tcp_handler [INFO   ] We begin!",
+                "flogging::handlers::tcp_handler::tests->tcp_handler [WARNING] Need more tests.",
+            ]
+        );
+    }
+
+    #[test]
+    fn reconnect() {
+        // Find a free port, then leave it unattended.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let mut h = TcpHandler::create(&addr.to_string()).unwrap();
+        h.set_formatter(FormatType::Simple.create(None));
+        h.set_backoff(Duration::ZERO, Duration::ZERO);
+        h.set_max_queued(2);
+
+        let mut le = LogEntry::create(Level::INFO, "reconnect".to_string(), "one".to_string());
+
+        for msg in ["one", "two", "three"] {
+            le.message = msg.to_string();
+            h.publish(&le);
+        }
+
+        assert!(!h.is_connected());
+        assert_eq!(h.queued(), 2);
+        assert_eq!(h.stats().published(), 3);
+        assert_eq!(h.stats().dropped(), 1);
+        assert!(h.stats().last_error().is_some());
+
+        let listener = TcpListener::bind(addr).unwrap();
+        h.flush();
+
+        assert!(h.is_connected());
+        assert_eq!(h.queued(), 0);
+        assert_eq!(h.stats().bytes_written(), 54);
+        assert_eq!(
+            read_lines(&listener, 2),
+            vec!["->reconnect [INFO   ] two", "->reconnect [INFO   ] three"]
+        );
+    }
+
+    #[test]
+    fn stalled() {
+        // Accepted by the backlog, but never read.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+
+        let mut h = TcpHandler::create(&addr).unwrap();
+        h.set_write_timeout(Duration::from_millis(100));
+        h.set_backoff(Duration::from_secs(60), Duration::from_secs(60));
+
+        // Far more than the socket buffers hold.
+        let le = LogEntry::create(Level::INFO, "stalled".to_string(), "x".repeat(1 << 20));
+        let start = Instant::now();
+
+        for _ in 0..32 {
+            h.publish(&le);
+        }
+
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(h.is_connected());
+        assert!(h.queued() > 0);
+        assert!(h.stats().last_error().is_some());
+        assert_eq!(h.stats().dropped(), 0);
+    }
+
+    #[test]
+    fn tcp_handler_test_mode() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+
+        let mut log = Logger::builder(module_path!())
+            .add_tcp_handler(&addr)
+            .set_fn_name("tcp_handler_test_mode")
+            .build();
+
+        let h = log.get_handler(Handler::Tcp).unwrap();
+        h.set_test_mode(true);
+        h.set_formatter(FormatType::Simple.create(None));
+
+        assert!(h.is_open());
+
+        log.info("trait methods");
+
+        let h = log.get_handler(Handler::Tcp).unwrap();
+
+        assert_eq!(
+            h.get_log(),
+            "flogging::handlers::tcp_handler::tests->tcp_handler_test_mode [INFO   ] trait methods\n"
+        );
+
+        h.close();
+        assert!(!h.is_open());
+    }
+
+    #[test]
+    #[should_panic(expected = "'address' must not be empty")]
+    fn address_empty() {
+        let _ = Logger::builder(module_path!()).add_tcp_handler("").build();
+    }
+}
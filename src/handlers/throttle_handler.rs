@@ -0,0 +1,446 @@
+//
+// File Name:    throttle_handler.rs
+// Directory:    src/handlers
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # ThrottleHandler
+//!
+
+use crate::*;
+use chrono::{DateTime, Local, TimeDelta};
+use std::{any::Any, borrow::Cow, collections::HashMap, fmt, io::Error, time::Duration};
+
+///
+/// A call site: the module path, file, line, and column of a log entry.
+///
+type Site = (String, Cow<'static, str>, u32, u32);
+
+///
+/// The state of the current window of a call site.
+///
+#[derive(Debug)]
+struct Window {
+    start: DateTime<Local>,
+    count: usize,
+    suppressed: u64,
+    last: Option<Last>,
+}
+
+///
+/// What the summary needs of the last log entry suppressed in a window.
+///
+#[derive(Debug)]
+struct Last {
+    level: Level,
+    fn_name: String,
+    timestamp: DateTime<Local>,
+}
+
+///
+/// Limits each call site to `limit` log entries per `window`, passing those on
+/// to a target handler, and suppressing the rest.
+///
+/// A call site is where the log entry was created: its module path, source file,
+/// line and column. So a `log.fine()` in a tight loop is throttled, without
+/// affecting any other log messages.
+///
+/// When a call site's window ends, with some of its entries suppressed, a summary is
+/// published with the next log entry, or [`flush()`][ThrottleHandler::flush]: a log
+/// entry from that call site, with the level, function name and timestamp of the last
+/// entry suppressed, and the message: "Suppressed N similar messages.". The windows
+/// that have ended are then dropped. [`summarize()`][ThrottleHandler::summarize],
+/// [`flush()`][ThrottleHandler::flush] and [`close()`][ThrottleHandler::close] publish
+/// any pending summaries.
+///
+/// The windows are measured using the timestamps of the log entries, as provided
+/// by the logger's clock.
+///
+/// The formatter, test mode, and log are all those of the target handler.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+/// use std::time::Duration;
+///
+/// let target = StringHandler::create("").unwrap();
+///
+/// let mut log = Logger::builder("app")
+///     .add_throttle_handler(Box::new(target), 2, Duration::from_secs(60))
+///     .set_level(Level::FINE)
+///     .set_fn_name("main")
+///     .build();
+///
+/// for n in 1..=5 {
+///     log.fine(&format!("Retry {n}"));
+/// }
+///
+/// log.info("Not throttled.");
+///
+/// let h = log.get_handler_as::<ThrottleHandler>().unwrap();
+/// h.summarize();
+///
+/// assert_eq!(
+///     h.get_log(),
+///     "app->main [FINE   ] Retry 1\n\
+///      app->main [FINE   ] Retry 2\n\
+///      app->main [INFO   ] Not throttled.\n\
+///      app->main [FINE   ] Suppressed 3 similar messages.\n"
+/// );
+/// ```
+///
+pub struct ThrottleHandler {
+    target: Box<dyn HandlerTrait>,
+    limit: usize,
+    window: TimeDelta,
+    sites: HashMap<Site, Window>,
+    ///
+    /// The timestamp of the latest log entry published, used to end the windows.
+    ///
+    latest: Option<DateTime<Local>>,
+    filter: Option<Box<dyn FilterTrait>>,
+    stats: HandlerStats,
+}
+
+impl ThrottleHandler {
+    ///
+    /// Creates a new `ThrottleHandler`.
+    ///
+    /// ## Parameters
+    /// - `target` - The handler to pass the log entries on to.
+    /// - `limit` - The maximum number of log entries passed on, per call site, per window.
+    /// - `window` - The span of time.
+    ///
+    pub fn new(target: Box<dyn HandlerTrait>, limit: usize, window: Duration) -> Self {
+        ThrottleHandler {
+            target,
+            limit,
+            window: TimeDelta::from_std(window).unwrap_or(TimeDelta::MAX),
+            sites: HashMap::new(),
+            latest: None,
+            filter: None,
+            stats: HandlerStats::default(),
+        }
+    }
+
+    ///
+    /// Returns the maximum number of log entries passed on, per call site, per window.
+    ///
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    ///
+    /// Returns the number of log entries currently suppressed, awaiting a summary.
+    ///
+    pub fn suppressed(&self) -> u64 {
+        self.sites.values().map(|w| w.suppressed).sum()
+    }
+
+    ///
+    /// Publish the summaries of all of the call sites with suppressed log entries,
+    /// without waiting for their windows to end.
+    ///
+    pub fn summarize(&mut self) {
+        for (site, window) in self.sites.iter_mut() {
+            summarize_window(self.target.as_mut(), site, window);
+        }
+    }
+
+    ///
+    /// Publish the summaries of the call sites whose windows have ended by `now`,
+    /// then drop those windows.
+    ///
+    fn prune(&mut self, now: DateTime<Local>) {
+        let target = self.target.as_mut();
+
+        self.sites.retain(|site, window| {
+            let ended = now - window.start >= self.window;
+
+            if ended {
+                summarize_window(target, site, window);
+            }
+
+            !ended
+        });
+    }
+}
+
+///
+/// Publish `entry` to `target`, if its filter (if any) accepts it.
+///
+fn forward(target: &mut dyn HandlerTrait, entry: &LogEntry) {
+    if target
+        .get_filter()
+        .is_none_or(|filter| filter.should_publish(entry))
+    {
+        target.publish(entry);
+    }
+}
+
+///
+/// Publish the summary of the log entries suppressed in the `window` of `site`, if any.
+///
+fn summarize_window(target: &mut dyn HandlerTrait, site: &Site, window: &mut Window) {
+    if let Some(last) = window.last.take() {
+        let (mod_path, file, line, column) = site;
+        let message = format!("Suppressed {} similar messages.", window.suppressed);
+
+        let mut entry = LogEntry::create_at(last.level, last.fn_name, message, last.timestamp);
+        entry.mod_path.clone_from(mod_path);
+        entry.file = file.clone();
+        entry.line = *line;
+        entry.column = *column;
+
+        window.suppressed = 0;
+        forward(target, &entry);
+    }
+}
+
+impl fmt::Debug for ThrottleHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThrottleHandler")
+            .field("target", &self.target.to_string())
+            .field("limit", &self.limit)
+            .field("window", &self.window)
+            .field("sites", &self.sites)
+            .field("latest", &self.latest)
+            .field("filter", &self.filter)
+            .field("stats", &self.stats)
+            .finish()
+    }
+}
+
+impl fmt::Display for ThrottleHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ThrottleHandler({}, {}s) -> {}",
+            self.limit,
+            self.window.num_seconds(),
+            self.target
+        )
+    }
+}
+
+impl HandlerTrait for ThrottleHandler {
+    ///
+    /// Create a new handler instance, with a [`StringHandler`] as its target,
+    /// and a limit of 10 log entries per call site per second.
+    ///
+    /// ## Parameters
+    /// - `name` - Passed on to create the target.
+    ///
+    fn create(name: &str) -> Result<Self, Error> {
+        Ok(ThrottleHandler::new(
+            Box::new(StringHandler::create(name)?),
+            10,
+            Duration::from_secs(1),
+        ))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    ///
+    /// Publishes any pending summaries, then closes the target handler.
+    ///
+    fn close(&mut self) {
+        self.summarize();
+        self.sites.clear();
+        self.target.close();
+    }
+
+    ///
+    /// Publishes any pending summaries, and drops the windows that have ended,
+    /// then flushes the target handler.
+    ///
+    fn flush(&mut self) {
+        if let Some(latest) = self.latest {
+            self.prune(latest);
+        }
+
+        self.summarize();
+        self.target.flush();
+    }
+
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
+    fn get_formatter(&self) -> Formatter {
+        self.target.get_formatter()
+    }
+
+    fn get_log(&self) -> String {
+        self.target.get_log()
+    }
+
+    fn is_open(&self) -> bool {
+        self.target.is_open()
+    }
+
+    fn publish(&mut self, log_entry: &LogEntry) {
+        self.stats.published += 1;
+        self.latest = Some(log_entry.timestamp);
+        self.prune(log_entry.timestamp);
+
+        let site = (
+            log_entry.mod_path.clone(),
+            log_entry.file.clone(),
+            log_entry.line,
+            log_entry.column,
+        );
+        let window = self.sites.entry(site).or_insert_with(|| Window {
+            start: log_entry.timestamp,
+            count: 0,
+            suppressed: 0,
+            last: None,
+        });
+
+        if window.count < self.limit {
+            window.count += 1;
+            forward(self.target.as_mut(), log_entry);
+        } else {
+            window.suppressed += 1;
+            self.stats.dropped += 1;
+
+            match &mut window.last {
+                Some(last) => {
+                    last.level = log_entry.level;
+                    last.fn_name.clone_from(&log_entry.fn_name);
+                    last.timestamp = log_entry.timestamp;
+                }
+                None => {
+                    window.last = Some(Last {
+                        level: log_entry.level,
+                        fn_name: log_entry.fn_name.clone(),
+                        timestamp: log_entry.timestamp,
+                    });
+                }
+            }
+        }
+    }
+
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
+    }
+
+    fn set_formatter(&mut self, formatter: Formatter) {
+        self.target.set_formatter(formatter);
+    }
+
+    fn set_test_mode(&mut self, state: bool) {
+        self.target.set_test_mode(state);
+    }
+
+    ///
+    /// Returns this handler's own counters. The suppressed log entries are
+    /// counted as dropped. Nothing is written by this handler, see the target
+    /// handler for that.
+    ///
+    fn stats(&self) -> HandlerStats {
+        self.stats.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use chrono::{Local, TimeDelta, TimeZone};
+    use std::{sync::Arc, time::Duration};
+
+    #[test]
+    fn prune() {
+        let start = Local.with_ymd_and_hms(2025, 7, 1, 9, 30, 0).unwrap();
+
+        let mut log = Logger::builder(module_path!())
+            .add_throttle_handler(
+                Box::new(StringHandler::create("").unwrap()),
+                1,
+                Duration::from_secs(3),
+            )
+            .set_clock(Arc::new(StepClock::new(start, TimeDelta::seconds(1))))
+            .set_fn_name("prune")
+            .build();
+
+        for n in 1..=3 {
+            log.info(&n.to_string());
+        }
+
+        // From another call site, after the first one's window has ended.
+        log.warning("Other.");
+
+        let h = log.get_handler_as::<ThrottleHandler>().unwrap();
+        assert_eq!(h.suppressed(), 0);
+        assert_eq!(h.sites.len(), 1);
+        assert_eq!(
+            h.get_log(),
+            "flogging::handlers::throttle_handler::tests->prune [INFO   ] 1
+flogging::handlers::throttle_handler::tests->prune [INFO   ] Suppressed 2 similar messages.
+flogging::handlers::throttle_handler::tests->prune [WARNING] Other.\n"
+        );
+    }
+
+    #[test]
+    fn window_ends() {
+        let start = Local.with_ymd_and_hms(2025, 7, 1, 9, 30, 0).unwrap();
+
+        let mut log = Logger::builder(module_path!())
+            .add_throttle_handler(
+                Box::new(StringHandler::create("").unwrap()),
+                1,
+                Duration::from_secs(3),
+            )
+            .set_clock(Arc::new(StepClock::new(start, TimeDelta::seconds(1))))
+            .set_fn_name("window_ends")
+            .build();
+
+        for n in 1..=5 {
+            log.info(&n.to_string());
+        }
+
+        let h = log.get_handler_as::<ThrottleHandler>().unwrap();
+        assert_eq!(h.limit(), 1);
+        assert_eq!(h.suppressed(), 1);
+        assert_eq!(h.stats().dropped(), 3);
+        assert_eq!(
+            h.to_string().lines().next().unwrap(),
+            "ThrottleHandler(1, 3s) -> dt_fmt: \"\" - fmt_string: \"{mod_path}->{fn_name} [{level:7}] {message}\""
+        );
+
+        h.close();
+
+        assert_eq!(
+            h.get_log(),
+            "flogging::handlers::throttle_handler::tests->window_ends [INFO   ] 1
+flogging::handlers::throttle_handler::tests->window_ends [INFO   ] Suppressed 2 similar messages.
+flogging::handlers::throttle_handler::tests->window_ends [INFO   ] 4
+flogging::handlers::throttle_handler::tests->window_ends [INFO   ] Suppressed 1 similar messages.\n"
+        );
+    }
+}
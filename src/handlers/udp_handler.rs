@@ -0,0 +1,336 @@
+//
+// File Name:    udp_handler.rs
+// Directory:    src/handlers
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # UdpHandler
+//!
+//! Sends log entries to a remote host, one UDP datagram per entry.
+//!
+
+use crate::*;
+use std::{
+    any::Any,
+    fmt,
+    io::{Error, ErrorKind::InvalidInput},
+    net::{SocketAddr, ToSocketAddrs, UdpSocket},
+};
+
+///
+/// The default maximum datagram size.
+///
+/// The largest payload that fits into a single Ethernet frame,
+/// without IP fragmentation.
+///
+pub const UDP_MAX_PACKET_SIZE: usize = 1472;
+
+///
+/// Sends log entries to the remote `host:port` whose address was provided
+/// during initialization, one datagram per entry.
+///
+/// This is a "fire and forget" handler, with very little overhead. There is
+/// no connection to maintain, and any error sending a datagram is silently
+/// ignored.
+///
+/// Formatted entries longer than the maximum packet size, by default:
+/// [`UDP_MAX_PACKET_SIZE`], are truncated to fit. Or, if truncation is
+/// turned off, they are dropped.
+///
+#[derive(Debug)]
+pub struct UdpHandler {
+    address: String,
+    formatter: Formatter,
+    filter: Option<Box<dyn FilterTrait>>,
+    socket: Option<UdpSocket>,
+    max_packet_size: usize,
+    truncate: bool,
+    writer: Option<Vec<u8>>,
+    stats: HandlerStats,
+}
+
+impl UdpHandler {
+    fn _create(address: &str) -> Result<Self, Error> {
+        if address.is_empty() {
+            return Err(Error::new(InvalidInput, "'address' must not be empty"));
+        }
+
+        let addr = address
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| Error::new(InvalidInput, format!("unknown host: {address}")))?;
+
+        Ok(UdpHandler {
+            address: address.to_string(),
+            formatter: FormatType::Iso8601.create(None),
+            filter: None,
+            socket: Some(connect_udp(addr)?),
+            max_packet_size: UDP_MAX_PACKET_SIZE,
+            truncate: true,
+            writer: None,
+            stats: HandlerStats::default(),
+        })
+    }
+
+    fn log(&self) -> String {
+        if let Some(w) = self.writer.to_owned() {
+            String::from_utf8(w).unwrap()
+        } else {
+            String::new()
+        }
+    }
+
+    ///
+    /// Set the maximum size of each datagram, in bytes.
+    ///
+    /// ## Parameters
+    /// - `max_packet_size` - The maximum size.
+    ///
+    pub fn set_max_packet_size(&mut self, max_packet_size: usize) {
+        self.max_packet_size = max_packet_size;
+    }
+
+    ///
+    /// Set whether oversized entries are truncated, or dropped.
+    ///
+    /// ## Parameters
+    /// - `truncate` - If `true` (the default), they are truncated.
+    ///
+    pub fn set_truncate(&mut self, truncate: bool) {
+        self.truncate = truncate;
+    }
+
+    ///
+    /// Fit the formatted entry into a datagram.
+    ///
+    /// Returns `None` if it is to be dropped.
+    ///
+    fn packet(&self, mut buf: String) -> Option<String> {
+        if buf.len() > self.max_packet_size {
+            if !self.truncate {
+                return None;
+            }
+
+            let mut end = self.max_packet_size;
+
+            while !buf.is_char_boundary(end) {
+                end -= 1;
+            }
+
+            buf.truncate(end);
+        }
+
+        Some(buf)
+    }
+}
+
+///
+/// Open a UDP socket, connected to `addr`.
+///
+pub(super) fn connect_udp(addr: SocketAddr) -> Result<UdpSocket, Error> {
+    let local: SocketAddr = if addr.is_ipv4() {
+        ([0, 0, 0, 0], 0).into()
+    } else {
+        ([0u16; 8], 0).into()
+    };
+
+    let socket = UdpSocket::bind(local)?;
+    socket.connect(addr)?;
+    Ok(socket)
+}
+
+impl fmt::Display for UdpHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} : {}", self.address, self.formatter)
+    }
+}
+
+impl HandlerTrait for UdpHandler {
+    ///
+    /// Create a new handler instance.
+    ///
+    /// ## Parameters
+    /// - `name` - The `host:port` address of the remote host.
+    ///
+    fn create(name: &str) -> Result<Self, Error> {
+        UdpHandler::_create(name)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    ///
+    /// Closes the socket.
+    ///
+    fn close(&mut self) {
+        self.socket = None;
+    }
+
+    ///
+    /// Datagrams are sent as they are published, so there is nothing to flush.
+    ///
+    fn flush(&mut self) {}
+
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
+    fn get_formatter(&self) -> Formatter {
+        self.formatter.clone()
+    }
+
+    fn get_log(&self) -> String {
+        self.log()
+    }
+
+    fn is_open(&self) -> bool {
+        self.socket.is_some()
+    }
+
+    fn publish(&mut self, log_entry: &LogEntry) {
+        self.stats.published += 1;
+
+        if !self.is_open() {
+            self.stats.dropped += 1;
+            return;
+        }
+
+        let Some(packet) = self.packet(self.formatter.format(log_entry)) else {
+            self.stats.dropped += 1;
+            return;
+        };
+
+        if let Some(w) = self.writer.as_mut() {
+            w.extend_from_slice(packet.as_bytes());
+            w.push(b'\n');
+            self.stats.wrote(packet.len() + 1);
+        } else if let Some(s) = &self.socket {
+            match s.send(packet.as_bytes()) {
+                Ok(n) => self.stats.wrote(n),
+                Err(e) => self.stats.error(e, 1),
+            }
+        }
+    }
+
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
+    }
+
+    fn set_formatter(&mut self, formatter: Formatter) {
+        self.formatter = formatter;
+    }
+
+    ///
+    /// Sets the test mode to `state`.
+    ///
+    /// If set to `true`, the datagrams are stored instead of being sent.
+    /// Use `get_log()` to obtain them.
+    ///
+    fn set_test_mode(&mut self, state: bool) {
+        if state {
+            self.writer = Some(Vec::new());
+        } else {
+            self.writer = None;
+        }
+    }
+
+    fn stats(&self) -> HandlerStats {
+        self.stats.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::{net::UdpSocket, time::Duration};
+
+    #[test]
+    fn udp_handler() {
+        let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+        server
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let addr = server.local_addr().unwrap().to_string();
+
+        let log = Logger::builder(module_path!())
+            .add_udp_handler_with(&addr, FormatType::Simple, None)
+            .set_fn_name("udp_handler")
+            .build();
+
+        log.info("We begin!");
+
+        let mut buf = [0u8; UDP_MAX_PACKET_SIZE];
+        let len = server.recv(&mut buf).unwrap();
+
+        assert_eq!(
+            String::from_utf8_lossy(&buf[..len]),
+            "flogging::handlers::udp_handler::tests->udp_handler [INFO   ] We begin!"
+        );
+    }
+
+    #[test]
+    fn truncation() {
+        let mut h = UdpHandler::create("127.0.0.1:9").unwrap();
+        h.set_formatter(FormatType::Simple.create(None));
+        h.set_test_mode(true);
+        h.set_max_packet_size(20);
+
+        // 'é' is 2 bytes, straddling the limit.
+        let le = LogEntry::create(Level::INFO, "f".to_string(), "12345é789".to_string());
+        h.publish(&le);
+
+        h.set_truncate(false);
+        h.publish(&le);
+
+        h.set_max_packet_size(UDP_MAX_PACKET_SIZE);
+        h.publish(&le);
+
+        assert_eq!(h.get_log(), "->f [INFO   ] 12345\n->f [INFO   ] 12345é789\n");
+        assert_eq!(h.stats().published(), 3);
+        assert_eq!(h.stats().dropped(), 1);
+        assert_eq!(h.stats().bytes_written(), h.get_log().len() as u64);
+    }
+
+    #[test]
+    fn udp_handler_test_mode() {
+        let mut log = Logger::builder(module_path!())
+            .add_udp_handler("127.0.0.1:9")
+            .set_fn_name("udp_handler_test_mode")
+            .build();
+
+        let h = log.get_handler(Handler::Udp).unwrap();
+        h.set_test_mode(true);
+
+        assert!(h.is_open());
+        assert_eq!(
+            h.to_string(),
+            "127.0.0.1:9 : dt_fmt: \"%+\" - fmt_string: \"{dt:35} {mod_path}->{fn_name} [{level:7}] {message}\""
+        );
+
+        h.close();
+        assert!(!h.is_open());
+    }
+}
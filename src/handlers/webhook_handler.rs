@@ -0,0 +1,471 @@
+//
+// File Name:    webhook_handler.rs
+// Directory:    src/handlers
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # WebhookHandler
+//!
+//! POSTs a chat message to a webhook, such as Slack's, when a serious log entry arrives.
+//!
+
+use super::formatters::json_escape;
+use crate::*;
+use std::{
+    any::Any,
+    fmt,
+    io::{Error, ErrorKind::InvalidInput},
+    time::{Duration, Instant},
+};
+use ureq::Agent;
+
+///
+/// The chat service receiving the webhook's messages.
+///
+/// This sets the JSON key holding the text of the message.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookService {
+    ///
+    /// Slack: `{"text": "..."}`.
+    ///
+    #[default]
+    Slack,
+    ///
+    /// Discord: `{"content": "..."}`.
+    ///
+    Discord,
+    ///
+    /// Microsoft Teams: `{"text": "..."}`.
+    ///
+    Teams,
+}
+
+impl WebhookService {
+    ///
+    /// Returns the JSON key holding the text of the message.
+    ///
+    pub fn key(&self) -> &'static str {
+        match self {
+            WebhookService::Slack | WebhookService::Teams => "text",
+            WebhookService::Discord => "content",
+        }
+    }
+}
+
+///
+/// POSTs a small JSON payload, as a chat message, to the webhook whose URL was
+/// provided during initialization, when a log entry at, or above, the trigger
+/// level (default: `Level::SEVERE`) arrives. Log entries below it are ignored.
+///
+/// The payload has one key, which depends on the [`WebhookService`] (default:
+/// [`Slack`][WebhookService::Slack]), holding the formatted log entry.
+///
+/// To stop a storm of log entries from spamming the channel, at most one message
+/// is sent per `min_interval` (default: 60 seconds). The log entries that arrive
+/// in between are suppressed, and counted. The next message sent includes that
+/// count. [`flush()`][WebhookHandler::flush] sends the count on its own, if
+/// nothing has been sent since.
+///
+/// As sending blocks the thread doing the logging, consider using
+/// [`LoggerBuilder::set_async()`] with this handler.
+///
+/// Requires the `http` feature.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+/// use std::time::Duration;
+///
+/// let mut h = WebhookHandler::create("https://hooks.slack.com/services/T0/B0/X").unwrap();
+/// h.set_trigger(Level::WARNING);
+/// h.set_min_interval(Duration::from_secs(300));
+/// h.set_test_mode(true);
+///
+/// let mut log = Logger::builder("app")
+///     .add_custom_handler("Alerts", Box::new(h))
+///     .set_fn_name("main")
+///     .build();
+///
+/// log.info("Ignored.");
+/// log.warning("Disk 90% full.");
+/// log.severe("Disk full!");
+///
+/// let h = log.get_handler_as::<WebhookHandler>().unwrap();
+/// assert_eq!(h.get_log(), "{\"text\":\"app->main [WARNING] Disk 90% full.\"}\n");
+/// ```
+///
+#[derive(Debug)]
+pub struct WebhookHandler {
+    url: String,
+    formatter: Formatter,
+    filter: Option<Box<dyn FilterTrait>>,
+    agent: Agent,
+    headers: Vec<(String, String)>,
+    service: WebhookService,
+    trigger: Level,
+    min_interval: Duration,
+    last_sent: Option<Instant>,
+    suppressed: u64,
+    open: bool,
+    writer: Option<Vec<u8>>,
+    stats: HandlerStats,
+}
+
+impl WebhookHandler {
+    fn _create(url: &str) -> Result<Self, Error> {
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err(Error::new(
+                InvalidInput,
+                format!("'url' must be 'http://' or 'https://': {url}"),
+            ));
+        }
+
+        let agent = Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(10)))
+            .build()
+            .into();
+
+        Ok(WebhookHandler {
+            url: url.to_string(),
+            formatter: FormatType::Simple.create(None),
+            filter: None,
+            agent,
+            headers: Vec::new(),
+            service: WebhookService::default(),
+            trigger: Level::SEVERE,
+            min_interval: Duration::from_secs(60),
+            last_sent: None,
+            suppressed: 0,
+            open: true,
+            writer: None,
+            stats: HandlerStats::default(),
+        })
+    }
+
+    fn log(&self) -> String {
+        if let Some(w) = self.writer.to_owned() {
+            String::from_utf8(w).unwrap()
+        } else {
+            String::new()
+        }
+    }
+
+    ///
+    /// Add a header to be sent with each request.
+    ///
+    /// ## Parameters
+    /// - `name` - The header's name.
+    /// - `value` - The header's value.
+    ///
+    pub fn add_header(&mut self, name: &str, value: &str) {
+        self.headers.push((name.to_string(), value.to_string()));
+    }
+
+    ///
+    /// Set the minimum time between messages.
+    ///
+    /// ## Parameters
+    /// - `min_interval` - The minimum time. `Duration::ZERO` turns off the rate limiting.
+    ///
+    pub fn set_min_interval(&mut self, min_interval: Duration) {
+        self.min_interval = min_interval;
+    }
+
+    ///
+    /// Set the chat service receiving the messages.
+    ///
+    /// ## Parameters
+    /// - `service` - The chat service.
+    ///
+    pub fn set_service(&mut self, service: WebhookService) {
+        self.service = service;
+    }
+
+    ///
+    /// Set the trigger level. Only log entries at, or above, it are sent.
+    ///
+    /// ## Parameters
+    /// - `trigger` - The trigger level.
+    ///
+    pub fn set_trigger(&mut self, trigger: Level) {
+        self.trigger = trigger;
+    }
+
+    ///
+    /// Returns the number of log entries suppressed since the last message was sent.
+    ///
+    pub fn suppressed(&self) -> u64 {
+        self.suppressed
+    }
+
+    ///
+    /// Returns `true` if a message was sent less than `min_interval` ago.
+    ///
+    fn is_limited(&self) -> bool {
+        self.last_sent
+            .is_some_and(|sent| sent.elapsed() < self.min_interval)
+    }
+
+    fn post(&self, body: &str) -> Result<(), ureq::Error> {
+        let mut request = self
+            .agent
+            .post(&self.url)
+            .header("Content-Type", "application/json");
+
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+
+        request.send(body).map(|_| ())
+    }
+
+    ///
+    /// Send `text` as a message, along with the count of suppressed log entries (if any).
+    ///
+    fn send(&mut self, mut text: String) {
+        if self.suppressed > 0 {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+
+            text.push_str(&format!("({} more suppressed)", self.suppressed));
+            self.suppressed = 0;
+        }
+
+        let body = format!("{{\"{}\":{}}}", self.service.key(), json_escape(&text));
+        self.last_sent = Some(Instant::now());
+
+        if let Some(w) = self.writer.as_mut() {
+            w.extend_from_slice(body.as_bytes());
+            w.push(b'\n');
+            self.stats.wrote(body.len() + 1);
+            return;
+        }
+
+        match self.post(&body) {
+            Ok(()) => self.stats.wrote(body.len()),
+            Err(e) => self.stats.error(e, 1),
+        }
+    }
+}
+
+impl fmt::Display for WebhookHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({:?}, {}) : {}",
+            self.url, self.service, self.trigger, self.formatter
+        )
+    }
+}
+
+impl HandlerTrait for WebhookHandler {
+    ///
+    /// Create a new handler instance.
+    ///
+    /// ## Parameters
+    /// - `name` - The URL of the webhook.
+    ///
+    fn create(name: &str) -> Result<Self, Error> {
+        WebhookHandler::_create(name)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    ///
+    /// Sends the count of suppressed log entries, if any, then closes the handler.
+    ///
+    fn close(&mut self) {
+        self.flush();
+        self.open = false;
+    }
+
+    ///
+    /// Sends the count of suppressed log entries, if any, once `min_interval` has
+    /// passed since the last message.
+    ///
+    fn flush(&mut self) {
+        if self.open && self.suppressed > 0 && !self.is_limited() {
+            self.send(String::new());
+        }
+    }
+
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
+    fn get_formatter(&self) -> Formatter {
+        self.formatter.clone()
+    }
+
+    fn get_log(&self) -> String {
+        self.log()
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn publish(&mut self, log_entry: &LogEntry) {
+        if log_entry.level < self.trigger {
+            return;
+        }
+
+        self.stats.published += 1;
+
+        if !self.open {
+            self.stats.dropped += 1;
+            return;
+        }
+
+        if self.is_limited() {
+            self.suppressed += 1;
+            self.stats.dropped += 1;
+            return;
+        }
+
+        self.send(self.formatter.format(log_entry));
+    }
+
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
+    }
+
+    fn set_formatter(&mut self, formatter: Formatter) {
+        self.formatter = formatter;
+    }
+
+    ///
+    /// Sets the test mode to `state`.
+    ///
+    /// If set to `true`, the body of each request is stored instead of
+    /// being sent. Use `get_log()` to obtain them.
+    ///
+    fn set_test_mode(&mut self, state: bool) {
+        if state {
+            self.writer = Some(Vec::new());
+        } else {
+            self.writer = None;
+        }
+    }
+
+    ///
+    /// Only the log entries at, or above, the trigger level are counted.
+    /// Those suppressed by the rate limiting are counted as dropped.
+    ///
+    fn stats(&self) -> HandlerStats {
+        self.stats.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::{thread, time::Duration};
+
+    #[test]
+    fn rate_limiting() {
+        let mut h = WebhookHandler::create("http://127.0.0.1:9/hook").unwrap();
+        h.set_test_mode(true);
+        h.set_min_interval(Duration::from_millis(100));
+
+        let mut le = LogEntry::create(Level::SEVERE, "storm".to_string(), String::new());
+
+        for n in 1..=4 {
+            le.message = n.to_string();
+            h.publish(&le);
+        }
+
+        assert_eq!(h.suppressed(), 3);
+
+        h.flush();
+        assert_eq!(h.get_log(), "{\"text\":\"->storm [SEVERE ] 1\"}\n");
+
+        thread::sleep(Duration::from_millis(150));
+        le.message = "5 \"quoted\"".to_string();
+        h.publish(&le);
+
+        assert_eq!(h.suppressed(), 0);
+        assert_eq!(
+            h.get_log(),
+            "{\"text\":\"->storm [SEVERE ] 1\"}
+{\"text\":\"->storm [SEVERE ] 5 \\\"quoted\\\"\\n(3 more suppressed)\"}\n"
+        );
+
+        h.publish(&le);
+        thread::sleep(Duration::from_millis(150));
+        h.close();
+
+        assert!(
+            h.get_log()
+                .ends_with("\n{\"text\":\"(1 more suppressed)\"}\n")
+        );
+        assert_eq!(h.stats().published(), 6);
+        assert_eq!(h.stats().dropped(), 4);
+    }
+
+    #[test]
+    fn trigger_and_service() {
+        let mut log = Logger::builder(module_path!())
+            .add_webhook_handler("http://127.0.0.1:9/hook")
+            .set_fn_name("trigger_and_service")
+            .build();
+
+        let h = log.get_handler_as::<WebhookHandler>().unwrap();
+        h.set_test_mode(true);
+        h.set_min_interval(Duration::ZERO);
+        h.set_service(WebhookService::Discord);
+        h.set_trigger(Level::WARNING);
+
+        assert_eq!(
+            h.to_string(),
+            "http://127.0.0.1:9/hook (Discord, WARNING) : dt_fmt: \"\" - fmt_string: \"{mod_path}->{fn_name} [{level:7}] {message}\""
+        );
+
+        log.info("Ignored.");
+        log.warning("one");
+        log.severe("two");
+
+        let h = log.get_handler(Handler::Webhook).unwrap();
+
+        assert_eq!(
+            h.get_log(),
+            "{\"content\":\"flogging::handlers::webhook_handler::tests->trigger_and_service [WARNING] one\"}
+{\"content\":\"flogging::handlers::webhook_handler::tests->trigger_and_service [SEVERE ] two\"}\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "'url' must be 'http://' or 'https://'")]
+    fn bad_url() {
+        let _ = Logger::builder(module_path!())
+            .add_webhook_handler("hooks.slack.com")
+            .build();
+    }
+}
@@ -53,6 +53,13 @@
 //! - [Choice](index.html#choice) - You can use either macros, methods, or a mix of both.
 //! - [Built-in options](index.html#built-in-options) - A range of handlers and formatters.
 //! - [Customization](index.html#customization) - You can create your own handlers and/or formatters.
+//! - [Bridge](bridge/index.html) - Route records from the `log` crate facade into flogging (`log` feature).
+//! - [Metrics](metrics/index.html) - Count the log entries published, by level, through the `metrics` crate facade
+//!   (`metrics` feature).
+//! - [Context](context/index.html) - Nested diagnostic context: scoped labels included in every log entry.
+//! - [Testing](testing/index.html) - Assert what was logged, with [`assert_logged!()`] and [`assert_not_logged!()`].
+//! - [Reconfiguration](struct.LogManager.html) - Change the levels, formatters, and more, of live loggers at runtime.
+//!   Optionally, by watching a configuration file (`watch` feature).
 //!
 //! ### Choice
 //!
@@ -66,22 +73,48 @@
 //! This crate has very easy to use macros. By using them, you remove a lot of the complexity
 //! from the process. Thus making it both simpler and less code cluttering, to use.
 //!
-//! - There is one macro ([`const_logger!()`]) that is used to setup a single module/file for logging.
+//! - There are two macros that are used to setup a single module/file for logging:
+//!     - [`const_logger!()`]
+//!     - [`static_logger!()`] - thread-safe, for multithreaded binaries and tests.
+//!
+//!   Both build the module's logger once, on first use, as the `static FLOGGING_LOGGER`.
+//!   The code generated by the macros only uses fully qualified paths, so any import style works.
 //! - There is one macro ([`#[logger]`][macro@logger]) that is applied as an attribute to each function/method
-//!   that you need to create log entries within.
-//! - There are nine macros that are used to actually log the messages:
+//!   that you need to create log entries within. It can also be applied to an `impl` block, and
+//!   with `#[logger(trace)]`, it logs the entry and exit of the function, however it returns.
+//!   `#[logger(panic)]` logs the exit if the function panics, and `#[logger(timed)]` logs the
+//!   time taken. `#[logger(level = "FINEST")]` sets the level for just that function, and
+//!   `#[logger(off)]` removes its logging altogether. `#[logger(params, skip(password))]` logs the
+//!   entry with the parameters' values, except for those redacted. `#[logger(capture_return)]`
+//!   logs the exit with the value returned.
+//! - There are twenty macros that are used to actually log the messages:
 //!     - [`config!()`]
+//!     - [`config_every!()`]
 //!     - [`entering!()`]
 //!     - [`exiting!()`]
 //!     - [`fine!()`]
+//!     - [`fine_every!()`]
 //!     - [`finer!()`]
+//!     - [`finer_every!()`]
 //!     - [`finest!()`]
+//!     - [`finest_every!()`]
 //!     - [`info!()`]
+//!     - [`info_every!()`] - as `info!()`, but only every `n`th time it is reached, or once per interval.
+//!     - [`log!()`] - at a level chosen at runtime.
+//!     - [`log_once!()`] - as `log!()`, but only the first time it is reached.
 //!     - [`severe!()`]
+//!     - [`severe_every!()`]
+//!     - [`throwing!()`]
 //!     - [`warning!()`]
-//! - There are two helper macros:
+//!     - [`warning_every!()`]
+//!     - [`warn_once!()`] - as `warning!()`, but only the first time it is reached.
+//! - There are three helper macros:
+//!     - [`flush!()`]
 //!     - [`get_handler!()`]
 //!     - [`set_level!()`]
+//! - There are two macros for testing what was logged:
+//!     - [`assert_logged!()`]
+//!     - [`assert_not_logged!()`]
 //!
 //! Check out the [Examples](index.html#examples) below, or **[The FLogging Guide]**, for how easy it is to get started.
 //!
@@ -100,12 +133,48 @@
 //!     - The number of `"{}"` will depend on the number of parameters.
 //!     - Ideal for logging concrete instances that have very good `Display` implementations,
 //!       or you just need their data without further explanation.
+//! - Key-Value Fields
+//!     - Fields may be appended after a `;`: `("Order placed."; user_id = uid, order = %order)`
+//!     - `key = value` and `key = %value` use `Display`, `key = ?value` uses `Debug`.
+//!     - A plain variable is shorthand for a field of the same name: `("Done."; uid, ?items)`
+//!     - The fields are attached to the [`LogEntry`], and shown by the formatters.
+//! - Lazy Evaluation
+//!     - The parameters are only evaluated and formatted if the logger is enabled for
+//!       the macro's level. So disabled `finest!()` calls cost next to nothing.
 //! - Special Cases
 //!     - [entering!] and [exiting!]
 //!     - These two macros have the same features as the others,
 //!       but they may also be used _without_ any parameters. In such
 //!       a case, their defaults will be used.
 //!
+//! ##### Compile-time Maximum Level
+//!
+//! The features `max_level_off`, `max_level_severe`, ..., `max_level_finest` set the lowest
+//! level that the macros will log. The macros for any lower levels expand to `if false { ... }`,
+//! so the binary carries neither their code nor their strings. The `release_max_level_*`
+//! features do the same, but only for builds without `debug_assertions`, such as release
+//! builds. If more than one is set, the most restrictive applies.
+//!
+//! ```text
+//! [dependencies]
+//! flogging = { version = "0.6.0", features = ["release_max_level_info"] }
+//! ```
+//!
+//! This only applies to the macros. The methods are unaffected. The excluded macros are
+//! still type-checked, so any variables that they alone use are not reported as unused.
+//!
+//! ##### Level Aliases
+//!
+//! For those used to the level names of the `log` and `tracing` crates, there are the
+//! constants [`Level::TRACE`], [`Level::DEBUG`], and [`Level::ERROR`]. With the `aliases`
+//! feature, there are also the macros `trace!()`, `debug!()`, and `error!()`. They are the
+//! same as [`finest!()`], [`fine!()`], and [`severe!()`], respectively.
+//!
+//! ```text
+//! [dependencies]
+//! flogging = { version = "0.6.0", features = ["aliases"] }
+//! ```
+//!
 //! #### Methods
 //!
 //! - [Logger](index.html#logger)
@@ -117,6 +186,10 @@
 //! coating over the real workers, the methods. There are two main mods/structs in this crate, [`Logger`](struct.Logger.html)
 //! and [`LoggerBuilder`](struct.LoggerBuilder.html).
 //!
+//! There are also the extension traits [`ResultExt`], to log the error of a `Result` in passing:
+//! `fallible().log_err(&log, Level::WARNING)?`, and [`OptionExt`], to log an `Option` that
+//! is `None`: `maybe_value.log_none(&log, "Cache miss.")`.
+//!
 //! ##### Logger
 //!
 //! `Logger` is the work-horse of the crate. It has all the methods for initializing each function/method
@@ -126,9 +199,14 @@
 //! To see how much more is involved, check-out the [`Logger`](struct.Logger.html)'s methods. There are
 //! plenty of examples throughout.
 //!
+//! - [`add_alert()`][Logger::add_alert]
+//! - [`add_enricher()`][Logger::add_enricher]
+//! - [`add_handler()`][Logger::add_handler]
 //! - [`builder()`][Logger::builder]
+//! - [`close()`][Logger::close]
 //! - [`config()`][Logger::config]
 //! - [`console_logger()`][Logger::console_logger]
+//! - [`counts()`][Logger::counts]
 //! - [`econsole_logger()`][Logger::econsole_logger]
 //! - [`custom_logger()`][Logger::custom_logger]
 //! - [`entering()`][Logger::entering]
@@ -136,80 +214,233 @@
 //! - [`exiting()`][Logger::exiting]
 //! - [`exiting_with()`][Logger::exiting_with]
 //! - [`file_logger()`][Logger::file_logger]
+//! - [`flush()`][Logger::flush]
 //! - [`fine()`][Logger::fine]
 //! - [`finer()`][Logger::finer]
 //! - [`finest()`][Logger::finest]
 //! - [`fn_name()`][Logger::fn_name]
+//! - [`get_logger()`][Logger::get_logger]
 //! - [`get_handler()`][Logger::get_handler]
+//! - [`get_handler_as()`][Logger::get_handler_as]
+//! - [`get_handler_by_id()`][Logger::get_handler_by_id]
+//! - [`handler_ids()`][Logger::handler_ids]
+//! - [`handler_stats()`][Logger::handler_stats]
+//! - [`handler_stats_by_id()`][Logger::handler_stats_by_id]
 //! - [`has_handler()`][Logger::has_handler]
 //! - [`info()`][Logger::info]
+//! - [`is_async()`][Logger::is_async]
+//! - [`is_enabled()`][Logger::is_enabled]
 //! - [`level()`][Logger::level]
+//! - [`level_map()`][Logger::level_map]
+//! - [`log_at()`][Logger::log_at]
+//! - [`module_level()`][Logger::module_level]
+//! - [`pconsole_logger()`][Logger::pconsole_logger]
+//! - [`remove_handler()`][Logger::remove_handler]
+//! - [`remove_handler_by_id()`][Logger::remove_handler_by_id]
+//! - [`reset_counts()`][Logger::reset_counts]
+//! - [`scope()`][Logger::scope]
+//! - [`set_clock()`][Logger::set_clock]
+//! - [`set_dedup()`][Logger::set_dedup]
+//! - [`set_fn_level()`][Logger::set_fn_level]
 //! - [`set_fn_name()`][Logger::set_fn_name]
 //! - [`set_level()`][Logger::set_level]
+//! - [`set_module_level()`][Logger::set_module_level]
+//! - [`set_redactor()`][Logger::set_redactor]
+//! - [`set_sampling()`][Logger::set_sampling]
 //! - [`severe()`][Logger::severe]
+//! - [`severe_err()`][Logger::severe_err]
+//! - [`shutdown()`][Logger::shutdown]
 //! - [`string_logger()`][Logger::string_logger]
+//! - [`throwing()`][Logger::throwing]
 //! - [`warning()`][Logger::warning]
+//! - [`with_fields()`][Logger::with_fields]
 //!
 //! ##### LoggerBuilder
 //!
 //! `LoggerBuilder` is used by `Logger` to provide various configuration options for setting up your logger.
 //! The available options/methods are:
 //!
+//! - [`add_alert()`][LoggerBuilder::add_alert()]
+//! - [`add_enricher()`][LoggerBuilder::add_enricher()]
+//! - [`add_binary_handler()`][LoggerBuilder::add_binary_handler()]
+//! - [`add_binary_handler_at()`][LoggerBuilder::add_binary_handler_at()]
+//! - [`add_binary_handler_with_filter()`][LoggerBuilder::add_binary_handler_with_filter()]
 //! - [`add_console_handler()`][LoggerBuilder::add_console_handler()]
+//! - [`add_console_handler_at()`][LoggerBuilder::add_console_handler_at()]
 //! - [`add_console_handler_with()`][LoggerBuilder::add_console_handler_with()]
+//! - [`add_console_handler_with_filter()`][LoggerBuilder::add_console_handler_with_filter()]
+//! - [`add_db_handler()`][LoggerBuilder::add_db_handler()] (`db` feature)
+//! - [`add_db_handler_at()`][LoggerBuilder::add_db_handler_at()] (`db` feature)
+//! - [`add_db_handler_with()`][LoggerBuilder::add_db_handler_with()] (`db` feature)
+//! - [`add_db_handler_with_filter()`][LoggerBuilder::add_db_handler_with_filter()] (`db` feature)
 //! - [`add_econsole_handler()`][LoggerBuilder::add_econsole_handler()]
+//! - [`add_econsole_handler_at()`][LoggerBuilder::add_econsole_handler_at()]
 //! - [`add_econsole_handler_with()`][LoggerBuilder::add_econsole_handler_with()]
-//! - [`add_console_handler()`][LoggerBuilder::add_pconsole_handler()]
-//! - [`add_console_handler_with()`][LoggerBuilder::add_pconsole_handler_with()]
+//! - [`add_econsole_handler_with_filter()`][LoggerBuilder::add_econsole_handler_with_filter()]
+//! - [`add_pconsole_handler()`][LoggerBuilder::add_pconsole_handler()]
+//! - [`add_pconsole_handler_at()`][LoggerBuilder::add_pconsole_handler_at()]
+//! - [`add_pconsole_handler_with()`][LoggerBuilder::add_pconsole_handler_with()]
+//! - [`add_pconsole_handler_with_filter()`][LoggerBuilder::add_pconsole_handler_with_filter()]
+//! - [`add_pconsole_handler_with_split()`][LoggerBuilder::add_pconsole_handler_with_split()]
 //! - [`add_custom_handler()`][LoggerBuilder::add_custom_handler()]
+//! - [`add_custom_handler_at()`][LoggerBuilder::add_custom_handler_at()]
 //! - [`add_custom_handler_with()`][LoggerBuilder::add_custom_handler_with()]
+//! - [`add_custom_handler_with_filter()`][LoggerBuilder::add_custom_handler_with_filter()]
+//! - [`add_email_handler()`][LoggerBuilder::add_email_handler()] (`email` feature)
+//! - [`add_email_handler_at()`][LoggerBuilder::add_email_handler_at()] (`email` feature)
+//! - [`add_email_handler_with()`][LoggerBuilder::add_email_handler_with()] (`email` feature)
+//! - [`add_email_handler_with_filter()`][LoggerBuilder::add_email_handler_with_filter()] (`email` feature)
 //! - [`add_file_handler()`][LoggerBuilder::add_file_handler()]
+//! - [`add_file_handler_at()`][LoggerBuilder::add_file_handler_at()]
 //! - [`add_file_handler_with()`][LoggerBuilder::add_file_handler_with()]
+//! - [`add_file_handler_with_filter()`][LoggerBuilder::add_file_handler_with_filter()]
+//! - [`add_http_handler()`][LoggerBuilder::add_http_handler()] (`http` feature)
+//! - [`add_http_handler_at()`][LoggerBuilder::add_http_handler_at()] (`http` feature)
+//! - [`add_http_handler_with()`][LoggerBuilder::add_http_handler_with()] (`http` feature)
+//! - [`add_http_handler_with_filter()`][LoggerBuilder::add_http_handler_with_filter()] (`http` feature)
+//! - [`add_memory_handler()`][LoggerBuilder::add_memory_handler()]
+//! - [`add_memory_handler_at()`][LoggerBuilder::add_memory_handler_at()]
+//! - [`add_memory_handler_with()`][LoggerBuilder::add_memory_handler_with()]
+//! - [`add_memory_handler_with_filter()`][LoggerBuilder::add_memory_handler_with_filter()]
+//! - [`add_rotating_file_handler()`][LoggerBuilder::add_rotating_file_handler()]
+//! - [`add_rotating_file_handler_at()`][LoggerBuilder::add_rotating_file_handler_at()]
+//! - [`add_rotating_file_handler_with()`][LoggerBuilder::add_rotating_file_handler_with()]
+//! - [`add_rotating_file_handler_with_filter()`][LoggerBuilder::add_rotating_file_handler_with_filter()]
 //! - [`add_string_handler()`][LoggerBuilder::add_string_handler()]
+//! - [`add_string_handler_at()`][LoggerBuilder::add_string_handler_at()]
 //! - [`add_string_handler_with()`][LoggerBuilder::add_string_handler_with()]
+//! - [`add_string_handler_with_filter()`][LoggerBuilder::add_string_handler_with_filter()]
+//! - [`add_syslog_handler()`][LoggerBuilder::add_syslog_handler()]
+//! - [`add_syslog_handler_at()`][LoggerBuilder::add_syslog_handler_at()]
+//! - [`add_syslog_handler_with()`][LoggerBuilder::add_syslog_handler_with()]
+//! - [`add_syslog_handler_with_filter()`][LoggerBuilder::add_syslog_handler_with_filter()]
+//! - [`add_tcp_handler()`][LoggerBuilder::add_tcp_handler()]
+//! - [`add_tcp_handler_at()`][LoggerBuilder::add_tcp_handler_at()]
+//! - [`add_tcp_handler_with()`][LoggerBuilder::add_tcp_handler_with()]
+//! - [`add_tcp_handler_with_filter()`][LoggerBuilder::add_tcp_handler_with_filter()]
+//! - [`add_throttle_handler()`][LoggerBuilder::add_throttle_handler()]
+//! - [`add_throttle_handler_at()`][LoggerBuilder::add_throttle_handler_at()]
+//! - [`add_throttle_handler_with()`][LoggerBuilder::add_throttle_handler_with()]
+//! - [`add_throttle_handler_with_filter()`][LoggerBuilder::add_throttle_handler_with_filter()]
+//! - [`add_udp_handler()`][LoggerBuilder::add_udp_handler()]
+//! - [`add_udp_handler_at()`][LoggerBuilder::add_udp_handler_at()]
+//! - [`add_udp_handler_with()`][LoggerBuilder::add_udp_handler_with()]
+//! - [`add_udp_handler_with_filter()`][LoggerBuilder::add_udp_handler_with_filter()]
+//! - [`add_webhook_handler()`][LoggerBuilder::add_webhook_handler()] (`http` feature)
+//! - [`add_webhook_handler_at()`][LoggerBuilder::add_webhook_handler_at()] (`http` feature)
+//! - [`add_webhook_handler_with()`][LoggerBuilder::add_webhook_handler_with()] (`http` feature)
+//! - [`add_webhook_handler_with_filter()`][LoggerBuilder::add_webhook_handler_with_filter()] (`http` feature)
+//! - [`parse_directives()`][LoggerBuilder::parse_directives()]
 //! - [`remove_file()`][LoggerBuilder::remove_file()]
+//! - [`set_async()`][LoggerBuilder::set_async()]
+//! - [`set_clock()`][LoggerBuilder::set_clock()]
+//! - [`set_color()`][LoggerBuilder::set_color()]
+//! - [`set_dedup()`][LoggerBuilder::set_dedup()]
+//! - [`set_file_options()`][LoggerBuilder::set_file_options()]
 //! - [`set_fn_name()`][LoggerBuilder::set_fn_name()]
 //! - [`set_level()`][LoggerBuilder::set_level()]
+//! - [`set_module_level()`][LoggerBuilder::set_module_level()]
+//! - [`set_printer()`][LoggerBuilder::set_printer()]
+//! - [`set_redactor()`][LoggerBuilder::set_redactor()]
+//! - [`set_sampling()`][LoggerBuilder::set_sampling()]
 //!
 //! And to finish:
 //! - [`build()`][LoggerBuilder::build()]
+//! - [`build_shared()`][LoggerBuilder::build_shared()]
+//! - [`try_build()`][LoggerBuilder::try_build()]
 //!
 //! These options/methods allow you a lot of flexibility in how you configure your logger. As you will typically
 //! have a different logger for each mod/file, you have a lot of control over what is logged, how it is formatted,
 //! and where it is stored/viewed. With the [`set_level()`][LoggerBuilder::set_level()] method, you can control
 //! this on a mod/file basis. Logging each mod/file differently, or even turning logging off when you no-longer require it.
+//! Where log entries from several modules pass through the same logger, such as via the `log` feature's bridge, use
+//! [`set_module_level()`][LoggerBuilder::set_module_level()] to give each of them its own level (see [`LevelMap`]).
+//! Coming from `env_logger`? Then [`parse_directives()`][LoggerBuilder::parse_directives()] accepts the familiar
+//! `RUST_LOG` style specs, such as: `info,my_app::net=finest,other_crate=off`. Or start with
+//! [`LoggerBuilder::from_env()`] to have the levels, handlers, log file and format type all set
+//! from environment variables, such as in container deployments.
+//!
+//! A logger can have any number of handlers, including several of the same kind. Each log entry is published
+//! to them in the order they were added.
+//!
+//! Each handler is checked as it is added, such as for an empty filename or a reused custom label. Where
+//! [`build()`][LoggerBuilder::build()] panics on the first problem found, [`try_build()`][LoggerBuilder::try_build()]
+//! returns it as a [`BuildError`].
 //!
 //! Check out **[The FLogging Guide]** for examples and further help.
 //!
+//! The default level of a new logger is `INFO`. This can be changed, without recompiling, by setting the
+//! [`FLOGGING_LEVEL`] environment variable to the name of the required level (case-insensitive). For example:
+//! `FLOGGING_LEVEL=fine`. Any level set in code, with `set_level()`, still takes precedence.
+//!
 //! **Note**
 //!
-//! As of version (0.4.0), you can only set the logging level for the logger. All handlers process every log entry
-//! that the logger accepts, based on the logger's current log level setting. This may change in a future version,
-//! allowing each handler to have its own logging level.
+//! By default, all handlers process every log entry that the logger accepts, based on the logger's current log level
+//! setting. Each handler can also have its own logging level, by adding it with one of the `add_*_handler_at()` methods.
+//! This can only narrow what that handler receives. For example, to log everything from `FINE` up to a file, but only
+//! `WARNING` and above to the console:
+//!
+//! ```
+//! extern crate flogging;
+//! use flogging::*;
+//!
+//! let mut log = Logger::builder(module_path!())
+//!     .set_level(Level::FINE)
+//!     .add_file_handler("test_logs/handler_levels.log")
+//!     .add_console_handler_at(Level::WARNING)
+//!     .build();
+//! ```
 //!
 //! ### Built-in options
 //!
 //! I have included a number of handlers to get you started:
 //!
+//! - [`BinaryHandler`]
+//!   - [`LogReader`]
 //! - [`ConsoleHandler`]
 //!   - [`ConsoleType::StdOut`]
 //!   - [`ConsoleType::StdErr`]
 //!   - [`ConsoleType::Production`]
+//!     - [`ProductionSplit`]
+//!   - [`ColorChoice`]
+//!   - [`PrinterTrait`]
+//! - [`DbHandler`] (`db` feature)
+//! - [`EmailHandler`] (`email` feature)
 //! - [`FileHandler`]
+//!   - [`FileOptions`]
+//!     - [`OpenMode`]
+//!   - [`FlushPolicy`]
+//!   - [`SyncPolicy`]
+//! - [`HttpHandler`] (`http` feature)
+//! - [`MemoryHandler`]
 //! - [`MockHandler`]
+//! - [`RotatingFileHandler`]
+//!   - [`Rotation`]
 //! - [`StringHandler`]
+//! - [`SyslogHandler`]
+//! - [`TcpHandler`]
+//! - [`ThrottleHandler`]
+//! - [`UdpHandler`]
+//! - [`WebhookHandler`] (`http` feature)
+//!   - [`WebhookService`]
 //!
 //! There are also a number of formatters as well:
 //!
 //! - [`Iso8601Formatter`]
+//! - [`JsonFormatter`]
 //! - [`MockFormatter`]
 //! - [`SimpleFormatter`]
 //! - [`UnixTimestampFormatter`]
 //!
+//! The `flog` binary views the log files written by the [`FileHandler`] and the
+//! [`BinaryHandler`]. It can follow them, filter them by level, module, and function,
+//! color them, and convert them to the other formats. Run `flog --help` for details.
+//!
 //! ### Customization
 //!
 //! - [Custom Handler](index.html#custom-handler)
 //! - [Custom Formatter](index.html#custom-formatter)
+//! - [Filters](index.html#filters)
 //!
 //! Now for the fun part - "Doing it _your_ way!!!"
 //!
@@ -244,6 +475,7 @@
 //! - [`Logger::custom_logger()`]
 //! - [`LoggerBuilder::add_custom_handler()`]
 //! - [`LoggerBuilder::add_custom_handler_with()`]
+//! - [`LoggerBuilder::add_db_handler_with()`] (`db` feature)
 //!
 //! #### Custom Formatter
 //!
@@ -270,8 +502,37 @@
 //! - [`LoggerBuilder::add_econsole_handler_with()`]
 //! - [`LoggerBuilder::add_pconsole_handler_with()`]
 //! - [`LoggerBuilder::add_custom_handler_with()`]
+//! - [`LoggerBuilder::add_email_handler_with()`] (`email` feature)
 //! - [`LoggerBuilder::add_file_handler_with()`]
+//! - [`LoggerBuilder::add_http_handler_with()`] (`http` feature)
+//! - [`LoggerBuilder::add_memory_handler_with()`]
+//! - [`LoggerBuilder::add_rotating_file_handler_with()`]
 //! - [`LoggerBuilder::add_string_handler_with()`]
+//! - [`LoggerBuilder::add_syslog_handler_with()`]
+//! - [`LoggerBuilder::add_tcp_handler_with()`]
+//! - [`LoggerBuilder::add_throttle_handler_with()`]
+//! - [`LoggerBuilder::add_udp_handler_with()`]
+//! - [`LoggerBuilder::add_webhook_handler_with()`] (`http` feature)
+//!
+//! #### Filters
+//!
+//! Sometimes, a handler should only publish some of the log entries that the logger accepts. For example, only
+//! those from a particular function. For this, implement the trait: [`FilterTrait`]. Any closure of the form
+//! `Fn(&LogEntry) -> bool` will also do.
+//!
+//! Alternatively, use the ready-made [`RegexFilter`], with its include and exclude patterns over the
+//! [`FilterField`]s of each log entry.
+//!
+//! Or the [`SampleFilter`], to keep only every `n`th of the log entries below a level, such as `WARNING`.
+//!
+//! Attach your filter to a handler with [`HandlerTrait::set_filter()`], or when building the logger with
+//! the `LoggerBuilder::add_*_handler_with_filter()` methods. Handlers without a filter still see everything.
+//!
+//! To add data, such as the build version or tenant ID, to every log entry, implement the trait:
+//! [`EnricherTrait`], or use a closure of the form `Fn(&mut LogEntry)`, and add it to the logger.
+//!
+//! To mask personal data and secrets, such as credit card numbers, email addresses, and bearer tokens, set a
+//! [`Redactor`] on the logger. It is applied to each log entry before any handler sees it.
 //!
 //! ## Examples
 //!
@@ -320,12 +581,9 @@
 //!     exiting!();
 //! }
 //!
-//! #[logger]
+//! #[logger(trace, capture_return)]
 //! fn error_prone() -> Result<(), Box<dyn Error>> {
-//!     entering!();
-//!     let rtn = Err(Box::from("Bad day!"));
-//!     exiting!();
-//!     rtn
+//!     Err(Box::from("Bad day!"))
 //! }
 //!
 //! #[logger]
@@ -351,7 +609,7 @@
 //!   Just something to log.
 //! flogging->do_something [FINE   ] Bit more detail.
 //! flogging->error_prone [FINER  ] Entry
-//! flogging->error_prone [FINER  ] Return
+//! flogging->error_prone [FINER  ] Return: (Err("Bad day!"))
 //! flogging->do_something [WARNING] Error: Bad day!
 //! flogging->do_something [FINER  ] Return
 //! flogging->main [INFO   ] Job's done.
@@ -368,9 +626,15 @@
 
 #![allow(unused_imports)]
 
+#[cfg(feature = "log")]
+pub mod bridge;
+pub mod context;
 mod handlers;
 mod logger;
 mod macros;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod testing;
 
 #[doc(inline)]
 pub use flogging_macros::*;
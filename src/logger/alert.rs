@@ -0,0 +1,199 @@
+//
+// File Name:    alert.rs
+// Directory:    src/logger
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Alert
+//!
+
+use super::{Level, LogEntry};
+use chrono::TimeDelta;
+use std::{collections::VecDeque, fmt, time::Duration};
+
+///
+/// Calls back when more than `threshold` log entries, at or above its level
+/// (default: `Level::WARNING`), are logged within `window` of each other.
+///
+/// The callback is given the triggering log entries, oldest first. They are
+/// then forgotten, so the next alert needs another `threshold + 1` entries.
+///
+/// The window is measured using the timestamps of the log entries, as provided
+/// by the logger's clock.
+///
+/// Added with [`LoggerBuilder::add_alert()`][crate::LoggerBuilder::add_alert] or
+/// [`Logger::add_alert()`][crate::Logger::add_alert].
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+/// use chrono::{Local, TimeDelta};
+/// use std::{
+///     sync::{Arc, Mutex},
+///     time::Duration,
+/// };
+///
+/// let paged = Arc::new(Mutex::new(Vec::new()));
+/// let pager = paged.clone();
+///
+/// let log = Logger::builder("app")
+///     .add_string_handler()
+///     .set_clock(Arc::new(StepClock::new(Local::now(), TimeDelta::seconds(1))))
+///     .add_alert(Alert::new(2, Duration::from_secs(10), move |entries: &[LogEntry]| {
+///         pager
+///             .lock()
+///             .unwrap()
+///             .extend(entries.iter().map(|le| le.message().to_string()));
+///     }))
+///     .build();
+///
+/// log.warning("Retrying.");
+/// log.info("Not counted.");
+/// log.warning("Retrying again.");
+/// assert!(paged.lock().unwrap().is_empty());
+///
+/// log.severe("Giving up!");
+/// assert_eq!(*paged.lock().unwrap(), ["Retrying.", "Retrying again.", "Giving up!"]);
+/// ```
+///
+pub struct Alert {
+    threshold: usize,
+    window: TimeDelta,
+    level: Level,
+    callback: Box<dyn FnMut(&[LogEntry]) + Send>,
+    entries: VecDeque<LogEntry>,
+}
+
+impl Alert {
+    ///
+    /// Create a new `Alert`.
+    ///
+    /// ## Parameters
+    /// - `threshold` - The number of log entries that may be logged within `window`,
+    ///   without triggering the alert.
+    /// - `window` - The span of time.
+    /// - `callback` - Called with the triggering log entries.
+    ///
+    pub fn new<F>(threshold: usize, window: Duration, callback: F) -> Self
+    where
+        F: FnMut(&[LogEntry]) + Send + 'static,
+    {
+        Alert {
+            threshold,
+            window: TimeDelta::from_std(window).unwrap_or(TimeDelta::MAX),
+            level: Level::WARNING,
+            callback: Box::new(callback),
+            entries: VecDeque::new(),
+        }
+    }
+
+    ///
+    /// Set the level of the log entries counted. Those below it are ignored.
+    ///
+    /// ## Parameters
+    /// - `level` - The lowest level counted.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn at_level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    ///
+    /// Count `entry`, calling back if that takes the number within the window past the threshold.
+    ///
+    pub(crate) fn check(&mut self, entry: &LogEntry) {
+        if entry.level < self.level {
+            return;
+        }
+
+        while self
+            .entries
+            .front()
+            .is_some_and(|first| entry.timestamp - first.timestamp > self.window)
+        {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back(entry.clone());
+
+        if self.entries.len() > self.threshold {
+            (self.callback)(self.entries.make_contiguous());
+            self.entries.clear();
+        }
+    }
+}
+
+impl fmt::Debug for Alert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Alert")
+            .field("threshold", &self.threshold)
+            .field("window", &self.window)
+            .field("level", &self.level)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use chrono::{Local, TimeDelta, TimeZone};
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    #[test]
+    fn window_expires() {
+        let start = Local.with_ymd_and_hms(2025, 7, 1, 9, 30, 0).unwrap();
+        let alerts = Arc::new(Mutex::new(0));
+        let count = alerts.clone();
+
+        let mut log = Logger::builder(module_path!())
+            .add_string_handler()
+            .set_clock(Arc::new(StepClock::new(start, TimeDelta::seconds(4))))
+            .build();
+
+        log.add_alert(
+            Alert::new(1, Duration::from_secs(5), move |entries: &[LogEntry]| {
+                assert_eq!(entries.len(), 2);
+                *count.lock().unwrap() += 1;
+            })
+            .at_level(Level::SEVERE),
+        );
+
+        // 0s and 8s: too far apart.
+        log.severe("one");
+        log.warning("ignored");
+        log.severe("two");
+        assert_eq!(*alerts.lock().unwrap(), 0);
+
+        // 12s: within 5s of "two".
+        log.severe("three");
+        assert_eq!(*alerts.lock().unwrap(), 1);
+
+        // 16s: "three" was forgotten, once the alert fired.
+        log.severe("four");
+        assert_eq!(*alerts.lock().unwrap(), 1);
+    }
+}
@@ -0,0 +1,149 @@
+//
+// File Name:    async_worker.rs
+// Directory:    src/logger
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Async Worker
+//!
+//! Background thread that formats and publishes log entries on behalf of
+//! an asynchronous [`Logger`][crate::Logger].
+//!
+
+use super::{HandlerId, Handlers, LogEntry, Selector, publish};
+use crate::{Handler, HandlerTrait};
+use std::{
+    sync::mpsc::{self, Sender, SyncSender},
+    thread::{self, JoinHandle},
+};
+
+///
+/// The requests queued for the background thread.
+///
+enum Request {
+    Publish(Box<LogEntry>),
+    AddHandler(HandlerId, Handler, Box<dyn HandlerTrait>),
+    RemoveHandler(Selector, Sender<Option<Box<dyn HandlerTrait>>>),
+    ForEach(fn(&mut dyn HandlerTrait), Sender<()>),
+}
+
+///
+/// Owns the handlers of an asynchronous `Logger`, for as long as it is running.
+///
+pub(crate) struct AsyncWorker {
+    sender: SyncSender<Request>,
+    handle: JoinHandle<Handlers>,
+}
+
+impl AsyncWorker {
+    ///
+    /// Start the background thread.
+    ///
+    /// ## Parameters
+    /// - `capacity` - The maximum number of entries queued, before `send()` blocks.
+    /// - `handlers` - The handlers to publish each entry to.
+    ///
+    pub(crate) fn spawn(capacity: usize, mut handlers: Handlers) -> Self {
+        let (sender, receiver) = mpsc::sync_channel::<Request>(capacity);
+
+        let handle = thread::spawn(move || {
+            for request in receiver {
+                match request {
+                    Request::Publish(entry) => publish(&mut handlers, &entry),
+                    Request::AddHandler(id, kind, handler) => {
+                        handlers.add_with_id(id, kind, handler);
+                    }
+                    Request::RemoveHandler(selector, reply) => {
+                        let _ = reply.send(handlers.remove(&selector));
+                    }
+                    Request::ForEach(f, reply) => {
+                        for entry in handlers.iter_mut() {
+                            f(entry.handler.as_mut());
+                        }
+
+                        let _ = reply.send(());
+                    }
+                }
+            }
+
+            handlers
+        });
+
+        AsyncWorker { sender, handle }
+    }
+
+    ///
+    /// Queue a log entry for publishing.
+    ///
+    /// Blocks if the queue is full.
+    ///
+    pub(crate) fn send(&self, entry: LogEntry) {
+        // Can only fail if the worker has died, in which case there is
+        // nowhere left to send the entry.
+        let _ = self.sender.send(Request::Publish(Box::new(entry)));
+    }
+
+    ///
+    /// Add a handler, after all of the entries currently queued.
+    ///
+    pub(crate) fn add_handler(&self, id: HandlerId, kind: Handler, handler: Box<dyn HandlerTrait>) {
+        let _ = self.sender.send(Request::AddHandler(id, kind, handler));
+    }
+
+    ///
+    /// Remove a handler, after all of the entries currently queued have been
+    /// published.
+    ///
+    /// Blocks until the background thread has removed it.
+    ///
+    pub(crate) fn remove_handler(&self, selector: Selector) -> Option<Box<dyn HandlerTrait>> {
+        let (reply, receiver) = mpsc::channel();
+
+        self.sender
+            .send(Request::RemoveHandler(selector, reply))
+            .ok()?;
+        receiver.recv().ok().flatten()
+    }
+
+    ///
+    /// Call `f` on each handler, after all of the entries currently queued have
+    /// been published.
+    ///
+    /// Blocks until the background thread has done so.
+    ///
+    pub(crate) fn for_each(&self, f: fn(&mut dyn HandlerTrait)) {
+        let (reply, receiver) = mpsc::channel();
+
+        if self.sender.send(Request::ForEach(f, reply)).is_ok() {
+            let _ = receiver.recv();
+        }
+    }
+
+    ///
+    /// Drain the queue, stop the background thread, and return the handlers.
+    ///
+    /// Returns `None` if the background thread panicked.
+    ///
+    pub(crate) fn shutdown(self) -> Option<Handlers> {
+        drop(self.sender);
+        self.handle.join().ok()
+    }
+}
@@ -0,0 +1,114 @@
+//
+// File Name:    build_error.rs
+// Directory:    src/logger
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # BuildError
+//!
+
+use crate::{FormatType, Handler};
+use std::{error::Error, fmt, io};
+
+///
+/// The reasons [`LoggerBuilder::try_build()`][crate::LoggerBuilder::try_build] can fail.
+///
+/// Only the first problem found is reported.
+///
+#[derive(Debug)]
+pub enum BuildError {
+    ///
+    /// The handler could not be created.
+    ///
+    Create(Handler, io::Error),
+
+    ///
+    /// The custom handler's label has already been used by another custom handler.
+    ///
+    DuplicateLabel(String),
+
+    ///
+    /// An environment variable read by [`LoggerBuilder::from_env()`][crate::LoggerBuilder::from_env]
+    /// has an invalid value.
+    ///
+    /// Holds the variable's name, and a description of the problem.
+    ///
+    Env(&'static str, String),
+
+    ///
+    /// The handler was given an empty filename, path, address, or URL.
+    ///
+    EmptyName(Handler),
+
+    ///
+    /// [`FormatType::Custom`] was required, but no custom formatter was supplied.
+    ///
+    MissingCustomFormatter(Handler),
+
+    ///
+    /// A custom formatter was supplied, but the format type is not [`FormatType::Custom`],
+    /// so it would be ignored.
+    ///
+    UnusedCustomFormatter(Handler, FormatType),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::Create(handler, e) => write!(f, "Failed to create {handler}: {e}"),
+            BuildError::DuplicateLabel(label) => {
+                write!(f, "Duplicate custom handler label: \"{label}\"")
+            }
+            BuildError::Env(var, msg) => write!(f, "Invalid {var}: {msg}"),
+            BuildError::EmptyName(handler) => {
+                let param = match handler {
+                    #[cfg(feature = "db")]
+                    Handler::Db => "path",
+                    #[cfg(feature = "email")]
+                    Handler::Email => "relay",
+                    #[cfg(feature = "http")]
+                    Handler::Http | Handler::Webhook => "url",
+                    Handler::Tcp | Handler::Udp => "address",
+                    _ => "filename",
+                };
+
+                write!(f, "{handler}: '{param}' must not be empty")
+            }
+            BuildError::MissingCustomFormatter(handler) => write!(
+                f,
+                "{handler} requires a custom formatter for FormatType::Custom"
+            ),
+            BuildError::UnusedCustomFormatter(handler, format_type) => write!(
+                f,
+                "{handler} was given a custom formatter, but FormatType::{format_type:?}"
+            ),
+        }
+    }
+}
+
+impl Error for BuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BuildError::Create(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
@@ -25,8 +25,38 @@
 //! # LoggerBuilder
 //!
 
+use super::{AsyncWorker, Handlers, Mailbox, Selector};
 use crate::*;
-use std::{cell::RefCell, collections::HashMap, fs};
+use std::{
+    cell::RefCell,
+    env, fs,
+    io::Error,
+    mem,
+    sync::{Arc, LazyLock, Mutex, RwLock},
+    time::Duration,
+};
+
+///
+/// The name of the environment variable holding the log file's path,
+/// used by [`LoggerBuilder::from_env()`] for the `file` handler.
+///
+pub const FLOGGING_FILE: &str = "FLOGGING_FILE";
+
+///
+/// The name of the environment variable holding the format type,
+/// used by [`LoggerBuilder::from_env()`] for every handler.
+///
+/// One of: `iso8601`, `json`, `ndjson`, `simple`, or `unixtimestamp`.
+///
+pub const FLOGGING_FORMAT: &str = "FLOGGING_FORMAT";
+
+///
+/// The name of the environment variable holding the comma separated list of handlers,
+/// used by [`LoggerBuilder::from_env()`].
+///
+/// Each one of: `console`, `econsole`, `pconsole`, `file`, or `string`.
+///
+pub const FLOGGING_HANDLERS: &str = "FLOGGING_HANDLERS";
 
 ///
 /// Used by [`Logger`] to provide more flexibility in the configuration of the
@@ -36,7 +66,19 @@ pub struct LoggerBuilder {
     mod_path: String,
     fn_name: String,
     level: Level,
-    handlers: RefCell<HashMap<Handler, Box<dyn HandlerTrait>>>,
+    level_map: LevelMap,
+    handlers: RefCell<Handlers>,
+    async_capacity: Option<usize>,
+    color: Option<ColorChoice>,
+    printer: Option<Arc<dyn PrinterTrait>>,
+    clock: Arc<dyn ClockTrait>,
+    alerts: Vec<Alert>,
+    sampling: Option<SampleFilter>,
+    enrichers: Vec<Box<dyn EnricherTrait>>,
+    file_options: FileOptions,
+    redactor: Option<Redactor>,
+    dedup: bool,
+    error: Option<BuildError>,
 }
 
 impl LoggerBuilder {
@@ -44,78 +86,99 @@ impl LoggerBuilder {
         LoggerBuilder {
             mod_path,
             fn_name: String::new(),
-            level: Level::default(),
-            handlers: RefCell::new(HashMap::new()),
+            level: Level::from_env().unwrap_or_default(),
+            level_map: LevelMap::new(),
+            handlers: RefCell::new(Handlers::default()),
+            async_capacity: None,
+            color: None,
+            printer: None,
+            clock: Arc::new(SystemClock),
+            alerts: Vec::new(),
+            sampling: None,
+            enrichers: Vec::new(),
+            file_options: FileOptions::default(),
+            redactor: None,
+            dedup: false,
+            error: None,
         }
     }
 
     ///
-    /// Adds a [`ConsoleHandler`] with the default formatter,
-    /// with output according to: [`ConsoleType::StdOut`].
+    /// Adds an [`Alert`], which is checked against each log entry.
+    ///
+    /// ## Parameters
+    /// - `alert` - The alert.
     ///
     /// ## Examples
     /// ```
     /// extern crate flogging;
     /// use flogging::*;
+    /// use std::time::Duration;
     ///
     /// let mut log = Logger::builder(module_path!())
     ///     .add_console_handler()
+    ///     .add_alert(Alert::new(10, Duration::from_secs(60), |entries: &[LogEntry]| {
+    ///         eprintln!("{} warnings in the last minute!", entries.len());
+    ///     }))
     ///     .build();
     /// ```
     ///
-    pub fn add_console_handler(self) -> Self {
-        self.add_handler_with(Handler::Console, None, None, None, None)
+    pub fn add_alert(mut self, alert: Alert) -> Self {
+        self.alerts.push(alert);
+        self
     }
 
     ///
-    /// Adds a [`ConsoleHandler`] with the required formatter,
-    /// with output according to: [`ConsoleType::StdOut`].
+    /// Adds an [`EnricherTrait`], which is run on each log entry, after those
+    /// already added.
     ///
     /// ## Parameters
-    /// - `format_type` - The format type used to produce the required formatter.
-    /// - `custom_formatter` - The optional boxed custom formatter.
-    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    /// - `enricher` - The enricher.
     ///
     /// ## Examples
-    /// First, using a provided formatter:
     /// ```
     /// extern crate flogging;
     /// use flogging::*;
     ///
     /// let mut log = Logger::builder(module_path!())
-    ///     .add_console_handler_with(FormatType::Iso8601, None)
+    ///     .add_console_handler()
+    ///     .add_enricher(Box::new(|le: &mut LogEntry| le.add_field("tenant", "acme")))
     ///     .build();
     /// ```
-    /// Now using a custom formatter:
+    ///
+    pub fn add_enricher(mut self, enricher: Box<dyn EnricherTrait>) -> Self {
+        self.enrichers.push(enricher);
+        self
+    }
+
+    ///
+    /// Adds a [`BinaryHandler`], writing the log entries in a compact binary format.
+    ///
+    /// Read them back with a [`LogReader`].
+    ///
+    /// ## Parameters
+    /// - `filename` - The name of the log file.
+    ///
+    /// ## Examples
     /// ```
     /// extern crate flogging;
     /// use flogging::*;
     ///
     /// let mut log = Logger::builder(module_path!())
-    ///     .add_console_handler_with(
-    ///         FormatType::Custom,
-    ///         Some(Box::new(MockFormatter::new())),
-    ///     )
+    ///     .add_binary_handler("test_logs/builder_binary.bin")
     ///     .build();
     /// ```
     ///
-    pub fn add_console_handler_with(
-        self,
-        format_type: FormatType,
-        custom_formatter: Option<Box<dyn FormatTrait>>,
-    ) -> Self {
-        self.add_handler_with(
-            Handler::Console,
-            None,
-            None,
-            Some(format_type),
-            custom_formatter,
-        )
+    pub fn add_binary_handler(self, filename: &str) -> Self {
+        self.add_handler_with(Handler::Binary, None, Some(filename), None, None)
     }
 
     ///
-    /// Adds a [`ConsoleHandler`] with the default formatter,
-    /// with output according to: [`ConsoleType::StdErr`].
+    /// Adds a [`BinaryHandler`] with its own level.
+    ///
+    /// ## Parameters
+    /// - `filename` - The name of the log file.
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
     ///
     /// ## Examples
     /// ```
@@ -123,67 +186,66 @@ impl LoggerBuilder {
     /// use flogging::*;
     ///
     /// let mut log = Logger::builder(module_path!())
-    ///     .add_econsole_handler()
+    ///     .add_binary_handler_at("test_logs/builder_binary.bin", Level::WARNING)
     ///     .build();
     /// ```
     ///
-    pub fn add_econsole_handler(self) -> Self {
-        self.add_handler_with(Handler::EConsole, None, None, None, None)
+    pub fn add_binary_handler_at(self, filename: &str, level: Level) -> Self {
+        self.add_binary_handler(filename).at_level(level)
     }
 
     ///
-    /// Adds a [`ConsoleHandler`] with the required formatter,
-    /// with output according to: [`ConsoleType::StdErr`].
+    /// Adds a [`BinaryHandler`] with the required filter.
     ///
     /// ## Parameters
-    /// - `format_type` - The format type used to produce the required formatter.
-    /// - `custom_formatter` - The optional boxed custom formatter.
-    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    /// - `filename` - The name of the log file.
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
     ///
     /// ## Examples
-    /// First, using a provided formatter:
     /// ```
     /// extern crate flogging;
     /// use flogging::*;
     ///
     /// let mut log = Logger::builder(module_path!())
-    ///     .add_econsole_handler_with(FormatType::Iso8601, None)
+    ///     .add_binary_handler_with_filter(
+    ///         "test_logs/builder_binary.bin",
+    ///         Box::new(|le: &LogEntry| le.fn_name() == "main"),
+    ///     )
     ///     .build();
     /// ```
-    /// Now using a custom formatter:
+    ///
+    pub fn add_binary_handler_with_filter(
+        self,
+        filename: &str,
+        filter: Box<dyn FilterTrait>,
+    ) -> Self {
+        self.add_binary_handler(filename).with_filter(filter)
+    }
+
+    ///
+    /// Adds a [`ConsoleHandler`] with the default formatter,
+    /// with output according to: [`ConsoleType::StdOut`].
+    ///
+    /// ## Examples
     /// ```
     /// extern crate flogging;
     /// use flogging::*;
     ///
     /// let mut log = Logger::builder(module_path!())
-    ///     .add_econsole_handler_with(
-    ///         FormatType::Custom,
-    ///         Some(Box::new(MockFormatter::new())),
-    ///     )
+    ///     .add_console_handler()
     ///     .build();
     /// ```
     ///
-    pub fn add_econsole_handler_with(
-        self,
-        format_type: FormatType,
-        custom_formatter: Option<Box<dyn FormatTrait>>,
-    ) -> Self {
-        self.add_handler_with(
-            Handler::EConsole,
-            None,
-            None,
-            Some(format_type),
-            custom_formatter,
-        )
+    pub fn add_console_handler(self) -> Self {
+        self.add_handler_with(Handler::Console, None, None, None, None)
     }
 
     ///
-    /// Adds a custom handler with the default formatter.
+    /// Adds a [`ConsoleHandler`] with the default formatter and its own level,
+    /// with output according to: [`ConsoleType::StdOut`].
     ///
     /// ## Parameters
-    /// - `label` - Unique identifier for this custom handler. Used when attempting to
-    ///   retrieve this handler: [`has_handler()`][Logger::has_handler], [`get_handler()`][Logger::get_handler]
-    /// - `custom_handler` - The boxed custom handler.
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
     ///
     /// ## Examples
     /// ```
@@ -191,30 +253,19 @@ impl LoggerBuilder {
     /// use flogging::*;
     ///
     /// let mut log = Logger::builder(module_path!())
-    ///     .add_custom_handler(
-    ///         "MockHandler",
-    ///         Box::new(MockHandler::create("What ever you need").unwrap()),
-    ///     )
+    ///     .add_console_handler_at(Level::WARNING)
     ///     .build();
     /// ```
     ///
-    pub fn add_custom_handler(self, label: &str, custom_handler: Box<dyn HandlerTrait>) -> Self {
-        self.add_handler_with(
-            Handler::Custom(label.to_string()),
-            Some(custom_handler),
-            None,
-            None,
-            None,
-        )
+    pub fn add_console_handler_at(self, level: Level) -> Self {
+        self.add_console_handler().at_level(level)
     }
 
     ///
-    /// Adds a custom handler with the required formatter.
+    /// Adds a [`ConsoleHandler`] with the required formatter,
+    /// with output according to: [`ConsoleType::StdOut`].
     ///
     /// ## Parameters
-    /// - `label` - Unique identifier for this custom handler. Used when attempting to
-    ///   retrieve this handler: [`has_handler()`][Logger::has_handler], [`get_handler()`][Logger::get_handler]
-    /// - `custom_handler` - The boxed custom handler.
     /// - `format_type` - The format type used to produce the required formatter.
     /// - `custom_formatter` - The optional boxed custom formatter.
     ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
@@ -226,12 +277,7 @@ impl LoggerBuilder {
     /// use flogging::*;
     ///
     /// let mut log = Logger::builder(module_path!())
-    ///     .add_custom_handler_with(
-    ///         "MockHandler",
-    ///         Box::new(MockHandler::create("What ever you need").unwrap()),
-    ///         FormatType::Simple,
-    ///         None,
-    ///     )
+    ///     .add_console_handler_with(FormatType::Iso8601, None)
     ///     .build();
     /// ```
     /// Now using a custom formatter:
@@ -240,25 +286,21 @@ impl LoggerBuilder {
     /// use flogging::*;
     ///
     /// let mut log = Logger::builder(module_path!())
-    ///     .add_custom_handler_with(
-    ///         "MockHandler",
-    ///         Box::new(MockHandler::create("What ever you need").unwrap()),
+    ///     .add_console_handler_with(
     ///         FormatType::Custom,
     ///         Some(Box::new(MockFormatter::new())),
     ///     )
     ///     .build();
     /// ```
     ///
-    pub fn add_custom_handler_with(
+    pub fn add_console_handler_with(
         self,
-        label: &str,
-        custom_handler: Box<dyn HandlerTrait>,
         format_type: FormatType,
         custom_formatter: Option<Box<dyn FormatTrait>>,
     ) -> Self {
         self.add_handler_with(
-            Handler::Custom(label.to_string()),
-            Some(custom_handler),
+            Handler::Console,
+            None,
             None,
             Some(format_type),
             custom_formatter,
@@ -266,12 +308,11 @@ impl LoggerBuilder {
     }
 
     ///
-    /// Adds a [`FileHandler`] with the default formatter.
+    /// Adds a [`ConsoleHandler`] with the default formatter and the required filter,
+    /// with output according to: [`ConsoleType::StdOut`].
     ///
     /// ## Parameters
-    ///
-    /// - `filename` - The name of the output log file. Must include any relevant
-    ///   path (relative or absolute).
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
     ///
     /// ## Examples
     /// ```
@@ -279,105 +320,134 @@ impl LoggerBuilder {
     /// use flogging::*;
     ///
     /// let mut log = Logger::builder(module_path!())
-    ///     .add_file_handler("test_logs/builder.log")
+    ///     .add_console_handler_with_filter(Box::new(|le: &LogEntry| le.fn_name() == "main"))
     ///     .build();
     /// ```
     ///
-    pub fn add_file_handler(self, filename: &str) -> Self {
-        self.add_handler_with(Handler::File, None, Some(filename), None, None)
+    pub fn add_console_handler_with_filter(self, filter: Box<dyn FilterTrait>) -> Self {
+        self.add_console_handler().with_filter(filter)
     }
 
     ///
-    /// Adds a [`FileHandler`] with the required formatter.
+    /// Adds a [`DbHandler`] with the default formatter.
+    ///
+    /// Requires the `db` feature.
     ///
     /// ## Parameters
-    /// - `filename` - The name of the output log file. Must include any relevant
-    ///   path (relative or absolute).
-    /// - `format_type` - The format type used to produce the required formatter.
-    /// - `custom_formatter` - The optional boxed custom formatter.
-    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    /// - `path` - The path of the database directory. Created if required.
     ///
     /// ## Examples
-    /// First, using a provided formatter:
     /// ```
     /// extern crate flogging;
     /// use flogging::*;
     ///
     /// let mut log = Logger::builder(module_path!())
-    ///     .add_file_handler_with("test_logs/builder.log", FormatType::Iso8601, None)
+    ///     .add_db_handler("test_logs/builder_db")
     ///     .build();
     /// ```
-    /// Now using a custom formatter:
+    ///
+    #[cfg(feature = "db")]
+    pub fn add_db_handler(self, path: &str) -> Self {
+        self.add_handler_with(Handler::Db, None, Some(path), None, None)
+    }
+
+    ///
+    /// Adds a [`DbHandler`] with the default formatter and its own level.
+    ///
+    /// Requires the `db` feature.
+    ///
+    /// ## Parameters
+    /// - `path` - The path of the database directory. Created if required.
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
+    ///
+    /// ## Examples
     /// ```
     /// extern crate flogging;
     /// use flogging::*;
     ///
     /// let mut log = Logger::builder(module_path!())
-    ///     .add_file_handler_with(
-    ///         "test_logs/builder.log",
-    ///         FormatType::Custom,
-    ///         Some(Box::new(MockFormatter::new())),
+    ///     .add_db_handler_at(
+    ///         "test_logs/builder_db",
+    ///         Level::WARNING,
     ///     )
     ///     .build();
     /// ```
     ///
-    pub fn add_file_handler_with(
+    #[cfg(feature = "db")]
+    pub fn add_db_handler_at(self, path: &str, level: Level) -> Self {
+        self.add_db_handler(path).at_level(level)
+    }
+
+    ///
+    /// Adds a [`DbHandler`] with the required formatter.
+    ///
+    /// The formatter is only used by [`DbHandler::report()`], as the
+    /// log entries themselves are stored unformatted.
+    ///
+    /// Requires the `db` feature.
+    ///
+    /// ## Parameters
+    /// - `path` - The path of the database directory. Created if required.
+    /// - `format_type` - The format type used to produce the required formatter.
+    /// - `custom_formatter` - The optional boxed custom formatter.
+    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_db_handler_with("test_logs/builder_db_with", FormatType::Simple, None)
+    ///     .build();
+    /// ```
+    ///
+    #[cfg(feature = "db")]
+    pub fn add_db_handler_with(
         self,
-        filename: &str,
+        path: &str,
         format_type: FormatType,
         custom_formatter: Option<Box<dyn FormatTrait>>,
     ) -> Self {
         self.add_handler_with(
-            Handler::File,
+            Handler::Db,
             None,
-            Some(filename),
+            Some(path),
             Some(format_type),
             custom_formatter,
         )
     }
 
-    fn add_handler_with(
-        mut self,
-        handler: Handler,
-        custom_handler: Option<Box<dyn HandlerTrait>>,
-        filename: Option<&str>,
-        format_type: Option<FormatType>,
-        custom_formatter: Option<Box<dyn FormatTrait>>,
-    ) -> Self {
-        let name = filename.unwrap_or(&self.mod_path);
-        let mut h: Box<dyn HandlerTrait> = match handler {
-            Handler::Console => {
-                Box::new(ConsoleHandler::create(ConsoleType::StdOut.as_str()).unwrap())
-            }
-            Handler::EConsole => {
-                Box::new(ConsoleHandler::create(ConsoleType::StdErr.as_str()).unwrap())
-            }
-            Handler::File => Box::new(FileHandler::create(name).unwrap()),
-            Handler::PConsole => {
-                Box::new(ConsoleHandler::create(ConsoleType::Production.as_str()).unwrap())
-            }
-            Handler::String => Box::new(StringHandler::create(name).unwrap()),
-            Handler::Custom(_) => custom_handler.unwrap(),
-        };
-
-        if let Some(f) = format_type {
-            h.set_formatter(match f {
-                FormatType::Iso8601 => f.create(None),
-                FormatType::Simple => f.create(None),
-                FormatType::UnixTimestamp => f.create(None),
-                FormatType::Custom => f.create(custom_formatter),
-            });
-        }
-
-        let map = self.handlers.get_mut();
-        map.insert(handler, h);
-
-        self
+    ///
+    /// Adds a [`DbHandler`] with the default formatter and the required filter.
+    ///
+    /// Requires the `db` feature.
+    ///
+    /// ## Parameters
+    /// - `path` - The path of the database directory. Created if required.
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_db_handler_with_filter(
+    ///         "test_logs/builder_db",
+    ///         Box::new(|le: &LogEntry| le.fn_name() == "main"),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    #[cfg(feature = "db")]
+    pub fn add_db_handler_with_filter(self, path: &str, filter: Box<dyn FilterTrait>) -> Self {
+        self.add_db_handler(path).with_filter(filter)
     }
 
     ///
     /// Adds a [`ConsoleHandler`] with the default formatter,
-    /// with output according to: [`ConsoleType::Production`].
+    /// with output according to: [`ConsoleType::StdErr`].
     ///
     /// ## Examples
     /// ```
@@ -385,17 +455,38 @@ impl LoggerBuilder {
     /// use flogging::*;
     ///
     /// let mut log = Logger::builder(module_path!())
-    ///     .add_pconsole_handler()
+    ///     .add_econsole_handler()
     ///     .build();
     /// ```
     ///
-    pub fn add_pconsole_handler(self) -> Self {
-        self.add_handler_with(Handler::PConsole, None, None, None, None)
+    pub fn add_econsole_handler(self) -> Self {
+        self.add_handler_with(Handler::EConsole, None, None, None, None)
+    }
+
+    ///
+    /// Adds a [`ConsoleHandler`] with the default formatter and its own level,
+    /// with output according to: [`ConsoleType::StdErr`].
+    ///
+    /// ## Parameters
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_econsole_handler_at(Level::WARNING)
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_econsole_handler_at(self, level: Level) -> Self {
+        self.add_econsole_handler().at_level(level)
     }
 
     ///
     /// Adds a [`ConsoleHandler`] with the required formatter,
-    /// with output according to: [`ConsoleType::Production`].
+    /// with output according to: [`ConsoleType::StdErr`].
     ///
     /// ## Parameters
     /// - `format_type` - The format type used to produce the required formatter.
@@ -409,7 +500,7 @@ impl LoggerBuilder {
     /// use flogging::*;
     ///
     /// let mut log = Logger::builder(module_path!())
-    ///     .add_pconsole_handler_with(FormatType::Iso8601, None)
+    ///     .add_econsole_handler_with(FormatType::Iso8601, None)
     ///     .build();
     /// ```
     /// Now using a custom formatter:
@@ -418,91 +509,2234 @@ impl LoggerBuilder {
     /// use flogging::*;
     ///
     /// let mut log = Logger::builder(module_path!())
-    ///     .add_pconsole_handler_with(
+    ///     .add_econsole_handler_with(
     ///         FormatType::Custom,
     ///         Some(Box::new(MockFormatter::new())),
     ///     )
     ///     .build();
     /// ```
     ///
-    pub fn add_pconsole_handler_with(
+    pub fn add_econsole_handler_with(
         self,
         format_type: FormatType,
         custom_formatter: Option<Box<dyn FormatTrait>>,
     ) -> Self {
         self.add_handler_with(
-            Handler::PConsole,
+            Handler::EConsole,
+            None,
+            None,
+            Some(format_type),
+            custom_formatter,
+        )
+    }
+
+    ///
+    /// Adds a [`ConsoleHandler`] with the default formatter and the required filter,
+    /// with output according to: [`ConsoleType::StdErr`].
+    ///
+    /// ## Parameters
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_econsole_handler_with_filter(Box::new(|le: &LogEntry| le.fn_name() == "main"))
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_econsole_handler_with_filter(self, filter: Box<dyn FilterTrait>) -> Self {
+        self.add_econsole_handler().with_filter(filter)
+    }
+
+    ///
+    /// Adds a custom handler with the default formatter.
+    ///
+    /// ## Parameters
+    /// - `label` - Unique identifier for this custom handler. Used when attempting to
+    ///   retrieve this handler: [`has_handler()`][Logger::has_handler], [`get_handler()`][Logger::get_handler]
+    /// - `custom_handler` - The boxed custom handler.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_custom_handler(
+    ///         "MockHandler",
+    ///         Box::new(MockHandler::create("What ever you need").unwrap()),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_custom_handler(self, label: &str, custom_handler: Box<dyn HandlerTrait>) -> Self {
+        self.add_handler_with(
+            Handler::Custom(label.to_string()),
+            Some(Ok(custom_handler)),
+            None,
+            None,
+            None,
+        )
+    }
+
+    ///
+    /// Adds a custom handler with the default formatter and its own level.
+    ///
+    /// ## Parameters
+    /// - `label` - Unique identifier for this custom handler. Used when attempting to
+    ///   retrieve this handler: [`has_handler()`][Logger::has_handler], [`get_handler()`][Logger::get_handler]
+    /// - `custom_handler` - The boxed custom handler.
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_custom_handler_at(
+    ///         "MockHandler",
+    ///         Box::new(MockHandler::create("What ever you need").unwrap()),
+    ///         Level::WARNING,
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_custom_handler_at(
+        self,
+        label: &str,
+        custom_handler: Box<dyn HandlerTrait>,
+        level: Level,
+    ) -> Self {
+        self.add_custom_handler(label, custom_handler)
+            .at_level(level)
+    }
+
+    ///
+    /// Adds a custom handler with the required formatter.
+    ///
+    /// ## Parameters
+    /// - `label` - Unique identifier for this custom handler. Used when attempting to
+    ///   retrieve this handler: [`has_handler()`][Logger::has_handler], [`get_handler()`][Logger::get_handler]
+    /// - `custom_handler` - The boxed custom handler.
+    /// - `format_type` - The format type used to produce the required formatter.
+    /// - `custom_formatter` - The optional boxed custom formatter.
+    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    ///
+    /// ## Examples
+    /// First, using a provided formatter:
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_custom_handler_with(
+    ///         "MockHandler",
+    ///         Box::new(MockHandler::create("What ever you need").unwrap()),
+    ///         FormatType::Simple,
+    ///         None,
+    ///     )
+    ///     .build();
+    /// ```
+    /// Now using a custom formatter:
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_custom_handler_with(
+    ///         "MockHandler",
+    ///         Box::new(MockHandler::create("What ever you need").unwrap()),
+    ///         FormatType::Custom,
+    ///         Some(Box::new(MockFormatter::new())),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_custom_handler_with(
+        self,
+        label: &str,
+        custom_handler: Box<dyn HandlerTrait>,
+        format_type: FormatType,
+        custom_formatter: Option<Box<dyn FormatTrait>>,
+    ) -> Self {
+        self.add_handler_with(
+            Handler::Custom(label.to_string()),
+            Some(Ok(custom_handler)),
+            None,
+            Some(format_type),
+            custom_formatter,
+        )
+    }
+
+    ///
+    /// Adds a custom handler with the default formatter and the required filter.
+    ///
+    /// ## Parameters
+    /// - `label` - Unique identifier for this custom handler. Used when attempting to
+    ///   retrieve this handler: [`has_handler()`][Logger::has_handler], [`get_handler()`][Logger::get_handler]
+    /// - `custom_handler` - The boxed custom handler.
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_custom_handler_with_filter(
+    ///         "MockHandler",
+    ///         Box::new(MockHandler::create("What ever you need").unwrap()),
+    ///         Box::new(|le: &LogEntry| le.fn_name() == "main"),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_custom_handler_with_filter(
+        self,
+        label: &str,
+        custom_handler: Box<dyn HandlerTrait>,
+        filter: Box<dyn FilterTrait>,
+    ) -> Self {
+        self.add_custom_handler(label, custom_handler)
+            .with_filter(filter)
+    }
+
+    ///
+    /// Adds an [`EmailHandler`] with the default formatter.
+    ///
+    /// Requires the `email` feature.
+    ///
+    /// ## Parameters
+    /// - `relay` - The SMTP relay to send the digests through: `host[:port]`.
+    /// - `from` - The email address of the sender.
+    /// - `to` - The email addresses of the recipients.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_email_handler("smtp.example.com", "app@example.com", &["ops@example.com"])
+    ///     .build();
+    /// ```
+    ///
+    #[cfg(feature = "email")]
+    pub fn add_email_handler(self, relay: &str, from: &str, to: &[&str]) -> Self {
+        self.add_handler_with(
+            Handler::Email,
+            Some(email_handler(relay, from, to)),
+            Some(relay),
             None,
             None,
+        )
+    }
+
+    ///
+    /// Adds an [`EmailHandler`] with the default formatter and its own level.
+    ///
+    /// Requires the `email` feature.
+    ///
+    /// ## Parameters
+    /// - `relay` - The SMTP relay to send the digests through: `host[:port]`.
+    /// - `from` - The email address of the sender.
+    /// - `to` - The email addresses of the recipients.
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
+    ///   Those below the handler's trigger level are still not sent.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_email_handler_at(
+    ///         "smtp.example.com",
+    ///         "app@example.com",
+    ///         &["ops@example.com"],
+    ///         Level::SEVERE,
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    #[cfg(feature = "email")]
+    pub fn add_email_handler_at(self, relay: &str, from: &str, to: &[&str], level: Level) -> Self {
+        self.add_email_handler(relay, from, to).at_level(level)
+    }
+
+    ///
+    /// Adds an [`EmailHandler`] with the required formatter.
+    ///
+    /// Requires the `email` feature.
+    ///
+    /// ## Parameters
+    /// - `relay` - The SMTP relay to send the digests through: `host[:port]`.
+    /// - `from` - The email address of the sender.
+    /// - `to` - The email addresses of the recipients.
+    /// - `format_type` - The format type used to produce the required formatter.
+    /// - `custom_formatter` - The optional boxed custom formatter.
+    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_email_handler_with(
+    ///         "smtp.example.com",
+    ///         "app@example.com",
+    ///         &["ops@example.com"],
+    ///         FormatType::Iso8601,
+    ///         None,
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    #[cfg(feature = "email")]
+    pub fn add_email_handler_with(
+        self,
+        relay: &str,
+        from: &str,
+        to: &[&str],
+        format_type: FormatType,
+        custom_formatter: Option<Box<dyn FormatTrait>>,
+    ) -> Self {
+        self.add_handler_with(
+            Handler::Email,
+            Some(email_handler(relay, from, to)),
+            Some(relay),
             Some(format_type),
             custom_formatter,
         )
     }
 
     ///
-    /// Adds a [`StringHandler`] with the default formatter.
+    /// Adds an [`EmailHandler`] with the default formatter and the required filter.
+    ///
+    /// Requires the `email` feature.
+    ///
+    /// ## Parameters
+    /// - `relay` - The SMTP relay to send the digests through: `host[:port]`.
+    /// - `from` - The email address of the sender.
+    /// - `to` - The email addresses of the recipients.
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_email_handler_with_filter(
+    ///         "smtp.example.com",
+    ///         "app@example.com",
+    ///         &["ops@example.com"],
+    ///         Box::new(|le: &LogEntry| le.fn_name() == "main"),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    #[cfg(feature = "email")]
+    pub fn add_email_handler_with_filter(
+        self,
+        relay: &str,
+        from: &str,
+        to: &[&str],
+        filter: Box<dyn FilterTrait>,
+    ) -> Self {
+        self.add_email_handler(relay, from, to).with_filter(filter)
+    }
+
+    ///
+    /// Adds a [`FileHandler`] with the default formatter.
+    ///
+    /// ## Parameters
+    ///
+    /// - `filename` - The name of the output log file. Must include any relevant
+    ///   path (relative or absolute).
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_file_handler("test_logs/builder.log")
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_file_handler(self, filename: &str) -> Self {
+        self.add_handler_with(Handler::File, None, Some(filename), None, None)
+    }
+
+    ///
+    /// Adds a [`FileHandler`] with the default formatter and its own level.
+    ///
+    /// ## Parameters
+    ///
+    /// - `filename` - The name of the output log file. Must include any relevant
+    ///   path (relative or absolute).
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_file_handler_at(
+    ///         "test_logs/builder.log",
+    ///         Level::WARNING,
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_file_handler_at(self, filename: &str, level: Level) -> Self {
+        self.add_file_handler(filename).at_level(level)
+    }
+
+    ///
+    /// Adds a [`FileHandler`] with the required formatter.
+    ///
+    /// ## Parameters
+    /// - `filename` - The name of the output log file. Must include any relevant
+    ///   path (relative or absolute).
+    /// - `format_type` - The format type used to produce the required formatter.
+    /// - `custom_formatter` - The optional boxed custom formatter.
+    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    ///
+    /// ## Examples
+    /// First, using a provided formatter:
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_file_handler_with("test_logs/builder.log", FormatType::Iso8601, None)
+    ///     .build();
+    /// ```
+    /// Now using a custom formatter:
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_file_handler_with(
+    ///         "test_logs/builder.log",
+    ///         FormatType::Custom,
+    ///         Some(Box::new(MockFormatter::new())),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_file_handler_with(
+        self,
+        filename: &str,
+        format_type: FormatType,
+        custom_formatter: Option<Box<dyn FormatTrait>>,
+    ) -> Self {
+        self.add_handler_with(
+            Handler::File,
+            None,
+            Some(filename),
+            Some(format_type),
+            custom_formatter,
+        )
+    }
+
+    ///
+    /// Adds a [`FileHandler`] with the default formatter and the required filter.
+    ///
+    /// ## Parameters
+    ///
+    /// - `filename` - The name of the output log file. Must include any relevant
+    ///   path (relative or absolute).
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_file_handler_with_filter(
+    ///         "test_logs/builder.log",
+    ///         Box::new(|le: &LogEntry| le.fn_name() == "main"),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_file_handler_with_filter(
+        self,
+        filename: &str,
+        filter: Box<dyn FilterTrait>,
+    ) -> Self {
+        self.add_file_handler(filename).with_filter(filter)
+    }
+
+    ///
+    /// Adds an [`HttpHandler`] with the default formatter.
+    ///
+    /// Requires the `http` feature.
+    ///
+    /// ## Parameters
+    /// - `url` - The URL of the endpoint to POST the batches of log entries to.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_http_handler("http://localhost:8080/ingest")
+    ///     .build();
+    /// ```
+    ///
+    #[cfg(feature = "http")]
+    pub fn add_http_handler(self, url: &str) -> Self {
+        self.add_handler_with(Handler::Http, None, Some(url), None, None)
+    }
+
+    ///
+    /// Adds an [`HttpHandler`] with the default formatter and its own level.
+    ///
+    /// Requires the `http` feature.
+    ///
+    /// ## Parameters
+    /// - `url` - The URL of the endpoint to POST the batches of log entries to.
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_http_handler_at(
+    ///         "http://localhost:8080/ingest",
+    ///         Level::WARNING,
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    #[cfg(feature = "http")]
+    pub fn add_http_handler_at(self, url: &str, level: Level) -> Self {
+        self.add_http_handler(url).at_level(level)
+    }
+
+    ///
+    /// Adds an [`HttpHandler`] with the required formatter.
+    ///
+    /// Requires the `http` feature.
+    ///
+    /// ## Parameters
+    /// - `url` - The URL of the endpoint to POST the batches of log entries to.
+    /// - `format_type` - The format type used to produce the required formatter.
+    /// - `custom_formatter` - The optional boxed custom formatter.
+    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_http_handler_with("http://localhost:8080/ingest", FormatType::Simple, None)
+    ///     .build();
+    /// ```
+    ///
+    #[cfg(feature = "http")]
+    pub fn add_http_handler_with(
+        self,
+        url: &str,
+        format_type: FormatType,
+        custom_formatter: Option<Box<dyn FormatTrait>>,
+    ) -> Self {
+        self.add_handler_with(
+            Handler::Http,
+            None,
+            Some(url),
+            Some(format_type),
+            custom_formatter,
+        )
+    }
+
+    ///
+    /// Adds an [`HttpHandler`] with the default formatter and the required filter.
+    ///
+    /// Requires the `http` feature.
+    ///
+    /// ## Parameters
+    /// - `url` - The URL of the endpoint to POST the batches of log entries to.
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_http_handler_with_filter(
+    ///         "http://localhost:8080/ingest",
+    ///         Box::new(|le: &LogEntry| le.fn_name() == "main"),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    #[cfg(feature = "http")]
+    pub fn add_http_handler_with_filter(self, url: &str, filter: Box<dyn FilterTrait>) -> Self {
+        self.add_http_handler(url).with_filter(filter)
+    }
+
+    fn add_handler_with(
+        mut self,
+        handler: Handler,
+        custom_handler: Option<Result<Box<dyn HandlerTrait>, Error>>,
+        filename: Option<&str>,
+        format_type: Option<FormatType>,
+        custom_formatter: Option<Box<dyn FormatTrait>>,
+    ) -> Self {
+        if self.error.is_none() {
+            match self.create_handler(handler.clone(), custom_handler, filename) {
+                Ok(h) => self.add_created_handler(handler, h, format_type, custom_formatter),
+                Err(e) => self.error = Some(e),
+            }
+        }
+
+        self
+    }
+
+    fn add_created_handler(
+        &mut self,
+        handler: Handler,
+        mut h: Box<dyn HandlerTrait>,
+        format_type: Option<FormatType>,
+        custom_formatter: Option<Box<dyn FormatTrait>>,
+    ) {
+        match (format_type, custom_formatter) {
+            (Some(FormatType::Custom), None) => {
+                self.error = Some(BuildError::MissingCustomFormatter(handler));
+                return;
+            }
+            (Some(f), Some(_)) if f != FormatType::Custom => {
+                self.error = Some(BuildError::UnusedCustomFormatter(handler, f));
+                return;
+            }
+            (Some(f), custom_formatter) => h.set_formatter(f.create(custom_formatter)),
+            (None, _) => {}
+        }
+
+        self.handlers.get_mut().add(handler, h);
+    }
+
+    ///
+    /// Validate the arguments, then create the handler.
+    ///
+    fn create_handler(
+        &mut self,
+        handler: Handler,
+        custom_handler: Option<Result<Box<dyn HandlerTrait>, Error>>,
+        filename: Option<&str>,
+    ) -> Result<Box<dyn HandlerTrait>, BuildError> {
+        if filename.is_some_and(|name| name.trim().is_empty()) {
+            return Err(BuildError::EmptyName(handler));
+        }
+
+        if let Handler::Custom(label) = &handler {
+            if self
+                .handlers
+                .get_mut()
+                .contains(&Selector::Kind(handler.clone()))
+            {
+                return Err(BuildError::DuplicateLabel(label.clone()));
+            }
+        }
+
+        let name = filename.unwrap_or(&self.mod_path);
+        let h: Result<Box<dyn HandlerTrait>, Error> = match handler {
+            Handler::Binary => {
+                BinaryHandler::create(name).map(|h| Box::new(h) as Box<dyn HandlerTrait>)
+            }
+            Handler::Console => ConsoleHandler::create(ConsoleType::StdOut.as_str())
+                .map(|h| Box::new(h) as Box<dyn HandlerTrait>),
+            #[cfg(feature = "db")]
+            Handler::Db => DbHandler::create(name).map(|h| Box::new(h) as Box<dyn HandlerTrait>),
+            Handler::EConsole => ConsoleHandler::create(ConsoleType::StdErr.as_str())
+                .map(|h| Box::new(h) as Box<dyn HandlerTrait>),
+            Handler::File => FileHandler::with_options(name, self.file_options.clone())
+                .map(|h| Box::new(h) as Box<dyn HandlerTrait>),
+            #[cfg(feature = "http")]
+            Handler::Http => {
+                HttpHandler::create(name).map(|h| Box::new(h) as Box<dyn HandlerTrait>)
+            }
+            Handler::PConsole => ConsoleHandler::create(ConsoleType::Production.as_str())
+                .map(|h| Box::new(h) as Box<dyn HandlerTrait>),
+            Handler::String => {
+                StringHandler::create(name).map(|h| Box::new(h) as Box<dyn HandlerTrait>)
+            }
+            Handler::Tcp => TcpHandler::create(name).map(|h| Box::new(h) as Box<dyn HandlerTrait>),
+            Handler::Udp => UdpHandler::create(name).map(|h| Box::new(h) as Box<dyn HandlerTrait>),
+            #[cfg(feature = "http")]
+            Handler::Webhook => {
+                WebhookHandler::create(name).map(|h| Box::new(h) as Box<dyn HandlerTrait>)
+            }
+            #[cfg(feature = "email")]
+            Handler::Email => custom_handler.expect("built-in handlers are created above"),
+            Handler::Memory
+            | Handler::RotatingFile
+            | Handler::Syslog
+            | Handler::Throttle
+            | Handler::Custom(_) => custom_handler.expect("built-in handlers are created above"),
+        };
+
+        h.map_err(|e| BuildError::Create(handler, e))
+    }
+
+    ///
+    /// Adds a [`MemoryHandler`], leaving its target handler's formatter as is.
+    ///
+    /// ## Parameters
+    /// - `target` - The boxed handler to push the buffered log entries to.
+    /// - `capacity` - The maximum number of log entries buffered.
+    /// - `push_level` - The level that triggers a push.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_memory_handler(
+    ///         Box::new(FileHandler::create("test_logs/builder_memory.log").unwrap()),
+    ///         500,
+    ///         Level::SEVERE,
+    ///     )
+    ///     .set_level(Level::FINEST)
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_memory_handler(
+        self,
+        target: Box<dyn HandlerTrait>,
+        capacity: usize,
+        push_level: Level,
+    ) -> Self {
+        self.add_handler_with(
+            Handler::Memory,
+            Some(Ok(Box::new(MemoryHandler::new(
+                target, capacity, push_level,
+            )))),
+            None,
+            None,
+            None,
+        )
+    }
+
+    ///
+    /// Adds a [`MemoryHandler`], leaving its target handler's formatter as is,
+    /// and with its own level.
+    ///
+    /// ## Parameters
+    /// - `target` - The boxed handler to push the buffered log entries to.
+    /// - `capacity` - The maximum number of log entries buffered.
+    /// - `push_level` - The level that triggers a push.
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_memory_handler_at(
+    ///         Box::new(FileHandler::create("test_logs/builder_memory.log").unwrap()),
+    ///         500,
+    ///         Level::SEVERE,
+    ///         Level::WARNING,
+    ///     )
+    ///     .set_level(Level::FINEST)
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_memory_handler_at(
+        self,
+        target: Box<dyn HandlerTrait>,
+        capacity: usize,
+        push_level: Level,
+        level: Level,
+    ) -> Self {
+        self.add_memory_handler(target, capacity, push_level)
+            .at_level(level)
+    }
+
+    ///
+    /// Adds a [`MemoryHandler`], with the required formatter set on its target handler.
+    ///
+    /// ## Parameters
+    /// - `target` - The boxed handler to push the buffered log entries to.
+    /// - `capacity` - The maximum number of log entries buffered.
+    /// - `push_level` - The level that triggers a push.
+    /// - `format_type` - The format type used to produce the required formatter.
+    /// - `custom_formatter` - The optional boxed custom formatter.
+    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_memory_handler_with(
+    ///         Box::new(ConsoleHandler::create("stderr").unwrap()),
+    ///         500,
+    ///         Level::WARNING,
+    ///         FormatType::Iso8601,
+    ///         None,
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_memory_handler_with(
+        self,
+        target: Box<dyn HandlerTrait>,
+        capacity: usize,
+        push_level: Level,
+        format_type: FormatType,
+        custom_formatter: Option<Box<dyn FormatTrait>>,
+    ) -> Self {
+        self.add_handler_with(
+            Handler::Memory,
+            Some(Ok(Box::new(MemoryHandler::new(
+                target, capacity, push_level,
+            )))),
+            None,
+            Some(format_type),
+            custom_formatter,
+        )
+    }
+
+    ///
+    /// Adds a [`MemoryHandler`], leaving its target handler's formatter as is,
+    /// and with the required filter.
+    ///
+    /// ## Parameters
+    /// - `target` - The boxed handler to push the buffered log entries to.
+    /// - `capacity` - The maximum number of log entries buffered.
+    /// - `push_level` - The level that triggers a push.
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_memory_handler_with_filter(
+    ///         Box::new(FileHandler::create("test_logs/builder_memory.log").unwrap()),
+    ///         500,
+    ///         Level::SEVERE,
+    ///         Box::new(|le: &LogEntry| le.fn_name() == "main"),
+    ///     )
+    ///     .set_level(Level::FINEST)
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_memory_handler_with_filter(
+        self,
+        target: Box<dyn HandlerTrait>,
+        capacity: usize,
+        push_level: Level,
+        filter: Box<dyn FilterTrait>,
+    ) -> Self {
+        self.add_memory_handler(target, capacity, push_level)
+            .with_filter(filter)
+    }
+
+    ///
+    /// Adds a [`ConsoleHandler`] with the default formatter,
+    /// with output according to: [`ConsoleType::Production`].
+    ///
+    /// `INFO` log entries go to `stdout` unformatted, the rest to `stderr`, formatted.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_pconsole_handler()
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_pconsole_handler(self) -> Self {
+        self.add_handler_with(Handler::PConsole, None, None, None, None)
+    }
+
+    ///
+    /// Adds a [`ConsoleHandler`] with the default formatter and its own level,
+    /// with output according to: [`ConsoleType::Production`].
+    ///
+    /// ## Parameters
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_pconsole_handler_at(Level::WARNING)
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_pconsole_handler_at(self, level: Level) -> Self {
+        self.add_pconsole_handler().at_level(level)
+    }
+
+    ///
+    /// Adds a [`ConsoleHandler`] with the required formatter,
+    /// with output according to: [`ConsoleType::Production`].
+    ///
+    /// ## Parameters
+    /// - `format_type` - The format type used to produce the required formatter.
+    /// - `custom_formatter` - The optional boxed custom formatter.
+    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    ///
+    /// ## Examples
+    /// First, using a provided formatter:
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_pconsole_handler_with(FormatType::Iso8601, None)
+    ///     .build();
+    /// ```
+    /// Now using a custom formatter:
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_pconsole_handler_with(
+    ///         FormatType::Custom,
+    ///         Some(Box::new(MockFormatter::new())),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_pconsole_handler_with(
+        self,
+        format_type: FormatType,
+        custom_formatter: Option<Box<dyn FormatTrait>>,
+    ) -> Self {
+        self.add_handler_with(
+            Handler::PConsole,
+            None,
+            None,
+            Some(format_type),
+            custom_formatter,
+        )
+    }
+
+    ///
+    /// Adds a [`ConsoleHandler`] with the default formatter and the required filter,
+    /// with output according to: [`ConsoleType::Production`].
+    ///
+    /// ## Parameters
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_pconsole_handler_with_filter(Box::new(|le: &LogEntry| le.fn_name() == "main"))
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_pconsole_handler_with_filter(self, filter: Box<dyn FilterTrait>) -> Self {
+        self.add_pconsole_handler().with_filter(filter)
+    }
+
+    ///
+    /// Adds a [`ConsoleHandler`] with the default formatter,
+    /// with output according to: [`ConsoleType::Production`], split as required.
+    ///
+    /// ## Parameters
+    /// - `split` - Which levels are printed to `stdout`, and whether they are formatted.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_pconsole_handler_with_split(ProductionSplit::new(Level::INFO, Level::WARNING))
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_pconsole_handler_with_split(self, split: ProductionSplit) -> Self {
+        self.add_pconsole_handler().with_split(split)
+    }
+
+    ///
+    /// Adds a [`RotatingFileHandler`] with the default formatter.
+    ///
+    /// ## Parameters
+    /// - `filename` - The name of the output log file. Must include any relevant
+    ///   path (relative or absolute).
+    /// - `rotation` - When, and how, the file is rotated.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_rotating_file_handler("test_logs/builder_rotating.log", Rotation::new(1024 * 1024))
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_rotating_file_handler(self, filename: &str, rotation: Rotation) -> Self {
+        self.add_handler_with(
+            Handler::RotatingFile,
+            Some(rotating_file_handler(filename, rotation)),
+            Some(filename),
+            None,
+            None,
+        )
+    }
+
+    ///
+    /// Adds a [`RotatingFileHandler`] with the default formatter and its own level.
+    ///
+    /// ## Parameters
+    /// - `filename` - The name of the output log file. Must include any relevant
+    ///   path (relative or absolute).
+    /// - `rotation` - When, and how, the file is rotated.
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_rotating_file_handler_at(
+    ///         "test_logs/builder_rotating.log", Rotation::new(1024 * 1024),
+    ///         Level::WARNING,
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_rotating_file_handler_at(
+        self,
+        filename: &str,
+        rotation: Rotation,
+        level: Level,
+    ) -> Self {
+        self.add_rotating_file_handler(filename, rotation)
+            .at_level(level)
+    }
+
+    ///
+    /// Adds a [`RotatingFileHandler`] with the required formatter.
+    ///
+    /// ## Parameters
+    /// - `filename` - The name of the output log file. Must include any relevant
+    ///   path (relative or absolute).
+    /// - `rotation` - When, and how, the file is rotated.
+    /// - `format_type` - The format type used to produce the required formatter.
+    /// - `custom_formatter` - The optional boxed custom formatter.
+    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_rotating_file_handler_with(
+    ///         "test_logs/builder_rotating.log",
+    ///         Rotation::new(1024 * 1024),
+    ///         FormatType::Simple,
+    ///         None,
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_rotating_file_handler_with(
+        self,
+        filename: &str,
+        rotation: Rotation,
+        format_type: FormatType,
+        custom_formatter: Option<Box<dyn FormatTrait>>,
+    ) -> Self {
+        self.add_handler_with(
+            Handler::RotatingFile,
+            Some(rotating_file_handler(filename, rotation)),
+            Some(filename),
+            Some(format_type),
+            custom_formatter,
+        )
+    }
+
+    ///
+    /// Adds a [`RotatingFileHandler`] with the default formatter and the required filter.
+    ///
+    /// ## Parameters
+    /// - `filename` - The name of the output log file. Must include any relevant
+    ///   path (relative or absolute).
+    /// - `rotation` - When, and how, the file is rotated.
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_rotating_file_handler_with_filter(
+    ///         "test_logs/builder_rotating.log", Rotation::new(1024 * 1024),
+    ///         Box::new(|le: &LogEntry| le.fn_name() == "main"),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_rotating_file_handler_with_filter(
+        self,
+        filename: &str,
+        rotation: Rotation,
+        filter: Box<dyn FilterTrait>,
+    ) -> Self {
+        self.add_rotating_file_handler(filename, rotation)
+            .with_filter(filter)
+    }
+
+    ///
+    /// Adds a [`StringHandler`] with the default formatter.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_string_handler()
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_string_handler(self) -> Self {
+        self.add_handler_with(Handler::String, None, None, None, None)
+    }
+
+    ///
+    /// Adds a [`StringHandler`] with the default formatter and its own level.
+    ///
+    /// ## Parameters
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_string_handler_at(Level::WARNING)
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_string_handler_at(self, level: Level) -> Self {
+        self.add_string_handler().at_level(level)
+    }
+
+    ///
+    /// Adds a [`StringHandler`] with the required formatter.
+    ///
+    /// ## Parameters
+    /// - `format_type` - The format type used to produce the required formatter.
+    /// - `custom_formatter` - The optional boxed custom formatter.
+    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    ///
+    /// ## Examples
+    /// First, using a provided formatter:
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_string_handler_with(FormatType::Iso8601, None)
+    ///     .build();
+    /// ```
+    /// Now using a custom formatter:
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_string_handler_with(
+    ///         FormatType::Custom,
+    ///         Some(Box::new(MockFormatter::new())),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_string_handler_with(
+        self,
+        format_type: FormatType,
+        custom_formatter: Option<Box<dyn FormatTrait>>,
+    ) -> Self {
+        self.add_handler_with(
+            Handler::String,
+            None,
+            None,
+            Some(format_type),
+            custom_formatter,
+        )
+    }
+
+    ///
+    /// Adds a [`StringHandler`] with the default formatter and the required filter.
+    ///
+    /// ## Parameters
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_string_handler_with_filter(Box::new(|le: &LogEntry| le.fn_name() == "main"))
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_string_handler_with_filter(self, filter: Box<dyn FilterTrait>) -> Self {
+        self.add_string_handler().with_filter(filter)
+    }
+
+    ///
+    /// Adds a [`SyslogHandler`] with the default formatter.
+    ///
+    /// ## Parameters
+    /// - `destination` - Where to send the messages: `""` for the local
+    ///   syslog socket ([`SYSLOG_SOCKET`]), the path of a unix datagram socket, or
+    ///   `host[:port]` for UDP to a remote syslog server.
+    /// - `facility` - The syslog facility to log under.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_syslog_handler("127.0.0.1:514", Facility::Local0)
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_syslog_handler(self, destination: &str, facility: Facility) -> Self {
+        self.add_handler_with(
+            Handler::Syslog,
+            Some(syslog_handler(destination, facility)),
+            None,
+            None,
+            None,
+        )
+    }
+
+    ///
+    /// Adds a [`SyslogHandler`] with the default formatter and its own level.
+    ///
+    /// ## Parameters
+    /// - `destination` - Where to send the messages: `""` for the local
+    ///   syslog socket ([`SYSLOG_SOCKET`]), the path of a unix datagram socket, or
+    ///   `host[:port]` for UDP to a remote syslog server.
+    /// - `facility` - The syslog facility to log under.
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_syslog_handler_at(
+    ///         "127.0.0.1:514", Facility::Local0,
+    ///         Level::WARNING,
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_syslog_handler_at(
+        self,
+        destination: &str,
+        facility: Facility,
+        level: Level,
+    ) -> Self {
+        self.add_syslog_handler(destination, facility)
+            .at_level(level)
+    }
+
+    ///
+    /// Adds a [`SyslogHandler`] with the required formatter.
+    ///
+    /// ## Parameters
+    /// - `destination` - Where to send the messages: `""` for the local
+    ///   syslog socket ([`SYSLOG_SOCKET`]), the path of a unix datagram socket, or
+    ///   `host[:port]` for UDP to a remote syslog server.
+    /// - `facility` - The syslog facility to log under.
+    /// - `format_type` - The format type used to produce the required formatter.
+    /// - `custom_formatter` - The optional boxed custom formatter.
+    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_syslog_handler_with("127.0.0.1:514", Facility::Daemon, FormatType::Ndjson, None)
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_syslog_handler_with(
+        self,
+        destination: &str,
+        facility: Facility,
+        format_type: FormatType,
+        custom_formatter: Option<Box<dyn FormatTrait>>,
+    ) -> Self {
+        self.add_handler_with(
+            Handler::Syslog,
+            Some(syslog_handler(destination, facility)),
+            None,
+            Some(format_type),
+            custom_formatter,
+        )
+    }
+
+    ///
+    /// Adds a [`SyslogHandler`] with the default formatter and the required filter.
+    ///
+    /// ## Parameters
+    /// - `destination` - Where to send the messages: `""` for the local
+    ///   syslog socket ([`SYSLOG_SOCKET`]), the path of a unix datagram socket, or
+    ///   `host[:port]` for UDP to a remote syslog server.
+    /// - `facility` - The syslog facility to log under.
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_syslog_handler_with_filter(
+    ///         "127.0.0.1:514", Facility::Local0,
+    ///         Box::new(|le: &LogEntry| le.fn_name() == "main"),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_syslog_handler_with_filter(
+        self,
+        destination: &str,
+        facility: Facility,
+        filter: Box<dyn FilterTrait>,
+    ) -> Self {
+        self.add_syslog_handler(destination, facility)
+            .with_filter(filter)
+    }
+
+    ///
+    /// Adds a [`TcpHandler`] with the default formatter.
+    ///
+    /// ## Parameters
+    /// - `address` - The `host:port` of the remote host to stream log entries to.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_tcp_handler("127.0.0.1:5170")
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_tcp_handler(self, address: &str) -> Self {
+        self.add_handler_with(Handler::Tcp, None, Some(address), None, None)
+    }
+
+    ///
+    /// Adds a [`TcpHandler`] with the default formatter and its own level.
+    ///
+    /// ## Parameters
+    /// - `address` - The `host:port` of the remote host to stream log entries to.
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_tcp_handler_at(
+    ///         "127.0.0.1:5170",
+    ///         Level::WARNING,
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_tcp_handler_at(self, address: &str, level: Level) -> Self {
+        self.add_tcp_handler(address).at_level(level)
+    }
+
+    ///
+    /// Adds a [`TcpHandler`] with the required formatter.
+    ///
+    /// ## Parameters
+    /// - `address` - The `host:port` of the remote host to stream log entries to.
+    /// - `format_type` - The format type used to produce the required formatter.
+    /// - `custom_formatter` - The optional boxed custom formatter.
+    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_tcp_handler_with("127.0.0.1:5170", FormatType::Ndjson, None)
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_tcp_handler_with(
+        self,
+        address: &str,
+        format_type: FormatType,
+        custom_formatter: Option<Box<dyn FormatTrait>>,
+    ) -> Self {
+        self.add_handler_with(
+            Handler::Tcp,
+            None,
+            Some(address),
+            Some(format_type),
+            custom_formatter,
+        )
+    }
+
+    ///
+    /// Adds a [`TcpHandler`] with the default formatter and the required filter.
+    ///
+    /// ## Parameters
+    /// - `address` - The `host:port` of the remote host to stream log entries to.
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_tcp_handler_with_filter(
+    ///         "127.0.0.1:5170",
+    ///         Box::new(|le: &LogEntry| le.fn_name() == "main"),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_tcp_handler_with_filter(self, address: &str, filter: Box<dyn FilterTrait>) -> Self {
+        self.add_tcp_handler(address).with_filter(filter)
+    }
+
+    ///
+    /// Adds a [`ThrottleHandler`], leaving its target handler's formatter as is.
+    ///
+    /// ## Parameters
+    /// - `target` - The boxed handler to pass the log entries on to.
+    /// - `limit` - The maximum number of log entries passed on, per call site, per window.
+    /// - `window` - The span of time.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    /// use std::time::Duration;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_throttle_handler(
+    ///         Box::new(FileHandler::create("test_logs/builder_throttle.log").unwrap()),
+    ///         10,
+    ///         Duration::from_secs(1),
+    ///     )
+    ///     .set_level(Level::FINE)
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_throttle_handler(
+        self,
+        target: Box<dyn HandlerTrait>,
+        limit: usize,
+        window: Duration,
+    ) -> Self {
+        self.add_handler_with(
+            Handler::Throttle,
+            Some(Ok(Box::new(ThrottleHandler::new(target, limit, window)))),
+            None,
+            None,
+            None,
+        )
+    }
+
+    ///
+    /// Adds a [`ThrottleHandler`], leaving its target handler's formatter as is,
+    /// and with its own level.
+    ///
+    /// ## Parameters
+    /// - `target` - The boxed handler to pass the log entries on to.
+    /// - `limit` - The maximum number of log entries passed on, per call site, per window.
+    /// - `window` - The span of time.
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    /// use std::time::Duration;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_throttle_handler_at(
+    ///         Box::new(FileHandler::create("test_logs/builder_throttle.log").unwrap()),
+    ///         10,
+    ///         Duration::from_secs(1),
+    ///         Level::FINE,
+    ///     )
+    ///     .set_level(Level::FINEST)
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_throttle_handler_at(
+        self,
+        target: Box<dyn HandlerTrait>,
+        limit: usize,
+        window: Duration,
+        level: Level,
+    ) -> Self {
+        self.add_throttle_handler(target, limit, window)
+            .at_level(level)
+    }
+
+    ///
+    /// Adds a [`ThrottleHandler`], with the required formatter set on its target handler.
+    ///
+    /// ## Parameters
+    /// - `target` - The boxed handler to pass the log entries on to.
+    /// - `limit` - The maximum number of log entries passed on, per call site, per window.
+    /// - `window` - The span of time.
+    /// - `format_type` - The format type used to produce the required formatter.
+    /// - `custom_formatter` - The optional boxed custom formatter.
+    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    /// use std::time::Duration;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_throttle_handler_with(
+    ///         Box::new(ConsoleHandler::create("stderr").unwrap()),
+    ///         10,
+    ///         Duration::from_secs(1),
+    ///         FormatType::Iso8601,
+    ///         None,
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_throttle_handler_with(
+        self,
+        target: Box<dyn HandlerTrait>,
+        limit: usize,
+        window: Duration,
+        format_type: FormatType,
+        custom_formatter: Option<Box<dyn FormatTrait>>,
+    ) -> Self {
+        self.add_handler_with(
+            Handler::Throttle,
+            Some(Ok(Box::new(ThrottleHandler::new(target, limit, window)))),
+            None,
+            Some(format_type),
+            custom_formatter,
+        )
+    }
+
+    ///
+    /// Adds a [`ThrottleHandler`], leaving its target handler's formatter as is,
+    /// and with the required filter.
+    ///
+    /// ## Parameters
+    /// - `target` - The boxed handler to pass the log entries on to.
+    /// - `limit` - The maximum number of log entries passed on, per call site, per window.
+    /// - `window` - The span of time.
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    /// use std::time::Duration;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_throttle_handler_with_filter(
+    ///         Box::new(FileHandler::create("test_logs/builder_throttle.log").unwrap()),
+    ///         10,
+    ///         Duration::from_secs(1),
+    ///         Box::new(|le: &LogEntry| le.fn_name() == "main"),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_throttle_handler_with_filter(
+        self,
+        target: Box<dyn HandlerTrait>,
+        limit: usize,
+        window: Duration,
+        filter: Box<dyn FilterTrait>,
+    ) -> Self {
+        self.add_throttle_handler(target, limit, window)
+            .with_filter(filter)
+    }
+
+    ///
+    /// Adds a [`UdpHandler`] with the default formatter.
+    ///
+    /// ## Parameters
+    /// - `address` - The `host:port` of the remote host to send log entries to.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_udp_handler("127.0.0.1:5170")
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_udp_handler(self, address: &str) -> Self {
+        self.add_handler_with(Handler::Udp, None, Some(address), None, None)
+    }
+
+    ///
+    /// Adds a [`UdpHandler`] with the default formatter and its own level.
+    ///
+    /// ## Parameters
+    /// - `address` - The `host:port` of the remote host to send log entries to.
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_udp_handler_at(
+    ///         "127.0.0.1:5170",
+    ///         Level::WARNING,
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_udp_handler_at(self, address: &str, level: Level) -> Self {
+        self.add_udp_handler(address).at_level(level)
+    }
+
+    ///
+    /// Adds a [`UdpHandler`] with the required formatter.
+    ///
+    /// ## Parameters
+    /// - `address` - The `host:port` of the remote host to send log entries to.
+    /// - `format_type` - The format type used to produce the required formatter.
+    /// - `custom_formatter` - The optional boxed custom formatter.
+    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_udp_handler_with("127.0.0.1:5170", FormatType::Ndjson, None)
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_udp_handler_with(
+        self,
+        address: &str,
+        format_type: FormatType,
+        custom_formatter: Option<Box<dyn FormatTrait>>,
+    ) -> Self {
+        self.add_handler_with(
+            Handler::Udp,
+            None,
+            Some(address),
+            Some(format_type),
+            custom_formatter,
+        )
+    }
+
+    ///
+    /// Adds a [`UdpHandler`] with the default formatter and the required filter.
+    ///
+    /// ## Parameters
+    /// - `address` - The `host:port` of the remote host to send log entries to.
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_udp_handler_with_filter(
+    ///         "127.0.0.1:5170",
+    ///         Box::new(|le: &LogEntry| le.fn_name() == "main"),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    pub fn add_udp_handler_with_filter(self, address: &str, filter: Box<dyn FilterTrait>) -> Self {
+        self.add_udp_handler(address).with_filter(filter)
+    }
+
+    ///
+    /// Adds a [`WebhookHandler`] with the default formatter.
+    ///
+    /// Requires the `http` feature.
+    ///
+    /// ## Parameters
+    /// - `url` - The URL of the webhook to POST the messages to.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_webhook_handler("https://hooks.slack.com/services/T0/B0/X")
+    ///     .build();
+    /// ```
+    ///
+    #[cfg(feature = "http")]
+    pub fn add_webhook_handler(self, url: &str) -> Self {
+        self.add_handler_with(Handler::Webhook, None, Some(url), None, None)
+    }
+
+    ///
+    /// Adds a [`WebhookHandler`] with the default formatter and its own level.
+    ///
+    /// Requires the `http` feature.
+    ///
+    /// ## Parameters
+    /// - `url` - The URL of the webhook to POST the messages to.
+    /// - `level` - The handler's own level. Only those log entries at, or above, it are published.
+    ///   Those below the handler's trigger level are still not sent.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_webhook_handler_at(
+    ///         "https://hooks.slack.com/services/T0/B0/X",
+    ///         Level::SEVERE,
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    #[cfg(feature = "http")]
+    pub fn add_webhook_handler_at(self, url: &str, level: Level) -> Self {
+        self.add_webhook_handler(url).at_level(level)
+    }
+
+    ///
+    /// Adds a [`WebhookHandler`] with the required formatter.
+    ///
+    /// Requires the `http` feature.
+    ///
+    /// ## Parameters
+    /// - `url` - The URL of the webhook to POST the messages to.
+    /// - `format_type` - The format type used to produce the required formatter.
+    /// - `custom_formatter` - The optional boxed custom formatter.
+    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_webhook_handler_with(
+    ///         "https://hooks.slack.com/services/T0/B0/X",
+    ///         FormatType::Iso8601,
+    ///         None,
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    #[cfg(feature = "http")]
+    pub fn add_webhook_handler_with(
+        self,
+        url: &str,
+        format_type: FormatType,
+        custom_formatter: Option<Box<dyn FormatTrait>>,
+    ) -> Self {
+        self.add_handler_with(
+            Handler::Webhook,
+            None,
+            Some(url),
+            Some(format_type),
+            custom_formatter,
+        )
+    }
+
+    ///
+    /// Adds a [`WebhookHandler`] with the default formatter and the required filter.
+    ///
+    /// Requires the `http` feature.
+    ///
+    /// ## Parameters
+    /// - `url` - The URL of the webhook to POST the messages to.
+    /// - `filter` - The boxed filter. Only those log entries it accepts are published.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_webhook_handler_with_filter(
+    ///         "https://hooks.slack.com/services/T0/B0/X",
+    ///         Box::new(|le: &LogEntry| le.fn_name() == "main"),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    #[cfg(feature = "http")]
+    pub fn add_webhook_handler_with_filter(self, url: &str, filter: Box<dyn FilterTrait>) -> Self {
+        self.add_webhook_handler(url).with_filter(filter)
+    }
+
+    ///
+    /// Complete the build process and produce the final [`Logger`] instance.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the handlers could not be added. See [`try_build()`][LoggerBuilder::try_build]
+    /// for the reasons, and for a way to handle them.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_console_handler()
+    ///     .build();
+    /// ```
+    ///
+    pub fn build(self) -> Logger {
+        self.try_build().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    ///
+    /// Complete the build process, and register the final [`Logger`] as a [`SharedLogger`].
+    ///
+    /// It is registered under its module path, replacing any existing logger of the same name,
+    /// and can then be obtained from anywhere with [`Logger::get_logger()`].
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::builder("my_app")
+    ///     .add_console_handler()
+    ///     .build_shared();
+    ///
+    /// log.lock().unwrap().info("Started.");
+    /// ```
+    ///
+    pub fn build_shared(self) -> SharedLogger {
+        let name = self.mod_path.clone();
+        LogManager::share(&name, self.build())
+    }
+
+    ///
+    /// Create a builder configured from the environment, allowing logging to be tuned
+    /// without rebuilding, such as for container deployments.
+    ///
+    /// The environment variables read are:
+    /// - [`FLOGGING_LEVEL`] - The levels, as a directives spec. See
+    ///   [`parse_directives()`][LoggerBuilder::parse_directives].
+    /// - [`FLOGGING_HANDLERS`] - The handlers to add. Default: `console`.
+    /// - [`FLOGGING_FILE`] - The log file's path. Required by the `file` handler.
+    /// - [`FLOGGING_FORMAT`] - The format type for every handler. Default: each handler's own.
+    ///
+    /// Any invalid value is reported by [`try_build()`][LoggerBuilder::try_build] as a
+    /// [`BuildError::Env`].
+    ///
+    /// Further handlers, and settings, can still be added before building.
+    ///
+    /// ## Parameters
+    /// - `mod_path` - The module path. Suggest using [`module_path!()`][module_path].
+    ///
+    /// ## Examples
+    /// ```text
+    /// FLOGGING_LEVEL=fine FLOGGING_HANDLERS=console,file FLOGGING_FILE=logs/app.log ./my_app
+    /// ```
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = LoggerBuilder::from_env(module_path!())
+    ///     .try_build()
+    ///     .expect("invalid logging environment");
+    /// ```
+    ///
+    pub fn from_env(mod_path: &str) -> Self {
+        let mut builder = LoggerBuilder::create(mod_path.to_string());
+
+        if let Ok(spec) = env::var(FLOGGING_LEVEL) {
+            match LevelMap::parse_directives(&spec) {
+                Ok((level, map)) => builder.set_directives(level, map),
+                Err(e) => {
+                    return builder.with_error(BuildError::Env(FLOGGING_LEVEL, e.to_string()));
+                }
+            }
+        }
+
+        let format_type = match env::var(FLOGGING_FORMAT) {
+            Ok(name) => match format_type(&name) {
+                Some(f) => Some(f),
+                None => {
+                    let msg = format!("Unknown format type: {name}");
+                    return builder.with_error(BuildError::Env(FLOGGING_FORMAT, msg));
+                }
+            },
+            Err(_) => None,
+        };
+
+        let handlers = env::var(FLOGGING_HANDLERS).unwrap_or("console".to_string());
+
+        for name in handlers.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            let (handler, filename) = match name.to_ascii_lowercase().as_str() {
+                "console" => (Handler::Console, None),
+                "econsole" => (Handler::EConsole, None),
+                "pconsole" => (Handler::PConsole, None),
+                "file" => match env::var(FLOGGING_FILE) {
+                    Ok(filename) => (Handler::File, Some(filename)),
+                    Err(_) => {
+                        let msg = "Required by the 'file' handler".to_string();
+                        return builder.with_error(BuildError::Env(FLOGGING_FILE, msg));
+                    }
+                },
+                "string" => (Handler::String, None),
+                _ => {
+                    let msg = format!("Unknown handler: {name}");
+                    return builder.with_error(BuildError::Env(FLOGGING_HANDLERS, msg));
+                }
+            };
+
+            builder = builder.add_handler_with(
+                handler,
+                None,
+                filename.as_deref(),
+                format_type.clone(),
+                None,
+            );
+        }
+
+        builder
+    }
+
+    ///
+    /// Configure the levels from a directives spec, in the style of `RUST_LOG`
+    /// for `env_logger`.
+    ///
+    /// The global level, if any, is set as with [`set_level()`][LoggerBuilder::set_level],
+    /// and each per-module level as with [`set_module_level()`][LoggerBuilder::set_module_level].
+    /// See [`LevelMap::parse_directives()`] for the syntax.
+    ///
+    /// ## Parameters
+    /// - `spec` - The directives, such as: `info,my_app::net=finest,other_crate=off`.
+    ///
+    /// Returns itself for chaining purposes, or an error if `spec` is invalid.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let spec = std::env::var("MY_APP_LOG").unwrap_or("warning,my_app::db=fine".to_string());
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_console_handler()
+    ///     .parse_directives(&spec)
+    ///     .expect("invalid MY_APP_LOG")
+    ///     .build();
+    /// ```
+    ///
+    pub fn parse_directives(mut self, spec: &str) -> Result<Self, LevelError> {
+        let (level, map) = LevelMap::parse_directives(spec)?;
+        self.set_directives(level, map);
+
+        Ok(self)
+    }
+
+    ///
+    /// Remove an existing log file.
+    ///
+    /// The purpose of this, is to allow resetting of the log file, each time
+    /// a test run is done. Alternatively, for the [`FileHandler`]s, use
+    /// [`set_file_options()`][LoggerBuilder::set_file_options] with [`OpenMode::Truncate`].
+    ///
+    /// ## Note
+    ///
+    /// This **must** be called _before_ adding the corresponding file handler
+    /// that is going to open this file.
+    ///
+    /// ## Parameters
+    ///
+    /// - `filename` - The name of the output log file. Must include any relevant
+    ///   path (relative or absolute).
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .remove_file("test_logs/builder.log")
+    ///     .add_file_handler("test_logs/builder.log")
+    ///     .build();
+    /// ```
+    ///
+    pub fn remove_file(self, filename: &str) -> Self {
+        let _ = fs::remove_file(filename).is_err();
+        self
+    }
+
+    ///
+    /// Publish log entries asynchronously, on a background thread.
+    ///
+    /// Each log entry accepted by the [`Logger`] is pushed onto a bounded queue.
+    /// The formatting and publishing of the entries, by each of the handlers,
+    /// is then done by the background thread. So heavy logging, for example
+    /// FINEST tracing to a file, no longer blocks the calling thread on I/O.
+    ///
+    /// The handlers are owned by the background thread until
+    /// [`Logger::shutdown()`] is called, or the `Logger` is dropped. Until then,
+    /// [`get_handler()`][Logger::get_handler] will return `None`.
+    ///
+    /// ## Parameters
+    /// - `capacity` - The maximum number of log entries queued. Logging blocks
+    ///   when the queue is full.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_file_handler("test_logs/builder.log")
+    ///     .set_level(Level::FINEST)
+    ///     .set_async(10_000)
+    ///     .build();
+    ///
+    /// log.finest("Doesn't wait for the file.");
+    /// log.shutdown();
+    /// ```
+    ///
+    pub fn set_async(mut self, capacity: usize) -> Self {
+        self.async_capacity = Some(capacity);
+        self
+    }
+
+    ///
+    /// Set the clock that provides the timestamp of each log entry.
+    ///
+    /// The default is the [`SystemClock`]. Use a [`FixedClock`] or [`StepClock`] to get
+    /// deterministic timestamps in tests.
+    ///
+    /// ## Parameters
+    /// - `clock` - The shared clock. See [`ClockTrait`].
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    /// use chrono::{Local, TimeDelta};
+    /// use std::sync::Arc;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_string_handler()
+    ///     .set_clock(Arc::new(StepClock::new(Local::now(), TimeDelta::seconds(1))))
+    ///     .build();
+    /// ```
+    ///
+    pub fn set_clock(mut self, clock: Arc<dyn ClockTrait>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    ///
+    /// Set whether or not the console handlers color their output by level.
+    ///
+    /// The default is [`ColorChoice::Auto`], coloring only when output is to a terminal.
+    ///
+    /// Applies to all of the [`ConsoleHandler`]s, whether added before or after this call.
+    /// Other handlers are unaffected. To color just the level within a formatter, use
+    /// the `{level_colored}` variable instead. See: [`FormatTrait::ft_fmt()`].
+    ///
+    /// ## Parameters
+    /// - `color` - The required color choice.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_console_handler()
+    ///     .set_color(ColorChoice::Always)
+    ///     .build();
+    /// ```
+    ///
+    pub fn set_color(mut self, color: ColorChoice) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    ///
+    /// Set whether consecutive identical log entries are collapsed into one line,
+    /// followed by: "Last message repeated N times.". See [`Logger::set_dedup()`].
+    ///
+    /// ## Parameters
+    /// - `state` - `true` to collapse them.
+    ///
+    /// Returns itself for chaining purposes.
     ///
     /// ## Examples
     /// ```
     /// extern crate flogging;
     /// use flogging::*;
     ///
-    /// let mut log = Logger::builder(module_path!())
-    ///     .add_string_handler()
+    /// let log = Logger::builder(module_path!())
+    ///     .add_file_handler("test_logs/builder_dedup.log")
+    ///     .set_dedup(true)
     ///     .build();
     /// ```
     ///
-    pub fn add_string_handler(self) -> Self {
-        self.add_handler_with(Handler::String, None, None, None, None)
+    pub fn set_dedup(mut self, state: bool) -> Self {
+        self.dedup = state;
+        self
     }
 
     ///
-    /// Adds a [`StringHandler`] with the required formatter.
+    /// Set the options used to open the files of the [`FileHandler`]s.
+    ///
+    /// ## Note
+    ///
+    /// This **must** be called _before_ adding the file handlers that are to use them.
     ///
     /// ## Parameters
-    /// - `format_type` - The format type used to produce the required formatter.
-    /// - `custom_formatter` - The optional boxed custom formatter.
-    ///   Used by the [`FormatType::Custom`] to produce a [`Formatter::Custom`].
+    /// - `options` - The options.
+    ///
+    /// Returns itself for chaining purposes.
     ///
     /// ## Examples
-    /// First, using a provided formatter:
     /// ```
     /// extern crate flogging;
     /// use flogging::*;
     ///
-    /// let mut log = Logger::builder(module_path!())
-    ///     .add_string_handler_with(FormatType::Iso8601, None)
+    /// let options = FileOptions::new();
+    /// #[cfg(unix)]
+    /// let options = options.mode(0o600);
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .set_file_options(options)
+    ///     .add_file_handler("test_logs/builder_file_options.log")
     ///     .build();
     /// ```
-    /// Now using a custom formatter:
+    ///
+    pub fn set_file_options(mut self, options: FileOptions) -> Self {
+        self.file_options = options;
+        self
+    }
+
+    ///
+    /// Set the current function/method name.
+    ///
+    /// ## Parameters
+    /// - `fn_name` - The name of the function/method in which you are
+    ///   logging.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn set_fn_name(mut self, fn_name: &str) -> Self {
+        self.fn_name = fn_name.to_string();
+        self
+    }
+
+    ///
+    /// Set the logging level for the [`Logger`] instance being configured.
+    ///
+    /// This overrides the default level, taken from the [`FLOGGING_LEVEL`]
+    /// environment variable if set, otherwise `INFO`.
+    ///
+    /// ## Parameters
+    /// - `level` - The new level to set.
+    ///
+    /// ## Examples
     /// ```
     /// extern crate flogging;
     /// use flogging::*;
     ///
     /// let mut log = Logger::builder(module_path!())
-    ///     .add_string_handler_with(
-    ///         FormatType::Custom,
-    ///         Some(Box::new(MockFormatter::new())),
-    ///     )
+    ///     .add_console_handler()
+    ///     .set_level(Level::ALL)
     ///     .build();
     /// ```
     ///
-    pub fn add_string_handler_with(
-        self,
-        format_type: FormatType,
-        custom_formatter: Option<Box<dyn FormatTrait>>,
-    ) -> Self {
-        self.add_handler_with(
-            Handler::String,
-            None,
-            None,
-            Some(format_type),
-            custom_formatter,
-        )
+    pub fn set_level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
     }
 
     ///
-    /// Complete the build process and produce the final [`Logger`] instance.
+    /// Set the logging level for log entries from `mod_path`, and its sub-modules,
+    /// overriding the level set with [`set_level()`][LoggerBuilder::set_level].
+    ///
+    /// See [`LevelMap`].
+    ///
+    /// ## Parameters
+    /// - `mod_path` - The module path.
+    /// - `level` - The new level to set.
     ///
     /// ## Examples
     /// ```
@@ -511,92 +2745,290 @@ impl LoggerBuilder {
     ///
     /// let mut log = Logger::builder(module_path!())
     ///     .add_console_handler()
+    ///     .set_level(Level::WARNING)
+    ///     .set_module_level("my_app::db", Level::FINEST)
+    ///     .set_module_level("other_crate", Level::OFF)
     ///     .build();
     /// ```
     ///
-    pub fn build(self) -> Logger {
-        Logger {
-            mod_path: self.mod_path.clone(),
-            fn_name: self.fn_name.clone(),
-            level: self.level,
-            handlers: self.handlers,
-        }
+    pub fn set_module_level(mut self, mod_path: &str, level: Level) -> Self {
+        self.level_map.set(mod_path, level);
+        self
     }
 
     ///
-    /// Remove an existing log file.
+    /// Set the printer used by all of the console handlers, in place of `println!()`
+    /// and `eprintln!()`, whether added before or after this call.
     ///
-    /// The purpose of this, is to allow resetting of the log file, each time
-    /// a test run is done.
+    /// Use it to keep log entries from corrupting an interactive progress bar, by printing
+    /// them through it. See [`PrinterTrait`].
     ///
-    /// ## Note
+    /// ## Parameters
+    /// - `printer` - The shared printer.
     ///
-    /// This **must** be called _before_ adding the corresponding file handler
-    /// that is going to open this file.
+    /// Returns itself for chaining purposes.
+    ///
+    /// ## Examples
+    /// ```text
+    /// let bar = ProgressBar::new(100);
+    /// let printer = bar.clone();
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_console_handler()
+    ///     .set_printer(Arc::new(move |line: &str| printer.println(line)))
+    ///     .build();
+    /// ```
+    ///
+    pub fn set_printer(mut self, printer: Arc<dyn PrinterTrait>) -> Self {
+        self.printer = Some(printer);
+        self
+    }
+
+    ///
+    /// Set the [`Redactor`], which masks personal data and secrets in each log entry,
+    /// before any handler sees it.
     ///
     /// ## Parameters
+    /// - `redactor` - The redactor.
     ///
-    /// - `filename` - The name of the output log file. Must include any relevant
-    ///   path (relative or absolute).
+    /// Returns itself for chaining purposes.
     ///
     /// ## Examples
     /// ```
     /// extern crate flogging;
     /// use flogging::*;
     ///
-    /// let mut log = Logger::builder(module_path!())
-    ///     .remove_file("test_logs/builder.log")
-    ///     .add_file_handler("test_logs/builder.log")
+    /// let log = Logger::builder(module_path!())
+    ///     .add_file_handler("test_logs/builder_redactor.log")
+    ///     .set_redactor(Redactor::new().credit_cards().emails().secret_keys())
     ///     .build();
     /// ```
     ///
-    pub fn remove_file(self, filename: &str) -> Self {
-        let _ = fs::remove_file(filename).is_err();
+    pub fn set_redactor(mut self, redactor: Redactor) -> Self {
+        self.redactor = Some(redactor);
         self
     }
 
     ///
-    /// Set the current function/method name.
+    /// Set the sampling of the log entries below its level. Only every `n`th of
+    /// them is logged, and those that are have the field: `sampled=1/n`.
+    ///
+    /// Use it to keep `FINEST` tracing affordable in production.
     ///
     /// ## Parameters
-    /// - `fn_name` - The name of the function/method in which you are
-    ///   logging.
+    /// - `sampling` - The sampling.
     ///
     /// Returns itself for chaining purposes.
     ///
-    pub fn set_fn_name(mut self, fn_name: &str) -> Self {
-        self.fn_name = fn_name.to_string();
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::builder(module_path!())
+    ///     .add_file_handler("test_logs/builder_sampling.log")
+    ///     .set_level(Level::FINEST)
+    ///     .set_sampling(SampleFilter::new(1000))
+    ///     .build();
+    /// ```
+    ///
+    pub fn set_sampling(mut self, sampling: SampleFilter) -> Self {
+        self.sampling = Some(sampling);
         self
     }
 
     ///
-    /// Set the logging level for the [`Logger`] instance being configured.
+    /// Complete the build process and produce the final [`Logger`] instance,
+    /// or the first problem found with the handlers added.
     ///
-    /// ## Parameters
-    /// - `level` - The new level to set.
+    /// Each handler is validated, and created, as it is added. Problems found are:
+    /// - an empty filename, path, address, or URL,
+    /// - a custom handler label that has already been used,
+    /// - [`FormatType::Custom`] without a custom formatter, or a custom formatter
+    ///   with any other format type,
+    /// - a handler that could not be created, such as a file that could not be opened.
     ///
     /// ## Examples
     /// ```
     /// extern crate flogging;
     /// use flogging::*;
     ///
-    /// let mut log = Logger::builder(module_path!())
+    /// let result = Logger::builder(module_path!())
     ///     .add_console_handler()
-    ///     .set_level(Level::ALL)
-    ///     .build();
+    ///     .add_file_handler("")
+    ///     .try_build();
+    ///
+    /// assert!(matches!(result, Err(BuildError::EmptyName(Handler::File))));
     /// ```
     ///
-    pub fn set_level(mut self, level: Level) -> Self {
-        self.level = level;
+    pub fn try_build(mut self) -> Result<Logger, BuildError> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+
+        // The start of logging, for `LogEntry::elapsed()`.
+        LazyLock::force(&super::log_entry::START);
+
+        for e in self.handlers.get_mut().iter_mut() {
+            if let Some(console) = e.handler.as_any_mut().downcast_mut::<ConsoleHandler>() {
+                if let Some(color) = self.color {
+                    console.set_color(color);
+                }
+
+                if self.printer.is_some() {
+                    console.set_printer(self.printer.clone());
+                }
+            }
+        }
+
+        let worker = self
+            .async_capacity
+            .map(|capacity| AsyncWorker::spawn(capacity, mem::take(self.handlers.get_mut())));
+
+        Ok(Logger {
+            mod_path: self.mod_path.clone(),
+            fn_name: self.fn_name.clone(),
+            level: RwLock::new(self.level),
+            fn_level: None,
+            level_map: RwLock::new(self.level_map),
+            handlers: Mutex::new(self.handlers.into_inner()),
+            worker,
+            mailbox: Mailbox::register(&self.mod_path),
+            fields: Mutex::default(),
+            clock: self.clock,
+            counts: Mutex::default(),
+            alerts: Mutex::new(self.alerts),
+            sampling: self.sampling,
+            enrichers: self.enrichers,
+            redactor: self.redactor,
+            dedup: self.dedup,
+            repeated: Mutex::new(None),
+            nested: Mutex::default(),
+            stand_in_for: None,
+        })
+    }
+
+    ///
+    /// Set the global level, if any, and each per-module level.
+    ///
+    fn set_directives(&mut self, level: Option<Level>, map: LevelMap) {
+        if let Some(level) = level {
+            self.level = level;
+        }
+
+        for (mod_path, level) in map.iter() {
+            self.level_map.set(mod_path, level);
+        }
+    }
+
+    ///
+    /// Set `level` as the most recently added handler's own level.
+    ///
+    fn at_level(mut self, level: Level) -> Self {
+        if self.error.is_some() {
+            return self;
+        }
+
+        if let Some(e) = self.handlers.get_mut().last_mut() {
+            e.level = Some(level);
+        }
+
+        self
+    }
+
+    ///
+    /// Record `error`, unless an earlier one has already been recorded.
+    ///
+    fn with_error(mut self, error: BuildError) -> Self {
+        if self.error.is_none() {
+            self.error = Some(error);
+        }
+
         self
     }
+
+    ///
+    /// Set `filter` on the most recently added handler.
+    ///
+    fn with_filter(mut self, filter: Box<dyn FilterTrait>) -> Self {
+        if self.error.is_some() {
+            return self;
+        }
+
+        if let Some(e) = self.handlers.get_mut().last_mut() {
+            e.handler.set_filter(Some(filter));
+        }
+
+        self
+    }
+
+    ///
+    /// Set `split` on the most recently added handler, a production console.
+    ///
+    fn with_split(mut self, split: ProductionSplit) -> Self {
+        if self.error.is_some() {
+            return self;
+        }
+
+        if let Some(console) = self
+            .handlers
+            .get_mut()
+            .last_mut()
+            .and_then(|e| e.handler.as_any_mut().downcast_mut::<ConsoleHandler>())
+        {
+            console.set_production_split(split);
+        }
+
+        self
+    }
+}
+
+fn format_type(name: &str) -> Option<FormatType> {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "iso8601" => Some(FormatType::Iso8601),
+        "json" => Some(FormatType::Json),
+        "ndjson" => Some(FormatType::Ndjson),
+        "simple" => Some(FormatType::Simple),
+        "unixtimestamp" => Some(FormatType::UnixTimestamp),
+        _ => None,
+    }
+}
+
+fn rotating_file_handler(
+    filename: &str,
+    rotation: Rotation,
+) -> Result<Box<dyn HandlerTrait>, Error> {
+    let mut h = RotatingFileHandler::create(filename)?;
+    h.set_rotation(rotation);
+    Ok(Box::new(h))
+}
+
+#[cfg(feature = "email")]
+fn email_handler(relay: &str, from: &str, to: &[&str]) -> Result<Box<dyn HandlerTrait>, Error> {
+    let mut h = EmailHandler::create(relay)?;
+    h.set_from(from)?;
+
+    for to in to {
+        h.add_recipient(to)?;
+    }
+
+    Ok(Box::new(h))
+}
+
+fn syslog_handler(destination: &str, facility: Facility) -> Result<Box<dyn HandlerTrait>, Error> {
+    let mut h = SyslogHandler::create(destination)?;
+    h.set_facility(facility);
+    Ok(Box::new(h))
 }
 
 #[cfg(test)]
 mod tests {
     use crate::*;
     use regex::Regex;
-    use std::io::{Result, Stdout, Write, stdout};
+    use std::{
+        fs,
+        io::{Result, Stdout, Write, stdout},
+    };
 
     #[test]
     fn temp() -> Result<()> {
@@ -661,7 +3093,7 @@ $";
 
     #[test]
     fn add_econsole_handler() {
-        let mut log = Logger::builder(module_path!())
+        let log = Logger::builder(module_path!())
             .add_econsole_handler()
             .set_fn_name("add_econsole_handler")
             .build();
@@ -672,7 +3104,7 @@ $";
 
     #[test]
     fn add_econsole_handler_with() {
-        let mut log = Logger::builder(module_path!())
+        let log = Logger::builder(module_path!())
             .add_econsole_handler_with(FormatType::Iso8601, None)
             .set_fn_name("add_econsole_handler_with")
             .build();
@@ -683,7 +3115,7 @@ $";
 
     #[test]
     fn add_custom_handler() {
-        let mut log = Logger::builder(module_path!())
+        let log = Logger::builder(module_path!())
             .add_custom_handler(
                 "Console",
                 Box::new(ConsoleHandler::create(ConsoleType::StdOut.as_str()).unwrap()),
@@ -697,7 +3129,7 @@ $";
 
     #[test]
     fn add_custom_handler_with() {
-        let mut log = Logger::builder(module_path!())
+        let log = Logger::builder(module_path!())
             .add_custom_handler_with(
                 "Console",
                 Box::new(ConsoleHandler::create(ConsoleType::StdOut.as_str()).unwrap()),
@@ -713,7 +3145,7 @@ $";
 
     #[test]
     fn add_file_handler() {
-        let mut log = Logger::builder(module_path!())
+        let log = Logger::builder(module_path!())
             .add_file_handler("test_logs/add_file_handler.log")
             .set_fn_name("add_file_handler")
             .build();
@@ -724,7 +3156,7 @@ $";
 
     #[test]
     fn add_file_handler_with() {
-        let mut log = Logger::builder(module_path!())
+        let log = Logger::builder(module_path!())
             .add_file_handler_with(
                 "test_logs/add_file_handler_with.log",
                 crate::FormatType::UnixTimestamp,
@@ -737,9 +3169,60 @@ $";
         log.warning("Need more tests.");
     }
 
+    #[test]
+    fn add_file_handler_ndjson() {
+        let filename = "test_logs/add_file_handler_ndjson.log";
+
+        let log = Logger::builder(module_path!())
+            .remove_file(filename)
+            .add_file_handler_with(filename, FormatType::Ndjson, None)
+            .set_fn_name("add_file_handler_ndjson")
+            .build();
+
+        log.info("We begin!");
+        log.warning("Need \"more\" tests.");
+        drop(log);
+
+        let text = fs::read_to_string(filename).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|l| l.starts_with('{') && l.ends_with('}')));
+        assert!(lines[1].ends_with(
+            "\"fn_name\":\"add_file_handler_ndjson\",\"level\":\"WARNING\",\"message\":\"Need \\\"more\\\" tests.\"}"
+        ));
+    }
+
+    #[test]
+    fn add_file_handler_twice() {
+        let first = "test_logs/add_file_handler_twice_1.log";
+        let second = "test_logs/add_file_handler_twice_2.log";
+
+        let log = Logger::builder(module_path!())
+            .remove_file(first)
+            .remove_file(second)
+            .add_file_handler(first)
+            .add_file_handler_with(second, FormatType::Simple, None)
+            .set_fn_name("add_file_handler_twice")
+            .build();
+
+        assert_eq!(log.handler_ids(Some(Handler::File)).len(), 2);
+
+        log.warning("Need more tests.");
+        drop(log);
+
+        assert!(fs::read_to_string(first).unwrap().ends_with(
+            "flogging::logger::builder::tests->add_file_handler_twice [WARNING] Need more tests.\n"
+        ));
+        assert_eq!(
+            fs::read_to_string(second).unwrap(),
+            "flogging::logger::builder::tests->add_file_handler_twice [WARNING] Need more tests.\n"
+        );
+    }
+
     #[test]
     fn add_pconsole_handler() {
-        let mut log = Logger::builder(module_path!())
+        let log = Logger::builder(module_path!())
             .add_pconsole_handler()
             .set_fn_name("add_pconsole_handler")
             .build();
@@ -750,7 +3233,7 @@ $";
 
     #[test]
     fn add_pconsole_handler_with() {
-        let mut log = Logger::builder(module_path!())
+        let log = Logger::builder(module_path!())
             .add_pconsole_handler_with(FormatType::Iso8601, None)
             .set_fn_name("add_pconsole_handler_with")
             .build();
@@ -761,7 +3244,7 @@ $";
 
     #[test]
     fn add_string_handler() {
-        let mut log = Logger::builder(module_path!())
+        let log = Logger::builder(module_path!())
             .add_string_handler()
             .set_fn_name("add_string_handler")
             .build();
@@ -771,8 +3254,34 @@ $";
     }
 
     #[test]
-    fn add_string_handler_with() {
+    fn add_string_handler_at() {
         let mut log = Logger::builder(module_path!())
+            .set_level(Level::FINE)
+            .add_string_handler_at(Level::WARNING)
+            .add_custom_handler("All", Box::new(StringHandler::create("All").unwrap()))
+            .set_fn_name("add_string_handler_at")
+            .build();
+
+        log.fine("We begin!");
+        log.info("Still going.");
+        log.severe("Need more tests.");
+
+        let warnings = log.get_handler(Handler::String).unwrap().get_log();
+        assert_eq!(
+            warnings,
+            "flogging::logger::builder::tests->add_string_handler_at [SEVERE ] Need more tests.\n"
+        );
+
+        let all = log
+            .get_handler(Handler::Custom("All".to_string()))
+            .unwrap()
+            .get_log();
+        assert_eq!(all.lines().count(), 3);
+    }
+
+    #[test]
+    fn add_string_handler_with() {
+        let log = Logger::builder(module_path!())
             .add_string_handler_with(crate::FormatType::Simple, None)
             .set_fn_name("add_string_handler_with")
             .build();
@@ -782,8 +3291,53 @@ $";
     }
 
     #[test]
-    fn remove_file() {
+    fn add_string_handler_with_filter() {
         let mut log = Logger::builder(module_path!())
+            .add_string_handler_with_filter(Box::new(|le: &LogEntry| le.fn_name() == "connect"))
+            .add_custom_handler("All", Box::new(StringHandler::create("All").unwrap()))
+            .set_fn_name("add_string_handler_with_filter")
+            .build();
+
+        log.info("We begin!");
+        log.set_fn_name("connect");
+        log.warning("Need more tests.");
+
+        let filtered = log.get_handler(Handler::String).unwrap().get_log();
+        assert_eq!(
+            filtered,
+            "flogging::logger::builder::tests->connect [WARNING] Need more tests.\n"
+        );
+
+        let all = log
+            .get_handler(Handler::Custom("All".to_string()))
+            .unwrap()
+            .get_log();
+        assert_eq!(all.lines().count(), 2);
+    }
+
+    #[test]
+    fn parse_directives() {
+        let log = Logger::builder(module_path!())
+            .set_module_level("my_app", Level::CONFIG)
+            .parse_directives("warning,my_app::net=finest,other_crate=off")
+            .unwrap()
+            .build();
+
+        assert_eq!(log.level(), Level::WARNING);
+        assert_eq!(log.module_level("my_app::net::tcp"), Level::FINEST);
+        assert_eq!(log.module_level("my_app"), Level::CONFIG);
+        assert_eq!(log.module_level("other_crate"), Level::OFF);
+
+        assert!(
+            Logger::builder(module_path!())
+                .parse_directives("my_app=noisy")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn remove_file() {
+        let log = Logger::builder(module_path!())
             .remove_file("test_logs/remove_file.log")
             .add_file_handler("test_logs/remove_file.log")
             .set_fn_name("add_file_handler")
@@ -792,4 +3346,57 @@ $";
         log.info("We begin!");
         log.warning("Need more tests.");
     }
+
+    #[test]
+    fn try_build() {
+        let result = Logger::builder(module_path!())
+            .add_file_handler("  ")
+            .try_build();
+        assert!(matches!(result, Err(BuildError::EmptyName(Handler::File))));
+
+        let result = Logger::builder(module_path!())
+            .add_custom_handler("Same", Box::new(StringHandler::create("1").unwrap()))
+            .add_custom_handler("Same", Box::new(StringHandler::create("2").unwrap()))
+            .try_build();
+        assert!(matches!(result, Err(BuildError::DuplicateLabel(label)) if label == "Same"));
+
+        let result = Logger::builder(module_path!())
+            .add_console_handler_with(FormatType::Custom, None)
+            .try_build();
+        assert!(matches!(
+            result,
+            Err(BuildError::MissingCustomFormatter(Handler::Console))
+        ));
+
+        let result = Logger::builder(module_path!())
+            .add_string_handler_with(FormatType::Json, Some(Box::new(MockFormatter::new())))
+            .try_build();
+        assert!(matches!(
+            result,
+            Err(BuildError::UnusedCustomFormatter(
+                Handler::String,
+                FormatType::Json
+            ))
+        ));
+
+        let result = Logger::builder(module_path!())
+            .add_file_handler("test_logs/no_such_dir/try_build.log")
+            .add_string_handler()
+            .try_build();
+        let err = result.err().unwrap();
+        assert!(matches!(err, BuildError::Create(Handler::File, _)));
+        assert!(std::error::Error::source(&err).is_some());
+
+        let log = Logger::builder(module_path!())
+            .add_string_handler()
+            .try_build()
+            .unwrap();
+        assert!(log.has_handler(Handler::String));
+    }
+
+    #[test]
+    #[should_panic(expected = "Handler::File: 'filename' must not be empty")]
+    fn build_panics() {
+        Logger::builder(module_path!()).add_file_handler("").build();
+    }
 }
@@ -0,0 +1,189 @@
+//
+// File Name:    clock.rs
+// Directory:    src/logger
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Clock
+//!
+//! The source of the timestamps of log entries.
+//!
+
+use chrono::{DateTime, Local, TimeDelta};
+use std::{
+    fmt,
+    sync::atomic::{AtomicI32, Ordering},
+};
+
+///
+/// Provides the timestamp of each log entry.
+///
+/// A [`Logger`][crate::Logger] uses the [`SystemClock`], unless another is set with
+/// [`LoggerBuilder::set_clock()`][crate::LoggerBuilder::set_clock] or
+/// [`Logger::set_clock()`][crate::Logger::set_clock]. Use a [`FixedClock`] or [`StepClock`]
+/// to get deterministic output in tests.
+///
+pub trait ClockTrait: Send + Sync {
+    ///
+    /// Returns the current date and time.
+    ///
+    fn now(&self) -> DateTime<Local>;
+}
+
+impl fmt::Debug for dyn ClockTrait {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ClockTrait")
+    }
+}
+
+///
+/// A clock that always returns the same date and time.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+/// use chrono::{Local, TimeZone};
+/// use std::sync::Arc;
+///
+/// let dt = Local.with_ymd_and_hms(2025, 7, 1, 9, 30, 0).unwrap();
+///
+/// let mut log = Logger::builder("app")
+///     .add_string_handler_with(FormatType::Iso8601, None)
+///     .set_clock(Arc::new(FixedClock::new(dt)))
+///     .build();
+///
+/// log.set_fn_name("main");
+/// log.info("Started.");
+///
+/// let h = log.get_handler(Handler::String).unwrap();
+/// assert_eq!(
+///     h.get_log(),
+///     format!("{:35} app->main [INFO   ] Started.\n", dt.format("%+").to_string())
+/// );
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock {
+    dt: DateTime<Local>,
+}
+
+impl FixedClock {
+    ///
+    /// Create a new `FixedClock`.
+    ///
+    /// ## Parameters
+    /// - `dt` - The date and time that it always returns.
+    ///
+    pub fn new(dt: DateTime<Local>) -> Self {
+        Self { dt }
+    }
+}
+
+impl ClockTrait for FixedClock {
+    fn now(&self) -> DateTime<Local> {
+        self.dt
+    }
+}
+
+///
+/// A clock that returns `start`, then advances by `step` on each call.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+/// use chrono::{Local, TimeDelta, TimeZone};
+///
+/// let start = Local.with_ymd_and_hms(2025, 7, 1, 9, 30, 0).unwrap();
+/// let clock = StepClock::new(start, TimeDelta::seconds(1));
+///
+/// assert_eq!(clock.now(), start);
+/// assert_eq!(clock.now(), start + TimeDelta::seconds(1));
+/// ```
+///
+#[derive(Debug)]
+pub struct StepClock {
+    start: DateTime<Local>,
+    step: TimeDelta,
+    count: AtomicI32,
+}
+
+impl StepClock {
+    ///
+    /// Create a new `StepClock`.
+    ///
+    /// ## Parameters
+    /// - `start` - The date and time returned by the first call.
+    /// - `step` - The amount to advance by, on each call.
+    ///
+    pub fn new(start: DateTime<Local>, step: TimeDelta) -> Self {
+        Self {
+            start,
+            step,
+            count: AtomicI32::new(0),
+        }
+    }
+}
+
+impl ClockTrait for StepClock {
+    fn now(&self) -> DateTime<Local> {
+        self.start + self.step * self.count.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+///
+/// The system clock: the [local][Local] date and time.
+///
+/// This is the default clock.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl ClockTrait for SystemClock {
+    fn now(&self) -> DateTime<Local> {
+        Local::now()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn step_clock() {
+        let start = Local.with_ymd_and_hms(2025, 7, 1, 9, 30, 0).unwrap();
+        let clock = StepClock::new(start, TimeDelta::milliseconds(250));
+
+        assert_eq!(clock.now(), start);
+        assert_eq!(clock.now(), start + TimeDelta::milliseconds(250));
+        assert_eq!(clock.now(), start + TimeDelta::milliseconds(500));
+    }
+
+    #[test]
+    fn system_clock() {
+        let before = Local::now();
+        let now = SystemClock.now();
+
+        assert!(before <= now && now <= Local::now());
+    }
+}
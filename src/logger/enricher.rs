@@ -0,0 +1,127 @@
+//
+// File Name:    enricher.rs
+// Directory:    src/logger
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Enricher
+//!
+//! Adds data to each log entry, before it is published.
+//!
+
+use super::LogEntry;
+use std::fmt;
+
+///
+/// Adds data, such as the build version, tenant ID, or feature-flag state, to each
+/// [`LogEntry`], without touching every log call.
+///
+/// The enrichers are added with [`LoggerBuilder::add_enricher()`][crate::LoggerBuilder::add_enricher]
+/// or [`Logger::add_enricher()`][crate::Logger::add_enricher], and are run in that order.
+/// They only see those entries that are loggable, and run before the logger's
+/// [`Redactor`][crate::Redactor], if any, and its handlers.
+///
+/// Any closure of the form `Fn(&mut LogEntry)` is an `EnricherTrait`.
+///
+/// ## Examples
+/// ```
+/// use flogging::*;
+///
+/// let mut log = Logger::builder("app")
+///     .add_string_handler()
+///     .add_enricher(Box::new(|le: &mut LogEntry| {
+///         le.add_field("version", env!("CARGO_PKG_VERSION"));
+///     }))
+///     .build();
+///
+/// log.info("Started.");
+///
+/// assert_eq!(
+///     log.get_handler(Handler::String).unwrap().get_log(),
+///     format!("app-> [INFO   ] Started. version={}\n", env!("CARGO_PKG_VERSION"))
+/// );
+/// ```
+///
+pub trait EnricherTrait: Send + Sync {
+    ///
+    /// Add to, or change, `log_entry`.
+    ///
+    /// ## Parameters
+    /// - `log_entry` - A mutable reference to the `LogEntry` to be enriched.
+    ///
+    fn enrich(&self, log_entry: &mut LogEntry);
+}
+
+impl<F> EnricherTrait for F
+where
+    F: Fn(&mut LogEntry) + Send + Sync,
+{
+    fn enrich(&self, log_entry: &mut LogEntry) {
+        self(log_entry)
+    }
+}
+
+impl fmt::Debug for dyn EnricherTrait {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EnricherTrait")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    struct FeatureFlag(AtomicBool);
+
+    impl EnricherTrait for FeatureFlag {
+        fn enrich(&self, log_entry: &mut LogEntry) {
+            if self.0.load(Ordering::Relaxed) {
+                log_entry.add_field("beta", "on");
+            }
+        }
+    }
+
+    #[test]
+    fn in_order() {
+        let mut log = Logger::builder(module_path!())
+            .add_string_handler()
+            .add_enricher(Box::new(FeatureFlag(AtomicBool::new(true))))
+            .set_fn_name("in_order")
+            .build();
+
+        log.add_enricher(Box::new(|le: &mut LogEntry| le.add_field("tenant", "acme")))
+            .with_fields([("id", "7")])
+            .info("Enriched.");
+
+        assert_eq!(
+            log.get_handler(Handler::String).unwrap().get_log(),
+            "flogging::logger::enricher::tests->in_order [INFO   ] Enriched. id=7 beta=on tenant=acme\n"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                Box::new(FeatureFlag(AtomicBool::new(false))) as Box<dyn EnricherTrait>
+            ),
+            "EnricherTrait"
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//
+// File Name:    handler_store.rs
+// Directory:    src/logger
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Handler Store
+//!
+//! Holds the handlers of a logger, allowing more than one of each kind.
+//!
+
+use crate::{Handler, HandlerTrait, Level};
+use std::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+///
+/// The source of unique handler ids.
+///
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+///
+/// Uniquely identifies a handler that has been added to a logger.
+///
+/// Returned by [`Logger::add_handler()`][crate::Logger::add_handler], and
+/// [`Logger::handler_ids()`][crate::Logger::handler_ids]. Use it to reach a
+/// particular handler, where a logger has more than one of the same kind.
+///
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HandlerId(u64);
+
+impl HandlerId {
+    ///
+    /// Allocate a new, unique, id.
+    ///
+    pub(crate) fn next() -> Self {
+        HandlerId(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl fmt::Display for HandlerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+///
+/// A handler, along with its kind, id, and own level (if any).
+///
+pub(crate) struct Entry {
+    pub(crate) id: HandlerId,
+    pub(crate) kind: Handler,
+    pub(crate) handler: Box<dyn HandlerTrait>,
+    pub(crate) level: Option<Level>,
+    ///
+    /// The number of log entries rejected by this handler's own level, or its filter.
+    ///
+    pub(crate) filtered: u64,
+}
+
+impl Entry {
+    ///
+    /// Returns `true` if a log entry of `level` passes this handler's own level (if any).
+    ///
+    pub(crate) fn accepts(&self, level: Level) -> bool {
+        self.level.is_none_or(|own| level >= own)
+    }
+}
+
+///
+/// Selects the handler(s) to operate on.
+///
+#[derive(Debug, Clone)]
+pub(crate) enum Selector {
+    Id(HandlerId),
+    Kind(Handler),
+}
+
+impl Selector {
+    fn matches(&self, entry: &Entry) -> bool {
+        match self {
+            Selector::Id(id) => entry.id == *id,
+            Selector::Kind(kind) => entry.kind == *kind,
+        }
+    }
+}
+
+///
+/// The handlers associated with a logger.
+///
+/// They are kept in the order they were added.
+///
+#[derive(Default)]
+pub(crate) struct Handlers {
+    entries: Vec<Entry>,
+}
+
+impl Handlers {
+    ///
+    /// Add a handler, returning its new id.
+    ///
+    pub(crate) fn add(&mut self, kind: Handler, handler: Box<dyn HandlerTrait>) -> HandlerId {
+        let id = HandlerId::next();
+        self.add_with_id(id, kind, handler);
+        id
+    }
+
+    ///
+    /// Add a handler, with an already allocated id.
+    ///
+    pub(crate) fn add_with_id(
+        &mut self,
+        id: HandlerId,
+        kind: Handler,
+        handler: Box<dyn HandlerTrait>,
+    ) {
+        self.entries.push(Entry {
+            id,
+            kind,
+            handler,
+            level: None,
+            filtered: 0,
+        });
+    }
+
+    ///
+    /// Returns `true` if any handler matches `selector`.
+    ///
+    pub(crate) fn contains(&self, selector: &Selector) -> bool {
+        self.entries.iter().any(|e| selector.matches(e))
+    }
+
+    ///
+    /// Returns the first entry that matches `selector`.
+    ///
+    pub(crate) fn get(&self, selector: &Selector) -> Option<&Entry> {
+        self.entries.iter().find(|e| selector.matches(e))
+    }
+
+    ///
+    /// Returns the first handler that matches `selector`.
+    ///
+    pub(crate) fn get_mut(&mut self, selector: &Selector) -> Option<&mut Box<dyn HandlerTrait>> {
+        self.entries
+            .iter_mut()
+            .find(|e| selector.matches(e))
+            .map(|e| &mut e.handler)
+    }
+
+    ///
+    /// Returns the ids of the handlers of the kind `kind`, or of all of them if `None`.
+    ///
+    pub(crate) fn ids(&self, kind: Option<&Handler>) -> Vec<HandlerId> {
+        self.entries
+            .iter()
+            .filter(|e| kind.is_none_or(|k| e.kind == *k))
+            .map(|e| e.id)
+            .collect()
+    }
+
+    ///
+    /// Returns an iterator over the handlers.
+    ///
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+
+    ///
+    /// Returns an iterator over the handlers, allowing each to be modified.
+    ///
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &mut Entry> {
+        self.entries.iter_mut()
+    }
+
+    ///
+    /// Returns the most recently added handler.
+    ///
+    pub(crate) fn last_mut(&mut self) -> Option<&mut Entry> {
+        self.entries.last_mut()
+    }
+
+    ///
+    /// Remove the first handler that matches `selector`.
+    ///
+    pub(crate) fn remove(&mut self, selector: &Selector) -> Option<Box<dyn HandlerTrait>> {
+        let pos = self.entries.iter().position(|e| selector.matches(e))?;
+        Some(self.entries.remove(pos).handler)
+    }
+
+    ///
+    /// Take out the first entry that matches `selector`, along with its position.
+    ///
+    /// Put it back with [`restore()`][Handlers::restore].
+    ///
+    pub(crate) fn take(&mut self, selector: &Selector) -> Option<(usize, Entry)> {
+        let pos = self.entries.iter().position(|e| selector.matches(e))?;
+        Some((pos, self.entries.remove(pos)))
+    }
+
+    ///
+    /// Put back an entry taken by [`take()`][Handlers::take], at its old position.
+    ///
+    pub(crate) fn restore(&mut self, pos: usize, entry: Entry) {
+        self.entries.insert(pos.min(self.entries.len()), entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn store() {
+        let mut handlers = Handlers::default();
+
+        let first = handlers.add(
+            Handler::String,
+            Box::new(StringHandler::create("").unwrap()),
+        );
+        let second = handlers.add(
+            Handler::String,
+            Box::new(StringHandler::create("").unwrap()),
+        );
+        let mock = handlers.add(
+            Handler::Custom("Mock".to_string()),
+            Box::new(MockHandler::default()),
+        );
+
+        assert_ne!(first, second);
+        assert_eq!(handlers.ids(Some(&Handler::String)), vec![first, second]);
+        assert_eq!(handlers.ids(None), vec![first, second, mock]);
+        assert!(handlers.contains(&Selector::Id(mock)));
+
+        handlers
+            .get_mut(&Selector::Id(second))
+            .unwrap()
+            .set_formatter(FormatType::Custom.create(None));
+        assert!(
+            handlers
+                .get_mut(&Selector::Kind(Handler::String))
+                .unwrap()
+                .get_formatter()
+                .to_string()
+                .contains("{message}")
+        );
+
+        assert!(handlers.remove(&Selector::Kind(Handler::String)).is_some());
+        assert_eq!(handlers.ids(None), vec![second, mock]);
+        assert!(!handlers.contains(&Selector::Id(first)));
+    }
+}
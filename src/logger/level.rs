@@ -39,8 +39,19 @@
 //! - FINEST (lowest level)
 //!
 //! In addition there is a level **OFF** that can be used to turn off logging.
+//!
+//! Each level has a numeric [`value()`][Level::value], the same as that of its
+//! `java.util.logging` counterpart. So a user-defined level can be placed between
+//! any two of them, with [`Level::custom()`].
 
-use std::{fmt, str::FromStr};
+use std::{
+    cmp::Ordering,
+    env,
+    error::Error,
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
 use strum::{EnumIter, IntoEnumIterator};
 
 ///
@@ -48,8 +59,10 @@ use strum::{EnumIter, IntoEnumIterator};
 ///
 /// Default level: INFO.
 ///
+/// Levels are compared by their numeric [`value()`][Level::value].
+///
 // #[allow(unused)]
-#[derive(Debug, Clone, Default, PartialEq, PartialOrd, EnumIter, Copy)]
+#[derive(Debug, Clone, Default, EnumIter, Copy)]
 pub enum Level {
     ///
     /// ALL is a special level that can be used to turn on logging
@@ -138,12 +151,148 @@ pub enum Level {
     /// OFF is a special level that can be used to turn off logging.
     ///
     OFF,
+
+    ///
+    /// A user-defined level, between the others. Created with [`Level::custom()`].
+    ///
+    /// It is not included in [`Level::iter()`], nor parsed from its name.
+    ///
+    #[strum(disabled)]
+    Custom {
+        ///
+        /// The name, such as: "NOTICE".
+        ///
+        name: &'static str,
+
+        ///
+        /// The numeric value, placing it among the other levels.
+        ///
+        value: i32,
+    },
 }
 
+///
+/// The name of the environment variable holding the default logging level,
+/// used by [`LoggerBuilder`][crate::LoggerBuilder].
+///
+/// For example:
+/// ```text
+/// FLOGGING_LEVEL=fine ./my_app
+/// ```
+///
+pub const FLOGGING_LEVEL: &str = "FLOGGING_LEVEL";
+
 impl Level {
+    ///
+    /// TRACE, as used by the `log` and `tracing` crates. An alias for FINEST.
+    ///
+    pub const TRACE: Level = Level::FINEST;
+
+    ///
+    /// DEBUG, as used by the `log` and `tracing` crates. An alias for FINE.
+    ///
+    pub const DEBUG: Level = Level::FINE;
+
+    ///
+    /// ERROR, as used by the `log` and `tracing` crates. An alias for SEVERE.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::string_logger("app");
+    /// log.set_level(Level::DEBUG);
+    ///
+    /// log.log_at(Level::TRACE, "Not logged.");
+    /// log.log_at(Level::ERROR, "Disk full.");
+    ///
+    /// assert_eq!(Level::ERROR, Level::SEVERE);
+    /// assert_eq!(log.level(), Level::FINE);
+    /// assert_eq!(
+    ///     log.get_handler(Handler::String).unwrap().get_log(),
+    ///     "app-> [SEVERE ] Disk full.\n"
+    /// );
+    /// ```
+    ///
+    pub const ERROR: Level = Level::SEVERE;
+
+    ///
+    /// Create a user-defined level.
+    ///
+    /// It is placed among the other levels by its `value`. For example, `850` is
+    /// between INFO (`800`) and WARNING (`900`). See [`value()`][Level::value].
+    ///
+    /// The formatters pad its `name`, as they do the other level names.
+    ///
+    /// ## Parameters
+    /// - `name` - The name, as shown in the log.
+    /// - `value` - The numeric value.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// const NOTICE: Level = Level::custom("NOTICE", 850);
+    ///
+    /// let mut log = Logger::string_logger("app");
+    /// log.set_level(NOTICE);
+    ///
+    /// log.info("Not logged.");
+    /// log.log_at(NOTICE, "Disk 80% full.");
+    /// log.warning("Disk 90% full.");
+    ///
+    /// assert!(Level::INFO < NOTICE && NOTICE < Level::WARNING);
+    /// assert_eq!(log.counts().accepted(NOTICE), 1);
+    /// assert_eq!(
+    ///     log.get_handler(Handler::String).unwrap().get_log(),
+    ///     "app-> [NOTICE ] Disk 80% full.\n\
+    ///      app-> [WARNING] Disk 90% full.\n"
+    /// );
+    /// ```
+    ///
+    pub const fn custom(name: &'static str, value: i32) -> Level {
+        Level::Custom { name, value }
+    }
+
+    ///
+    /// Returns an iterator over the built-in levels, from ALL to OFF, in ascending order.
+    ///
+    /// Custom levels are not included.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let names: Vec<&str> = Level::iter().map(|level| level.as_str()).collect();
+    /// assert_eq!(names[1..4], ["FINEST", "FINER", "FINE"]);
+    ///
+    /// let mut counts: HashMap<Level, u64> = Level::iter().map(|level| (level, 0)).collect();
+    /// *counts.get_mut(&Level::WARNING).unwrap() += 1;
+    ///
+    /// assert_eq!(Level::iter().max(), Some(Level::OFF));
+    /// ```
+    ///
+    pub fn iter() -> impl DoubleEndedIterator<Item = Level> + ExactSizeIterator + Clone {
+        <Level as IntoEnumIterator>::iter()
+    }
+
+    ///
+    /// Obtain the level set by the environment variable: [`FLOGGING_LEVEL`].
+    ///
+    /// Returns `None` if it is not set, or is not a valid level name.
+    ///
+    pub fn from_env() -> Option<Level> {
+        env::var(FLOGGING_LEVEL).ok()?.parse().ok()
+    }
+
     ///
     /// Converts a level to its string version.
     ///
+    /// For the built-in levels, this is the upper-case name of the variant, such as
+    /// "WARNING". It is stable, and is accepted by `from_str()`. So it can be used as
+    /// the level's key in configuration files, and the like.
+    ///
     pub const fn as_str(&self) -> &'static str {
         match self {
             Level::ALL => "ALL",
@@ -155,8 +304,112 @@ impl Level {
             Level::WARNING => "WARNING",
             Level::SEVERE => "SEVERE",
             Level::OFF => "OFF",
+            Level::Custom { name, .. } => name,
         }
     }
+
+    ///
+    /// Returns the highest of the built-in levels that is not above this one.
+    ///
+    /// Used where each built-in level is mapped to something else, such as a color.
+    ///
+    pub(crate) fn base(&self) -> Level {
+        match self {
+            Level::Custom { value, .. } => Level::iter()
+                .take_while(|level| level.value() <= *value)
+                .last()
+                .unwrap_or(Level::ALL),
+            level => *level,
+        }
+    }
+
+    ///
+    /// Returns the position of a built-in level, in the order they are declared.
+    ///
+    pub(crate) const fn index(&self) -> Option<usize> {
+        match self {
+            Level::ALL => Some(0),
+            Level::FINEST => Some(1),
+            Level::FINER => Some(2),
+            Level::FINE => Some(3),
+            Level::CONFIG => Some(4),
+            Level::INFO => Some(5),
+            Level::WARNING => Some(6),
+            Level::SEVERE => Some(7),
+            Level::OFF => Some(8),
+            Level::Custom { .. } => None,
+        }
+    }
+
+    ///
+    /// Returns the numeric value of this level, by which the levels are ordered.
+    ///
+    /// The built-in levels have those of `java.util.logging`:
+    ///
+    /// | Level   | Value        |
+    /// |---------|--------------|
+    /// | ALL     | `i32::MIN`   |
+    /// | FINEST  | 300          |
+    /// | FINER   | 400          |
+    /// | FINE    | 500          |
+    /// | CONFIG  | 700          |
+    /// | INFO    | 800          |
+    /// | WARNING | 900          |
+    /// | SEVERE  | 1000         |
+    /// | OFF     | `i32::MAX`   |
+    ///
+    pub const fn value(&self) -> i32 {
+        match self {
+            Level::ALL => i32::MIN,
+            Level::FINEST => 300,
+            Level::FINER => 400,
+            Level::FINE => 500,
+            Level::CONFIG => 700,
+            Level::INFO => 800,
+            Level::WARNING => 900,
+            Level::SEVERE => 1000,
+            Level::OFF => i32::MAX,
+            Level::Custom { value, .. } => *value,
+        }
+    }
+}
+
+///
+/// Levels are equal if they have the same name and value.
+///
+impl PartialEq for Level {
+    fn eq(&self, other: &Self) -> bool {
+        self.value() == other.value() && self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Level {}
+
+///
+/// Levels are ordered by their value, then by name.
+///
+impl Ord for Level {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value()
+            .cmp(&other.value())
+            .then_with(|| self.as_str().cmp(other.as_str()))
+    }
+}
+
+impl PartialOrd for Level {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+///
+/// Consistent with `Eq`: the name and value are hashed.
+///
+impl Hash for Level {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value().hash(state);
+        self.as_str().hash(state);
+    }
 }
 
 impl fmt::Display for Level {
@@ -165,36 +418,68 @@ impl fmt::Display for Level {
     }
 }
 
-#[allow(dead_code)]
+///
+/// Returned from `FromStr::from_str()` when an unknown string
+/// is passed-in.
+///
 #[derive(Debug)]
 pub struct LevelError {
     msg: String,
 }
 
+impl LevelError {
+    pub(crate) fn new(msg: String) -> Self {
+        LevelError { msg }
+    }
+}
+
+impl fmt::Display for LevelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.msg.fmt(f)
+    }
+}
+
+impl Error for LevelError {}
+
+///
+/// The level names are case-insensitive, and any surrounding whitespace is ignored.
+///
+/// The aliases "TRACE", "DEBUG", and "ERROR" are also accepted, as
+/// [`Level::TRACE`], [`Level::DEBUG`], and [`Level::ERROR`].
+///
 impl FromStr for Level {
     type Err = LevelError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "ALL" => Ok(Level::ALL),
-            "FINEST" => Ok(Level::FINEST),
-            "FINER" => Ok(Level::FINER),
-            "FINE" => Ok(Level::FINE),
-            "CONFIG" => Ok(Level::CONFIG),
-            "INFO" => Ok(Level::INFO),
-            "WARNING" => Ok(Level::WARNING),
-            "SEVERE" => Ok(Level::SEVERE),
-            "OFF" => Ok(Level::OFF),
-            _ => Err(LevelError {
-                msg: format!("Unknown Level: {s}"),
-            }),
-        }
+        const ALIASES: [(&str, Level); 3] = [
+            ("TRACE", Level::TRACE),
+            ("DEBUG", Level::DEBUG),
+            ("ERROR", Level::ERROR),
+        ];
+
+        let name = s.trim();
+
+        Level::iter()
+            .map(|level| (level.as_str(), level))
+            .chain(ALIASES)
+            .find(|(level_name, _)| level_name.eq_ignore_ascii_case(name))
+            .map(|(_, level)| level)
+            .ok_or_else(|| LevelError::new(format!("Unknown Level: {s}")))
+    }
+}
+
+impl TryFrom<&str> for Level {
+    type Error = LevelError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
 
     #[test]
     fn compare_levels() {
@@ -214,6 +499,54 @@ mod tests {
             assert_eq!(level, l);
         }
 
-        assert!(Level::from_str("DEBUG").is_err());
+        assert_eq!(Level::from_str(" fine\n").unwrap(), Level::FINE);
+        assert_eq!(Level::try_from("Warning").unwrap(), Level::WARNING);
+
+        assert_eq!(Level::from_str("debug").unwrap(), Level::FINE);
+        assert_eq!(Level::from_str("Trace").unwrap(), Level::FINEST);
+        assert_eq!(Level::from_str("ERROR").unwrap(), Level::SEVERE);
+
+        let err = Level::from_str("VERBOSE").unwrap_err();
+        assert_eq!(err.to_string(), "Unknown Level: VERBOSE");
+        assert!(Level::try_from("").is_err());
+    }
+
+    #[test]
+    fn custom() {
+        const NOTICE: Level = Level::custom("NOTICE", 850);
+        const TRACE: Level = Level::custom("TRACE", 100);
+
+        assert!(Level::INFO < NOTICE && NOTICE < Level::WARNING);
+        assert!(Level::ALL < TRACE && TRACE < Level::FINEST);
+        assert_eq!(NOTICE, Level::custom("NOTICE", 850));
+        assert_ne!(NOTICE, Level::custom("NOTICE", 860));
+
+        assert_eq!(NOTICE.base(), Level::INFO);
+        assert_eq!(TRACE.base(), Level::ALL);
+        assert_eq!(Level::FINE.base(), Level::FINE);
+
+        assert_eq!(format!("[{NOTICE:7}]"), "[NOTICE ]");
+        assert!(Level::iter().all(|level| level.index().is_some()));
+        assert!(Level::from_str("NOTICE").is_err());
+    }
+
+    #[test]
+    fn ordering() {
+        const NOTICE: Level = Level::custom("NOTICE", 850);
+
+        let mut levels = vec![Level::SEVERE, NOTICE, Level::ALL, Level::INFO];
+        levels.sort();
+        assert_eq!(levels, [Level::ALL, Level::INFO, NOTICE, Level::SEVERE]);
+
+        assert_eq!(Level::iter().len(), 9);
+        assert!(Level::iter().zip(Level::iter().skip(1)).all(|(a, b)| a < b));
+        assert_eq!(Level::iter().min(), Some(Level::ALL));
+        assert_eq!(Level::iter().rev().next(), Some(Level::OFF));
+        assert_eq!(NOTICE.max(Level::WARNING), Level::WARNING);
+
+        let set: HashSet<Level> = Level::iter().chain([NOTICE, Level::DEBUG]).collect();
+        assert_eq!(set.len(), 10);
+        assert!(set.contains(&Level::custom("NOTICE", 850)));
+        assert!(!set.contains(&Level::custom("NOTICE", 860)));
     }
 }
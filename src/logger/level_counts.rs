@@ -0,0 +1,141 @@
+//
+// File Name:    level_counts.rs
+// Directory:    src/logger
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Level Counts
+//!
+
+use super::Level;
+
+///
+/// The number of built-in levels.
+///
+const LEVELS: usize = 9;
+
+///
+/// The number of log entries a logger has accepted at each [`Level`], along
+/// with the number it has rejected, because they were below its level, or
+/// were dropped by its sampling.
+///
+/// Returned by [`Logger::counts()`][crate::Logger::counts].
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// let log = Logger::string_logger("app");
+///
+/// log.info("Started.");
+/// log.severe("Disk full!");
+/// log.severe("Disk still full!");
+/// log.fine("Not logged.");
+///
+/// let counts = log.counts();
+///
+/// assert_eq!(counts.accepted(Level::SEVERE), 2);
+/// assert_eq!(counts.accepted(Level::INFO), 1);
+/// assert_eq!(counts.total(), 3);
+/// assert_eq!(counts.rejected(), 1);
+/// assert_eq!(
+///     counts.iter().collect::<Vec<_>>(),
+///     vec![(Level::INFO, 1), (Level::SEVERE, 2)]
+/// );
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LevelCounts {
+    accepted: [u64; LEVELS],
+    custom: Vec<(Level, u64)>,
+    rejected: u64,
+}
+
+impl LevelCounts {
+    ///
+    /// Returns the number of log entries accepted at `level`.
+    ///
+    /// ## Parameters
+    /// - `level` - The level required.
+    ///
+    pub fn accepted(&self, level: Level) -> u64 {
+        match level.index() {
+            Some(index) => self.accepted[index],
+            None => self
+                .custom
+                .iter()
+                .find(|(custom, _)| *custom == level)
+                .map_or(0, |(_, count)| *count),
+        }
+    }
+
+    ///
+    /// Returns each level, along with the number of log entries accepted at it,
+    /// from the lowest level to the highest. Levels with none are skipped.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (Level, u64)> {
+        let mut counts: Vec<_> = Level::iter()
+            .map(|level| (level, self.accepted(level)))
+            .filter(|(_, count)| *count > 0)
+            .chain(self.custom.iter().copied())
+            .collect();
+
+        counts.sort_by_key(|(level, _)| *level);
+        counts.into_iter()
+    }
+
+    ///
+    /// Returns the number of log entries rejected, because they were below the
+    /// logger's level, or were dropped by its [sampling][crate::Logger::set_sampling].
+    ///
+    pub fn rejected(&self) -> u64 {
+        self.rejected
+    }
+
+    ///
+    /// Returns the number of log entries accepted, at all levels.
+    ///
+    pub fn total(&self) -> u64 {
+        self.accepted.iter().sum::<u64>() + self.custom.iter().map(|(_, count)| count).sum::<u64>()
+    }
+
+    ///
+    /// Count a log entry accepted at `level`.
+    ///
+    pub(crate) fn accept(&mut self, level: Level) {
+        if let Some(index) = level.index() {
+            self.accepted[index] += 1;
+        } else if let Some((_, count)) = self.custom.iter_mut().find(|(custom, _)| *custom == level)
+        {
+            *count += 1;
+        } else {
+            self.custom.push((level, 1));
+        }
+    }
+
+    ///
+    /// Count a log entry rejected by the level gate, or the sampling.
+    ///
+    pub(crate) fn reject(&mut self) {
+        self.rejected += 1;
+    }
+}
@@ -0,0 +1,252 @@
+//
+// File Name:    level_map.rs
+// Directory:    src/logger
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Level Map
+//!
+
+use super::{Level, LevelError};
+use std::fmt;
+
+///
+/// Maps module paths to their own logging [`Level`]s.
+///
+/// This allows the log entries from different modules, passing through
+/// the same logger configuration, to have different verbosity.
+///
+/// A module path matches an entry in the map if it is the same, or is a
+/// sub-module of it. For example: `my_app::db` matches both `my_app::db` and
+/// `my_app::db::pool`, but not `my_app::dbx`. Where more than one entry matches,
+/// the longest (most specific) one is used.
+///
+/// ## Examples
+/// ```
+/// use flogging::*;
+///
+/// let mut map = LevelMap::new();
+/// map.set("my_app", Level::WARNING);
+/// map.set("my_app::db", Level::FINEST);
+///
+/// assert_eq!(map.get("my_app::db::pool"), Some(Level::FINEST));
+/// assert_eq!(map.get("my_app::net"), Some(Level::WARNING));
+/// assert_eq!(map.get("other_crate"), None);
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LevelMap {
+    levels: Vec<(String, Level)>,
+}
+
+impl LevelMap {
+    ///
+    /// Creates a new, empty, `LevelMap`.
+    ///
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    ///
+    /// Parse a directives spec, in the style of `RUST_LOG` for `env_logger`.
+    ///
+    /// The spec is a comma separated list of directives, each of which is one of:
+    /// - `level` - Sets the global level, such as: `info`.
+    /// - `mod_path=level` - Sets the level for a module, and its sub-modules.
+    /// - `mod_path` - Turns on all logging for a module: the same as `mod_path=all`.
+    ///
+    /// The level names are case-insensitive. Empty directives are ignored.
+    ///
+    /// ## Parameters
+    /// - `spec` - The directives, such as: `info,my_app::net=finest,other_crate=off`.
+    ///
+    /// Returns the global level, if any, and the per-module levels. Otherwise, an
+    /// error if a directive's level is invalid.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let (level, map) = LevelMap::parse_directives("info,my_app::net=finest,other_crate=off").unwrap();
+    ///
+    /// assert_eq!(level, Some(Level::INFO));
+    /// assert_eq!(map.get("my_app::net"), Some(Level::FINEST));
+    /// assert_eq!(map.get("other_crate"), Some(Level::OFF));
+    /// ```
+    ///
+    pub fn parse_directives(spec: &str) -> Result<(Option<Level>, LevelMap), LevelError> {
+        let mut level = None;
+        let mut map = LevelMap::new();
+
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((mod_path, name)) => {
+                    let mod_path = mod_path.trim();
+
+                    if mod_path.is_empty() {
+                        return Err(LevelError::new(format!(
+                            "Missing module path in directive: {directive}"
+                        )));
+                    }
+
+                    map.set(mod_path, name.parse()?);
+                }
+                None => match directive.parse() {
+                    Ok(l) => level = Some(l),
+                    Err(_) => map.set(directive, Level::ALL),
+                },
+            }
+        }
+
+        Ok((level, map))
+    }
+
+    ///
+    /// Find the level for `mod_path`.
+    ///
+    /// ## Parameters
+    /// - `mod_path` - The module path to look up.
+    ///
+    /// Returns the level of the most specific matching entry, or `None` if there is
+    /// no match.
+    ///
+    pub fn get(&self, mod_path: &str) -> Option<Level> {
+        self.levels
+            .iter()
+            .filter(|(path, _)| is_within(mod_path, path))
+            .max_by_key(|(path, _)| path.len())
+            .map(|(_, level)| *level)
+    }
+
+    ///
+    /// Returns `true` if there are no entries.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    ///
+    /// Returns an iterator over the entries, in the order they were first set.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Level)> {
+        self.levels
+            .iter()
+            .map(|(path, level)| (path.as_str(), *level))
+    }
+
+    ///
+    /// Returns the number of entries.
+    ///
+    pub fn len(&self) -> usize {
+        self.levels.len()
+    }
+
+    ///
+    /// Returns the most verbose level of all the entries, if any.
+    ///
+    pub fn most_verbose(&self) -> Option<Level> {
+        self.levels
+            .iter()
+            .map(|(_, level)| *level)
+            .reduce(|a, b| if b < a { b } else { a })
+    }
+
+    ///
+    /// Remove the entry for `mod_path`.
+    ///
+    /// ## Parameters
+    /// - `mod_path` - The module path of the entry.
+    ///
+    /// Returns the level of the removed entry, if there was one.
+    ///
+    pub fn remove(&mut self, mod_path: &str) -> Option<Level> {
+        let pos = self.levels.iter().position(|(path, _)| path == mod_path)?;
+        Some(self.levels.remove(pos).1)
+    }
+
+    ///
+    /// Set the level for `mod_path`, and its sub-modules.
+    ///
+    /// ## Parameters
+    /// - `mod_path` - The module path.
+    /// - `level` - The level to set. Replaces any previous setting.
+    ///
+    pub fn set(&mut self, mod_path: &str, level: Level) {
+        match self.levels.iter_mut().find(|(path, _)| path == mod_path) {
+            Some(entry) => entry.1 = level,
+            None => self.levels.push((mod_path.to_string(), level)),
+        }
+    }
+}
+
+impl fmt::Display for LevelMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list: Vec<String> = self
+            .levels
+            .iter()
+            .map(|(path, level)| format!("{path}={level}"))
+            .collect();
+
+        list.join(",").fmt(f)
+    }
+}
+
+///
+/// Checks whether `mod_path` is `parent`, or one of its sub-modules.
+///
+pub(super) fn is_within(mod_path: &str, parent: &str) -> bool {
+    mod_path
+        .strip_prefix(parent)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup() {
+        let mut map = LevelMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.get("my_app"), None);
+
+        map.set("my_app", Level::WARNING);
+        map.set("my_app::db", Level::FINE);
+        map.set("my_app::db", Level::FINEST);
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get("my_app"), Some(Level::WARNING));
+        assert_eq!(map.get("my_app::db"), Some(Level::FINEST));
+        assert_eq!(map.get("my_app::db::pool"), Some(Level::FINEST));
+        assert_eq!(map.get("my_app::dbx"), Some(Level::WARNING));
+        assert_eq!(map.get("my_apps"), None);
+        assert_eq!(map.most_verbose(), Some(Level::FINEST));
+        assert_eq!(map.to_string(), "my_app=WARNING,my_app::db=FINEST");
+
+        assert_eq!(map.remove("my_app::db"), Some(Level::FINEST));
+        assert_eq!(map.remove("my_app::db"), None);
+        assert_eq!(map.get("my_app::db::pool"), Some(Level::WARNING));
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            vec![("my_app", Level::WARNING)]
+        );
+    }
+}
@@ -26,13 +26,34 @@
 //!
 
 use chrono::{DateTime, Local};
-use std::{fmt, time::Instant};
-use super::Level;
+use std::{
+    borrow::Cow,
+    error, fmt,
+    panic::Location,
+    sync::{
+        LazyLock,
+        atomic::{AtomicU64, Ordering},
+    },
+    thread::{self, ThreadId},
+    time::{Duration, Instant},
+};
+use super::{Level, LogEntryBuilder};
+use crate::context;
+
+///
+/// The sequence number of the next log entry.
+///
+static SEQ: AtomicU64 = AtomicU64::new(1);
+
+///
+/// The start of logging: when the first logger was built, or log entry created.
+///
+pub(crate) static START: LazyLock<Instant> = LazyLock::new(Instant::now);
 
 ///
 /// Used to provide relevant information about each log entry.
 ///
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LogEntry {
     pub(crate) timestamp: DateTime<Local>,
     pub(crate) mod_path: String,
@@ -43,6 +64,44 @@ pub struct LogEntry {
     pub(crate) fn_name: String,
     pub(crate) level: Level,
     pub(crate) message: String,
+    ///
+    /// The structured data attached to this log entry, as key/value pairs,
+    /// in the order they were given.
+    ///
+    pub(crate) fields: Vec<(String, String)>,
+    ///
+    /// The [context][crate::context] labels in scope when this log entry
+    /// was created, outermost first.
+    ///
+    pub(crate) context: Vec<String>,
+    ///
+    /// The [correlation ID][crate::context::correlation_id] set when this
+    /// log entry was created, if any.
+    ///
+    pub(crate) correlation_id: Option<String>,
+    ///
+    /// The thread on which this log entry was created.
+    ///
+    pub(crate) thread_id: ThreadId,
+    pub(crate) thread_name: Option<String>,
+    ///
+    /// The source code location at which this log entry was created.
+    ///
+    pub(crate) file: Cow<'static, str>,
+    pub(crate) line: u32,
+    pub(crate) column: u32,
+    ///
+    /// The process-wide sequence number of this log entry.
+    ///
+    pub(crate) seq: u64,
+    ///
+    /// The time since the [start][START] of logging, when this log entry was created.
+    ///
+    pub(crate) elapsed: Duration,
+    ///
+    /// The error attached to this log entry, if any, with its source chain.
+    ///
+    pub(crate) error: Option<String>,
 }
 
 impl fmt::Display for LogEntry {
@@ -57,45 +116,302 @@ impl fmt::Display for LogEntry {
 
 #[allow(unused)]
 impl LogEntry {
+    #[track_caller]
     pub(crate) fn create(level: Level, fn_name: String, message: String) -> LogEntry {
+        LogEntry::create_at(level, fn_name, message, Local::now())
+    }
+
+    #[track_caller]
+    pub(crate) fn create_at(
+        level: Level,
+        fn_name: String,
+        message: String,
+        timestamp: DateTime<Local>,
+    ) -> LogEntry {
+        let thread = thread::current();
+        let location = Location::caller();
+
         LogEntry {
-            timestamp: Local::now(),
+            timestamp,
             mod_path: String::new(),
             fn_name,
             level,
             message,
+            fields: Vec::new(),
+            context: context::current(),
+            correlation_id: context::correlation_id(),
+            thread_id: thread.id(),
+            thread_name: thread.name().map(str::to_string),
+            file: location.file().into(),
+            line: location.line(),
+            column: location.column(),
+            seq: SEQ.fetch_add(1, Ordering::Relaxed),
+            elapsed: START.elapsed(),
+            error: None,
         }
     }
 
-    pub(crate) fn fn_name(&self) -> String {
-        self.fn_name.clone()
+    ///
+    /// Attach a field to this log entry, after those it already has.
+    ///
+    /// Use it in an [`EnricherTrait`][crate::EnricherTrait].
+    ///
+    /// ## Parameters
+    /// - `key` - The key of the field.
+    /// - `value` - The value of the field.
+    ///
+    pub fn add_field(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.fields.push((key.into(), value.into()));
+    }
+
+    ///
+    /// Start building a log entry, without going through a [`Logger`][crate::Logger].
+    ///
+    /// See [`LogEntryBuilder`][crate::LogEntryBuilder] for the defaults.
+    ///
+    #[track_caller]
+    pub fn builder() -> LogEntryBuilder {
+        LogEntryBuilder::create()
+    }
+
+    ///
+    /// Returns the column of the source code at which this log entry was created.
+    ///
+    /// This is `0` if not known.
+    ///
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+
+    ///
+    /// Returns the [context][crate::context] labels that were in scope when this
+    /// log entry was created, outermost first.
+    ///
+    pub fn context(&self) -> &[String] {
+        &self.context
+    }
+
+    ///
+    /// Returns the context labels joined with `/`, as: `batch-42/item-7`.
+    ///
+    pub(crate) fn context_text(&self) -> String {
+        self.context.join("/")
+    }
+
+    ///
+    /// Returns the [correlation ID][crate::context::correlation_id] that was set when
+    /// this log entry was created, if any.
+    ///
+    pub fn correlation_id(&self) -> Option<&str> {
+        self.correlation_id.as_deref()
+    }
+
+    ///
+    /// Returns the time since the start of logging, when this log entry was created.
+    ///
+    /// The start is when the first logger in this process was built, or the first log
+    /// entry was created, whichever came first.
+    ///
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    ///
+    /// Returns the elapsed time as text, with millisecond precision: `+12.304s`.
+    ///
+    pub(crate) fn elapsed_text(&self) -> String {
+        format!(
+            "+{}.{:03}s",
+            self.elapsed.as_secs(),
+            self.elapsed.subsec_millis()
+        )
+    }
+
+    ///
+    /// Returns the error attached to this log entry, if any.
+    ///
+    /// This is the error's message, followed by that of each error in its
+    /// [`source()`][error::Error::source] chain, separated by `": "`.
+    ///
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    ///
+    /// Returns the value of the field `key`, if present.
+    ///
+    /// ## Parameters
+    /// - `key` - The key of the required field.
+    ///
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    ///
+    /// Returns the structured data attached to this log entry, as key/value pairs,
+    /// in the order they were given.
+    ///
+    pub fn fields(&self) -> &[(String, String)] {
+        &self.fields
+    }
+
+    ///
+    /// Returns the fields as text, in the style of `logfmt`: `key=value key2="value 2"`.
+    ///
+    /// Any value that is empty, or contains a space, `=` or `"`, is quoted.
+    ///
+    pub(crate) fn fields_text(&self) -> String {
+        let mut buf = String::new();
+
+        for (key, value) in &self.fields {
+            if !buf.is_empty() {
+                buf.push(' ');
+            }
+
+            buf.push_str(key);
+            buf.push('=');
+            buf.push_str(&quote(value));
+        }
+
+        buf
+    }
+
+    ///
+    /// Returns the source file in which this log entry was created: the call site
+    /// of the logging macro or method, as given by [`file!()`].
+    ///
+    /// This is empty if not known.
+    ///
+    pub fn file(&self) -> &str {
+        &self.file
     }
 
-    pub(crate) fn level(&self) -> Level {
+    ///
+    /// Returns the name of the function/method inside which this
+    /// log entry was generated.
+    ///
+    pub fn fn_name(&self) -> &str {
+        &self.fn_name
+    }
+
+    ///
+    /// Returns the level of this log entry.
+    ///
+    pub fn level(&self) -> Level {
         self.level
     }
 
-    pub(crate) fn message(&self) -> String {
-        self.message.clone()
+    ///
+    /// Returns the line of the source code at which this log entry was created.
+    ///
+    /// This is `0` if not known.
+    ///
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    ///
+    /// Returns the message text.
+    ///
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    ///
+    /// Returns the module path of the source of this log entry.
+    ///
+    pub fn mod_path(&self) -> &str {
+        &self.mod_path
+    }
+
+    ///
+    /// Returns the sequence number of this log entry.
+    ///
+    /// Every log entry created in this process is numbered in order, starting from `1`,
+    /// regardless of which logger or thread created it. So entries published by
+    /// different handlers can be cross-referenced. This is `0` if not known.
+    ///
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    pub(crate) fn set_error(&mut self, err: &dyn error::Error) {
+        let mut text = err.to_string();
+        let mut source = err.source();
+
+        while let Some(e) = source {
+            text.push_str(": ");
+            text.push_str(&e.to_string());
+            source = e.source();
+        }
+
+        self.error = Some(text);
     }
 
-    pub(crate) fn mod_path(&self) -> String {
-        self.mod_path.clone()
+    pub(crate) fn set_fields(&mut self, fields: Vec<(String, String)>) {
+        self.fields = fields;
     }
 
     pub(crate) fn set_fn_name(&mut self, fn_name: String) {
         self.fn_name = fn_name.clone();
     }
 
+    pub(crate) fn set_location(&mut self, file: String, line: u32, column: u32) {
+        self.file = file.into();
+        self.line = line;
+        self.column = column;
+    }
+
     pub(crate) fn set_mod_path(&mut self, mod_path: String) {
-        self.mod_path = mod_path.clone();
+        self.mod_path = mod_path;
+    }
+
+    ///
+    /// Returns the id of the thread on which this log entry was created.
+    ///
+    pub fn thread_id(&self) -> ThreadId {
+        self.thread_id
+    }
+
+    ///
+    /// Returns the thread id as text: the number inside `ThreadId(5)`.
+    ///
+    pub(crate) fn thread_id_text(&self) -> String {
+        format!("{:?}", self.thread_id)
+            .trim_start_matches("ThreadId(")
+            .trim_end_matches(')')
+            .to_string()
     }
 
-    pub(crate) fn timestamp(&self) -> DateTime<Local> {
+    ///
+    /// Returns the name of the thread on which this log entry was created, if it has one.
+    ///
+    pub fn thread_name(&self) -> Option<&str> {
+        self.thread_name.as_deref()
+    }
+
+    ///
+    /// Returns the date and time this log entry was created.
+    ///
+    pub fn timestamp(&self) -> DateTime<Local> {
         self.timestamp
     }
 }
 
+///
+/// Returns `value` as a `logfmt` value: quoted if it is empty, or contains a space, `=` or `"`.
+///
+pub(crate) fn quote(value: &str) -> String {
+    if value.is_empty() || value.contains([' ', '=', '"']) {
+        format!("{value:?}")
+    } else {
+        value.to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,12 +425,126 @@ mod tests {
         println!("\nlog_entry: {log_entry}\n");
 
         assert_eq!(log_entry.level(), Level::INFO);
-        assert_eq!(log_entry.message(), "message".to_string());
-        assert_eq!(log_entry.fn_name(), "to_string".to_string());
+        assert_eq!(log_entry.message(), "message");
+        assert_eq!(log_entry.fn_name(), "to_string");
         assert_eq!(log_entry.mod_path(), module_path!());
         assert!(log_entry.timestamp().timestamp() > 0);
 
         log_entry.set_fn_name("fn_name".to_owned());
-        assert_eq!(log_entry.fn_name(), "fn_name".to_string());
+        assert_eq!(log_entry.fn_name(), "fn_name");
+    }
+
+    #[test]
+    fn context() {
+        let _g = context::push("batch-42");
+        let _h = context::push("item-7");
+        let log_entry =
+            LogEntry::create(Level::INFO, "context".to_string(), "message".to_string());
+
+        assert_eq!(log_entry.context(), ["batch-42", "item-7"]);
+        assert_eq!(log_entry.context_text(), "batch-42/item-7");
+        assert_eq!(log_entry.correlation_id(), None);
+
+        let _r = context::set_correlation_id("req-7");
+        let log_entry =
+            LogEntry::create(Level::INFO, "context".to_string(), "message".to_string());
+        assert_eq!(log_entry.correlation_id(), Some("req-7"));
+    }
+
+    #[test]
+    fn location() {
+        let (log_entry, line) = (
+            LogEntry::create(Level::INFO, "location".to_string(), "message".to_string()),
+            line!(),
+        );
+
+        assert_eq!(log_entry.file(), file!());
+        assert_eq!(log_entry.line(), line - 1);
+        assert_eq!(log_entry.column(), 13);
+    }
+
+    #[test]
+    fn elapsed() {
+        let mut log_entry =
+            LogEntry::create(Level::INFO, "elapsed".to_string(), "message".to_string());
+        assert!(log_entry.elapsed() <= START.elapsed());
+
+        log_entry.elapsed = Duration::from_millis(12_304);
+        assert_eq!(log_entry.elapsed_text(), "+12.304s");
+
+        log_entry.elapsed = Duration::from_micros(5_900);
+        assert_eq!(log_entry.elapsed_text(), "+0.005s");
+    }
+
+    #[test]
+    fn seq() {
+        let first = LogEntry::create(Level::INFO, "seq".to_string(), "first".to_string());
+        let second = LogEntry::create(Level::INFO, "seq".to_string(), "second".to_string());
+
+        assert!(first.seq() > 0);
+        assert!(second.seq() > first.seq());
+    }
+
+    #[test]
+    fn thread() {
+        let log_entry = thread::Builder::new()
+            .name("worker".to_string())
+            .spawn(|| LogEntry::create(Level::INFO, "thread".to_string(), "message".to_string()))
+            .unwrap()
+            .join()
+            .unwrap();
+
+        assert_eq!(log_entry.thread_name(), Some("worker"));
+        assert_ne!(log_entry.thread_id(), thread::current().id());
+        assert!(log_entry.thread_id_text().parse::<u64>().is_ok());
+    }
+
+    #[test]
+    fn error() {
+        #[derive(Debug)]
+        struct Outer(std::num::ParseIntError);
+
+        impl fmt::Display for Outer {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("bad port")
+            }
+        }
+
+        impl error::Error for Outer {
+            fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let mut log_entry =
+            LogEntry::create(Level::SEVERE, "error".to_string(), "message".to_string());
+        assert_eq!(log_entry.error(), None);
+
+        log_entry.set_error(&Outer("x".parse::<u16>().unwrap_err()));
+        assert_eq!(
+            log_entry.error(),
+            Some("bad port: invalid digit found in string")
+        );
+    }
+
+    #[test]
+    fn fields() {
+        let mut log_entry =
+            LogEntry::create(Level::INFO, "fields".to_string(), "message".to_string());
+        assert!(log_entry.fields().is_empty());
+        assert_eq!(log_entry.fields_text(), "");
+
+        log_entry.set_fields(vec![
+            ("user_id".to_string(), "42".to_string()),
+            ("name".to_string(), "Jo Bloggs".to_string()),
+            ("note".to_string(), String::new()),
+        ]);
+
+        assert_eq!(log_entry.field("user_id"), Some("42"));
+        assert_eq!(log_entry.field("order"), None);
+        assert_eq!(
+            log_entry.fields_text(),
+            "user_id=42 name=\"Jo Bloggs\" note=\"\""
+        );
     }
 }
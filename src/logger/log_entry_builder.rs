@@ -0,0 +1,232 @@
+//
+// File Name:    log_entry_builder.rs
+// Directory:    src/logger
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # LogEntry Builder
+//!
+
+use super::{Level, LogEntry};
+use chrono::{DateTime, Local};
+use std::error;
+
+///
+/// Builds a [`LogEntry`], without going through a [`Logger`][crate::Logger].
+///
+/// Use it to feed log entries from other sources into handlers, or to test
+/// custom handlers, filters, and formatters.
+///
+/// Created with [`LogEntry::builder()`]. Each log entry starts out as the logger
+/// would create it: at level `INFO`, timestamped now, numbered, on the current thread,
+/// and located at the call to `builder()`. Any of these can then be changed.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// let entry = LogEntry::builder()
+///     .level(Level::WARNING)
+///     .mod_path("importer")
+///     .fn_name("read_row")
+///     .message("Skipped a bad row.")
+///     .field("row", "17")
+///     .build();
+///
+/// let mut h = StringHandler::create("").unwrap();
+/// h.publish(&entry);
+///
+/// assert_eq!(h.get_log(), "importer->read_row [WARNING] Skipped a bad row. row=17\n");
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct LogEntryBuilder {
+    entry: LogEntry,
+}
+
+impl LogEntryBuilder {
+    #[track_caller]
+    pub(super) fn create() -> Self {
+        LogEntryBuilder {
+            entry: LogEntry::create(Level::INFO, String::new(), String::new()),
+        }
+    }
+
+    ///
+    /// Set the correlation ID.
+    ///
+    /// ## Parameters
+    /// - `id` - The correlation ID.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn correlation_id(mut self, id: &str) -> Self {
+        self.entry.correlation_id = Some(id.to_string());
+        self
+    }
+
+    ///
+    /// Attach an error, with its `source()` chain.
+    ///
+    /// ## Parameters
+    /// - `err` - The error.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn error(mut self, err: &dyn error::Error) -> Self {
+        self.entry.set_error(err);
+        self
+    }
+
+    ///
+    /// Attach a field, after those already attached.
+    ///
+    /// ## Parameters
+    /// - `key` - The key of the field.
+    /// - `value` - The value of the field.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn field(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.entry.add_field(key, value);
+        self
+    }
+
+    ///
+    /// Set the name of the function/method in which the log entry was created.
+    ///
+    /// ## Parameters
+    /// - `fn_name` - The function/method name.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn fn_name(mut self, fn_name: &str) -> Self {
+        self.entry.set_fn_name(fn_name.to_string());
+        self
+    }
+
+    ///
+    /// Set the level.
+    ///
+    /// ## Parameters
+    /// - `level` - The level.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn level(mut self, level: Level) -> Self {
+        self.entry.level = level;
+        self
+    }
+
+    ///
+    /// Set the source code location. Use `0` for `line` and `column` if not known.
+    ///
+    /// ## Parameters
+    /// - `file` - The source file.
+    /// - `line` - The line.
+    /// - `column` - The column.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn location(mut self, file: &str, line: u32, column: u32) -> Self {
+        self.entry.set_location(file.to_string(), line, column);
+        self
+    }
+
+    ///
+    /// Set the message.
+    ///
+    /// ## Parameters
+    /// - `message` - The message.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn message(mut self, message: &str) -> Self {
+        self.entry.message = message.to_string();
+        self
+    }
+
+    ///
+    /// Set the module path.
+    ///
+    /// ## Parameters
+    /// - `mod_path` - The module path.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn mod_path(mut self, mod_path: &str) -> Self {
+        self.entry.set_mod_path(mod_path.to_string());
+        self
+    }
+
+    ///
+    /// Set the timestamp.
+    ///
+    /// ## Parameters
+    /// - `timestamp` - The date and time.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn timestamp(mut self, timestamp: DateTime<Local>) -> Self {
+        self.entry.timestamp = timestamp;
+        self
+    }
+
+    ///
+    /// Returns the completed log entry.
+    ///
+    pub fn build(self) -> LogEntry {
+        self.entry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use chrono::{Local, TimeZone};
+    use std::io::{Error, ErrorKind};
+
+    #[test]
+    fn builder() {
+        let timestamp = Local.with_ymd_and_hms(2025, 7, 1, 9, 30, 0).unwrap();
+
+        let defaults = LogEntry::builder().build();
+        assert_eq!(defaults.level(), Level::INFO);
+        assert_eq!(defaults.file(), file!());
+        assert!(defaults.seq() > 0);
+
+        let entry = LogEntry::builder()
+            .timestamp(timestamp)
+            .location("other.rs", 7, 3)
+            .correlation_id("req-1")
+            .error(&Error::new(ErrorKind::NotFound, "gone"))
+            .build();
+
+        assert_eq!(entry.timestamp(), timestamp);
+        assert_eq!(
+            (entry.file(), entry.line(), entry.column()),
+            ("other.rs", 7, 3)
+        );
+        assert_eq!(entry.correlation_id(), Some("req-1"));
+        assert_eq!(entry.error(), Some("gone"));
+    }
+}
@@ -0,0 +1,678 @@
+//
+// File Name:    log_manager.rs
+// Directory:    src/logger
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Log Manager
+//!
+//! Keeps a registry of all the live [`Logger`]s, so that they can be
+//! reconfigured at runtime, without restarting the process.
+//!
+//! Also holds the named [`SharedLogger`]s, for access from anywhere in the
+//! program with [`Logger::get_logger()`].
+//!
+
+use super::level_map::is_within;
+use crate::*;
+use std::collections::HashMap;
+use std::sync::{
+    Arc, LazyLock, Mutex, PoisonError, Weak,
+    atomic::{AtomicBool, Ordering},
+};
+#[cfg(feature = "watch")]
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread::{self, JoinHandle},
+    time::{Duration, SystemTime},
+};
+
+///
+/// A reconfiguration, to be applied to each of the matching loggers.
+///
+type Reconfig = Arc<dyn Fn(&mut Logger) + Send + Sync>;
+
+///
+/// A change of levels, to be applied to each of the matching loggers.
+///
+/// Unlike a [`Reconfig`], it only needs a shared reference to the logger.
+///
+type Relevel = Arc<dyn Fn(&Logger) + Send + Sync>;
+
+///
+/// A registered logger: its module path and mailbox.
+///
+type Registration = (String, Weak<Mailbox>);
+
+///
+/// The registry of live loggers.
+///
+static REGISTRY: LazyLock<Mutex<Vec<Registration>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+///
+/// A [`Logger`] that is shared, between modules and threads.
+///
+/// Produced by [`LoggerBuilder::build_shared()`], and obtained with
+/// [`Logger::get_logger()`].
+///
+pub type SharedLogger = Arc<Mutex<Logger>>;
+
+///
+/// The shared loggers, by name.
+///
+static SHARED: LazyLock<Mutex<HashMap<String, SharedLogger>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+///
+/// Set while every logger is muted. See [`LogManager::mute()`].
+///
+static MUTED: AtomicBool = AtomicBool::new(false);
+
+///
+/// Holds the reconfigurations waiting to be applied to a logger.
+///
+/// They are applied by the logger itself. The level changes are applied by its next
+/// log entry. The other reconfigurations, the next time it is checked with
+/// [`is_enabled()`][Logger::is_enabled], or is locked by one of the logging macros.
+///
+#[derive(Default)]
+pub(crate) struct Mailbox {
+    pending: AtomicBool,
+    reconfigs: Mutex<Vec<Reconfig>>,
+    relevel_pending: AtomicBool,
+    relevels: Mutex<Vec<Relevel>>,
+}
+
+impl Mailbox {
+    ///
+    /// Create a new mailbox, registering it against `mod_path`.
+    ///
+    pub(crate) fn register(mod_path: &str) -> Arc<Mailbox> {
+        let mailbox = Arc::new(Mailbox::default());
+        let mut registry = REGISTRY.lock().unwrap_or_else(PoisonError::into_inner);
+
+        registry.retain(|(_, mb)| mb.strong_count() > 0);
+        registry.push((mod_path.to_string(), Arc::downgrade(&mailbox)));
+
+        mailbox
+    }
+
+    ///
+    /// Checks whether there are any reconfigurations waiting to be applied.
+    ///
+    pub(crate) fn is_pending(&self) -> bool {
+        self.pending.load(Ordering::Acquire)
+    }
+
+    ///
+    /// Apply any pending reconfigurations to `logger`.
+    ///
+    pub(crate) fn deliver(&self, logger: &mut Logger) {
+        if self.pending.swap(false, Ordering::AcqRel) {
+            let reconfigs = std::mem::take(
+                &mut *self
+                    .reconfigs
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner),
+            );
+
+            for reconfig in reconfigs {
+                reconfig(logger);
+            }
+        }
+    }
+
+    ///
+    /// Checks whether there are any level changes waiting to be applied.
+    ///
+    pub(crate) fn is_relevel_pending(&self) -> bool {
+        self.relevel_pending.load(Ordering::Acquire)
+    }
+
+    ///
+    /// Apply any pending level changes to `logger`.
+    ///
+    pub(crate) fn deliver_levels(&self, logger: &Logger) {
+        if self.relevel_pending.swap(false, Ordering::AcqRel) {
+            let relevels =
+                std::mem::take(&mut *self.relevels.lock().unwrap_or_else(PoisonError::into_inner));
+
+            for relevel in relevels {
+                relevel(logger);
+            }
+        }
+    }
+
+    fn post(&self, reconfig: Reconfig) {
+        self.reconfigs
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(reconfig);
+        self.pending.store(true, Ordering::Release);
+    }
+
+    fn post_relevel(&self, relevel: Relevel) {
+        self.relevels
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(relevel);
+        self.relevel_pending.store(true, Ordering::Release);
+    }
+}
+
+///
+/// Provides runtime access to all of the live [`Logger`]s.
+///
+/// Every `Logger` produced by [`LoggerBuilder::build()`] is registered automatically,
+/// against its module path, and is removed when dropped.
+///
+/// A reconfiguration is applied by each matching logger itself, on whichever thread that
+/// happens to be. So, there is no need for any locking by the caller.
+///
+/// The level changes, made by [`set_level()`][LogManager::set_level],
+/// [`apply_directives()`][LogManager::apply_directives], and a watched configuration file,
+/// are applied by the next log entry, even from a logging method such as [`Logger::info()`],
+/// which only has `&self`.
+///
+/// Any other reconfiguration, posted by [`reconfigure()`][LogManager::reconfigure], needs
+/// `&mut` access to the logger. So it is applied the next time the logger is checked with
+/// [`Logger::is_enabled()`], or is locked by one of the logging macros.
+///
+/// ## Examples
+/// ```
+/// use flogging::*;
+///
+/// let mut log = Logger::builder("my_app::db")
+///     .add_string_handler()
+///     .build();
+///
+/// // Elsewhere, say on receiving a signal:
+/// LogManager::set_level("my_app", Level::FINE);
+///
+/// log.fine("Now visible.");
+///
+/// let buf = log.get_handler(Handler::String).unwrap().get_log();
+/// assert_eq!(buf, "my_app::db-> [FINE   ] Now visible.\n");
+/// ```
+///
+pub struct LogManager;
+
+impl LogManager {
+    ///
+    /// Apply a directives spec to each of the live loggers.
+    ///
+    /// The global level, if any, is set on every logger, and the per-module levels are
+    /// added to their [`LevelMap`]s. See [`LevelMap::parse_directives()`] for the syntax.
+    ///
+    /// ## Parameters
+    /// - `spec` - The directives, such as: `info,my_app::net=finest,other_crate=off`.
+    ///
+    /// Returns the number of loggers to be reconfigured, or an error if `spec` is invalid.
+    ///
+    pub fn apply_directives(spec: &str) -> Result<usize, LevelError> {
+        let (level, map) = LevelMap::parse_directives(spec)?;
+
+        Ok(LogManager::relevel("", move |logger| {
+            if let Some(level) = level {
+                logger.store_level(level);
+            }
+
+            for (mod_path, level) in map.iter() {
+                logger.store_module_level(mod_path, level);
+            }
+        }))
+    }
+
+    ///
+    /// Obtain a shared handle to the logger registered as `name`.
+    ///
+    /// See [`Logger::get_logger()`].
+    ///
+    /// ## Parameters
+    /// - `name` - The name of the logger: its module path.
+    ///
+    pub fn get_logger(name: &str) -> Option<SharedLogger> {
+        SHARED
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(name)
+            .cloned()
+    }
+
+    ///
+    /// Checks whether every logger is muted. See [`mute()`][LogManager::mute].
+    ///
+    pub fn is_muted() -> bool {
+        MUTED.load(Ordering::Relaxed)
+    }
+
+    ///
+    /// Returns the module paths of all of the live loggers.
+    ///
+    pub fn loggers() -> Vec<String> {
+        let mut registry = REGISTRY.lock().unwrap_or_else(PoisonError::into_inner);
+
+        registry.retain(|(_, mb)| mb.strong_count() > 0);
+        registry.iter().map(|(path, _)| path.clone()).collect()
+    }
+
+    ///
+    /// Silence every logger, whatever its level, until [`unmute()`][LogManager::unmute]
+    /// is called.
+    ///
+    /// Takes effect straight away, on every thread. Useful in tests and benchmarks, which
+    /// don't want any log output. The log entries are counted as rejected.
+    ///
+    /// ## Examples
+    /// Run as a standalone crate, so that muting does not silence the other doctests.
+    /// ```standalone_crate
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::string_logger("app");
+    ///
+    /// LogManager::mute();
+    /// log.severe("Not logged.");
+    /// assert!(!log.is_enabled(Level::SEVERE));
+    ///
+    /// LogManager::unmute();
+    /// log.info("Logged.");
+    ///
+    /// assert_eq!(
+    ///     log.get_handler(Handler::String).unwrap().get_log(),
+    ///     "app-> [INFO   ] Logged.\n"
+    /// );
+    /// ```
+    ///
+    pub fn mute() {
+        MUTED.store(true, Ordering::Relaxed);
+    }
+
+    ///
+    /// Reconfigure each of the live loggers, whose module path is `mod_path`,
+    /// or one of its sub-modules.
+    ///
+    /// ## Parameters
+    /// - `mod_path` - The module path to match. Use `""` to match every logger.
+    /// - `reconfig` - Applied to each matching logger. For example, to change its
+    ///   level, or a handler's formatter.
+    ///
+    /// Returns the number of loggers to be reconfigured.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// LogManager::reconfigure("my_app::net", |logger| {
+    ///     if let Some(handler) = logger.get_handler(Handler::Console) {
+    ///         handler.set_formatter(FormatType::Iso8601.create(None));
+    ///     }
+    /// });
+    /// ```
+    ///
+    pub fn reconfigure<F>(mod_path: &str, reconfig: F) -> usize
+    where
+        F: Fn(&mut Logger) + Send + Sync + 'static,
+    {
+        let reconfig: Reconfig = Arc::new(reconfig);
+
+        LogManager::post_matching(mod_path, |mailbox| mailbox.post(Arc::clone(&reconfig)))
+    }
+
+    ///
+    /// Change the levels of each of the live loggers, whose module path is `mod_path`,
+    /// or one of its sub-modules.
+    ///
+    /// The same as [`reconfigure()`][LogManager::reconfigure], except that `relevel`
+    /// only needs a shared reference, so it is applied by the next log entry.
+    ///
+    /// Returns the number of loggers to be reconfigured.
+    ///
+    fn relevel<F>(mod_path: &str, relevel: F) -> usize
+    where
+        F: Fn(&Logger) + Send + Sync + 'static,
+    {
+        let relevel: Relevel = Arc::new(relevel);
+
+        LogManager::post_matching(mod_path, |mailbox| {
+            mailbox.post_relevel(Arc::clone(&relevel))
+        })
+    }
+
+    ///
+    /// Call `post` with the mailbox of each of the live loggers, whose module path is
+    /// `mod_path`, or one of its sub-modules.
+    ///
+    /// Returns the number of loggers posted to.
+    ///
+    fn post_matching(mod_path: &str, post: impl Fn(&Mailbox)) -> usize {
+        let mut registry = REGISTRY.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut count = 0;
+
+        registry.retain(|(path, mb)| match mb.upgrade() {
+            Some(mailbox) => {
+                if mod_path.is_empty() || is_within(path, mod_path) {
+                    post(&mailbox);
+                    count += 1;
+                }
+
+                true
+            }
+            None => false,
+        });
+
+        count
+    }
+
+    ///
+    /// Remove the logger registered as `name`.
+    ///
+    /// The logger is dropped once every other shared handle to it has gone.
+    ///
+    /// ## Parameters
+    /// - `name` - The name of the logger: its module path.
+    ///
+    /// Returns the removed logger, if there was one.
+    ///
+    pub fn remove_logger(name: &str) -> Option<SharedLogger> {
+        SHARED
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(name)
+    }
+
+    ///
+    /// Set the logging level of each of the live loggers, whose module path is `mod_path`,
+    /// or one of its sub-modules.
+    ///
+    /// ## Parameters
+    /// - `mod_path` - The module path to match. Use `""` to match every logger.
+    /// - `level` - The new logging level to set.
+    ///
+    /// Returns the number of loggers to be reconfigured.
+    ///
+    pub fn set_level(mod_path: &str, level: Level) -> usize {
+        LogManager::relevel(mod_path, move |logger| {
+            logger.store_level(level);
+        })
+    }
+
+    ///
+    /// Register `logger` as `name`, replacing any previously registered one.
+    ///
+    pub(crate) fn share(name: &str, logger: Logger) -> SharedLogger {
+        let shared = Arc::new(Mutex::new(logger));
+
+        let previous = SHARED
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(name.to_string(), Arc::clone(&shared));
+        drop(previous);
+
+        shared
+    }
+
+    ///
+    /// Undo [`mute()`][LogManager::mute], so that each logger logs according to its
+    /// own level again.
+    ///
+    pub fn unmute() {
+        MUTED.store(false, Ordering::Relaxed);
+    }
+
+    ///
+    /// Watch a configuration file, applying its directives to the live loggers whenever
+    /// it changes.
+    ///
+    /// Requires the `watch` feature.
+    ///
+    /// The file holds a directives spec, as used by [`apply_directives()`][LogManager::apply_directives].
+    /// Lines starting with `#` are ignored, and the remaining lines are joined with commas. For example:
+    /// ```text
+    /// # my_app logging
+    /// info
+    /// my_app::net=finest
+    /// other_crate=off
+    /// ```
+    ///
+    /// The file is checked every `interval`, on a background thread, and is applied straight away
+    /// if it exists. An invalid spec is ignored, leaving the current configuration in place. See
+    /// [`ConfigWatcher::last_error()`].
+    ///
+    /// ## Parameters
+    /// - `path` - The path of the configuration file.
+    /// - `interval` - How often to check for changes.
+    ///
+    /// Returns the `ConfigWatcher`. The file is watched until it is stopped, or dropped.
+    ///
+    #[cfg(feature = "watch")]
+    pub fn watch<P: AsRef<Path>>(path: P, interval: Duration) -> ConfigWatcher {
+        ConfigWatcher::spawn(path.as_ref().to_path_buf(), interval)
+    }
+}
+
+///
+/// Watches a configuration file, on a background thread.
+///
+/// Returned by [`LogManager::watch()`]. Requires the `watch` feature.
+///
+#[cfg(feature = "watch")]
+pub struct ConfigWatcher {
+    stop: Arc<AtomicBool>,
+    last_error: Arc<Mutex<Option<String>>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+#[cfg(feature = "watch")]
+impl ConfigWatcher {
+    fn spawn(path: PathBuf, interval: Duration) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let last_error = Arc::new(Mutex::new(None));
+        let (thread_stop, thread_error) = (Arc::clone(&stop), Arc::clone(&last_error));
+
+        let thread = thread::spawn(move || {
+            let mut modified: Option<SystemTime> = None;
+
+            while !thread_stop.load(Ordering::Acquire) {
+                let current = fs::metadata(&path).and_then(|m| m.modified()).ok();
+
+                if current.is_some() && current != modified {
+                    modified = current;
+
+                    let result = fs::read_to_string(&path)
+                        .map_err(|e| e.to_string())
+                        .and_then(|text| {
+                            LogManager::apply_directives(&directives(&text))
+                                .map_err(|e| e.to_string())
+                        });
+
+                    *thread_error.lock().unwrap_or_else(PoisonError::into_inner) = result.err();
+                }
+
+                thread::park_timeout(interval);
+            }
+        });
+
+        ConfigWatcher {
+            stop,
+            last_error,
+            thread: Some(thread),
+        }
+    }
+
+    ///
+    /// Returns the error from the last attempt to apply the configuration file, if it failed.
+    ///
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    ///
+    /// Stop watching the configuration file.
+    ///
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, Ordering::Release);
+
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(feature = "watch")]
+impl Drop for ConfigWatcher {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+///
+/// Convert the text of a configuration file into a directives spec.
+///
+#[cfg(feature = "watch")]
+fn directives(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect::<Vec<&str>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn reconfigure() {
+        let mut log = Logger::builder("log_manager::reconfigure")
+            .add_string_handler()
+            .build();
+
+        assert!(LogManager::loggers().contains(&"log_manager::reconfigure".to_string()));
+        assert_eq!(
+            LogManager::reconfigure("log_manager::reconfigure", |logger| {
+                logger.set_level(Level::FINEST);
+
+                if let Some(handler) = logger.get_handler(Handler::String) {
+                    handler.set_formatter(FormatType::Custom.create(None));
+                }
+            }),
+            1
+        );
+        assert_eq!(LogManager::set_level("log_manager::other", Level::OFF), 0);
+
+        assert!(log.is_enabled(Level::FINEST));
+        log.finest("Now visible.");
+
+        assert_eq!(log.level(), Level::FINEST);
+        assert_eq!(
+            log.get_handler(Handler::String).unwrap().get_log(),
+            "MockFormatter\n"
+        );
+
+        drop(log);
+        assert!(!LogManager::loggers().contains(&"log_manager::reconfigure".to_string()));
+    }
+
+    #[test]
+    fn set_level() {
+        let mut log = Logger::builder("log_manager::set_level")
+            .add_string_handler()
+            .build();
+
+        assert_eq!(
+            LogManager::set_level("log_manager::set_level", Level::FINE),
+            1
+        );
+
+        // Applied by the log entry itself, through `&self`.
+        log.fine("Now visible.");
+        log.finer("Still hidden.");
+
+        assert_eq!(
+            log.get_handler(Handler::String).unwrap().get_log(),
+            "log_manager::set_level-> [FINE   ] Now visible.\n"
+        );
+    }
+
+    #[test]
+    fn shared() {
+        assert!(Logger::get_logger("log_manager::shared").is_none());
+
+        let shared = Logger::builder("log_manager::shared")
+            .add_string_handler()
+            .build_shared();
+
+        let worker = thread::spawn(|| {
+            let log = Logger::get_logger("log_manager::shared").unwrap();
+            log.lock().unwrap().warning("From the worker.");
+        });
+        worker.join().unwrap();
+
+        let log = Logger::get_logger("log_manager::shared").unwrap();
+        assert!(Arc::ptr_eq(&log, &shared));
+        assert_eq!(
+            log.lock()
+                .unwrap()
+                .get_handler(Handler::String)
+                .unwrap()
+                .get_log(),
+            "log_manager::shared-> [WARNING] From the worker.\n"
+        );
+
+        assert!(LogManager::remove_logger("log_manager::shared").is_some());
+        assert!(Logger::get_logger("log_manager::shared").is_none());
+    }
+
+    #[cfg(feature = "watch")]
+    #[test]
+    fn watch() {
+        let filename = "test_logs/log_manager_watch.conf";
+        fs::create_dir_all("test_logs").unwrap();
+        // Only a module-scoped directive, so the other tests' loggers are unaffected.
+        fs::write(filename, "# levels\n\nlog_manager::watch::db = finest\n").unwrap();
+
+        let mut log = Logger::builder("log_manager::watch::db")
+            .add_string_handler()
+            .build();
+
+        let watcher = LogManager::watch(filename, Duration::from_millis(10));
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(watcher.last_error(), None);
+        watcher.stop();
+
+        assert!(log.is_enabled(Level::FINEST));
+        assert_eq!(log.module_level("log_manager::watch::db"), Level::FINEST);
+        assert_eq!(
+            directives("# levels\nwarning\n\n a=fine \n"),
+            "warning,a=fine".to_string()
+        );
+    }
+}
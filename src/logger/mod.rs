@@ -32,35 +32,120 @@
 
 #![allow(clippy::needless_doctest_main)]
 
+mod alert;
+mod async_worker;
+mod build_error;
+mod clock;
 mod builder;
+mod enricher;
+mod handler_store;
 mod level;
+mod level_counts;
+mod level_map;
 mod log_entry;
+mod log_entry_builder;
+mod log_manager;
+mod option_ext;
+mod redactor;
+mod result_ext;
+mod scope;
 
 use anyhow::{Context, Error, Result};
+use std::borrow::Cow;
 use std::cell::{LazyCell, RefCell};
+use std::collections::HashSet;
 use std::collections::hash_map::IterMut;
-use std::collections::{HashMap, HashSet};
 use std::f32::consts;
 use std::fs::{File, exists};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::io::Write;
 use std::marker::PhantomData;
 use std::module_path;
 use std::ops::DerefMut;
 use std::path::Path;
 use std::sync::mpsc::Sender;
-use std::sync::{Arc, MutexGuard, PoisonError, mpsc};
-use std::thread;
-use std::{fmt, fs};
+use std::sync::{
+    Arc, Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard, Weak, mpsc,
+};
+use std::{error, fmt, fs, mem};
 
+pub use alert::Alert;
+use async_worker::AsyncWorker;
+pub use build_error::BuildError;
 pub use builder::*;
-pub use level::Level;
+pub use clock::*;
+pub use enricher::EnricherTrait;
+pub use handler_store::HandlerId;
+pub(crate) use handler_store::{Handlers, Selector};
+pub use level::{FLOGGING_LEVEL, Level, LevelError};
+pub use level_counts::LevelCounts;
+pub use level_map::LevelMap;
 pub use log_entry::LogEntry;
+pub use log_entry_builder::LogEntryBuilder;
+pub(crate) use log_entry::quote;
+use log_manager::Mailbox;
+pub use log_manager::*;
+pub use option_ext::OptionExt;
+pub use redactor::Redactor;
+pub use result_ext::ResultExt;
+pub use scope::{HandlerGuard, Scope};
 
 use crate::*;
 
 ///
 /// This is the work-horse, providing the primary methods of the crate.
 ///
+/// ## Handler Order
+///
+/// The handlers are kept in the order they were added, whether by the [`LoggerBuilder`],
+/// or by [`add_handler()`][Logger::add_handler]. Each log entry is published to them in that
+/// order, and they are listed in that order by the `Display` output of the logger. So, for
+/// example, console output from the first handler always comes before that of the second.
+///
+/// ## Shared References
+///
+/// The logging methods, such as [`info()`][Logger::info], and [`with_fields()`][Logger::with_fields],
+/// as well as [`flush()`][Logger::flush] and [`close()`][Logger::close], only need `&self`. So a logger can be passed around as `&Logger`, and logged to from
+/// immutable contexts. Changing its configuration, such as [`set_level()`][Logger::set_level],
+/// still needs `&mut self`.
+///
+/// A `Logger` is also `Send` and `Sync`. Its handlers are behind a lock, so it can be
+/// shared between threads as an `Arc<Logger>`, or held in a `static LazyLock<Logger>`.
+/// The fields set by [`with_fields()`][Logger::with_fields] are kept for each thread, so
+/// they only go with that thread's next log entry.
+///
+/// Likewise, [`set_fn_name()`][Logger::set_fn_name] needs `&mut self`, and would apply to
+/// every thread. A [`scope()`][Logger::scope] carries the function name instead.
+///
+/// The levels set by the [`LogManager`] are applied by the next log entry, even through
+/// a shared reference. Any other reconfiguration, posted by [`LogManager::reconfigure()`],
+/// needs `&mut` access, so a shared logger does not apply it. Use a [`SharedLogger`] for that.
+///
+/// A handler, enricher, or alert may itself log to the logger it is running for, such as
+/// to report its own failure. That log entry is published once the one being published
+/// has been.
+///
+/// ## Examples
+/// ```
+/// use flogging::*;
+/// use std::{sync::Arc, thread};
+///
+/// let log = Arc::new(Logger::string_logger("app"));
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|id| {
+///         let log = Arc::clone(&log);
+///         thread::spawn(move || log.with_fields([("worker", id.to_string())]).info("Done."))
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+///
+/// assert_eq!(log.counts().accepted(Level::INFO), 4);
+/// ```
+///
 pub struct Logger {
     ///
     /// Identify the source of log messages passed to this logger.
@@ -78,19 +163,92 @@ pub struct Logger {
     ///
     /// Default level used by `log(msg)`.
     ///
-    level: Level,
+    level: RwLock<Level>,
+
+    ///
+    /// The level for the current function/method, which overrides all the others.
+    ///
+    fn_level: Option<Level>,
+
+    ///
+    /// The per-module levels, which override `level`.
+    ///
+    level_map: RwLock<LevelMap>,
+
+    ///
+    /// Holds the handlers associated with this logger, in the order they were added.
+    ///
+    handlers: Mutex<Handlers>,
+
+    ///
+    /// The background worker, if this logger is running asynchronously.
+    ///
+    worker: Option<AsyncWorker>,
+
+    ///
+    /// Receives the reconfigurations from the [`LogManager`].
+    ///
+    mailbox: Arc<Mailbox>,
+
+    ///
+    /// Provides the timestamp of each log entry.
+    ///
+    clock: Arc<dyn ClockTrait>,
+
+    ///
+    /// The number of log entries accepted at each level, and rejected.
+    ///
+    counts: Mutex<LevelCounts>,
+
+    ///
+    /// The alerts checked against each log entry.
+    ///
+    alerts: Mutex<Vec<Alert>>,
+
+    ///
+    /// Keeps only a sample of the log entries below its level, if set.
+    ///
+    sampling: Option<SampleFilter>,
+
+    ///
+    /// The enrichers run on each log entry, in order.
+    ///
+    enrichers: Vec<Box<dyn EnricherTrait>>,
+
+    ///
+    /// Masks personal data and secrets in each log entry, if set.
+    ///
+    redactor: Option<Redactor>,
+
+    ///
+    /// Collapse consecutive identical log entries. See [`set_dedup()`][Logger::set_dedup].
+    ///
+    dedup: bool,
+
+    ///
+    /// The key of the most recent log entry, and the number of times it has since been repeated.
+    ///
+    repeated: Mutex<Option<Repeated>>,
 
     ///
-    /// Holds the handlers associated with this logger.
+    /// The log entries logged while this logger was publishing, on the same thread.
+    /// See `_log()`.
     ///
-    handlers: RefCell<HashMap<Handler, Box<dyn HandlerTrait>>>,
+    nested: Mutex<Vec<LogEntry>>,
+
+    ///
+    /// The address of the module level logger that this is a stand-in for, if it is one.
+    /// See [`stand_in()`][Logger::stand_in].
+    ///
+    stand_in_for: Option<usize>,
 }
 
 impl Logger {
     ///
     /// Create a new Logger instance.
     ///
-    /// Logging level is set to it's default setting (INFO).
+    /// Logging level is set to it's default setting (INFO), unless overridden
+    /// by the [`FLOGGING_LEVEL`] environment variable.
     ///
     /// No `handlers` are set. Use the various methods of
     /// [`LoggerBuilder`] to configure the new `Logger`.
@@ -113,6 +271,77 @@ impl Logger {
         LoggerBuilder::create(mod_path.to_string())
     }
 
+    ///
+    /// Add an alert to this logger, after it has been built.
+    ///
+    /// ## Parameters
+    /// - `alert` - The alert to check each log entry against.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn add_alert(&mut self, alert: Alert) -> &mut Self {
+        lock_mut(&mut self.alerts).push(alert);
+        self
+    }
+
+    ///
+    /// Add an enricher to this logger, after it has been built. It is run after
+    /// those already added.
+    ///
+    /// ## Parameters
+    /// - `enricher` - The enricher to run on each log entry.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn add_enricher(&mut self, enricher: Box<dyn EnricherTrait>) -> &mut Self {
+        self.enrichers.push(enricher);
+        self
+    }
+
+    ///
+    /// Add a handler to this logger, after it has been built.
+    ///
+    /// Any existing handlers, including those of the same kind, are kept.
+    ///
+    /// If this logger is running asynchronously, the handler is added after the log
+    /// entries already queued.
+    ///
+    /// ## Parameters
+    /// - `handler` - The enum identifying the handler.
+    /// - `custom_handler` - The boxed handler to add.
+    ///
+    /// Returns the id of the new handler.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::console_logger("app");
+    ///
+    /// // Start a diagnostic session.
+    /// let id = log.add_handler(Handler::String, Box::new(StringHandler::create("").unwrap()));
+    /// log.warning("Disk almost full.");
+    ///
+    /// // ... and end it.
+    /// let h = log.remove_handler_by_id(id).unwrap();
+    /// assert_eq!(h.get_log(), "app-> [WARNING] Disk almost full.\n");
+    /// ```
+    ///
+    pub fn add_handler(
+        &mut self,
+        handler: Handler,
+        custom_handler: Box<dyn HandlerTrait>,
+    ) -> HandlerId {
+        let id = HandlerId::next();
+
+        match &self.worker {
+            Some(worker) => worker.add_handler(id, handler, custom_handler),
+            None => lock_mut(&mut self.handlers).add_with_id(id, handler, custom_handler),
+        }
+
+        id
+    }
+
     ///
     /// Log a CONFIG message.
     ///
@@ -132,15 +361,17 @@ impl Logger {
     /// log.config("Some text to store.");
     /// ```
     ///
-    pub fn config(&mut self, msg: &str) {
-        self.log(Level::CONFIG, &self.fn_name(), msg);
+    #[track_caller]
+    pub fn config(&self, msg: &str) {
+        self.log(Level::CONFIG, msg.into());
     }
 
     ///
     /// Create new Logger instance, with a `ConsoleHandler`, output
     /// set to: [`std::io::stdout`].
     ///
-    /// Logging level is set to it's default setting (INFO).
+    /// Logging level is set to it's default setting (INFO), unless overridden
+    /// by the [`FLOGGING_LEVEL`] environment variable.
     ///
     /// ## Parameters
     /// - `mod_path`- The module path. Suggest using [`module_path!()`].
@@ -165,6 +396,57 @@ impl Logger {
         Logger::builder(mod_path).add_console_handler().build()
     }
 
+    ///
+    /// Close each of the handlers, after publishing any pending log entries.
+    ///
+    /// If the logger is running asynchronously, this blocks until the queued log
+    /// entries have been published, and the handlers closed.
+    ///
+    /// This is done for you when the logger is dropped.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::file_logger(module_path!(), "test_logs/logger_close.log");
+    ///
+    /// log.info("The last entry.");
+    /// log.close();
+    ///
+    /// assert!(!log.get_handler(Handler::File).unwrap().is_open());
+    /// ```
+    ///
+    pub fn close(&self) {
+        self.publish_repeated();
+        self.for_each_handler(|h| h.close());
+    }
+
+    ///
+    /// Get the number of log entries this logger has accepted at each level, and
+    /// the number it has rejected, because they were below its level.
+    ///
+    /// Useful for health checks, such as: "12 SEVERE since start", and for checking
+    /// the level configuration in tests.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::string_logger(module_path!());
+    /// log.set_level(Level::WARNING);
+    ///
+    /// log.info("Not logged.");
+    /// log.severe("Logged.");
+    ///
+    /// let counts = log.counts();
+    /// assert_eq!(counts.accepted(Level::SEVERE), 1);
+    /// assert_eq!(counts.rejected(), 1);
+    /// ```
+    ///
+    pub fn counts(&self) -> LevelCounts {
+        lock(&self.counts).clone()
+    }
+
     ///
     /// Create new Logger instance, with a custom handler.
     ///
@@ -188,8 +470,8 @@ impl Logger {
     ///
     /// log.warning("Don't over do it.");
     /// ```
-    /// [`MockHandler`] doesn't publish anything, as [`publish()`][MockHandler::publish()] is a **NoOp** method.
-    /// It is used here to make the example work.
+    /// [`MockHandler`] doesn't write anything, as [`publish()`][MockHandler::publish()] only records
+    /// the log entries. It is used here to make the example work.
     ///
     /// However, it is expected that _your_ custom handler will do a little more.
     ///
@@ -203,7 +485,8 @@ impl Logger {
     /// Create new Logger instance, with a `ConsoleHandler`, output
     /// set to: [`std::io::stderr`].
     ///
-    /// Logging level is set to it's default setting (INFO).
+    /// Logging level is set to it's default setting (INFO), unless overridden
+    /// by the [`FLOGGING_LEVEL`] environment variable.
     ///
     /// ## Parameters
     /// - `mod_path`- The module path. Suggest using [`module_path!()`].
@@ -270,8 +553,9 @@ impl Logger {
     /// flogging::my_mod->my_func [FINER  ] Entry
     /// ```
     ///
-    pub fn entering(&mut self) {
-        self.log(Level::FINER, &self.fn_name(), "Entry");
+    #[track_caller]
+    pub fn entering(&self) {
+        self.log(Level::FINER, "Entry".into());
     }
 
     ///
@@ -319,12 +603,9 @@ impl Logger {
     /// flogging::my_mod->my_func [FINER  ] Entry: (data: "Some data")
     /// ```
     ///
-    pub fn entering_with(&mut self, msg: &str) {
-        self.log(
-            Level::FINER,
-            &self.fn_name(),
-            &("Entry: (".to_string() + msg + ")"),
-        );
+    #[track_caller]
+    pub fn entering_with(&self, msg: &str) {
+        self.log(Level::FINER, format!("Entry: ({msg})").into());
     }
 
     ///
@@ -369,8 +650,9 @@ impl Logger {
     /// flogging::my_mod->my_func [FINER  ] Return
     /// ```
     ///
-    pub fn exiting(&mut self) {
-        self.log(Level::FINER, &self.fn_name(), "Return");
+    #[track_caller]
+    pub fn exiting(&self) {
+        self.log(Level::FINER, "Return".into());
     }
 
     ///
@@ -420,18 +702,16 @@ impl Logger {
     /// flogging::my_mod->my_func [FINER  ] Return: (rtn: true)
     /// ```
     ///
-    pub fn exiting_with(&mut self, msg: &str) {
-        self.log(
-            Level::FINER,
-            &self.fn_name(),
-            &("Return: (".to_string() + msg + ")"),
-        );
+    #[track_caller]
+    pub fn exiting_with(&self, msg: &str) {
+        self.log(Level::FINER, format!("Return: ({msg})").into());
     }
 
     ///
     /// Create new Logger instance, with a `FileHandler`.
     ///
-    /// Logging level is set to it's default setting (INFO).
+    /// Logging level is set to it's default setting (INFO), unless overridden
+    /// by the [`FLOGGING_LEVEL`] environment variable.
     ///
     /// ## Parameters
     /// - `mod_path`- The module path. Suggest using [`std::module_path`][mp].
@@ -459,6 +739,35 @@ impl Logger {
         Logger::builder(mod_path).add_file_handler(filename).build()
     }
 
+    ///
+    /// Flush each of the handlers, after publishing any pending log entries.
+    ///
+    /// What flushing does is up to each handler. For example, a [`FileHandler`]
+    /// writes out its buffer, and an `HttpHandler` sends its current batch.
+    /// Note: a [`StringHandler`] clears its log.
+    ///
+    /// If the logger is running asynchronously, this blocks until the queued log
+    /// entries have been published, and the handlers flushed.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::file_logger(module_path!(), "test_logs/logger_flush.log");
+    ///
+    /// log.get_handler_as::<FileHandler>()
+    ///     .unwrap()
+    ///     .set_flush_policy(FlushPolicy::new(1000));
+    ///
+    /// log.info("Buffered.");
+    /// log.flush();
+    /// ```
+    ///
+    pub fn flush(&self) {
+        self.publish_repeated();
+        self.for_each_handler(|h| h.flush());
+    }
+
     ///
     /// Log a FINE message.
     ///
@@ -484,8 +793,9 @@ impl Logger {
     /// flogging->main [FINE   ] Some text to store.
     /// ```
     ///
-    pub fn fine(&mut self, msg: &str) {
-        self.log(Level::FINE, &self.fn_name(), msg);
+    #[track_caller]
+    pub fn fine(&self, msg: &str) {
+        self.log(Level::FINE, msg.into());
     }
 
     ///
@@ -513,8 +823,9 @@ impl Logger {
     /// flogging->main [FINER  ] Some text to store.
     /// ```
     ///
-    pub fn finer(&mut self, msg: &str) {
-        self.log(Level::FINER, &self.fn_name(), msg);
+    #[track_caller]
+    pub fn finer(&self, msg: &str) {
+        self.log(Level::FINER, msg.into());
     }
 
     ///
@@ -542,8 +853,9 @@ impl Logger {
     /// flogging->main [FINEST ] Some text to store.
     /// ```
     ///
-    pub fn finest(&mut self, msg: &str) {
-        self.log(Level::FINEST, &self.fn_name(), msg);
+    #[track_caller]
+    pub fn finest(&self, msg: &str) {
+        self.log(Level::FINEST, msg.into());
     }
 
     ///
@@ -553,9 +865,41 @@ impl Logger {
         self.fn_name.clone()
     }
 
+    ///
+    /// Obtain a shared handle to an existing logger.
+    ///
+    /// Loggers are registered by name, their module path, when built with
+    /// [`LoggerBuilder::build_shared()`]. They remain registered until removed with
+    /// [`LogManager::remove_logger()`].
+    ///
+    /// ## Parameters
+    /// - `name` - The name of the logger: its module path.
+    ///
+    /// Returns Some shared logger, or None if there is no logger registered as `name`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// Logger::builder("my_app::db")
+    ///     .add_console_handler()
+    ///     .build_shared();
+    ///
+    /// // Elsewhere, possibly on another thread:
+    /// let log = Logger::get_logger("my_app::db").unwrap();
+    /// log.lock().unwrap().info("Connected.");
+    /// ```
+    ///
+    pub fn get_logger(name: &str) -> Option<SharedLogger> {
+        LogManager::get_logger(name)
+    }
+
     ///
     /// Get required `Handler`.
     ///
+    /// Where there is more than one of this kind, the first one added is returned.
+    /// Use [`get_handler_by_id()`][Logger::get_handler_by_id] to reach the others.
+    ///
     /// ## Parameters
     /// - `handler` - The enum of the required handler.
     ///
@@ -574,12 +918,139 @@ impl Logger {
     /// println!("{h}");
     /// ```
     pub fn get_handler(&mut self, handler: Handler) -> Option<Box<&mut dyn HandlerTrait>> {
-        match self.handlers.get_mut().get_mut(&handler) {
+        match lock_mut(&mut self.handlers).get_mut(&Selector::Kind(handler)) {
+            Some(val) => Some(Box::new(&mut **val)),
+            None => None,
+        }
+    }
+
+    ///
+    /// Get the first handler of the concrete type `T`.
+    ///
+    /// This gives access to the methods of `T` that are not part of [`HandlerTrait`],
+    /// such as those of a custom handler.
+    ///
+    /// Returns Some handler, or None if there is no handler of that type.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_memory_handler(
+    ///         Box::new(StringHandler::create("target").unwrap()),
+    ///         100,
+    ///         Level::SEVERE,
+    ///     )
+    ///     .build();
+    ///
+    /// let memory = log.get_handler_as::<MemoryHandler>().unwrap();
+    /// memory.set_push_level(Level::WARNING);
+    /// assert_eq!(memory.push_level(), Level::WARNING);
+    /// ```
+    pub fn get_handler_as<T: HandlerTrait + 'static>(&mut self) -> Option<&mut T> {
+        lock_mut(&mut self.handlers)
+            .iter_mut()
+            .find_map(|e| e.handler.as_any_mut().downcast_mut::<T>())
+    }
+
+    ///
+    /// Get the handler with the required id.
+    ///
+    /// ## Parameters
+    /// - `id` - The id of the required handler.
+    ///
+    /// Returns Some boxed handler, or None.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_file_handler("test_logs/get_handler_by_id_1.log")
+    ///     .add_file_handler("test_logs/get_handler_by_id_2.log")
+    ///     .build();
+    ///
+    /// let ids = log.handler_ids(Some(Handler::File));
+    /// assert_eq!(ids.len(), 2);
+    ///
+    /// let second = log.get_handler_by_id(ids[1]).unwrap();
+    /// println!("{second}");
+    /// ```
+    pub fn get_handler_by_id(&mut self, id: HandlerId) -> Option<Box<&mut dyn HandlerTrait>> {
+        match lock_mut(&mut self.handlers).get_mut(&Selector::Id(id)) {
             Some(val) => Some(Box::new(&mut **val)),
             None => None,
         }
     }
 
+    ///
+    /// Get the ids of the handlers added to this logger, in the order they were added.
+    ///
+    /// If this logger is running asynchronously, its handlers are held by the background
+    /// thread, so there are none to report.
+    ///
+    /// ## Parameters
+    /// - `handler` - The kind of handlers required, or `None` for all of them.
+    ///
+    pub fn handler_ids(&self, handler: Option<Handler>) -> Vec<HandlerId> {
+        lock(&self.handlers).ids(handler.as_ref())
+    }
+
+    ///
+    /// Get the counters of the required `Handler`.
+    ///
+    /// These are the handler's own [`stats()`][HandlerTrait::stats], along with the
+    /// number of log entries this logger did not pass to it, because of its level
+    /// or filter.
+    ///
+    /// If this logger is running asynchronously, its handlers are held by the background
+    /// thread, so there are none to report.
+    ///
+    /// ## Parameters
+    /// - `handler` - The enum of the required handler.
+    ///
+    /// Returns `None` if this logger doesn't have the handler.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let log = Logger::string_logger("app");
+    /// log.info("Started.");
+    ///
+    /// let stats = log.handler_stats(Handler::String).unwrap();
+    /// assert_eq!(stats.published(), 1);
+    ///
+    /// assert!(log.handler_stats(Handler::File).is_none());
+    /// ```
+    ///
+    pub fn handler_stats(&self, handler: Handler) -> Option<HandlerStats> {
+        self.handler_stats_for(&Selector::Kind(handler))
+    }
+
+    ///
+    /// Get the counters of the handler with the id `id`.
+    ///
+    /// The same as [`handler_stats()`][Logger::handler_stats], where this logger has
+    /// more than one of the same kind of handler.
+    ///
+    /// ## Parameters
+    /// - `id` - The id of the required handler.
+    ///
+    pub fn handler_stats_by_id(&self, id: HandlerId) -> Option<HandlerStats> {
+        self.handler_stats_for(&Selector::Id(id))
+    }
+
+    fn handler_stats_for(&self, selector: &Selector) -> Option<HandlerStats> {
+        let handlers = lock(&self.handlers);
+        let entry = handlers.get(selector)?;
+        let mut stats = entry.handler.stats();
+        stats.filtered = entry.filtered;
+        Some(stats)
+    }
+
     ///
     /// Check if the required `Handler` has been added to this `Logger`.
     ///
@@ -592,13 +1063,13 @@ impl Logger {
     /// ```
     /// use flogging::*;
     ///
-    /// let mut log = Logger::string_logger(module_path!());
+    /// let log = Logger::string_logger(module_path!());
     /// log.info("Some text to store.");
     ///
     /// println!("This logger has a 'StringHandler': {}", log.has_handler(Handler::String));
     /// ```
     pub fn has_handler(&self, handler: Handler) -> bool {
-        self.handlers.borrow().contains_key(&handler)
+        lock(&self.handlers).contains(&Selector::Kind(handler))
     }
     ///
     /// Log a INFO message.
@@ -625,163 +1096,894 @@ impl Logger {
     /// flogging->main [INFO   ] Some text to store.
     /// ```
     ///
-    pub fn info(&mut self, msg: &str) {
-        self.log(Level::INFO, &self.fn_name(), msg);
+    #[track_caller]
+    pub fn info(&self, msg: &str) {
+        self.log(Level::INFO, msg.into());
     }
 
     ///
-    /// Check if a message of the given level would actually be logged by this logger.
+    /// Check if a message of the given level would actually be logged by this logger,
+    /// after applying any pending reconfiguration from the [`LogManager`]. Nothing is
+    /// logged while the loggers are muted, by [`LogManager::mute()`].
+    ///
+    /// The logging macros do the same, through a [`Scope`], to skip formatting their
+    /// arguments, when the log entry would be discarded anyway.
     ///
     /// ## Parameters
     /// - `level` - The level to compare with.
     ///
     /// Returns `true` if it is loggable, `false` if not.
     ///
-    fn is_loggable(&self, level: &Level) -> bool {
-        *level >= self.level
-    }
-
-    ///
-    /// Checks whether or not this logger is processing log requests.
-    ///
-    /// Returns `true` if it is, `false` if not.
-    ///
     /// ## Examples
     /// ```
     /// use flogging::*;
     ///
-    /// let mut log = Logger::console_logger(module_path!());
-    /// log.set_level(Level::OFF);
-    /// log.set_fn_name("main");
-    ///
-    /// let msg = "The program might become unstable.";
-    /// log.warning(msg);
+    /// let mut log = Logger::string_logger(module_path!());
     ///
-    /// if !log.is_logging() {
-    ///     eprintln!{"{msg}"};
-    /// }
-    /// ```
-    /// Output to [`std::io::stderr`]:
-    /// ```text
-    /// The program might become unstable.
+    /// assert!(log.is_enabled(Level::INFO));
+    /// assert!(!log.is_enabled(Level::FINEST));
     /// ```
     ///
-    pub fn is_logging(&self) -> bool {
-        self.level() != &Level::OFF
+    pub fn is_enabled(&mut self, level: Level) -> bool {
+        self.reconfigure();
+        self.is_loggable(&level)
     }
 
     ///
-    /// Obtain the current logging level for this Log instance.
+    /// Check if a message of the given level would actually be logged by this logger.
+    ///
+    /// ## Parameters
+    /// - `level` - The level to compare with.
     ///
-    pub fn level(&self) -> &Level {
-        &self.level
+    /// Returns `true` if it is loggable, `false` if not.
+    ///
+    pub(crate) fn is_loggable(&self, level: &Level) -> bool {
+        self.is_loggable_in(self.fn_level, level)
     }
 
     ///
-    /// Log a `LogEntry`.
+    /// Check if a message of the given level would actually be logged by this logger,
+    /// from within a function with the given level.
     ///
-    /// All the other logging methods in this class call through this method to actually
+    /// ## Parameters
+    /// - `fn_level` - The level for the function, if not the logger's.
+    /// - `level` - The level to compare with.
+    ///
+    /// Returns `true` if it is loggable, `false` if not.
+    ///
+    fn is_loggable_in(&self, fn_level: Option<Level>, level: &Level) -> bool {
+        match fn_level {
+            Some(fn_level) => !LogManager::is_muted() && *level >= fn_level,
+            None => self.is_loggable_from(&self.mod_path, level),
+        }
+    }
+
+    ///
+    /// Check if a message of the given level, from the given module, would actually
+    /// be logged by this logger.
+    ///
+    /// ## Parameters
+    /// - `mod_path` - The module path of the source of the message.
+    /// - `level` - The level to compare with.
+    ///
+    /// Returns `true` if it is loggable, `false` if not.
+    ///
+    pub(crate) fn is_loggable_from(&self, mod_path: &str, level: &Level) -> bool {
+        !LogManager::is_muted() && *level >= self.module_level(mod_path)
+    }
+
+    ///
+    /// Checks whether or not this logger is publishing its log entries
+    /// asynchronously, on a background thread.
+    ///
+    /// See [`LoggerBuilder::set_async()`].
+    ///
+    pub fn is_async(&self) -> bool {
+        self.worker.is_some()
+    }
+
+    ///
+    /// Checks whether or not this logger is processing log requests.
+    ///
+    /// Returns `true` if it is, `false` if not.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::console_logger(module_path!());
+    /// log.set_level(Level::OFF);
+    /// log.set_fn_name("main");
+    ///
+    /// let msg = "The program might become unstable.";
+    /// log.warning(msg);
+    ///
+    /// if !log.is_logging() {
+    ///     eprintln!{"{msg}"};
+    /// }
+    /// ```
+    /// Output to [`std::io::stderr`]:
+    /// ```text
+    /// The program might become unstable.
+    /// ```
+    ///
+    pub fn is_logging(&self) -> bool {
+        self.level() != Level::OFF
+    }
+
+    ///
+    /// Obtain the current logging level for this Log instance.
+    ///
+    pub fn level(&self) -> Level {
+        self.relevel();
+        *read(&self.level)
+    }
+
+    ///
+    /// Obtain a copy of the per-module levels for this Log instance.
+    ///
+    pub fn level_map(&self) -> LevelMap {
+        self.relevel();
+        read(&self.level_map).clone()
+    }
+
+    ///
+    /// Log a `LogEntry`.
+    ///
+    /// All the other logging methods in this class call through this method to actually
     /// perform any logging.
     ///
+    /// A log entry logged from inside one of this logger's own handlers, enrichers, or
+    /// alerts, on the same thread, is queued. It is then published by the outer call,
+    /// once that has finished with them.
+    ///
     /// ## Parameters
     /// - `entry` - The `LogEntry` to be published.
     ///
-    fn _log(&mut self, entry: &mut LogEntry) {
-        entry.set_mod_path(self.mod_path.clone());
+    fn _log(&self, entry: LogEntry) {
+        let Some(_publishing) = Publishing::enter(self) else {
+            lock(&self.nested).push(entry);
+            return;
+        };
+
+        self.publish_entry(entry);
+        self.publish_nested();
+    }
+
+    ///
+    /// Publish the log entries queued by `_log()`, until there are none left.
+    ///
+    /// Only called while this thread is marked as publishing to this logger.
+    ///
+    fn publish_nested(&self) {
+        loop {
+            let nested = mem::take(&mut *lock(&self.nested));
+
+            if nested.is_empty() {
+                break;
+            }
+
+            for entry in nested {
+                self.publish_entry(entry);
+            }
+        }
+    }
+
+    ///
+    /// Enrich, redact, and check `entry` against the alerts, then send it to the handlers.
+    ///
+    fn publish_entry(&self, mut entry: LogEntry) {
+        if entry.mod_path.is_empty() {
+            entry.set_mod_path(self.mod_path.clone());
+        }
+
+        for enricher in &self.enrichers {
+            enricher.enrich(&mut entry);
+        }
+
+        if let Some(redactor) = &self.redactor {
+            redactor.redact(&mut entry);
+        }
+
+        #[cfg(feature = "metrics")]
+        crate::metrics::published(entry.level());
+
+        for alert in lock(&self.alerts).iter_mut() {
+            alert.check(&entry);
+        }
+
+        if self.dedup {
+            let key = Repeated::key(&entry);
+            let mut repeated = lock(&self.repeated);
+
+            if let Some(r) = repeated.as_mut().filter(|r| r.key == key) {
+                r.last = Some(entry);
+                r.count += 1;
+                return;
+            }
+
+            let previous = repeated.replace(Repeated {
+                key,
+                count: 0,
+                last: None,
+            });
+            drop(repeated);
+            self.send_repeated(previous);
+        }
+
+        self.send(entry);
+    }
+
+    ///
+    /// Log a message at a level chosen at runtime.
+    ///
+    /// If the logger is currently enabled for `level`, then the message is forwarded to
+    /// all the registered output `Handler` objects. Levels `ALL` and `OFF` are not logged.
+    ///
+    /// ## Parameters
+    /// - `level` - The level of the message.
+    /// - `msg` - The string message.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::string_logger("app");
+    /// log.set_fn_name("check_disk");
+    ///
+    /// for free_pct in [50, 5] {
+    ///     let level = if free_pct < 10 { Level::WARNING } else { Level::INFO };
+    ///     log.log_at(level, &format!("Free space: {free_pct}%"));
+    /// }
+    ///
+    /// let h = log.get_handler(Handler::String).unwrap();
+    /// assert_eq!(
+    ///     h.get_log(),
+    ///     "app->check_disk [INFO   ] Free space: 50%\n\
+    ///      app->check_disk [WARNING] Free space: 5%\n"
+    /// );
+    /// ```
+    ///
+    #[track_caller]
+    pub fn log_at(&self, level: Level, msg: &str) {
+        if !matches!(level, Level::ALL | Level::OFF) {
+            self.log(level, msg.into());
+        }
+    }
+
+    ///
+    /// Log a message that has already been formatted.
+    ///
+    /// This is used by the code generated by the logging macros, so that the
+    /// `String` they format becomes the message of the log entry, without being copied.
+    ///
+    /// ## Parameters
+    /// - `level` - The level of the message.
+    /// - `msg` - The string message.
+    ///
+    #[doc(hidden)]
+    #[track_caller]
+    pub fn log_owned(&self, level: Level, msg: String) {
+        self.log(level, msg.into());
+    }
+
+    ///
+    /// Log a message, with no arguments.
+    ///
+    /// If the logger is currently enabled for the given message level then the given
+    /// message is forwarded to all the registered output `Handler` objects.
+    ///
+    /// ## Parameters
+    /// - `level` - One of the message level identifiers, e.g., SEVERE.
+    /// - `msg` - The string message. It is only copied if it is logged, and borrowed.
+    ///
+    #[track_caller]
+    fn log(&self, level: Level, msg: Cow<'_, str>) {
+        self.log_error(level, msg, None);
+    }
+
+    ///
+    /// Log a message, with an optional error attached.
+    ///
+    /// The same as [`log()`][Logger::log], with the error's message and its
+    /// [`source()`][error::Error::source] chain stored on the `LogEntry`.
+    ///
+    #[track_caller]
+    fn log_error(&self, level: Level, msg: Cow<'_, str>, error: Option<&dyn error::Error>) {
+        self.log_in(&self.fn_name, self.fn_level, level, msg, error);
+    }
+
+    ///
+    /// Log a message, with an optional error attached, from within the given function.
+    ///
+    /// The same as [`log_error()`][Logger::log_error], with the function's name and
+    /// level supplied, rather than this logger's. See [`Scope`].
+    ///
+    #[track_caller]
+    fn log_in(
+        &self,
+        fn_name: &str,
+        fn_level: Option<Level>,
+        level: Level,
+        msg: Cow<'_, str>,
+        error: Option<&dyn error::Error>,
+    ) {
+        let mut fields = self.take_fields();
+
+        if !self.is_loggable_in(fn_level, &level) || !self.sample(level, &mut fields) {
+            lock(&self.counts).reject();
+            return;
+        }
+
+        lock(&self.counts).accept(level);
+
+        // build LogEntry
+        let mut log_entry = LogEntry::create_at(
+            level,
+            fn_name.to_string(),
+            msg.into_owned(),
+            self.clock.now(),
+        );
+        log_entry.set_fields(fields);
+
+        if let Some(error) = error {
+            log_entry.set_error(error);
+        }
+
+        // Send LogEntry
+        self._log(log_entry);
+    }
+
+    ///
+    /// Log a message on behalf of another module.
+    ///
+    /// The same as [`log()`][Logger::log], except that the `LogEntry` has the
+    /// supplied module path, rather than this logger's.
+    ///
+    /// ## Parameters
+    /// - `level` - One of the message level identifiers, e.g., SEVERE.
+    /// - `mod_path` - The module path of the source of the message.
+    /// - `msg` - The string message.
+    /// - `location` - The source file and line of the message, if known.
+    ///
+    #[cfg(feature = "log")]
+    pub(crate) fn log_from(
+        &self,
+        level: Level,
+        mod_path: &str,
+        msg: &str,
+        location: Option<(&str, u32)>,
+    ) {
+        let mut fields = Vec::new();
+
+        if !self.is_loggable_from(mod_path, &level) || !self.sample(level, &mut fields) {
+            lock(&self.counts).reject();
+            return;
+        }
+
+        lock(&self.counts).accept(level);
+
+        let mut log_entry =
+            LogEntry::create_at(level, String::new(), msg.to_string(), self.clock.now());
+        log_entry.set_mod_path(mod_path.to_string());
+        log_entry.set_fields(fields);
+
+        if let Some((file, line)) = location {
+            log_entry.set_location(file.to_string(), line, 0);
+        }
+
+        self._log(log_entry);
+    }
+
+    ///
+    /// Obtain the logging level that applies to log entries from `mod_path`.
+    ///
+    /// This is the level set for the most specific matching module path in the
+    /// [`level_map()`][Logger::level_map], otherwise this logger's [`level()`][Logger::level].
+    ///
+    /// ## Parameters
+    /// - `mod_path` - The module path to look up.
+    ///
+    pub fn module_level(&self, mod_path: &str) -> Level {
+        self.relevel();
+        read(&self.level_map)
+            .get(mod_path)
+            .unwrap_or_else(|| *read(&self.level))
+    }
+
+    ///
+    /// Create new Logger instance, with a `ConsoleHandler`, output
+    /// according to: [`ConsoleType::Production`].
+    ///
+    /// Logging level is set to it's default setting (INFO), unless overridden
+    /// by the [`FLOGGING_LEVEL`] environment variable.
+    ///
+    /// All `INFO` level log entries are output to [`std::io::stdout`], without any formatting.
+    /// All other levels are processed through the formatter, first, and output to
+    /// [`std::io::stderr`]. Ideal for CLI apps, where `INFO` is the normal output.
+    ///
+    /// ## Parameters
+    /// - `mod_path`- The module path. Suggest using [`module_path!()`].
+    ///
+    /// Returns a configured `Logger`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::pconsole_logger(module_path!());
+    /// log.set_fn_name("main");
+    ///
+    /// log.warning("Don't over do it.");
+    /// ```
+    /// Output to stderr:
+    /// ```text
+    /// flogging->main [WARNING] Don't over do it.
+    /// ```
+    ///
+    pub fn pconsole_logger(mod_path: &str) -> Logger {
+        Logger::builder(mod_path).add_pconsole_handler().build()
+    }
+
+    ///
+    /// Apply any reconfigurations posted by the [`LogManager`].
+    ///
+    pub(crate) fn reconfigure(&mut self) {
+        self.relevel();
+
+        if self.mailbox.is_pending() {
+            let mailbox = Arc::clone(&self.mailbox);
+            mailbox.deliver(self);
+        }
+    }
+
+    ///
+    /// Create a stand-in for the module level logger at `addr`, that is already held
+    /// further up this thread.
+    ///
+    /// It has no handlers, and is not registered with the [`LogManager`]. Its log
+    /// entries are deferred, for the module level logger to log once it is released.
+    ///
+    pub(crate) fn stand_in(addr: usize) -> Logger {
+        Logger {
+            mod_path: String::new(),
+            fn_name: String::new(),
+            level: RwLock::new(Level::ALL),
+            fn_level: None,
+            level_map: RwLock::default(),
+            handlers: Mutex::default(),
+            worker: None,
+            mailbox: Arc::default(),
+            clock: Arc::new(SystemClock),
+            counts: Mutex::default(),
+            alerts: Mutex::default(),
+            sampling: None,
+            enrichers: Vec::new(),
+            redactor: None,
+            dedup: false,
+            repeated: Mutex::new(None),
+            nested: Mutex::default(),
+            stand_in_for: Some(addr),
+        }
+    }
+
+    ///
+    /// Apply any level changes posted by the [`LogManager`].
+    ///
+    /// Unlike [`reconfigure()`][Logger::reconfigure], this only needs `&self`, so it
+    /// is done by every log entry.
+    ///
+    fn relevel(&self) {
+        if self.mailbox.is_relevel_pending() {
+            self.mailbox.deliver_levels(self);
+        }
+    }
+
+    ///
+    /// Remove an existing log file.
+    ///
+    /// The purpose of this, is to allow resetting of the log file, each time
+    /// a test run is done.
+    ///
+    /// ## Note
+    ///
+    /// This **must** be called _before_ adding the corresponding file handler
+    /// that is going to open this file.
+    ///
+    /// ## Parameters
+    ///
+    /// - `filename` - The name of the output log file. Must include any relevant
+    ///   path (relative or absolute).
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// Logger::remove_file("test_logs/logger.log");
+    /// let mut log = Logger::file_logger(module_path!(), "test_logs/logger.log");
+    /// log.set_fn_name("main");
+    /// log.info("Something to log.");
+    /// ```
+    ///
+    pub fn remove_file(filename: &str) {
+        let _ = fs::remove_file(filename).is_err();
+    }
+
+    ///
+    /// Remove a handler from this logger.
+    ///
+    /// Where there is more than one of this kind, the first one added is removed.
+    ///
+    /// If this logger is running asynchronously, the log entries already queued are
+    /// published first.
+    ///
+    /// ## Parameters
+    /// - `handler` - The enum identifying the handler.
+    ///
+    /// Returns the removed handler, or `None` if there wasn't one.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::string_logger(module_path!());
+    /// log.info("Some text to store.");
+    ///
+    /// let h = log.remove_handler(Handler::String).unwrap();
+    /// assert!(!log.has_handler(Handler::String));
+    /// println!("{}", h.get_log());
+    /// ```
+    ///
+    pub fn remove_handler(&mut self, handler: Handler) -> Option<Box<dyn HandlerTrait>> {
+        self.remove(Selector::Kind(handler))
+    }
+
+    ///
+    /// Remove the handler with the required id from this logger.
+    ///
+    /// If this logger is running asynchronously, the log entries already queued are
+    /// published first.
+    ///
+    /// ## Parameters
+    /// - `id` - The id of the handler, as returned by [`add_handler()`][Logger::add_handler]
+    ///   or [`handler_ids()`][Logger::handler_ids].
+    ///
+    /// Returns the removed handler, or `None` if there wasn't one.
+    ///
+    pub fn remove_handler_by_id(&mut self, id: HandlerId) -> Option<Box<dyn HandlerTrait>> {
+        self.remove(Selector::Id(id))
+    }
+
+    fn remove(&mut self, selector: Selector) -> Option<Box<dyn HandlerTrait>> {
+        match &self.worker {
+            Some(worker) => worker.remove_handler(selector),
+            None => lock_mut(&mut self.handlers).remove(&selector),
+        }
+    }
+
+    ///
+    /// Log an `entry` that was logged elsewhere, such as by a nested use of a module
+    /// level logger, whose lock was already held. See [`LockLogger`].
+    ///
+    /// It is checked against this logger's level for its own module, then published
+    /// as though it had been logged here.
+    ///
+    pub(crate) fn replay(&self, entry: LogEntry) {
+        if !self.is_loggable_from(&self.mod_path, &entry.level) {
+            lock(&self.counts).reject();
+            return;
+        }
 
-        for handler in self.handlers.get_mut() {
-            handler.1.publish(entry);
+        lock(&self.counts).accept(entry.level);
+        self._log(entry);
+    }
+
+    ///
+    /// Reset the counts returned by [`counts()`][Logger::counts] to zero.
+    ///
+    pub fn reset_counts(&mut self) {
+        *lock_mut(&mut self.counts) = LevelCounts::default();
+    }
+
+    ///
+    /// Take the fields set by [`with_fields()`][Logger::with_fields] on this thread.
+    ///
+    fn take_fields(&self) -> Vec<(String, String)> {
+        let mine = Arc::as_ptr(&self.mailbox);
+
+        FIELDS.with_borrow_mut(|pending| {
+            let pos = pending
+                .iter()
+                .position(|(mailbox, _)| mailbox.as_ptr() == mine);
+
+            pos.map(|pos| pending.swap_remove(pos).1)
+                .unwrap_or_default()
+        })
+    }
+
+    ///
+    /// Apply the sampling, if any, to a log entry at `level`.
+    ///
+    /// Returns `false` if it is to be dropped. Otherwise, if sampled, the note
+    /// is added to its `fields`.
+    ///
+    fn sample(&self, level: Level, fields: &mut Vec<(String, String)>) -> bool {
+        let Some(sampling) = &self.sampling else {
+            return true;
+        };
+
+        if !sampling.keep(level) {
+            return false;
+        }
+
+        if let Some(note) = sampling.note(level) {
+            fields.push(("sampled".to_string(), note));
         }
+
+        true
+    }
+
+    ///
+    /// Call `f` on each of the handlers, wherever they are.
+    ///
+    fn for_each_handler(&self, f: fn(&mut dyn HandlerTrait)) {
+        // Already publishing on this thread, so the handlers are locked further up.
+        let Some(_publishing) = Publishing::enter(self) else {
+            return;
+        };
+
+        match &self.worker {
+            Some(worker) => worker.for_each(f),
+            None => {
+                for entry in lock(&self.handlers).iter_mut() {
+                    f(entry.handler.as_mut());
+                }
+            }
+        }
+
+        self.publish_nested();
+    }
+
+    ///
+    /// Publish the "repeated" line for the most recent log entry, if it has been repeated.
+    ///
+    fn publish_repeated(&self) {
+        let repeated = lock(&self.repeated).take();
+        self.send_repeated(repeated);
+    }
+
+    ///
+    /// Send the "repeated" line for `repeated`, if the log entry was repeated.
+    ///
+    fn send_repeated(&self, repeated: Option<Repeated>) {
+        if let Some(Repeated {
+            count,
+            last: Some(mut last),
+            ..
+        }) = repeated
+        {
+            last.message = format!("Last message repeated {count} times.");
+            self.send(last);
+        }
+    }
+
+    ///
+    /// Send `entry` to the handlers, or to the background worker.
+    ///
+    fn send(&self, entry: LogEntry) {
+        if let Some(addr) = self.stand_in_for {
+            crate::macros::defer(addr, entry);
+            return;
+        }
+
+        match &self.worker {
+            Some(worker) => worker.send(entry),
+            None => publish(&mut lock(&self.handlers), &entry),
+        }
+    }
+
+    ///
+    /// Obtain a [`Scope`], for logging from within the function/method `fn_name`.
+    ///
+    /// Unlike [`set_fn_name()`][Logger::set_fn_name], this does not change the logger.
+    /// The function's name is carried by the scope, and set on each log entry made
+    /// through it. So functions, or threads, sharing the one logger, each have their
+    /// own log entries labelled correctly.
+    ///
+    /// ## Parameters
+    /// - `fn_name` - The name of the function/method in which you are logging.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::string_logger("app");
+    ///
+    /// fn connect(log: &Logger) {
+    ///     let scope = log.scope("connect");
+    ///     scope.info("Connecting.");
+    ///     load(log);
+    ///     scope.info("Connected.");
+    /// }
+    ///
+    /// fn load(log: &Logger) {
+    ///     log.scope("load").info("Loading.");
+    /// }
+    ///
+    /// connect(&log);
+    ///
+    /// assert_eq!(
+    ///     log.get_handler(Handler::String).unwrap().get_log(),
+    ///     "app->connect [INFO   ] Connecting.\n\
+    ///      app->load [INFO   ] Loading.\n\
+    ///      app->connect [INFO   ] Connected.\n"
+    /// );
+    /// ```
+    ///
+    pub fn scope<'a>(&'a self, fn_name: &'a str) -> Scope<'a> {
+        Scope::new(self, fn_name)
     }
 
     ///
-    /// Log a message, with no arguments.
+    /// Set the clock that provides the timestamp of each log entry.
+    ///
+    /// ## Parameters
+    /// - `clock` - The new clock. See [`ClockTrait`].
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn set_clock(&mut self, clock: Arc<dyn ClockTrait>) -> &mut Self {
+        self.clock = clock;
+        self
+    }
+
+    ///
+    /// Set the redactor, which masks personal data and secrets in each log entry,
+    /// before any handler sees it.
+    ///
+    /// ## Parameters
+    /// - `redactor` - The redactor, or `None` to log everything as is.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::string_logger("app");
+    /// log.set_redactor(Some(Redactor::new().bearer_tokens()));
+    ///
+    /// log.info("Sent: Authorization: Bearer eyJhbGciOi.J9");
+    ///
+    /// assert_eq!(
+    ///     log.get_handler(Handler::String).unwrap().get_log(),
+    ///     "app-> [INFO   ] Sent: Authorization: Bearer [TOKEN]\n"
+    /// );
+    /// ```
+    ///
+    pub fn set_redactor(&mut self, redactor: Option<Redactor>) -> &mut Self {
+        self.redactor = redactor;
+        self
+    }
+
+    ///
+    /// Set the sampling of the log entries below its level. Only every `n`th of
+    /// them is logged, and those that are have the field: `sampled=1/n`.
+    ///
+    /// The log entries dropped are counted as [rejected][LevelCounts::rejected].
+    ///
+    /// ## Parameters
+    /// - `sampling` - The sampling, or `None` to log everything.
+    ///
+    /// Returns itself for chaining purposes.
     ///
-    /// If the logger is currently enabled for the given message level then the given
-    /// message is forwarded to all the registered output `Handler` objects.
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
     ///
-    /// ## Parameters
-    /// - `level` - One of the message level identifiers, e.g., SEVERE.
-    /// - `fn_name` - The name of the function/method from-which this method
-    ///   was called.
-    /// - `msg` - The string message.
+    /// let mut log = Logger::string_logger("app");
+    /// log.set_level(Level::FINEST);
+    /// log.set_sampling(Some(SampleFilter::new(100).below(Level::INFO)));
     ///
-    fn log(&mut self, level: Level, fn_name: &str, msg: &str) {
-        if !self.is_loggable(&level) {
-            return;
-        }
-
-        // build LogEntry
-        let mut log_entry = LogEntry::create(level, fn_name.to_string(), msg.to_string());
-        // Send LogEntry
-        self._log(&mut log_entry);
+    /// for n in 1..=250 {
+    ///     log.finest(&format!("Step {n}"));
+    /// }
+    ///
+    /// assert_eq!(
+    ///     log.get_handler(Handler::String).unwrap().get_log(),
+    ///     "app-> [FINEST ] Step 1 sampled=1/100\n\
+    ///      app-> [FINEST ] Step 101 sampled=1/100\n\
+    ///      app-> [FINEST ] Step 201 sampled=1/100\n"
+    /// );
+    /// assert_eq!(log.counts().rejected(), 247);
+    /// ```
+    ///
+    pub fn set_sampling(&mut self, sampling: Option<SampleFilter>) -> &mut Self {
+        self.sampling = sampling;
+        self
     }
 
     ///
-    /// Create new Logger instance, with a `ConsoleHandler`, output
-    /// set to: [`std::io::stdout`].
-    ///
-    /// Logging level is set to it's default setting (INFO).
+    /// Set whether consecutive identical log entries are collapsed.
     ///
-    /// All `INFO` level log entries are output without any formatting.
-    /// All other levels are processed through the formatter, first.
+    /// If `true`, a log entry with the same level, module path, function name, message,
+    /// fields, context, and error as the one before it is not published. Instead, when a different one
+    /// arrives, or this logger is dropped, it is preceded by the line:
+    /// "Last message repeated N times.", like syslog. So a retry loop does not fill
+    /// the log with identical lines.
     ///
     /// ## Parameters
-    /// - `mod_path`- The module path. Suggest using [`module_path!()`].
+    /// - `state` - `true` to collapse them.
     ///
-    /// Returns a configured `Logger`.
+    /// Returns itself for chaining purposes.
     ///
     /// ## Examples
     /// ```
     /// use flogging::*;
     ///
-    /// let mut log = Logger::pconsole_logger(module_path!());
-    /// log.set_fn_name("main");
+    /// let mut log = Logger::string_logger("app");
+    /// log.set_dedup(true).set_fn_name("connect");
     ///
-    /// log.warning("Don't over do it.");
-    /// ```
-    /// Output to stderr:
-    /// ```text
-    /// flogging->main [WARNING] Don't over do it.
+    /// for _ in 0..5 {
+    ///     log.warning("Connection refused.");
+    /// }
+    ///
+    /// log.info("Connected.");
+    ///
+    /// assert_eq!(
+    ///     log.get_handler(Handler::String).unwrap().get_log(),
+    ///     "app->connect [WARNING] Connection refused.\n\
+    ///      app->connect [WARNING] Last message repeated 4 times.\n\
+    ///      app->connect [INFO   ] Connected.\n"
+    /// );
     /// ```
     ///
-    pub fn pconsole_logger(mod_path: &str) -> Logger {
-        Logger::builder(mod_path).add_pconsole_handler().build()
+    pub fn set_dedup(&mut self, state: bool) -> &mut Self {
+        if !state {
+            self.publish_repeated();
+        }
+
+        self.dedup = state;
+        self
     }
 
     ///
-    /// Remove an existing log file.
-    ///
-    /// The purpose of this, is to allow resetting of the log file, each time
-    /// a test run is done.
-    ///
-    /// ## Note
+    /// Set the logging level for the current function/method.
     ///
-    /// This **must** be called _before_ adding the corresponding file handler
-    /// that is going to open this file.
+    /// While it is set, it overrides both this logger's level, and its per-module
+    /// levels. [`Scope::set_fn_level()`] does the same for just the one scope, without
+    /// changing the logger. That is used by [`#[logger(level = "...")]`][macro@crate::logger].
     ///
     /// ## Parameters
+    /// - `level` - The new level, or `None` to return to the logger's levels.
     ///
-    /// - `filename` - The name of the output log file. Must include any relevant
-    ///   path (relative or absolute).
+    /// Returns itself for chaining purposes.
     ///
     /// ## Examples
     /// ```
     /// use flogging::*;
     ///
-    /// Logger::remove_file("test_logs/logger.log");
-    /// let mut log = Logger::file_logger(module_path!(), "test_logs/logger.log");
-    /// log.set_fn_name("main");
-    /// log.info("Something to log.");
+    /// let mut log = Logger::string_logger(module_path!());
+    ///
+    /// log.set_fn_level(Some(Level::FINEST));
+    /// assert!(log.is_enabled(Level::FINEST));
+    ///
+    /// log.set_fn_level(None);
+    /// assert!(!log.is_enabled(Level::FINEST));
     /// ```
     ///
-    pub fn remove_file(filename: &str) {
-        let _ = fs::remove_file(filename).is_err();
+    pub fn set_fn_level(&mut self, level: Option<Level>) -> &mut Self {
+        self.fn_level = level;
+        self
     }
 
     ///
     /// Set the current function/method name.
     ///
+    /// This changes the logger, for every log entry that follows, wherever it is
+    /// logged from. To label the log entries of just one function, which may be
+    /// interleaved with those of others, use a [`scope()`][Logger::scope] instead.
+    ///
     /// ## Parameters
     /// - `fn_name` - The name of the function/method in which you are
     ///   logging.
@@ -802,10 +2004,119 @@ impl Logger {
     /// Returns itself for chaining purposes.
     ///
     pub fn set_level(&mut self, level: Level) -> &mut Self {
-        self.level = level;
+        self.store_level(level);
+        self
+    }
+
+    ///
+    /// Set logging level for this Log instance, by its name.
+    ///
+    /// The case of `name` is ignored, as is any surrounding whitespace.
+    ///
+    /// ## Parameters
+    /// - `name` - The name of the new logging level, such as: "warning".
+    ///
+    /// Returns itself for chaining purposes, or an error if `name` is not a level.
+    ///
+    /// ## Examples
+    /// ```
+    /// extern crate flogging;
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::string_logger(module_path!());
+    ///
+    /// log.set_level_str("warning").unwrap();
+    /// assert_eq!(log.level(), Level::WARNING);
+    ///
+    /// let err = log.set_level_str("loud").unwrap_err();
+    /// assert_eq!(err.to_string(), "Unknown Level: loud");
+    /// assert_eq!(log.level(), Level::WARNING);
+    /// ```
+    ///
+    pub fn set_level_str(&mut self, name: &str) -> Result<&mut Self, LevelError> {
+        self.store_level(name.parse()?);
+        Ok(self)
+    }
+
+    ///
+    /// Set the logging level for log entries from `mod_path`, and its sub-modules.
+    ///
+    /// This overrides the logger's own level, for those modules.
+    ///
+    /// ## Parameters
+    /// - `mod_path` - The module path.
+    /// - `level` - The new logging level to set.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder("my_app::db")
+    ///     .add_string_handler()
+    ///     .build();
+    ///
+    /// log.set_module_level("my_app::db", Level::FINEST);
+    /// log.finest("Query plan: full scan.");
+    ///
+    /// let buf = log.get_handler(Handler::String).unwrap().get_log();
+    /// assert_eq!(buf, "my_app::db-> [FINEST ] Query plan: full scan.\n");
+    /// ```
+    ///
+    pub fn set_module_level(&mut self, mod_path: &str, level: Level) -> &mut Self {
+        self.store_module_level(mod_path, level);
         self
     }
 
+    ///
+    /// Set the logging level, through a shared reference.
+    ///
+    fn store_level(&self, level: Level) {
+        *write(&self.level) = level;
+    }
+
+    ///
+    /// Set the logging level for `mod_path`, through a shared reference.
+    ///
+    fn store_module_level(&self, mod_path: &str, level: Level) {
+        write(&self.level_map).set(mod_path, level);
+    }
+
+    ///
+    /// Stop asynchronous logging.
+    ///
+    /// Blocks until every queued log entry has been published, then stops the
+    /// background thread. The handlers are returned to this logger, which then
+    /// continues logging synchronously.
+    ///
+    /// This is a **NoOp** if the logger is not running asynchronously.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::builder(module_path!())
+    ///     .add_string_handler()
+    ///     .set_async(1000)
+    ///     .build();
+    /// log.set_fn_name("main");
+    ///
+    /// log.info("Some text to store.");
+    /// log.shutdown();
+    ///
+    /// let log_str = log.get_handler(Handler::String).unwrap().get_log();
+    /// assert!(log_str.ends_with("->main [INFO   ] Some text to store.\n"));
+    /// ```
+    ///
+    pub fn shutdown(&mut self) {
+        if let Some(worker) = self.worker.take() {
+            if let Some(handlers) = worker.shutdown() {
+                *lock_mut(&mut self.handlers) = handlers;
+            }
+        }
+    }
+
     ///
     /// Log a SEVERE message.
     ///
@@ -831,14 +2142,46 @@ impl Logger {
     /// flogging->main [SEVERE ] Some text to store.
     /// ```
     ///
-    pub fn severe(&mut self, msg: &str) {
-        self.log(Level::SEVERE, &self.fn_name(), msg);
+    #[track_caller]
+    pub fn severe(&self, msg: &str) {
+        self.log(Level::SEVERE, msg.into());
+    }
+
+    ///
+    /// Log a SEVERE message, with an error attached.
+    ///
+    /// The error's message, and those of its [`source()`][error::Error::source] chain,
+    /// are stored on the log entry. See: [`LogEntry::error()`].
+    ///
+    /// ## Parameters
+    /// - `msg` - The string message.
+    /// - `err` - The error to attach.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::string_logger("app");
+    /// log.set_fn_name("load");
+    ///
+    /// if let Err(e) = std::fs::read_to_string("missing.toml") {
+    ///     log.severe_err("Config not loaded.", &e);
+    /// }
+    ///
+    /// let h = log.get_handler(Handler::String).unwrap();
+    /// assert!(h.get_log().starts_with("app->load [SEVERE ] Config not loaded. error="));
+    /// ```
+    ///
+    #[track_caller]
+    pub fn severe_err(&self, msg: &str, err: &dyn error::Error) {
+        self.log_error(Level::SEVERE, msg.into(), Some(err));
     }
 
     ///
     /// Create new Logger instance, with a `ConsoleHandler`.
     ///
-    /// Logging level is set to it's default setting (INFO).
+    /// Logging level is set to it's default setting (INFO), unless overridden
+    /// by the [`FLOGGING_LEVEL`] environment variable.
     ///
     /// I expect this will be primarily used during unit testing of
     /// the logging output. Though, any requirement to pass-on the log entry,
@@ -871,6 +2214,45 @@ impl Logger {
         Logger::builder(mod_path).add_string_handler().build()
     }
 
+    ///
+    /// Log throwing an error.
+    ///
+    /// This is a convenience method that can be used to log that a function/method
+    /// is returning an error, such as just before `return Err(e)`.
+    ///
+    /// A `LogEntry` is created with a log level of FINER and the message "Throw",
+    /// with the error attached. See: [`LogEntry::error()`].
+    ///
+    /// ## Parameters
+    /// - `err` - The error being thrown.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    /// use std::num::ParseIntError;
+    ///
+    /// let mut log = Logger::string_logger("app");
+    /// log.set_level(Level::FINER);
+    /// log.set_fn_name("parse");
+    ///
+    /// let rtn: Result<u8, ParseIntError> = "x".parse();
+    ///
+    /// if let Err(e) = &rtn {
+    ///     log.throwing(e);
+    /// }
+    ///
+    /// let h = log.get_handler(Handler::String).unwrap();
+    /// assert_eq!(
+    ///     h.get_log(),
+    ///     "app->parse [FINER  ] Throw error=\"invalid digit found in string\"\n"
+    /// );
+    /// ```
+    ///
+    #[track_caller]
+    pub fn throwing(&self, err: &dyn error::Error) {
+        self.log_error(Level::FINER, "Throw".into(), Some(err));
+    }
+
     ///
     /// Log a WARNING message.
     ///
@@ -896,8 +2278,71 @@ impl Logger {
     /// flogging->main [WARNING] Some text to store.
     /// ```
     ///
-    pub fn warning(&mut self, msg: &str) {
-        self.log(Level::WARNING, &self.fn_name(), msg);
+    #[track_caller]
+    pub fn warning(&self, msg: &str) {
+        self.log(Level::WARNING, msg.into());
+    }
+
+    ///
+    /// Attach structured data, as key/value pairs, to the next log entry made by
+    /// this logger.
+    ///
+    /// The fields apply to that one log entry only, and are discarded even if it is
+    /// not loggable. They are available to filters and formatters via
+    /// [`LogEntry::fields()`], and are included in the output of the built-in
+    /// formatters. The level macros accept them after a `;`, such as:
+    /// `info!("Order placed."; user_id = uid, order = %order)`.
+    ///
+    /// ## Parameters
+    /// - `fields` - The key/value pairs.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::string_logger("app");
+    ///
+    /// log.with_fields([("user_id", "42"), ("order", "A-17")])
+    ///     .info("Order placed.");
+    ///
+    /// assert_eq!(
+    ///     log.get_handler(Handler::String).unwrap().get_log(),
+    ///     "app-> [INFO   ] Order placed. user_id=42 order=A-17\n"
+    /// );
+    /// ```
+    ///
+    pub fn with_fields<K, V>(&self, fields: impl IntoIterator<Item = (K, V)>) -> &Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let fields = fields
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+
+        let mine = Arc::as_ptr(&self.mailbox);
+
+        FIELDS.with_borrow_mut(|pending| {
+            // Replace any not yet taken, and drop those of the loggers that are gone.
+            pending.retain(|(mailbox, _)| mailbox.strong_count() > 0 && mailbox.as_ptr() != mine);
+            pending.push((Arc::downgrade(&self.mailbox), fields));
+        });
+        self
+    }
+}
+
+impl Drop for Logger {
+    ///
+    /// Publishes any pending log entries, then closes each of the handlers, so
+    /// that none of their buffered output is lost.
+    ///
+    fn drop(&mut self) {
+        self.publish_repeated();
+        self.shutdown();
+        self.close();
     }
 }
 
@@ -905,18 +2350,156 @@ impl fmt::Display for Logger {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut buf = String::new();
 
-        for elem in self.handlers.borrow().iter() {
-            let s = format!("{}: {}\n", elem.0, elem.1);
+        for elem in lock(&self.handlers).iter() {
+            let s = format!("{}: {}\n", elem.kind, elem.handler);
             buf.push_str(&s);
         }
 
         writeln!(
             f,
             "{}::{} - [{}]\n\n{}",
-            self.mod_path, self.fn_name, self.level, buf
+            self.mod_path,
+            self.fn_name,
+            read(&self.level),
+            buf
         )
     }
 }
 
+///
+/// The most recent log entry, when collapsing consecutive identical log entries.
+/// See [`Logger::set_dedup()`].
+///
+struct Repeated {
+    ///
+    /// Identifies the log entry. See [`key()`][Repeated::key].
+    ///
+    key: u64,
+
+    ///
+    /// The number of times it has since been repeated.
+    ///
+    count: u64,
+
+    ///
+    /// The latest repeat, if any.
+    ///
+    last: Option<LogEntry>,
+}
+
+impl Repeated {
+    ///
+    /// Hash everything about `entry` that makes it the same log entry, except for
+    /// when, and where from, it was logged.
+    ///
+    fn key(entry: &LogEntry) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        entry.level.hash(&mut hasher);
+        entry.mod_path.hash(&mut hasher);
+        entry.fn_name.hash(&mut hasher);
+        entry.message.hash(&mut hasher);
+        entry.fields.hash(&mut hasher);
+        entry.context.hash(&mut hasher);
+        entry.correlation_id.hash(&mut hasher);
+        entry.error.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+thread_local! {
+    ///
+    /// The loggers, by address, that this thread is in the middle of publishing to.
+    ///
+    static PUBLISHING: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+
+    ///
+    /// The fields set by [`with_fields()`][Logger::with_fields] on this thread, for the
+    /// next log entry of each logger, by its mailbox.
+    ///
+    /// The `Weak` keeps the mailbox's address from being reused by another logger.
+    ///
+    static FIELDS: RefCell<Vec<(Weak<Mailbox>, Vec<(String, String)>)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+///
+/// Marks this thread as publishing to a logger, until dropped.
+///
+struct Publishing(usize);
+
+impl Publishing {
+    ///
+    /// Returns `None` if this thread is already publishing to `logger`.
+    ///
+    fn enter(logger: &Logger) -> Option<Publishing> {
+        let addr = logger as *const Logger as usize;
+
+        PUBLISHING.with_borrow_mut(|publishing| {
+            if publishing.contains(&addr) {
+                None
+            } else {
+                publishing.push(addr);
+                Some(Publishing(addr))
+            }
+        })
+    }
+}
+
+impl Drop for Publishing {
+    fn drop(&mut self) {
+        PUBLISHING.with_borrow_mut(|publishing| publishing.retain(|&addr| addr != self.0));
+    }
+}
+
+///
+/// Lock `mutex`, recovering it if poisoned. A panic in one thread's handler
+/// should not stop every other thread from logging.
+///
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+///
+/// Read-lock `rwlock`, recovering it if poisoned.
+///
+fn read<T>(rwlock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    rwlock.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+///
+/// Write-lock `rwlock`, recovering it if poisoned.
+///
+fn write<T>(rwlock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    rwlock.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+///
+/// Obtain the value of `mutex`, through exclusive access, recovering it if poisoned.
+///
+fn lock_mut<T>(mutex: &mut Mutex<T>) -> &mut T {
+    mutex.get_mut().unwrap_or_else(PoisonError::into_inner)
+}
+
+///
+/// Publish `entry` to each of the `handlers`, whose own level and filter (if any) accept it.
+///
+/// The handlers are published to in the order they were added.
+///
+fn publish(handlers: &mut Handlers, entry: &LogEntry) {
+    for e in handlers.iter_mut() {
+        if e.accepts(entry.level())
+            && e
+                .handler
+                .get_filter()
+                .is_none_or(|filter| filter.should_publish(entry))
+        {
+            e.handler.publish(entry);
+        } else {
+            e.filtered += 1;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests;
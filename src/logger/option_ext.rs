@@ -0,0 +1,131 @@
+//
+// File Name:    option_ext.rs
+// Directory:    src/logger
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Option Extension
+//!
+
+use crate::{Level, Logger};
+
+///
+/// Extends [`Option`] with logging when it is `None`.
+///
+pub trait OptionExt {
+    ///
+    /// Log `msg` at level FINE, if this is `None`, then return `self` unchanged.
+    ///
+    /// The message is logged with `log`'s current function name.
+    ///
+    /// ## Parameters
+    /// - `log` - The logger to use.
+    /// - `msg` - The string message.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut log = Logger::string_logger("app");
+    /// log.set_level(Level::FINE);
+    /// log.set_fn_name("lookup");
+    ///
+    /// let cache = HashMap::from([("a", 1)]);
+    ///
+    /// for key in ["a", "b"] {
+    ///     let _ = cache.get(key).log_none(&log, &format!("Cache miss for: {key}"));
+    /// }
+    ///
+    /// let h = log.get_handler(Handler::String).unwrap();
+    /// assert_eq!(h.get_log(), "app->lookup [FINE   ] Cache miss for: b\n");
+    /// ```
+    ///
+    fn log_none(self, log: &Logger, msg: &str) -> Self;
+
+    ///
+    /// Log `msg` at `level`, if this is `None`, then return `self` unchanged.
+    ///
+    /// The message is logged with `log`'s current function name.
+    ///
+    /// ## Parameters
+    /// - `log` - The logger to use.
+    /// - `level` - The level to log the message at.
+    /// - `msg` - The string message.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::string_logger("app");
+    /// log.set_fn_name("main");
+    ///
+    /// let home = None::<String>.log_none_at(&log, Level::WARNING, "HOME is not set.");
+    /// assert!(home.is_none());
+    ///
+    /// let h = log.get_handler(Handler::String).unwrap();
+    /// assert_eq!(h.get_log(), "app->main [WARNING] HOME is not set.\n");
+    /// ```
+    ///
+    fn log_none_at(self, log: &Logger, level: Level, msg: &str) -> Self;
+}
+
+impl<T> OptionExt for Option<T> {
+    #[track_caller]
+    fn log_none(self, log: &Logger, msg: &str) -> Self {
+        self.log_none_at(log, Level::FINE, msg)
+    }
+
+    #[track_caller]
+    fn log_none_at(self, log: &Logger, level: Level, msg: &str) -> Self {
+        if self.is_none() {
+            log.log_at(level, msg);
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn log_none() {
+        let mut log = Logger::string_logger(module_path!());
+        log.set_level(Level::FINE);
+        log.set_fn_name("log_none");
+
+        assert_eq!(Some(1).log_none(&log, "Not logged."), Some(1));
+        assert_eq!(None::<u8>.log_none(&log, "Missing."), None);
+        assert_eq!(
+            None::<u8>.log_none_at(&log, Level::SEVERE, "Required."),
+            None
+        );
+
+        let h = log.get_handler(Handler::String).unwrap();
+        assert_eq!(
+            h.get_log(),
+            "flogging::logger::option_ext::tests->log_none [FINE   ] Missing.\n\
+             flogging::logger::option_ext::tests->log_none [SEVERE ] Required.\n"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//
+// File Name:    redactor.rs
+// Directory:    src/logger
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Redactor
+//!
+
+use super::LogEntry;
+use regex::Regex;
+use std::fmt;
+
+///
+/// Masks personal data and secrets in each log entry, before any handler sees it.
+///
+/// There are two kinds of rules:
+/// - Patterns: regular expressions, using the syntax of the [`regex`] crate. Each
+///   match, in the message, the field values, the context, and the error, is
+///   replaced with its mask. The mask may refer to capture groups, such as: `$1`.
+/// - Keys: field keys, matched ignoring ASCII case. The whole value of each such
+///   field is replaced with its mask.
+///
+/// There are ready-made rules for the usual suspects: [`credit_cards()`][Redactor::credit_cards],
+/// [`emails()`][Redactor::emails], [`bearer_tokens()`][Redactor::bearer_tokens], and
+/// [`secret_keys()`][Redactor::secret_keys].
+///
+/// Set with [`LoggerBuilder::set_redactor()`][crate::LoggerBuilder::set_redactor] or
+/// [`Logger::set_redactor()`][crate::Logger::set_redactor]. So it is enforced in one
+/// place, rather than at each call site.
+///
+/// ## Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+///
+/// let redactor = Redactor::new()
+///     .credit_cards()
+///     .emails()
+///     .secret_keys()
+///     .pattern(r"\b\d{3}-\d{2}-(\d{4})\b", "***-**-$1")
+///     .unwrap();
+///
+/// let mut log = Logger::builder("app")
+///     .add_string_handler()
+///     .set_redactor(redactor)
+///     .set_fn_name("pay")
+///     .build();
+///
+/// log.with_fields([("password", "hunter2")])
+///     .info("Card 4111 1111 1111 1111 used by bob@example.com, SSN 123-45-6789.");
+///
+/// assert_eq!(
+///     log.get_handler(Handler::String).unwrap().get_log(),
+///     "app->pay [INFO   ] Card [CARD] used by [EMAIL], SSN ***-**-6789. password=***\n"
+/// );
+/// ```
+///
+#[derive(Debug, Default, Clone)]
+pub struct Redactor {
+    patterns: Vec<(Regex, String)>,
+    keys: Vec<(String, String)>,
+}
+
+impl Redactor {
+    ///
+    /// Creates a new instance of `Redactor`, without any rules.
+    ///
+    /// As is, it changes nothing.
+    ///
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    ///
+    /// Adds the rule masking bearer tokens, such as those of an `Authorization`
+    /// header, with: `Bearer [TOKEN]`.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn bearer_tokens(self) -> Self {
+        self.builtin(r"(?i)\bbearer\s+[A-Za-z0-9\-._~+/]+=*", "Bearer [TOKEN]")
+    }
+
+    ///
+    /// Adds the rule masking credit card numbers, of 13 to 19 digits, optionally
+    /// grouped by spaces or dashes, with: `[CARD]`.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn credit_cards(self) -> Self {
+        self.builtin(r"\b(?:\d[ -]?){12,18}\d\b", "[CARD]")
+    }
+
+    ///
+    /// Adds the rule masking email addresses with: `[EMAIL]`.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn emails(self) -> Self {
+        self.builtin(
+            r"[A-Za-z0-9._%+\-]+@[A-Za-z0-9.\-]+\.[A-Za-z]{2,}",
+            "[EMAIL]",
+        )
+    }
+
+    ///
+    /// Adds a key rule.
+    ///
+    /// ## Parameters
+    /// - `key` - The field key, matched ignoring ASCII case.
+    /// - `mask` - The replacement for the whole value.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn key(mut self, key: &str, mask: &str) -> Self {
+        self.keys.push((key.to_string(), mask.to_string()));
+        self
+    }
+
+    ///
+    /// Adds a pattern rule.
+    ///
+    /// ## Parameters
+    /// - `pattern` - The regular expression.
+    /// - `mask` - The replacement for each match. It may refer to capture groups, such as: `$1`.
+    ///
+    /// Returns an error if `pattern` is invalid.
+    ///
+    pub fn pattern(mut self, pattern: &str, mask: &str) -> Result<Self, regex::Error> {
+        self.patterns.push((Regex::new(pattern)?, mask.to_string()));
+        Ok(self)
+    }
+
+    ///
+    /// Adds the key rules masking the fields: `api_key`, `authorization`, `password`,
+    /// `secret`, and `token`, with: `***`.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn secret_keys(self) -> Self {
+        ["api_key", "authorization", "password", "secret", "token"]
+            .iter()
+            .fold(self, |redactor, key| redactor.key(key, "***"))
+    }
+
+    ///
+    /// Adds one of the ready-made pattern rules.
+    ///
+    fn builtin(self, pattern: &str, mask: &str) -> Self {
+        self.pattern(pattern, mask)
+            .expect("The ready-made patterns are valid")
+    }
+
+    ///
+    /// Apply the rules to `entry`.
+    ///
+    pub(crate) fn redact(&self, entry: &mut LogEntry) {
+        entry.message = self.redact_text(&entry.message);
+
+        for (key, value) in &mut entry.fields {
+            *value = match self.keys.iter().find(|(k, _)| k.eq_ignore_ascii_case(key)) {
+                Some((_, mask)) => mask.clone(),
+                None => self.redact_text(value),
+            };
+        }
+
+        for item in &mut entry.context {
+            *item = self.redact_text(item);
+        }
+
+        if let Some(error) = &mut entry.error {
+            *error = self.redact_text(error);
+        }
+    }
+
+    ///
+    /// Apply the pattern rules to `text`.
+    ///
+    fn redact_text(&self, text: &str) -> String {
+        self.patterns
+            .iter()
+            .fold(text.to_string(), |text, (re, mask)| {
+                re.replace_all(&text, mask.as_str()).into_owned()
+            })
+    }
+}
+
+impl fmt::Display for Redactor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let patterns = self
+            .patterns
+            .iter()
+            .map(|(re, mask)| format!("\"{re}\" => \"{mask}\""));
+        let keys = self
+            .keys
+            .iter()
+            .map(|(key, mask)| format!("{key} => \"{mask}\""));
+
+        write!(
+            f,
+            "[{}]",
+            patterns.chain(keys).collect::<Vec<String>>().join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn redact() {
+        let redactor = Redactor::new()
+            .bearer_tokens()
+            .key("session", "<hidden>")
+            .pattern("x+", "$0$0")
+            .unwrap();
+
+        assert_eq!(
+            redactor.to_string(),
+            "[\"(?i)\\bbearer\\s+[A-Za-z0-9\\-._~+/]+=*\" => \"Bearer [TOKEN]\", \"x+\" => \"$0$0\", session => \"<hidden>\"]"
+        );
+
+        let mut entry = LogEntry::create(
+            Level::INFO,
+            "redact".to_string(),
+            "Authorization: bearer abc.DEF-123==".to_string(),
+        );
+        entry.set_fields(vec![
+            ("SESSION".to_string(), "s3cr3t".to_string()),
+            ("box".to_string(), "xx".to_string()),
+        ]);
+        entry.context = vec!["user=x".to_string()];
+        entry.error = Some("Bearer zzz rejected".to_string());
+
+        redactor.redact(&mut entry);
+
+        assert_eq!(entry.message, "Authorization: Bearer [TOKEN]");
+        assert_eq!(entry.fields[0].1, "<hidden>");
+        assert_eq!(entry.fields[1].1, "xxxx");
+        assert_eq!(entry.context, ["user=xx"]);
+        assert_eq!(entry.error.as_deref(), Some("Bearer [TOKEN] rejected"));
+    }
+}
@@ -0,0 +1,128 @@
+//
+// File Name:    result_ext.rs
+// Directory:    src/logger
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Result Extension
+//!
+
+use crate::{Level, Logger};
+use std::fmt;
+
+///
+/// Extends [`Result`] with logging of its error.
+///
+pub trait ResultExt {
+    ///
+    /// Log the error, if this is `Err`, then return `self` unchanged.
+    ///
+    /// The message is the error's `Display` text, logged with `log`'s current function name.
+    ///
+    /// ## Parameters
+    /// - `log` - The logger to use.
+    /// - `level` - The level to log the error at.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    /// use std::num::ParseIntError;
+    ///
+    /// fn parse_port(log: &mut Logger, text: &str) -> Result<u16, ParseIntError> {
+    ///     log.set_fn_name("parse_port");
+    ///
+    ///     let port = text.parse().log_err(log, Level::WARNING)?;
+    ///     Ok(port)
+    /// }
+    ///
+    /// let mut log = Logger::string_logger("app");
+    ///
+    /// assert_eq!(parse_port(&mut log, "80"), Ok(80));
+    /// assert!(parse_port(&mut log, "http").is_err());
+    ///
+    /// let h = log.get_handler(Handler::String).unwrap();
+    /// assert_eq!(
+    ///     h.get_log(),
+    ///     "app->parse_port [WARNING] invalid digit found in string\n"
+    /// );
+    /// ```
+    ///
+    fn log_err(self, log: &Logger, level: Level) -> Self;
+}
+
+impl<T, E: fmt::Display> ResultExt for Result<T, E> {
+    #[track_caller]
+    fn log_err(self, log: &Logger, level: Level) -> Self {
+        if let Err(e) = &self {
+            log.log_at(level, &e.to_string());
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn log_err() {
+        let mut log = Logger::string_logger(module_path!());
+        log.set_fn_name("log_err");
+
+        let ok: Result<u8, String> = Ok(1);
+        assert_eq!(ok.log_err(&log, Level::SEVERE), Ok(1));
+
+        let err: Result<u8, String> = Err("Failed.".to_string());
+        assert!(err.log_err(&log, Level::SEVERE).is_err());
+
+        let h = log.get_handler(Handler::String).unwrap();
+        assert_eq!(
+            h.get_log(),
+            "flogging::logger::result_ext::tests->log_err [SEVERE ] Failed.\n"
+        );
+
+        // The entry's location is that of the caller.
+        let mut log = Logger::builder(module_path!())
+            .add_string_handler_with(FormatType::Custom, Some(Box::new(LineFormatter)))
+            .build();
+        let line = line!() + 1;
+        let _ = Err::<(), _>("Failed.").log_err(&log, Level::SEVERE);
+
+        let h = log.get_handler(Handler::String).unwrap();
+        assert_eq!(h.get_log(), format!("{line}\n"));
+    }
+
+    #[derive(Clone)]
+    struct LineFormatter;
+
+    impl std::fmt::Display for LineFormatter {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            "LineFormatter".fmt(f)
+        }
+    }
+
+    impl FormatTrait for LineFormatter {
+        fn format(&self, log_entry: &LogEntry) -> String {
+            log_entry.line().to_string()
+        }
+    }
+}
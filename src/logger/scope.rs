@@ -0,0 +1,474 @@
+//
+// File Name:    scope.rs
+// Directory:    src/logger
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Scope
+//!
+
+use super::{
+    Level, Logger,
+    handler_store::{Entry, Selector},
+    lock_mut,
+};
+use crate::{Handler, HandlerTrait};
+use std::{
+    borrow::Cow,
+    error, fmt,
+    ops::{Deref, DerefMut},
+};
+
+///
+/// A logger, along with the name of the function/method being logged from.
+///
+/// Obtained from [`Logger::scope()`], or [`Scope::new()`]. It has the same logging
+/// methods as the [`Logger`], each setting the scope's function name on the log entry.
+/// Nothing is changed on the logger itself. So, unlike with
+/// [`Logger::set_fn_name()`], the log entries of functions that are interleaved,
+/// whether by calls or by threads, are each labelled correctly.
+///
+/// A scope is lightweight: just the logger (or a reference to it), the name, and
+/// an optional level. It may be used for a single log entry, or held for the whole
+/// function. It also derefs to the `Logger`, for its other methods.
+///
+/// The code generated by the [`#[logger]`][macro@crate::logger] attribute logs through
+/// a scope, holding the locked module level logger.
+///
+/// ## Examples
+/// ```
+/// use flogging::*;
+/// use std::{sync::Arc, thread};
+///
+/// let log = Arc::new(Logger::string_logger("app"));
+///
+/// let handles: Vec<_> = (0..2)
+///     .map(|id| {
+///         let log = Arc::clone(&log);
+///
+///         thread::spawn(move || {
+///             let scope = Scope::new(log, "worker");
+///             scope.info(&format!("Worker {id}."));
+///         })
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+///
+/// log.scope("main").info("Done.");
+///
+/// let mut log = Arc::into_inner(log).unwrap();
+/// let text = log.get_handler(Handler::String).unwrap().get_log();
+///
+/// assert!(text.contains("app->worker [INFO   ] Worker 0.\n"));
+/// assert!(text.contains("app->worker [INFO   ] Worker 1.\n"));
+/// assert!(text.ends_with("app->main [INFO   ] Done.\n"));
+/// ```
+///
+pub struct Scope<'a, L: Deref<Target = Logger> = &'a Logger> {
+    logger: L,
+    fn_name: &'a str,
+    fn_level: Option<Level>,
+}
+
+impl<'a, L: Deref<Target = Logger>> Scope<'a, L> {
+    ///
+    /// Create a new scope.
+    ///
+    /// ## Parameters
+    /// - `logger` - The logger, or anything that derefs to one, such as an `Arc<Logger>`,
+    ///   or a `MutexGuard<Logger>`.
+    /// - `fn_name` - The name of the function/method in which you are logging.
+    ///
+    pub fn new(logger: L, fn_name: &'a str) -> Self {
+        Scope {
+            logger,
+            fn_name,
+            fn_level: None,
+        }
+    }
+
+    ///
+    /// Log a CONFIG message. See [`Logger::config()`].
+    ///
+    /// ## Parameters
+    /// - `msg` - The string message.
+    ///
+    #[track_caller]
+    pub fn config(&self, msg: &str) {
+        self.log(Level::CONFIG, msg.into());
+    }
+
+    ///
+    /// Log a method entry, with the message "Entry". See [`Logger::entering()`].
+    ///
+    #[track_caller]
+    pub fn entering(&self) {
+        self.log(Level::FINER, "Entry".into());
+    }
+
+    ///
+    /// Log a method entry, with a message. See [`Logger::entering_with()`].
+    ///
+    /// ## Parameters
+    /// - `msg` - The string message.
+    ///
+    #[track_caller]
+    pub fn entering_with(&self, msg: &str) {
+        self.log(Level::FINER, format!("Entry: ({msg})").into());
+    }
+
+    ///
+    /// Log a method return, with the message "Return". See [`Logger::exiting()`].
+    ///
+    #[track_caller]
+    pub fn exiting(&self) {
+        self.log(Level::FINER, "Return".into());
+    }
+
+    ///
+    /// Log a method return, with a message. See [`Logger::exiting_with()`].
+    ///
+    /// ## Parameters
+    /// - `msg` - The string message.
+    ///
+    #[track_caller]
+    pub fn exiting_with(&self, msg: &str) {
+        self.log(Level::FINER, format!("Return: ({msg})").into());
+    }
+
+    ///
+    /// Log a FINE message. See [`Logger::fine()`].
+    ///
+    /// ## Parameters
+    /// - `msg` - The string message.
+    ///
+    #[track_caller]
+    pub fn fine(&self, msg: &str) {
+        self.log(Level::FINE, msg.into());
+    }
+
+    ///
+    /// Log a FINER message. See [`Logger::finer()`].
+    ///
+    /// ## Parameters
+    /// - `msg` - The string message.
+    ///
+    #[track_caller]
+    pub fn finer(&self, msg: &str) {
+        self.log(Level::FINER, msg.into());
+    }
+
+    ///
+    /// Log a FINEST message. See [`Logger::finest()`].
+    ///
+    /// ## Parameters
+    /// - `msg` - The string message.
+    ///
+    #[track_caller]
+    pub fn finest(&self, msg: &str) {
+        self.log(Level::FINEST, msg.into());
+    }
+
+    ///
+    /// Obtain the level for this scope, if it overrides the logger's levels.
+    ///
+    pub fn fn_level(&self) -> Option<Level> {
+        self.fn_level
+    }
+
+    ///
+    /// Obtain the name of the function/method of this scope.
+    ///
+    pub fn fn_name(&self) -> &str {
+        self.fn_name
+    }
+
+    ///
+    /// Log an INFO message. See [`Logger::info()`].
+    ///
+    /// ## Parameters
+    /// - `msg` - The string message.
+    ///
+    #[track_caller]
+    pub fn info(&self, msg: &str) {
+        self.log(Level::INFO, msg.into());
+    }
+
+    ///
+    /// Check if a message of the given level would actually be logged from this scope.
+    ///
+    /// Unlike [`Logger::is_enabled()`], this does not apply any pending reconfiguration
+    /// from the [`LogManager`][crate::LogManager], as the logger is not held mutably.
+    ///
+    /// ## Parameters
+    /// - `level` - The level to compare with.
+    ///
+    /// Returns `true` if it is loggable, `false` if not.
+    ///
+    pub fn is_enabled(&self, level: Level) -> bool {
+        self.logger.is_loggable_in(self.fn_level, &level)
+    }
+
+    ///
+    /// Log a message at a level chosen at runtime. See [`Logger::log_at()`].
+    ///
+    /// ## Parameters
+    /// - `level` - The level of the message.
+    /// - `msg` - The string message.
+    ///
+    #[track_caller]
+    pub fn log_at(&self, level: Level, msg: &str) {
+        if !matches!(level, Level::ALL | Level::OFF) {
+            self.log(level, msg.into());
+        }
+    }
+
+    ///
+    /// Log a message that has already been formatted.
+    ///
+    /// This is used by the code generated by the logging macros.
+    ///
+    /// ## Parameters
+    /// - `level` - The level of the message.
+    /// - `msg` - The string message.
+    ///
+    #[doc(hidden)]
+    #[track_caller]
+    pub fn log_owned(&self, level: Level, msg: String) {
+        self.log(level, msg.into());
+    }
+
+    #[track_caller]
+    fn log(&self, level: Level, msg: Cow<'_, str>) {
+        self.log_error(level, msg, None);
+    }
+
+    #[track_caller]
+    fn log_error(&self, level: Level, msg: Cow<'_, str>, error: Option<&dyn error::Error>) {
+        self.logger
+            .log_in(self.fn_name, self.fn_level, level, msg, error);
+    }
+
+    ///
+    /// Set the level for this scope.
+    ///
+    /// While it is set, it overrides both the logger's level, and its per-module
+    /// levels, for the log entries made through this scope.
+    ///
+    /// ## Parameters
+    /// - `level` - The level, or `None` to use the logger's levels.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let log = Logger::string_logger("app");
+    /// let scope = log.scope("checksum").set_fn_level(Some(Level::OFF));
+    ///
+    /// scope.severe("Not logged.");
+    /// log.scope("main").info("Logged.");
+    ///
+    /// assert!(!scope.is_enabled(Level::SEVERE));
+    /// assert_eq!(log.counts().accepted(Level::INFO), 1);
+    /// ```
+    ///
+    pub fn set_fn_level(mut self, level: Option<Level>) -> Self {
+        self.fn_level = level;
+        self
+    }
+
+    ///
+    /// Log a SEVERE message. See [`Logger::severe()`].
+    ///
+    /// ## Parameters
+    /// - `msg` - The string message.
+    ///
+    #[track_caller]
+    pub fn severe(&self, msg: &str) {
+        self.log(Level::SEVERE, msg.into());
+    }
+
+    ///
+    /// Log a SEVERE message, with an error attached. See [`Logger::severe_err()`].
+    ///
+    /// ## Parameters
+    /// - `msg` - The string message.
+    /// - `err` - The error to attach.
+    ///
+    #[track_caller]
+    pub fn severe_err(&self, msg: &str, err: &dyn error::Error) {
+        self.log_error(Level::SEVERE, msg.into(), Some(err));
+    }
+
+    ///
+    /// Log throwing an error. See [`Logger::throwing()`].
+    ///
+    /// ## Parameters
+    /// - `err` - The error being thrown.
+    ///
+    #[track_caller]
+    pub fn throwing(&self, err: &dyn error::Error) {
+        self.log_error(Level::FINER, "Throw".into(), Some(err));
+    }
+
+    ///
+    /// Log a WARNING message. See [`Logger::warning()`].
+    ///
+    /// ## Parameters
+    /// - `msg` - The string message.
+    ///
+    #[track_caller]
+    pub fn warning(&self, msg: &str) {
+        self.log(Level::WARNING, msg.into());
+    }
+
+    ///
+    /// Attach structured data, as key/value pairs, to the next log entry made by
+    /// the logger. See [`Logger::with_fields()`].
+    ///
+    /// ## Parameters
+    /// - `fields` - The key/value pairs.
+    ///
+    /// Returns itself for chaining purposes.
+    ///
+    pub fn with_fields<K, V>(&self, fields: impl IntoIterator<Item = (K, V)>) -> &Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.logger.with_fields(fields);
+        self
+    }
+}
+
+impl<'a, L: DerefMut<Target = Logger>> Scope<'a, L> {
+    ///
+    /// Get the required `Handler`, keeping hold of this scope's logger for as long as
+    /// it is in use. This is what [`get_handler!()`][crate::get_handler] returns.
+    ///
+    /// Where there is more than one of this kind, the first one added is returned.
+    ///
+    /// ## Parameters
+    /// - `handler` - The enum of the required handler.
+    ///
+    /// Returns Some handler, or None.
+    ///
+    /// ## Examples
+    /// ```
+    /// use flogging::*;
+    ///
+    /// let mut log = Logger::string_logger(module_path!());
+    ///
+    /// let h = Scope::new(&mut log, "main")
+    ///     .into_handler(Handler::String)
+    ///     .unwrap();
+    /// assert!(h.get_log().is_empty());
+    /// ```
+    pub fn into_handler(mut self, handler: Handler) -> Option<HandlerGuard<'a, L>> {
+        let (pos, entry) = lock_mut(&mut self.handlers).take(&Selector::Kind(handler))?;
+
+        Some(HandlerGuard {
+            scope: self,
+            pos,
+            entry: Some(entry),
+        })
+    }
+}
+
+impl<L: Deref<Target = Logger>> Deref for Scope<'_, L> {
+    type Target = Logger;
+
+    fn deref(&self) -> &Logger {
+        &self.logger
+    }
+}
+
+impl<L: DerefMut<Target = Logger>> DerefMut for Scope<'_, L> {
+    fn deref_mut(&mut self) -> &mut Logger {
+        &mut self.logger
+    }
+}
+
+impl<L: Deref<Target = Logger>> fmt::Debug for Scope<'_, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scope")
+            .field("fn_name", &self.fn_name)
+            .field("fn_level", &self.fn_level)
+            .finish_non_exhaustive()
+    }
+}
+
+///
+/// A handler, taken from the logger of a [`Scope`], by [`Scope::into_handler()`].
+///
+/// The logger is held until this is dropped, when the handler is put back. Meanwhile,
+/// the log entries of this thread are deferred, and other threads wait for the logger.
+///
+pub struct HandlerGuard<'a, L: DerefMut<Target = Logger>> {
+    scope: Scope<'a, L>,
+    pos: usize,
+    ///
+    /// Only `None` once it has been put back.
+    ///
+    entry: Option<Entry>,
+}
+
+impl<L: DerefMut<Target = Logger>> Deref for HandlerGuard<'_, L> {
+    type Target = dyn HandlerTrait;
+
+    fn deref(&self) -> &Self::Target {
+        match &self.entry {
+            Some(entry) => entry.handler.as_ref(),
+            None => unreachable!("the handler has been put back"),
+        }
+    }
+}
+
+impl<L: DerefMut<Target = Logger>> DerefMut for HandlerGuard<'_, L> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match &mut self.entry {
+            Some(entry) => entry.handler.as_mut(),
+            None => unreachable!("the handler has been put back"),
+        }
+    }
+}
+
+impl<L: DerefMut<Target = Logger>> Drop for HandlerGuard<'_, L> {
+    fn drop(&mut self) {
+        if let Some(entry) = self.entry.take() {
+            lock_mut(&mut self.scope.handlers).restore(self.pos, entry);
+        }
+    }
+}
+
+impl<L: DerefMut<Target = Logger>> fmt::Debug for HandlerGuard<'_, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HandlerGuard")
+            .field("scope", &self.scope)
+            .field("pos", &self.pos)
+            .finish_non_exhaustive()
+    }
+}
@@ -31,7 +31,7 @@ use super::*;
 fn has_handler() {
     use super::*;
 
-    let mut log = Logger::string_logger(module_path!());
+    let log = Logger::string_logger(module_path!());
     log.info("Some text to store.");
 
     assert!(log.has_handler(Handler::String));
@@ -50,6 +50,53 @@ fn get_handler() {
     assert!(log.get_handler(Handler::Console).is_none());
 }
 
+#[test]
+fn into_handler() {
+    let mut log = Logger::builder(module_path!())
+        .add_string_handler()
+        .add_console_handler()
+        .build();
+    let ids = log.handler_ids(None);
+
+    let h = Scope::new(&mut log, "into_handler")
+        .into_handler(Handler::String)
+        .unwrap();
+    assert!(h.get_log().is_empty());
+    drop(h);
+
+    // Put back in its place.
+    assert_eq!(log.handler_ids(None), ids);
+    assert_eq!(log.handler_ids(Some(Handler::String)), vec![ids[0]]);
+    assert!(
+        Scope::new(&mut log, "into_handler")
+            .into_handler(Handler::File)
+            .is_none()
+    );
+}
+
+#[test]
+fn get_handler_as() {
+    let mut log = Logger::builder(module_path!())
+        .add_memory_handler(
+            Box::new(StringHandler::create("target").unwrap()),
+            10,
+            Level::SEVERE,
+        )
+        .build();
+
+    let memory = log.get_handler_as::<MemoryHandler>().unwrap();
+    assert_eq!(memory.capacity(), 10);
+
+    memory.set_push_level(Level::INFO);
+    log.info("Pushed straight through.");
+
+    assert_eq!(
+        log.get_handler_as::<MemoryHandler>().unwrap().push_level(),
+        Level::INFO
+    );
+    assert!(log.get_handler_as::<StringHandler>().is_none());
+}
+
 #[test]
 fn is_logging() {
     let mut log = Logger::console_logger(module_path!());
@@ -62,3 +109,493 @@ fn is_logging() {
     assert!(!log.is_logging());
     assert!(!log.is_loggable(&Level::WARNING));
 }
+
+#[test]
+fn log_at() {
+    let mut log = Logger::string_logger(module_path!());
+    log.set_fn_name("log_at");
+
+    log.log_at(Level::OFF, "Off.");
+    log.log_at(Level::ALL, "All.");
+    log.log_at(Level::FINE, "Fine.");
+    log.log_at(Level::SEVERE, "Severe.");
+
+    assert_eq!(
+        log.get_handler(Handler::String).unwrap().get_log(),
+        "flogging::logger::tests->log_at [SEVERE ] Severe.\n"
+    );
+}
+
+#[test]
+fn async_logging() {
+    let mut log = Logger::builder(module_path!())
+        .add_string_handler()
+        .set_level(Level::FINEST)
+        .set_async(4)
+        .build();
+    log.set_fn_name("async_logging");
+
+    assert!(log.is_async());
+    assert!(log.get_handler(Handler::String).is_none());
+
+    for i in 0..100 {
+        log.finest(&format!("Entry: {i}"));
+    }
+
+    log.shutdown();
+    assert!(!log.is_async());
+
+    let buf = log.get_handler(Handler::String).unwrap().get_log();
+    let lines: Vec<&str> = buf.lines().collect();
+
+    assert_eq!(lines.len(), 100);
+    assert_eq!(
+        lines[99],
+        "flogging::logger::tests->async_logging [FINEST ] Entry: 99"
+    );
+
+    // Now synchronous again.
+    log.info("Done.");
+    assert!(
+        log.get_handler(Handler::String)
+            .unwrap()
+            .get_log()
+            .ends_with("[INFO   ] Done.\n")
+    );
+}
+
+#[test]
+fn module_levels() {
+    let log = Logger::builder("my_app::db")
+        .add_string_handler()
+        .set_level(Level::WARNING)
+        .set_module_level("my_app::db", Level::FINEST)
+        .set_module_level("other_crate", Level::OFF)
+        .build();
+
+    assert_eq!(log.module_level("my_app::db::pool"), Level::FINEST);
+    assert_eq!(log.module_level("my_app::net"), Level::WARNING);
+    assert_eq!(log.level_map().len(), 2);
+
+    assert!(log.is_loggable(&Level::FINEST));
+    assert!(!log.is_loggable_from("my_app::net", &Level::INFO));
+    assert!(!log.is_loggable_from("other_crate", &Level::SEVERE));
+}
+
+#[test]
+fn add_remove_handler() {
+    let mut log = Logger::builder(module_path!())
+        .add_string_handler()
+        .set_async(10)
+        .build();
+    log.set_fn_name("add_remove_handler");
+
+    log.info("Before.");
+    log.add_handler(
+        Handler::Custom("Diag".to_string()),
+        Box::new(StringHandler::create("Diag").unwrap()),
+    );
+    log.info("During.");
+
+    let diag = log
+        .remove_handler(Handler::Custom("Diag".to_string()))
+        .unwrap();
+    log.info("After.");
+
+    assert_eq!(
+        diag.get_log(),
+        "flogging::logger::tests->add_remove_handler [INFO   ] During.\n"
+    );
+    assert!(log.remove_handler(Handler::Custom("Diag".to_string())).is_none());
+
+    log.shutdown();
+    assert_eq!(
+        log.get_handler(Handler::String).unwrap().get_log().lines().count(),
+        3
+    );
+    assert!(log.remove_handler(Handler::String).is_some());
+    assert!(!log.has_handler(Handler::String));
+}
+
+#[test]
+fn handler_order() {
+    use std::sync::{Arc, Mutex};
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let visit = |name: &'static str| {
+        let order = Arc::clone(&order);
+        Box::new(move |_: &LogEntry| {
+            order.lock().unwrap().push(name);
+            true
+        })
+    };
+
+    let log = Logger::builder("order")
+        .add_custom_handler_with_filter(
+            "C",
+            Box::new(StringHandler::create("C").unwrap()),
+            visit("C"),
+        )
+        .add_string_handler_with_filter(visit("String"))
+        .add_custom_handler_with_filter(
+            "A",
+            Box::new(StringHandler::create("A").unwrap()),
+            visit("A"),
+        )
+        .add_custom_handler_with_filter(
+            "B",
+            Box::new(StringHandler::create("B").unwrap()),
+            visit("B"),
+        )
+        .build();
+
+    log.info("First.");
+    log.info("Second.");
+
+    assert_eq!(
+        *order.lock().unwrap(),
+        vec!["C", "String", "A", "B", "C", "String", "A", "B"]
+    );
+
+    let kinds: Vec<String> = log
+        .to_string()
+        .lines()
+        .filter(|l| l.starts_with("Handler::"))
+        .filter_map(|l| l.split_once(": ").map(|(kind, _)| kind.to_string()))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            "Handler::Custom(C)",
+            "Handler::String",
+            "Handler::Custom(A)",
+            "Handler::Custom(B)"
+        ]
+    );
+}
+
+#[test]
+fn handler_stats() {
+    let mut log = Logger::builder("stats")
+        .add_string_handler_with_filter(Box::new(|e: &LogEntry| !e.message().contains("secret")))
+        .add_custom_handler_at(
+            "Warnings",
+            Box::new(StringHandler::create("Warnings").unwrap()),
+            Level::WARNING,
+        )
+        .build();
+    log.set_fn_name("handler_stats");
+
+    log.info("one");
+    log.info("secret");
+    log.warning("three");
+
+    let stats = log.handler_stats(Handler::String).unwrap();
+    assert_eq!(stats.published(), 2);
+    assert_eq!(stats.filtered(), 1);
+    assert_eq!(stats.dropped(), 0);
+    assert_eq!(
+        stats.bytes_written(),
+        ("stats->handler_stats [INFO   ] one".len() + "stats->handler_stats [WARNING] three".len())
+            as u64
+    );
+    assert_eq!(stats.last_error(), None);
+
+    let ids = log.handler_ids(Some(Handler::Custom("Warnings".to_string())));
+    let stats = log.handler_stats_by_id(ids[0]).unwrap();
+    assert_eq!(stats.published(), 1);
+    assert_eq!(stats.filtered(), 2);
+
+    let h = log
+        .get_handler(Handler::Custom("Warnings".to_string()))
+        .unwrap();
+    assert_eq!(h.stats().published(), 1);
+    assert_eq!(h.stats().filtered(), 0);
+
+    assert!(log.handler_stats(Handler::Console).is_none());
+}
+
+#[test]
+fn counts() {
+    let mut log = Logger::builder("counts")
+        .add_string_handler()
+        .set_level(Level::FINE)
+        .build();
+
+    log.finest("one");
+    log.fine("two");
+    log.info("three");
+    log.warning("four");
+    log.warning("five");
+    log.log_at(Level::OFF, "six");
+
+    let counts = log.counts();
+    assert_eq!(counts.accepted(Level::FINEST), 0);
+    assert_eq!(counts.accepted(Level::FINE), 1);
+    assert_eq!(counts.accepted(Level::WARNING), 2);
+    assert_eq!(counts.total(), 4);
+    assert_eq!(counts.rejected(), 1);
+
+    log.set_fn_level(Some(Level::SEVERE));
+    log.warning("seven");
+    assert_eq!(log.counts().rejected(), 2);
+
+    log.reset_counts();
+    assert_eq!(log.counts(), LevelCounts::default());
+    assert_eq!(log.counts().iter().count(), 0);
+}
+
+#[test]
+fn dedup() {
+    let mut log = Logger::builder("dedup")
+        .add_string_handler()
+        .set_dedup(true)
+        .set_fn_name("dedup")
+        .build();
+
+    log.info("one");
+    log.info("two");
+    log.info("two");
+    log.warning("two");
+    log.warning("two");
+    log.warning("two");
+    log.set_dedup(false);
+    log.warning("two");
+
+    assert_eq!(
+        log.get_handler(Handler::String).unwrap().get_log(),
+        "dedup->dedup [INFO   ] one
+dedup->dedup [INFO   ] two
+dedup->dedup [INFO   ] Last message repeated 1 times.
+dedup->dedup [WARNING] two
+dedup->dedup [WARNING] Last message repeated 2 times.
+dedup->dedup [WARNING] two\n"
+    );
+}
+
+#[test]
+fn dedup_fields() {
+    let mut log = Logger::builder("dedup")
+        .add_string_handler()
+        .set_dedup(true)
+        .set_fn_name("dedup_fields")
+        .build();
+
+    // Only the same message with the same fields is collapsed.
+    log.with_fields([("id", "1")]).info("Saved.");
+    log.with_fields([("id", "2")]).info("Saved.");
+    log.with_fields([("id", "2")]).info("Saved.");
+    log.info("Saved.");
+
+    assert_eq!(
+        log.get_handler(Handler::String).unwrap().get_log(),
+        "dedup->dedup_fields [INFO   ] Saved. id=1
+dedup->dedup_fields [INFO   ] Saved. id=2
+dedup->dedup_fields [INFO   ] Last message repeated 1 times. id=2
+dedup->dedup_fields [INFO   ] Saved.\n"
+    );
+}
+
+#[test]
+fn flush_and_close() {
+    let filename = "test_logs/logger_flush_and_close.log";
+
+    for capacity in [None, Some(10)] {
+        let mut builder = Logger::builder(module_path!())
+            .remove_file(filename)
+            .add_file_handler(filename);
+
+        if let Some(capacity) = capacity {
+            builder = builder.set_async(capacity);
+        }
+
+        let mut log = builder.build();
+
+        // Only reachable when not async, as the worker then owns the handlers.
+        if let Some(h) = log.get_handler_as::<FileHandler>() {
+            h.set_flush_policy(FlushPolicy::new(100));
+        }
+
+        log.info("one");
+        log.flush();
+        assert_eq!(fs::read_to_string(filename).unwrap().lines().count(), 1);
+
+        log.info("two");
+        log.close();
+        assert_eq!(fs::read_to_string(filename).unwrap().lines().count(), 2);
+
+        log.info("dropped");
+        log.shutdown();
+        assert!(!log.get_handler(Handler::File).unwrap().is_open());
+    }
+}
+
+#[test]
+fn shared_flush_and_close() {
+    let filename = "test_logs/logger_shared_flush_and_close.log";
+
+    let mut log = Logger::builder(module_path!())
+        .remove_file(filename)
+        .add_file_handler(filename)
+        .build();
+    log.get_handler_as::<FileHandler>()
+        .unwrap()
+        .set_flush_policy(FlushPolicy::new(100));
+
+    let log = Arc::new(log);
+
+    let worker = {
+        let log = Arc::clone(&log);
+
+        thread::spawn(move || {
+            log.info("one");
+            log.flush();
+        })
+    };
+    worker.join().unwrap();
+    assert_eq!(fs::read_to_string(filename).unwrap().lines().count(), 1);
+
+    log.info("two");
+    log.close();
+    assert_eq!(fs::read_to_string(filename).unwrap().lines().count(), 2);
+}
+
+#[test]
+fn shared_reference() {
+    // Only needs `&Logger`.
+    fn process(log: &Logger, items: &[&str]) {
+        for item in items {
+            log.with_fields([("item", *item)]).fine("Processed.");
+        }
+
+        log.warning("Done.");
+    }
+
+    let mut log = Logger::string_logger("shared");
+    log.set_level(Level::FINE);
+    log.set_fn_name("process");
+
+    process(&log, &["a", "b"]);
+
+    assert_eq!(log.counts().accepted(Level::FINE), 2);
+    assert_eq!(
+        log.get_handler(Handler::String).unwrap().get_log(),
+        "shared->process [FINE   ] Processed. item=a
+shared->process [FINE   ] Processed. item=b
+shared->process [WARNING] Done.\n"
+    );
+}
+
+#[test]
+fn fields_per_logger() {
+    let mut first = Logger::string_logger("first");
+    let mut second = Logger::string_logger("second");
+
+    // Not taken by another logger.
+    first.with_fields([("n", "1")]);
+    second.info("Second.");
+    first.info("First.");
+
+    assert_eq!(
+        first.get_handler(Handler::String).unwrap().get_log(),
+        "first-> [INFO   ] First. n=1\n"
+    );
+    assert_eq!(
+        second.get_handler(Handler::String).unwrap().get_log(),
+        "second-> [INFO   ] Second.\n"
+    );
+
+    // Not kept once their logger is gone.
+    Logger::string_logger("gone").with_fields([("n", "2")]);
+    second.with_fields([("n", "3")]);
+    assert_eq!(FIELDS.with_borrow(Vec::len), 1);
+
+    second.info("Third.");
+    assert_eq!(FIELDS.with_borrow(Vec::len), 0);
+}
+
+#[test]
+fn send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Logger>();
+
+    let log = Arc::new(Logger::string_logger("sync"));
+
+    let handles: Vec<_> = (0..4)
+        .map(|id| {
+            let log = Arc::clone(&log);
+
+            thread::spawn(move || {
+                for _ in 0..10 {
+                    log.with_fields([("worker", id.to_string())])
+                        .info("Working.");
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let mut log = Arc::into_inner(log).unwrap();
+    let buf = log.get_handler(Handler::String).unwrap().get_log();
+
+    assert_eq!(log.counts().accepted(Level::INFO), 40);
+
+    // Each worker's fields only went with its own log entries.
+    for id in 0..4 {
+        assert_eq!(buf.matches(&format!("Working. worker={id}\n")).count(), 10);
+    }
+}
+
+#[test]
+fn nested() {
+    use std::{sync::LazyLock, time::Duration};
+
+    // The alert logs to this same logger, while it is still publishing.
+    static LOG: LazyLock<Logger> = LazyLock::new(|| {
+        Logger::builder("nested")
+            .add_string_handler()
+            .add_alert(
+                Alert::new(0, Duration::from_secs(60), |_: &[LogEntry]| {
+                    LOG.warning("Alert raised.")
+                })
+                .at_level(Level::SEVERE),
+            )
+            .build()
+    });
+
+    LOG.severe("Disk full.");
+    LOG.info("Carrying on.");
+
+    assert_eq!(LOG.counts().accepted(Level::WARNING), 1);
+    assert_eq!(LOG.handler_stats(Handler::String).unwrap().published(), 3);
+}
+
+#[test]
+fn scope() {
+    let mut log = Logger::string_logger("scope");
+    log.set_fn_name("main");
+
+    let outer = log.scope("outer");
+    let inner = log.scope("inner").set_fn_level(Some(Level::FINE));
+
+    outer.info("One.");
+    inner.fine("Two.");
+    outer.fine("Not logged.");
+    inner.with_fields([("n", "3")]).warning("Three.");
+    log.info("Four.");
+
+    assert_eq!(outer.fn_name(), "outer");
+    assert_eq!(inner.fn_level(), Some(Level::FINE));
+    assert!(!outer.is_enabled(Level::FINE));
+    assert_eq!(log.fn_name(), "main");
+    assert_eq!(
+        log.get_handler(Handler::String).unwrap().get_log(),
+        "scope->outer [INFO   ] One.
+scope->inner [FINE   ] Two.
+scope->inner [WARNING] Three. n=3
+scope->main [INFO   ] Four.\n"
+    );
+}
@@ -25,7 +25,13 @@
 //! # Macros
 //!
 
-use crate::Logger;
+use crate::{LogEntry, Logger};
+use std::cell::{LazyCell, RefCell, RefMut};
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
 
 ///
 /// Setup module level logger access.
@@ -41,6 +47,13 @@ use crate::Logger;
 /// The code you put in here will depend on what configuration of `Logger` you
 /// want to setup.
 ///
+/// The `Logger` is built once, the first time it is used, and is then held in a
+/// module level `static`. So its handlers (open files, etc.) are only constructed
+/// once per module, no matter how many times the attributed functions are called.
+///
+/// **Note:** Despite its name, this now expands to the same `static` as
+/// [`static_logger!()`][crate::static_logger]. The name is retained for backward compatibility.
+///
 /// # Examples
 /// ```
 /// extern crate flogging;
@@ -57,10 +70,387 @@ use crate::Logger;
 #[macro_export]
 macro_rules! const_logger {
     ($block:block) => {
-        use flogging::Logger as FLogger;
-        use std::cell::{LazyCell as FLazyCell, RefCell as FRefCell};
+        $crate::static_logger!($block);
+    };
+}
 
+///
+/// Setup thread-safe module level logger access.
+///
+/// It creates a `static LazyLock<Mutex<Logger>>`, so the one `Logger` is built
+/// once, and is then shared by every thread calling into the module.
+///
+/// The `static` is named `FLOGGING_LOGGER`, which is what the code generated by the
+/// [`#[logger]`][crate::logger] attribute refers to. That code, and the code generated by the
+/// logging macros, only use fully qualified paths (`::flogging::Level`, etc.). So neither
+/// `use flogging::*;`, nor any other particular import, is required. The `static` can
+/// also be used directly:
+///
+/// ```
+/// use flogging::{LockLogger, Logger, info, logger, static_logger};
+///
+/// static_logger!({
+///     Logger::string_logger(module_path!())
+/// });
+///
+/// #[logger]
+/// fn work() {
+///     info!("Working.");
+/// }
+///
+/// fn main() {
+///     work();
+///
+///     let level = FLOGGING_LOGGER.lock_logger().level();
+///     assert_eq!(level, flogging::Level::INFO);
+/// }
+/// ```
+///
+/// The basic macro syntax is:
+///
+/// ```text
+/// static_logger!({/* the block of Rust code to build a Logger goes here */});
+/// ```
+/// Notice there are curly braces "`{}`" wrapping the inner Rust code.
+/// **They are required.**
+///
+/// # Examples
+/// ```
+/// extern crate flogging;
+/// use flogging::*;
+/// use std::thread;
+///
+/// static_logger!({
+///     Logger::builder(module_path!())
+///         .set_level(Level::FINEST)
+///         .add_console_handler()
+///         .build()
+/// });
+///
+/// #[logger]
+/// fn worker(id: usize) {
+///     entering!("id: {id}");
+///     info!("Working hard.");
+///     exiting!();
+/// }
+///
+/// fn main() {
+///     let handles: Vec<_> = (0..4).map(|id| thread::spawn(move || worker(id))).collect();
+///
+///     for handle in handles {
+///         handle.join().unwrap();
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! static_logger {
+    ($block:block) => {
         // Setup module level logger access.
-        const LOGGER: FLazyCell<FRefCell<FLogger>> = FLazyCell::new(|| FRefCell::new({ $block }));
+        static FLOGGING_LOGGER: ::std::sync::LazyLock<::std::sync::Mutex<$crate::Logger>> =
+            ::std::sync::LazyLock::new(|| ::std::sync::Mutex::new({ $block }));
     };
 }
+
+///
+/// Provides exclusive access to a module level `Logger`.
+///
+/// This is used by the code generated by the [`#[logger]`][crate::logger] attribute,
+/// so that it works with the `FLOGGING_LOGGER` set up by [`static_logger!()`], as well as
+/// with a hand written `const FLOGGING_LOGGER: LazyCell<RefCell<Logger>>`.
+///
+/// ## Reentrancy
+///
+/// A handler, enricher, or alert may itself log to the same module level logger, such as
+/// to report its own failure. That would be while the logger is already held, further up
+/// the same thread. So, rather than deadlock (or panic), that nested use is given a
+/// stand-in logger, with no handlers. Its log entries are then logged by the real one,
+/// once it is released.
+///
+#[doc(hidden)]
+pub trait LockLogger {
+    ///
+    /// The guard returned by [`lock_logger()`][LockLogger::lock_logger].
+    ///
+    type Guard<'a>: DerefMut<Target = Logger>
+    where
+        Self: 'a;
+
+    ///
+    /// Obtain exclusive access to the `Logger`.
+    ///
+    /// A poisoned lock is recovered, rather than causing another panic.
+    ///
+    fn lock_logger(&self) -> Self::Guard<'_>;
+}
+
+impl LockLogger for LazyCell<RefCell<Logger>> {
+    type Guard<'a> = LoggerGuard<'a>;
+
+    fn lock_logger(&self) -> Self::Guard<'_> {
+        let addr = self as *const Self as usize;
+
+        match self.try_borrow_mut() {
+            Ok(logger) => LoggerGuard::held(addr, Access::Borrowed(logger)),
+            Err(_) => LoggerGuard::nested(addr),
+        }
+    }
+}
+
+impl LockLogger for LazyLock<Mutex<Logger>> {
+    type Guard<'a> = LoggerGuard<'a>;
+
+    fn lock_logger(&self) -> Self::Guard<'_> {
+        let addr = self as *const Self as usize;
+
+        if HELD.with_borrow(|held| held.contains(&addr)) {
+            return LoggerGuard::nested(addr);
+        }
+
+        let logger = self.lock().unwrap_or_else(PoisonError::into_inner);
+        LoggerGuard::held(addr, Access::Locked(logger))
+    }
+}
+
+thread_local! {
+    ///
+    /// The module level loggers, by address, that this thread holds.
+    ///
+    static HELD: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+
+    ///
+    /// The log entries from the nested uses of the module level loggers, by address,
+    /// waiting for the logger to be released.
+    ///
+    static DEFERRED: RefCell<Vec<(usize, LogEntry)>> = const { RefCell::new(Vec::new()) };
+}
+
+///
+/// Defer `entry`, logged by a stand-in, until the module level logger at `addr` is released.
+///
+pub(crate) fn defer(addr: usize, entry: LogEntry) {
+    DEFERRED.with_borrow_mut(|deferred| deferred.push((addr, entry)));
+}
+
+///
+/// The access to a module level `Logger`, given by [`LockLogger::lock_logger()`].
+///
+#[doc(hidden)]
+pub struct LoggerGuard<'a> {
+    addr: usize,
+    access: Access<'a>,
+}
+
+enum Access<'a> {
+    Locked(MutexGuard<'a, Logger>),
+    Borrowed(RefMut<'a, Logger>),
+
+    ///
+    /// The stand-in for a logger already held by this thread.
+    ///
+    Nested(Logger),
+}
+
+impl LoggerGuard<'_> {
+    ///
+    /// Any reconfigurations posted by the `LogManager` are applied, now that the
+    /// logger is held.
+    ///
+    fn held<'a>(addr: usize, access: Access<'a>) -> LoggerGuard<'a> {
+        HELD.with_borrow_mut(|held| held.push(addr));
+
+        let mut guard = LoggerGuard { addr, access };
+        guard.reconfigure();
+        guard
+    }
+
+    fn nested<'a>(addr: usize) -> LoggerGuard<'a> {
+        LoggerGuard {
+            addr,
+            access: Access::Nested(Logger::stand_in(addr)),
+        }
+    }
+}
+
+impl Deref for LoggerGuard<'_> {
+    type Target = Logger;
+
+    fn deref(&self) -> &Logger {
+        match &self.access {
+            Access::Locked(logger) => logger,
+            Access::Borrowed(logger) => logger,
+            Access::Nested(logger) => logger,
+        }
+    }
+}
+
+impl DerefMut for LoggerGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Logger {
+        match &mut self.access {
+            Access::Locked(logger) => logger,
+            Access::Borrowed(logger) => logger,
+            Access::Nested(logger) => logger,
+        }
+    }
+}
+
+impl Drop for LoggerGuard<'_> {
+    ///
+    /// A stand-in has already deferred its log entries. Otherwise, the log entries
+    /// deferred while this logger was held are logged, before it is released.
+    ///
+    fn drop(&mut self) {
+        let addr = self.addr;
+
+        if let Access::Nested(_) = self.access {
+            return;
+        }
+
+        loop {
+            let entries = DEFERRED.with_borrow_mut(|deferred| {
+                let (mine, others) = mem::take(deferred)
+                    .into_iter()
+                    .partition::<Vec<_>, _>(|(a, _)| *a == addr);
+                *deferred = others;
+                mine
+            });
+
+            if entries.is_empty() {
+                break;
+            }
+
+            for (_, entry) in entries {
+                self.replay(entry);
+            }
+        }
+
+        HELD.with_borrow_mut(|held| held.retain(|&a| a != addr));
+    }
+}
+
+///
+/// Runs a closure when dropped.
+///
+/// This is used by the code generated by [`#[logger(trace)]`][crate::logger], so that
+/// the exit from a function is logged, however it returns: falling through the end,
+/// by an early `return`, or by the `?` operator. With `#[logger(panic)]`, it is also
+/// run while unwinding from a panic.
+///
+#[doc(hidden)]
+pub struct ExitGuard<F: FnMut()> {
+    on_exit: F,
+}
+
+impl<F: FnMut()> ExitGuard<F> {
+    ///
+    /// Create a new guard, to run `on_exit` when it is dropped.
+    ///
+    pub fn new(on_exit: F) -> Self {
+        Self { on_exit }
+    }
+}
+
+impl<F: FnMut()> Drop for ExitGuard<F> {
+    fn drop(&mut self) {
+        (self.on_exit)();
+    }
+}
+
+///
+/// How often the `*_every!()` macros log: every `n`th time they are reached,
+/// or at most once per interval.
+///
+/// Converted from an integer (`n`), or a [`Duration`] (the interval).
+///
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Every {
+    ///
+    /// Every `n`th time. `0` is taken as `1`.
+    ///
+    Count(u64),
+    ///
+    /// At most once per interval.
+    ///
+    Interval(Duration),
+}
+
+impl From<Duration> for Every {
+    fn from(interval: Duration) -> Self {
+        Every::Interval(interval)
+    }
+}
+
+impl From<i32> for Every {
+    fn from(n: i32) -> Self {
+        Every::Count(n.max(0) as u64)
+    }
+}
+
+impl From<u32> for Every {
+    fn from(n: u32) -> Self {
+        Every::Count(n as u64)
+    }
+}
+
+impl From<u64> for Every {
+    fn from(n: u64) -> Self {
+        Every::Count(n)
+    }
+}
+
+impl From<usize> for Every {
+    fn from(n: usize) -> Self {
+        Every::Count(n as u64)
+    }
+}
+
+///
+/// The state of one call site of the `*_every!()` macros.
+///
+/// This is used by the code generated by those macros, as a `static` at each call site.
+///
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct EveryState {
+    count: AtomicU64,
+    last: Mutex<Option<Instant>>,
+}
+
+impl EveryState {
+    ///
+    /// Create a new state, for a call site not yet reached.
+    ///
+    pub const fn new() -> Self {
+        Self {
+            count: AtomicU64::new(0),
+            last: Mutex::new(None),
+        }
+    }
+
+    ///
+    /// Count the call site as reached.
+    ///
+    /// Returns `true` if it is to log this time: the first time, then as set by `every`.
+    ///
+    pub fn tick(&self, every: impl Into<Every>) -> bool {
+        match every.into() {
+            Every::Count(n) => self.count.fetch_add(1, Ordering::Relaxed) % n.max(1) == 0,
+            Every::Interval(interval) => {
+                let mut last = self.last.lock().unwrap_or_else(PoisonError::into_inner);
+
+                if last.is_some_and(|last| last.elapsed() < interval) {
+                    return false;
+                }
+
+                *last = Some(Instant::now());
+                true
+            }
+        }
+    }
+}
+
+impl Default for EveryState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
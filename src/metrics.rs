@@ -0,0 +1,158 @@
+//
+// File Name:    metrics.rs
+// Directory:    src
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Metrics
+//!
+//! Counts the log entries published by every [`Logger`][crate::Logger], through the
+//! [`metrics`] crate facade. So logging volume and error rates show up on existing
+//! dashboards, such as Prometheus, without a custom handler.
+//!
+//! Requires the `metrics` feature:
+//! ```text
+//! [dependencies]
+//! flogging = { version = "0.6.0", features = ["metrics"] }
+//! ```
+//!
+//! Each log entry published increments the counter [`ENTRIES_TOTAL`], with the label
+//! `level` set to its [`Level`], such as:
+//! ```text
+//! flogging_entries_total{level="SEVERE"}
+//! ```
+//!
+//! The log entries rejected by the logger's level are not counted. Nor is any filtering
+//! by the handlers. Nothing is recorded until a `metrics` recorder, or exporter, is
+//! installed by the application.
+//!
+
+use crate::Level;
+
+///
+/// The name of the counter of log entries published.
+///
+pub const ENTRIES_TOTAL: &str = "flogging_entries_total";
+
+///
+/// Describe the counter [`ENTRIES_TOTAL`] to the installed recorder.
+///
+/// This is optional. Call it after installing the recorder, to have the
+/// description included with the counter, such as in Prometheus' `# HELP` line.
+///
+pub fn describe() {
+    ::metrics::describe_counter!(
+        ENTRIES_TOTAL,
+        "The number of log entries published, by level."
+    );
+}
+
+///
+/// Count a log entry published at `level`.
+///
+pub(crate) fn published(level: Level) {
+    ::metrics::counter!(ENTRIES_TOTAL, "level" => level.as_str()).increment(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use ::metrics::{
+        Counter, CounterFn, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString, Unit,
+    };
+    use std::sync::{Arc, Mutex};
+
+    ///
+    /// Records the counters incremented, as: `name{label=value}: count`.
+    ///
+    #[derive(Default)]
+    struct TestRecorder {
+        counts: Arc<Mutex<Vec<(String, u64)>>>,
+    }
+
+    struct TestCounter {
+        key: String,
+        counts: Arc<Mutex<Vec<(String, u64)>>>,
+    }
+
+    impl CounterFn for TestCounter {
+        fn increment(&self, value: u64) {
+            let mut counts = self.counts.lock().unwrap();
+
+            match counts.iter_mut().find(|(key, _)| *key == self.key) {
+                Some((_, count)) => *count += value,
+                None => counts.push((self.key.clone(), value)),
+            }
+        }
+
+        fn absolute(&self, _value: u64) {}
+    }
+
+    impl Recorder for TestRecorder {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            let labels: Vec<String> = key
+                .labels()
+                .map(|l| format!("{}={}", l.key(), l.value()))
+                .collect();
+
+            Counter::from_arc(Arc::new(TestCounter {
+                key: format!("{}{{{}}}", key.name(), labels.join(",")),
+                counts: Arc::clone(&self.counts),
+            }))
+        }
+
+        fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::noop()
+        }
+
+        fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::noop()
+        }
+    }
+
+    #[test]
+    fn entries_total() {
+        let recorder = TestRecorder::default();
+
+        ::metrics::with_local_recorder(&recorder, || {
+            describe();
+
+            let log = Logger::string_logger(module_path!());
+            log.info("one");
+            log.severe("two");
+            log.fine("Not published.");
+            log.severe("three");
+        });
+
+        assert_eq!(
+            *recorder.counts.lock().unwrap(),
+            vec![
+                ("flogging_entries_total{level=INFO}".to_string(), 1),
+                ("flogging_entries_total{level=SEVERE}".to_string(), 2),
+            ]
+        );
+    }
+}
@@ -0,0 +1,295 @@
+//
+// File Name:    testing.rs
+// Directory:    src
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Testing
+//!
+//! Helpers to unit-test your logging, without hand-rolled regexes:
+//! - [`assert_logged!()`][crate::assert_logged] - Asserts that a matching log entry was logged.
+//! - [`assert_not_logged!()`][crate::assert_not_logged] - Asserts that no matching log entry was logged.
+//!
+//! They inspect the log held by a logger's handlers, such as a
+//! [`StringHandler`][crate::StringHandler], or by a single handler. A log entry matches
+//! if its line contains the level, as a whole word, and the line also meets the optional
+//! condition:
+//! - `contains "text"` - The line contains the text.
+//! - `matches "regex"` - The line matches the regular expression.
+//!
+//! ## Examples
+//! ```
+//! use flogging::*;
+//!
+//! let mut log = Logger::string_logger("app");
+//! log.warning("The sky is falling!");
+//!
+//! assert_logged!(log, Level::WARNING, contains "sky is falling");
+//! assert_logged!(log, Level::WARNING, matches r"sky is \w+!");
+//! assert_not_logged!(log, Level::SEVERE);
+//! assert_not_logged!(log, Level::INFO, contains "sky");
+//! ```
+//!
+
+use crate::{HandlerTrait, Level, Logger};
+use regex::Regex;
+
+///
+/// A source of logged lines: a [`Logger`], or a handler.
+///
+/// This is what [`assert_logged!()`][crate::assert_logged] and
+/// [`assert_not_logged!()`][crate::assert_not_logged] inspect.
+///
+pub trait Logged {
+    ///
+    /// Returns each line logged, as formatted by the handler(s).
+    ///
+    fn logged_lines(&mut self) -> Vec<String>;
+}
+
+impl Logged for Logger {
+    ///
+    /// Returns the lines logged by all of this logger's handlers, in the order the
+    /// handlers were added.
+    ///
+    fn logged_lines(&mut self) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        for id in self.handler_ids(None) {
+            if let Some(h) = self.get_handler_by_id(id) {
+                lines.extend(h.logged_lines());
+            }
+        }
+
+        lines
+    }
+}
+
+impl<T: HandlerTrait + ?Sized> Logged for T {
+    fn logged_lines(&mut self) -> Vec<String> {
+        self.get_log().lines().map(str::to_string).collect()
+    }
+}
+
+///
+/// The condition a logged line must also meet. Used by the assertion macros.
+///
+#[doc(hidden)]
+pub enum Condition<'a> {
+    Any,
+    Contains(&'a str),
+    Matches(&'a str),
+}
+
+///
+/// The implementation of [`assert_logged!()`][crate::assert_logged] and
+/// [`assert_not_logged!()`][crate::assert_not_logged].
+///
+/// Panics if `expected` is not whether or not a matching line was logged.
+///
+#[doc(hidden)]
+#[track_caller]
+pub fn check_logged(lines: Vec<String>, level: Level, condition: Condition, expected: bool) {
+    let regex = match condition {
+        Condition::Matches(re) => Some(Regex::new(re).unwrap_or_else(|e| panic!("{e}"))),
+        _ => None,
+    };
+
+    let is_match = |line: &String| {
+        line.split(|c: char| !c.is_ascii_alphabetic())
+            .any(|word| word == level.as_str())
+            && match condition {
+                Condition::Any => true,
+                Condition::Contains(text) => line.contains(text),
+                Condition::Matches(_) => regex.as_ref().is_some_and(|re| re.is_match(line)),
+            }
+    };
+
+    let found = lines.iter().find(|line| is_match(line));
+
+    match (found, expected) {
+        (None, true) => panic!(
+            "expected a {level} log entry{}, in:\n{}",
+            describe(&condition),
+            lines.join("\n")
+        ),
+        (Some(line), false) => panic!(
+            "expected no {level} log entry{}, found:\n{line}",
+            describe(&condition)
+        ),
+        _ => {}
+    }
+}
+
+///
+/// Returns the description of `condition`, for the panic message.
+///
+fn describe(condition: &Condition) -> String {
+    match condition {
+        Condition::Any => String::new(),
+        Condition::Contains(text) => format!(" containing {text:?}"),
+        Condition::Matches(re) => format!(" matching {re:?}"),
+    }
+}
+
+///
+/// Asserts that a log entry at `level` was logged, optionally one meeting a condition.
+///
+/// The log is that held by `log`: a [`Logger`], or a handler. See [`testing`][crate::testing].
+///
+/// ## Parameters
+/// - `log` - The `Logger`, or handler, to inspect.
+/// - `level` - The [`Level`] of the log entry.
+/// - `contains "text"` - (Optional) The log entry contains the text.
+/// - `matches "regex"` - (Optional) The log entry matches the regular expression.
+///
+/// ## Examples
+/// ```
+/// use flogging::*;
+///
+/// let mut log = Logger::string_logger("app");
+/// log.info("Started.");
+///
+/// assert_logged!(log, Level::INFO);
+/// assert_logged!(log, Level::INFO, contains "Started");
+///
+/// let h = log.get_handler(Handler::String).unwrap();
+/// assert_logged!(h, Level::INFO, matches "^app->");
+/// ```
+///
+#[macro_export]
+macro_rules! assert_logged {
+    ($log:expr, $level:expr $(,)?) => {
+        $crate::__check_logged!($log, $level, $crate::testing::Condition::Any, true)
+    };
+    ($log:expr, $level:expr, contains $text:expr $(,)?) => {
+        $crate::__check_logged!(
+            $log,
+            $level,
+            $crate::testing::Condition::Contains(&$text),
+            true
+        )
+    };
+    ($log:expr, $level:expr, matches $re:expr $(,)?) => {
+        $crate::__check_logged!(
+            $log,
+            $level,
+            $crate::testing::Condition::Matches(&$re),
+            true
+        )
+    };
+}
+
+///
+/// Asserts that no log entry at `level` was logged, optionally none meeting a condition.
+///
+/// This takes the same parameters as [`assert_logged!()`][crate::assert_logged].
+///
+/// ## Examples
+/// ```
+/// use flogging::*;
+///
+/// let mut log = Logger::string_logger("app");
+/// log.fine("Not logged, at the default level.");
+/// log.info("Started.");
+///
+/// assert_not_logged!(log, Level::FINE);
+/// assert_not_logged!(log, Level::INFO, contains "Stopped");
+/// ```
+///
+#[macro_export]
+macro_rules! assert_not_logged {
+    ($log:expr, $level:expr $(,)?) => {
+        $crate::__check_logged!($log, $level, $crate::testing::Condition::Any, false)
+    };
+    ($log:expr, $level:expr, contains $text:expr $(,)?) => {
+        $crate::__check_logged!(
+            $log,
+            $level,
+            $crate::testing::Condition::Contains(&$text),
+            false
+        )
+    };
+    ($log:expr, $level:expr, matches $re:expr $(,)?) => {
+        $crate::__check_logged!(
+            $log,
+            $level,
+            $crate::testing::Condition::Matches(&$re),
+            false
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_logged {
+    ($log:expr, $level:expr, $condition:expr, $expected:expr) => {{
+        use $crate::testing::Logged as _;
+
+        $crate::testing::check_logged(($log).logged_lines(), $level, $condition, $expected)
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Logged;
+    use crate::*;
+
+    #[test]
+    fn logger_and_handler() {
+        let mut log = Logger::builder("app")
+            .add_string_handler()
+            .add_string_handler_with(FormatType::Iso8601, None)
+            .set_level(Level::FINEST)
+            .set_fn_name("main")
+            .build();
+
+        log.fine("Fine detail.");
+        log.finest("Finest detail.");
+
+        assert_eq!(log.logged_lines().len(), 4);
+        assert_logged!(log, Level::FINE, contains "Fine detail");
+        assert_logged!(log, Level::FINEST);
+        assert_not_logged!(log, Level::FINER);
+        assert_not_logged!(log, Level::FINE, contains "Finest");
+
+        let h = log.get_handler(Handler::String).unwrap();
+        assert_logged!(h, Level::FINE, matches r"^app->main \[FINE +\]");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a WARNING log entry containing \"sky\"")]
+    fn missing() {
+        let mut log = Logger::string_logger("app");
+        log.info("The sky is falling!");
+
+        assert_logged!(log, Level::WARNING, contains "sky");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected no INFO log entry, found:")]
+    fn unexpected() {
+        let mut log = Logger::string_logger("app");
+        log.info("The sky is falling!");
+
+        assert_not_logged!(log, Level::INFO);
+    }
+}
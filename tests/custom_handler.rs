@@ -27,7 +27,7 @@
 
 #![allow(dead_code)]
 
-use std::{fmt, io::Error};
+use std::{any::Any, fmt, io::Error};
 
 use flogging::*;
 
@@ -35,6 +35,7 @@ use flogging::*;
 pub(crate) struct CustomHandler {
     name: String,
     formatter: Formatter,
+    filter: Option<Box<dyn FilterTrait>>,
     log: Vec<String>,
 }
 
@@ -43,6 +44,7 @@ impl CustomHandler {
         CustomHandler {
             name: name.to_string(),
             formatter: FormatType::Simple.create(None),
+            filter: None,
             log: Vec::new(),
         }
     }
@@ -82,12 +84,24 @@ impl HandlerTrait for CustomHandler {
         Ok(Self::new(name))
     }
 
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
     fn close(&mut self) {}
 
     fn flush(&mut self) {
         self.log.clear();
     }
 
+    fn get_filter(&self) -> Option<&dyn FilterTrait> {
+        self.filter.as_deref()
+    }
+
     fn get_formatter(&self) -> Formatter {
         self.formatter.clone()
     }
@@ -105,6 +119,10 @@ impl HandlerTrait for CustomHandler {
         self.log.push(self.formatter.format(log_entry));
     }
 
+    fn set_filter(&mut self, filter: Option<Box<dyn FilterTrait>>) {
+        self.filter = filter;
+    }
+
     fn set_formatter(&mut self, format: Formatter) {
         self.formatter = format;
     }
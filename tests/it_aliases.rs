@@ -0,0 +1,60 @@
+//
+// File Name:    it_aliases.rs
+// Directory:    tests
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Integration Tests - Level Aliases
+//!
+//! Run with: `cargo test --features aliases`.
+//!
+
+#![cfg(feature = "aliases")]
+
+use flogging::*;
+
+static_logger!({
+    Logger::builder(module_path!())
+        .add_string_handler()
+        .set_level(Level::DEBUG)
+        .build()
+});
+
+#[logger]
+fn aliases() {
+    trace!("Not logged.");
+    debug!("Cache miss: {}", 42);
+    error!("Disk full.");
+}
+
+#[test]
+fn alias_macros() {
+    aliases();
+
+    let mut log = FLOGGING_LOGGER.lock_logger();
+    let log = log.get_handler(Handler::String).unwrap().get_log();
+
+    assert_eq!(
+        log,
+        "it_aliases->aliases [FINE   ] Cache miss: 42\n\
+         it_aliases->aliases [SEVERE ] Disk full.\n"
+    );
+}
@@ -137,5 +137,9 @@ mod tests {
         assert!(is_logging!());
         set_level!(Level::OFF);
         assert!(!is_logging!());
+        set_level!("Finest");
+        assert!(is_logging!());
+        set_level!("off");
+        assert!(!is_logging!());
     }
 }
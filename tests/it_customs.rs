@@ -64,7 +64,7 @@ mod test {
 
     #[test]
     fn add_console_handler_with_custom_formatter() {
-        let mut log = Logger::builder(module_path!())
+        let log = Logger::builder(module_path!())
             .add_console_handler_with(
                 FormatType::Custom,
                 Some(Box::new(CustomFormatter::new())),
@@ -76,7 +76,7 @@ mod test {
 
     #[test]
     fn add_econsole_handler_with_custom_formatter() {
-        let mut log = Logger::builder(module_path!())
+        let log = Logger::builder(module_path!())
             .add_econsole_handler_with(
                 FormatType::Custom,
                 Some(Box::new(CustomFormatter::new())),
@@ -0,0 +1,84 @@
+//
+// File Name:    it_env_builder.rs
+// Directory:    tests
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Builder Configured from the Environment
+//!
+//! Kept in its own test binary, as setting the environment variables
+//! would otherwise affect the loggers of concurrently running tests.
+//!
+
+use flogging::*;
+
+#[test]
+fn from_env() {
+    // SAFETY: This is the only test in this binary, so no other thread is
+    // accessing the environment.
+    unsafe {
+        std::env::set_var(FLOGGING_LEVEL, "warning,app::db=fine");
+        std::env::set_var(FLOGGING_HANDLERS, "string, Console");
+        std::env::set_var(FLOGGING_FORMAT, "unixtimestamp");
+    }
+
+    let mut log = LoggerBuilder::from_env("app").try_build().unwrap();
+    assert_eq!(log.level(), Level::WARNING);
+    assert_eq!(log.module_level("app::db"), Level::FINE);
+    assert!(log.has_handler(Handler::String));
+    assert!(log.has_handler(Handler::Console));
+
+    log.warning("Stored.");
+    let text = log.get_handler(Handler::String).unwrap().get_log();
+    assert!(text.ends_with(" app-> [WARNING] Stored.\n"), "{text}");
+    assert!(
+        text.split_once('.').unwrap().0.parse::<u64>().is_ok(),
+        "{text}"
+    );
+
+    unsafe { std::env::remove_var(FLOGGING_HANDLERS) };
+    let log = LoggerBuilder::from_env("app").build();
+    assert!(log.has_handler(Handler::Console));
+    assert!(!log.has_handler(Handler::String));
+
+    unsafe { std::env::set_var(FLOGGING_HANDLERS, "file") };
+    let result = LoggerBuilder::from_env("app").try_build();
+    assert!(matches!(result, Err(BuildError::Env(FLOGGING_FILE, _))));
+
+    unsafe { std::env::set_var(FLOGGING_FILE, "test_logs/from_env.log") };
+    let log = LoggerBuilder::from_env("app").build();
+    assert!(log.has_handler(Handler::File));
+
+    unsafe { std::env::set_var(FLOGGING_HANDLERS, "console,carrier_pigeon") };
+    let err = LoggerBuilder::from_env("app").try_build().err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Invalid FLOGGING_HANDLERS: Unknown handler: carrier_pigeon"
+    );
+
+    unsafe { std::env::set_var(FLOGGING_FORMAT, "xml") };
+    let result = LoggerBuilder::from_env("app").try_build();
+    assert!(matches!(result, Err(BuildError::Env(FLOGGING_FORMAT, _))));
+
+    unsafe { std::env::set_var(FLOGGING_LEVEL, "=fine") };
+    let result = LoggerBuilder::from_env("app").try_build();
+    assert!(matches!(result, Err(BuildError::Env(FLOGGING_LEVEL, _))));
+}
@@ -0,0 +1,55 @@
+//
+// File Name:    it_env_level.rs
+// Directory:    tests
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Default Level from the Environment
+//!
+//! Kept in its own test binary, as setting the environment variable
+//! would otherwise affect the loggers of concurrently running tests.
+//!
+
+use flogging::*;
+
+#[test]
+fn env_level() {
+    // SAFETY: This is the only test in this binary, so no other thread is
+    // accessing the environment.
+    unsafe { std::env::set_var(FLOGGING_LEVEL, "fine") };
+    assert_eq!(Level::from_env(), Some(Level::FINE));
+
+    let mut log = Logger::builder("app").add_string_handler().build();
+    assert_eq!(log.level(), Level::FINE);
+
+    log.fine("Now visible.");
+    assert_eq!(
+        log.get_handler(Handler::String).unwrap().get_log(),
+        "app-> [FINE   ] Now visible.\n"
+    );
+
+    let log = Logger::builder("app").set_level(Level::WARNING).build();
+    assert_eq!(log.level(), Level::WARNING);
+
+    unsafe { std::env::set_var(FLOGGING_LEVEL, "verbose") };
+    assert_eq!(Level::from_env(), None);
+    assert_eq!(Logger::builder("app").build().level(), Level::INFO);
+}
@@ -0,0 +1,81 @@
+//
+// File Name:    it_fields.rs
+// Directory:    tests
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Integration Tests - Key-Value Fields
+//!
+
+#[cfg(test)]
+mod tests {
+    use flogging::*;
+
+    const_logger!({
+        Logger::builder(module_path!())
+            .add_string_handler()
+            .set_level(Level::FINEST)
+            .build()
+    });
+
+    #[derive(Debug)]
+    struct Order {
+        id: &'static str,
+    }
+
+    #[logger]
+    #[test]
+    fn fields() {
+        let user_id = 42;
+        let order = Order { id: "A-17" };
+        let items = vec![1, 2];
+
+        info!("Order placed: {}", order.id; user_id, order = %order.id, ?items);
+        warning!("Low stock."; r#type = "widget", note = "two words");
+        fine!(user_id; count = items.len());
+
+        if let Some(h) = get_handler!(Handler::String) {
+            let log = h.get_log();
+
+            assert!(
+                log.contains(
+                    "[INFO   ] Order placed: A-17 user_id=42 order=A-17 items=\"[1, 2]\"\n"
+                )
+            );
+            assert!(log.contains("[WARNING] Low stock. type=widget note=\"two words\"\n"));
+            assert!(log.contains("[FINE   ] 42 count=2\n"));
+        } else {
+            panic!("Missing the StringHandler!");
+        }
+    }
+
+    #[logger]
+    #[test]
+    fn no_fields() {
+        info!("No fields;");
+
+        if let Some(h) = get_handler!(Handler::String) {
+            assert!(h.get_log().contains("[INFO   ] No fields;\n"));
+        } else {
+            panic!("Missing the StringHandler!");
+        }
+    }
+}
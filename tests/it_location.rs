@@ -0,0 +1,82 @@
+//
+// File Name:    it_location.rs
+// Directory:    tests
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Integration Tests - Source Location
+//!
+
+#[cfg(test)]
+mod tests {
+    use flogging::*;
+    use std::fmt;
+
+    #[derive(Clone)]
+    struct LocationFormatter;
+
+    impl fmt::Display for LocationFormatter {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            "LocationFormatter".fmt(f)
+        }
+    }
+
+    impl FormatTrait for LocationFormatter {
+        fn format(&self, log_entry: &LogEntry) -> String {
+            self.ft_fmt(
+                String::new(),
+                "{file}:{line} {message}".to_string(),
+                log_entry,
+            )
+        }
+    }
+
+    const_logger!({
+        Logger::builder(module_path!())
+            .add_string_handler_with(FormatType::Custom, Some(Box::new(LocationFormatter)))
+            .set_level(Level::FINEST)
+            .build()
+    });
+
+    #[logger]
+    #[test]
+    fn macros() {
+        let line = line!();
+        entering!();
+        info!("Here.");
+        warning!("There."; id = 7);
+
+        if let Some(h) = get_handler!(Handler::String) {
+            assert_eq!(
+                h.get_log(),
+                format!(
+                    "{file}:{} Entry\n{file}:{} Here.\n{file}:{} There. id=7\n",
+                    line + 1,
+                    line + 2,
+                    line + 3,
+                    file = file!()
+                )
+            );
+        } else {
+            panic!("Missing the StringHandler!");
+        }
+    }
+}
@@ -0,0 +1,415 @@
+//
+// File Name:    it_logger_attr.rs
+// Directory:    tests
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Integration Tests - `#[logger]` Attribute
+//!
+
+#[cfg(test)]
+mod tests {
+    use flogging::*;
+    use std::fmt;
+
+    #[derive(Clone)]
+    struct NameFormatter;
+
+    impl fmt::Display for NameFormatter {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            "NameFormatter".fmt(f)
+        }
+    }
+
+    impl FormatTrait for NameFormatter {
+        fn format(&self, log_entry: &LogEntry) -> String {
+            self.ft_fmt(String::new(), "{fn_name} {message}".to_string(), log_entry)
+        }
+    }
+
+    mod impl_block {
+        use super::*;
+
+        const_logger!({
+            Logger::builder(module_path!())
+                .add_string_handler_with(FormatType::Custom, Some(Box::new(NameFormatter)))
+                .set_level(Level::FINEST)
+                .build()
+        });
+
+        struct Counter<T> {
+            count: T,
+        }
+
+        #[logger]
+        impl<T: Copy + fmt::Display + std::ops::Add<Output = T>> Counter<T> {
+            fn add(&mut self, value: T) -> T {
+                self.count = self.count + value;
+                info!("count: {}", self.count);
+                self.count
+            }
+
+            #[logger(reset_counter)]
+            fn reset(&mut self, value: T) {
+                self.count = value;
+                info!("reset");
+            }
+        }
+
+        #[logger]
+        impl fmt::Display for Counter<u32> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fine!("formatting");
+                write!(f, "Counter({})", self.count)
+            }
+        }
+
+        #[test]
+        fn type_method() {
+            let mut counter = Counter { count: 1u32 };
+            counter.add(2);
+            counter.reset(0);
+            let _ = counter.to_string();
+
+            let mut log = FLOGGING_LOGGER.lock_logger();
+            let log = log.get_handler(Handler::String).unwrap().get_log();
+
+            assert_eq!(
+                log,
+                "Counter::add count: 3\nreset_counter reset\nCounter::fmt formatting\n"
+            );
+        }
+    }
+
+    mod trace {
+        use super::*;
+
+        const_logger!({
+            Logger::builder(module_path!())
+                .add_string_handler_with(FormatType::Custom, Some(Box::new(NameFormatter)))
+                .set_level(Level::FINEST)
+                .build()
+        });
+
+        #[logger(trace)]
+        fn parse(text: &str) -> Result<u32, std::num::ParseIntError> {
+            let value = text.parse::<u32>()?;
+
+            if value == 0 {
+                return Ok(1);
+            }
+
+            fine!("value: {value}");
+            Ok(value)
+        }
+
+        #[test]
+        fn exit_paths() {
+            assert!(parse("x").is_err());
+            assert_eq!(parse("0"), Ok(1));
+            assert_eq!(parse("7"), Ok(7));
+
+            let mut log = FLOGGING_LOGGER.lock_logger();
+            let log = log.get_handler(Handler::String).unwrap().get_log();
+
+            assert_eq!(
+                log,
+                "parse Entry\nparse Return\n\
+                 parse Entry\nparse Return\n\
+                 parse Entry\nparse value: 7\nparse Return\n"
+            );
+        }
+    }
+
+    mod panic {
+        use super::*;
+
+        const_logger!({
+            Logger::builder(module_path!())
+                .add_string_handler_with(FormatType::Custom, Some(Box::new(NameFormatter)))
+                .set_level(Level::FINEST)
+                .build()
+        });
+
+        #[logger(trace, panic)]
+        fn divide(a: u32, b: u32) -> u32 {
+            fine!("dividing");
+            a / b
+        }
+
+        #[logger(panic)]
+        fn quiet(a: u32) -> u32 {
+            a
+        }
+
+        #[test]
+        fn unwind() {
+            assert_eq!(divide(6, 3), 2);
+            assert!(std::panic::catch_unwind(|| divide(1, 0)).is_err());
+            assert_eq!(quiet(1), 1);
+
+            let mut log = FLOGGING_LOGGER.lock_logger();
+            let log = log.get_handler(Handler::String).unwrap().get_log();
+
+            assert_eq!(
+                log,
+                "divide Entry\ndivide dividing\ndivide Return\n\
+                 divide Entry\ndivide dividing\ndivide Exit (panicked)\n"
+            );
+        }
+    }
+
+    mod timed {
+        use super::*;
+
+        const_logger!({
+            Logger::builder(module_path!())
+                .add_string_handler_with(FormatType::Custom, Some(Box::new(NameFormatter)))
+                .set_level(Level::FINEST)
+                .build()
+        });
+
+        #[logger(timed)]
+        fn nap(millis: u64) {
+            std::thread::sleep(std::time::Duration::from_millis(millis));
+        }
+
+        #[test]
+        fn took() {
+            nap(5);
+
+            let mut log = FLOGGING_LOGGER.lock_logger();
+            let log = log.get_handler(Handler::String).unwrap().get_log();
+
+            assert!(log.starts_with("nap Return (took "), "{log}");
+            assert!(log.ends_with("ms)\n"), "{log}");
+        }
+    }
+
+    mod level {
+        use super::*;
+
+        const_logger!({
+            Logger::builder(module_path!())
+                .add_string_handler_with(FormatType::Custom, Some(Box::new(NameFormatter)))
+                .set_level(Level::INFO)
+                .build()
+        });
+
+        #[logger(level = "FINEST")]
+        fn chatty() {
+            finest!("deep");
+        }
+
+        #[logger]
+        fn normal() {
+            finest!("hidden");
+            info!("shown");
+        }
+
+        // The variables only used by the removed macros are still used.
+        #[logger(trace, off)]
+        #[deny(unused_variables)]
+        fn hot(value: u32) -> u32 {
+            let doubled = value * 2;
+            info!("value: {value}, doubled: {doubled}");
+
+            if is_logging!() { value + 1 } else { value }
+        }
+
+        #[test]
+        fn override_and_off() {
+            chatty();
+            normal();
+            assert_eq!(hot(1), 1);
+
+            let mut log = FLOGGING_LOGGER.lock_logger();
+            let log = log.get_handler(Handler::String).unwrap().get_log();
+
+            assert_eq!(log, "chatty deep\nnormal shown\n");
+        }
+    }
+
+    mod params {
+        use super::*;
+
+        const_logger!({
+            Logger::builder(module_path!())
+                .add_string_handler_with(FormatType::Custom, Some(Box::new(NameFormatter)))
+                .set_level(Level::FINEST)
+                .build()
+        });
+
+        #[logger(params, skip(password))]
+        fn login(user: &str, password: &str, #[redact] token: u64, attempt: u8) -> bool {
+            !user.is_empty() && !password.is_empty() && token > 0 && attempt < 3
+        }
+
+        #[test]
+        fn redacted() {
+            assert!(login("mary", "secret", 42, 1));
+
+            let mut log = FLOGGING_LOGGER.lock_logger();
+            let log = log.get_handler(Handler::String).unwrap().get_log();
+
+            assert_eq!(
+                log,
+                "login Entry: (user: \"mary\", password: ***, token: ***, attempt: 1)\n"
+            );
+        }
+    }
+
+    mod capture_return {
+        use super::*;
+
+        const_logger!({
+            Logger::builder(module_path!())
+                .add_string_handler_with(FormatType::Custom, Some(Box::new(NameFormatter)))
+                .set_level(Level::FINEST)
+                .build()
+        });
+
+        #[logger(capture_return)]
+        fn clamp(value: i32) -> i32 {
+            if value < 0 {
+                return 0;
+            }
+
+            let double = |v: i32| -> i32 {
+                if v > 100 {
+                    return 100;
+                }
+
+                v * 2
+            };
+
+            double(value).min(10)
+        }
+
+        #[logger(trace, capture_return)]
+        fn parse(text: &str) -> Result<u32, std::num::ParseIntError> {
+            let value = text.parse::<u32>()?;
+            Ok(value)
+        }
+
+        #[test]
+        fn values() {
+            assert_eq!(clamp(-3), 0);
+            assert_eq!(clamp(3), 6);
+            assert_eq!(clamp(30), 10);
+            assert_eq!(parse("4"), Ok(4));
+            assert!(parse("x").is_err());
+
+            let mut log = FLOGGING_LOGGER.lock_logger();
+            let log = log.get_handler(Handler::String).unwrap().get_log();
+
+            assert_eq!(
+                log,
+                "clamp Return: (0)\nclamp Return: (6)\nclamp Return: (10)\n\
+                 parse Entry\nparse Return: (Ok(4))\n\
+                 parse Entry\nparse Return\n"
+            );
+        }
+    }
+
+    mod hygiene {
+        // No glob import, and names that clash with those of `flogging`.
+        #[allow(dead_code)]
+        struct Logger;
+        #[allow(dead_code)]
+        enum Level {
+            Info,
+        }
+        #[allow(dead_code)]
+        const LOGGER: u8 = 0;
+
+        #[allow(unused_macros)]
+        macro_rules! format {
+            ($($arg:tt)*) => {
+                compile_error!("The std `format!` was shadowed.")
+            };
+        }
+
+        flogging::const_logger!({
+            flogging::Logger::builder(module_path!())
+                .add_string_handler()
+                .set_level(flogging::Level::FINEST)
+                .build()
+        });
+
+        #[flogging::logger(trace, timed, params)]
+        fn work(count: u32) {
+            flogging::info!("Hygienic: {count}"; count);
+            flogging::log!(flogging::Level::FINE, "Any import style.");
+        }
+
+        #[test]
+        fn no_glob_import() {
+            work(3);
+
+            let mut log = flogging::LockLogger::lock_logger(&FLOGGING_LOGGER);
+            let log = log
+                .get_handler(flogging::Handler::String)
+                .unwrap()
+                .get_log();
+
+            assert!(log.contains("Hygienic: 3 count=3"), "{log}");
+            assert!(log.contains("Any import style."), "{log}");
+            assert!(log.contains("Entry: (count: 3)"), "{log}");
+        }
+    }
+
+    mod reentrant {
+        use super::*;
+        use std::time::Duration;
+
+        // The alert logs to this same logger, while it is still held.
+        const_logger!({
+            Logger::builder(module_path!())
+                .add_string_handler_with(FormatType::Custom, Some(Box::new(NameFormatter)))
+                .add_alert(
+                    Alert::new(0, Duration::from_secs(60), |_: &[LogEntry]| report())
+                        .at_level(Level::SEVERE),
+                )
+                .build()
+        });
+
+        #[logger]
+        fn report() {
+            warning!("Alert raised.");
+        }
+
+        #[logger]
+        fn fail() {
+            severe!("Disk full.");
+        }
+
+        #[test]
+        fn log_from_alert() {
+            fail();
+
+            let mut log = FLOGGING_LOGGER.lock_logger();
+            let log = log.get_handler(Handler::String).unwrap().get_log();
+
+            assert_eq!(log, "fail Disk full.\nreport Alert raised.\n");
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//
+// File Name:    it_msg_args.rs
+// Directory:    tests
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Integration Tests - Message Arguments
+//!
+
+#[cfg(test)]
+mod tests {
+    use flogging::*;
+
+    const_logger!({
+        Logger::builder(module_path!())
+            .add_string_handler()
+            .set_level(Level::FINEST)
+            .build()
+    });
+
+    fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    #[logger]
+    #[test]
+    fn arguments() {
+        let (a, b) = (2, 3);
+
+        info!(add(a, b));
+        info!(add(a, b), add(b, b));
+        info!("a, b: {}", add(a, b),);
+        info!(r"raw, {}", a);
+        info!("no placeholder, just text");
+        info!(a, "text, with commas");
+        info!("sum: {}", add(a, b); total = add(a, b), pair = ?(a, b));
+
+        if let Some(h) = get_handler!(Handler::String) {
+            let log = h.get_log();
+            let messages: Vec<&str> = log
+                .lines()
+                .map(|line| line.split_once("] ").unwrap().1)
+                .collect();
+
+            assert_eq!(
+                messages,
+                [
+                    "5",
+                    "5, 6",
+                    "a, b: 5",
+                    "raw, 2",
+                    "no placeholder, just text",
+                    "2, text, with commas",
+                    "sum: 5 total=5 pair=\"(2, 3)\"",
+                ]
+            );
+        } else {
+            panic!("Missing the StringHandler!");
+        }
+    }
+}
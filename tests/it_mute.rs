@@ -0,0 +1,61 @@
+//
+// File Name:    it_mute.rs
+// Directory:    tests
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Muting Every Logger
+//!
+//! Kept in its own test binary, as muting would otherwise silence the loggers
+//! of concurrently running tests.
+//!
+
+use flogging::*;
+
+#[test]
+fn mute() {
+    let mut log = Logger::builder("app")
+        .add_string_handler()
+        .set_level(Level::FINEST)
+        .build();
+    log.set_fn_level(Some(Level::ALL));
+
+    assert!(!LogManager::is_muted());
+    LogManager::mute();
+    assert!(LogManager::is_muted());
+
+    log.severe("Muted.");
+    assert!(!log.is_enabled(Level::SEVERE));
+
+    log.set_fn_level(None);
+    log.severe("Still muted.");
+
+    LogManager::unmute();
+    assert!(!LogManager::is_muted());
+
+    log.fine("Unmuted.");
+
+    assert_eq!(log.counts().rejected(), 2);
+    assert_eq!(
+        log.get_handler(Handler::String).unwrap().get_log(),
+        "app-> [FINE   ] Unmuted.\n"
+    );
+}
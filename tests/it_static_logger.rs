@@ -0,0 +1,231 @@
+//
+// File Name:    it_static_logger.rs
+// Directory:    tests
+// Project Name: flogging
+//
+// Copyright (C) 2025 Bradley Willcott
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This library (crate) is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This library (crate) is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this library (crate).  If not, see <https://www.gnu.org/licenses/>.
+//
+
+//!
+//! # Integration Tests - Static Logger
+//!
+
+#[cfg(test)]
+mod tests {
+    use flogging::*;
+    use std::thread;
+
+    static_logger!({
+        Logger::builder(module_path!())
+            .add_string_handler()
+            .set_level(Level::FINEST)
+            .build()
+    });
+
+    #[logger]
+    fn worker(id: usize) -> usize {
+        entering!("id: {id}");
+        let rtn = helper(id);
+        exiting!("rtn: {rtn}");
+        rtn
+    }
+
+    #[logger]
+    fn helper(id: usize) -> usize {
+        finest!("Helping: {id}");
+        id * 2
+    }
+
+    #[logger]
+    fn parse_port(text: &str) -> Result<u16, std::num::ParseIntError> {
+        text.parse().inspect_err(|e| throwing!(e))
+    }
+
+    #[logger]
+    #[test]
+    fn throwing() {
+        assert!(parse_port("http").is_err());
+
+        if let Some(h) = get_handler!(Handler::String) {
+            assert!(h.get_log().contains(
+                "->parse_port [FINER  ] Throw error=\"invalid digit found in string\"\n"
+            ));
+        } else {
+            panic!("Missing the StringHandler!");
+        }
+    }
+
+    #[logger]
+    #[test]
+    fn held_handler() {
+        let h = get_handler!(Handler::String).unwrap();
+
+        // Deferred until the handler is dropped.
+        info!("Held.");
+        assert!(!h.get_log().contains("Held."));
+
+        drop(h);
+
+        let h = get_handler!(Handler::String).unwrap();
+        assert!(h.get_log().contains("->held_handler [INFO   ] Held.\n"));
+    }
+
+    #[logger]
+    #[test]
+    fn multithreaded() {
+        let handles: Vec<_> = (0..4)
+            .map(|id| thread::spawn(move || worker(id)))
+            .collect();
+
+        let total: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+        assert_eq!(total, 12);
+
+        if let Some(h) = get_handler!(Handler::String) {
+            let log = h.get_log();
+
+            for id in 0..4 {
+                assert!(log.contains(&format!("->worker [FINER  ] Entry: (id: {id})")));
+                assert!(log.contains(&format!("->helper [FINEST ] Helping: {id}")));
+            }
+        } else {
+            panic!("Missing the StringHandler!");
+        }
+    }
+}
+
+#[cfg(test)]
+mod const_logger {
+    use flogging::*;
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    static BUILDS: AtomicUsize = AtomicUsize::new(0);
+
+    const_logger!({
+        BUILDS.fetch_add(1, Ordering::SeqCst);
+
+        Logger::builder(module_path!())
+            .add_string_handler()
+            .build()
+    });
+
+    #[logger]
+    fn my_func(count: usize) {
+        info!("Call: {count}");
+    }
+
+    #[test]
+    fn built_once() {
+        for count in 0..5 {
+            my_func(count);
+        }
+
+        assert_eq!(BUILDS.load(Ordering::SeqCst), 1);
+    }
+
+    #[logger]
+    #[test]
+    fn dynamic_level() {
+        for level in [Level::WARNING, Level::FINE, Level::OFF] {
+            log!(level, "Level: {level}"; id = 7);
+        }
+
+        if let Some(h) = get_handler!(Handler::String) {
+            let log = h.get_log();
+
+            assert!(log.contains("->dynamic_level [WARNING] Level: WARNING id=7\n"));
+            assert!(!log.contains("Level: FINE"));
+            assert!(!log.contains("Level: OFF"));
+        } else {
+            panic!("Missing the StringHandler!");
+        }
+    }
+
+    #[logger]
+    #[test]
+    fn lazy_arguments() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let expensive = || CALLS.fetch_add(1, Ordering::SeqCst);
+
+        finest!("Not logged: {}", expensive());
+        fine!("Not logged."; calls = expensive());
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+        info!("Logged: {}", expensive());
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[logger]
+    fn deprecated(n: usize) {
+        warn_once!("Deprecated: {n}");
+        log_once!(Level::INFO, "Once: {n}");
+    }
+
+    #[logger]
+    #[test]
+    fn once() {
+        for n in 0..3 {
+            deprecated(n);
+        }
+
+        if let Some(h) = get_handler!(Handler::String) {
+            let log = h.get_log();
+
+            assert!(log.contains("->deprecated [WARNING] Deprecated: 0\n"));
+            assert!(log.contains("->deprecated [INFO   ] Once: 0\n"));
+            assert!(!log.contains("Deprecated: 1"));
+            assert!(!log.contains("Once: 2"));
+        } else {
+            panic!("Missing the StringHandler!");
+        }
+    }
+
+    #[logger]
+    fn progress(n: usize) {
+        info_every!(2, "Every: {n}");
+        warning_every!(Duration::from_secs(3600), "Hourly: {n}");
+    }
+
+    #[logger]
+    #[test]
+    fn every() {
+        for n in 0..5 {
+            progress(n);
+        }
+
+        if let Some(h) = get_handler!(Handler::String) {
+            let log = h.get_log();
+
+            for n in [0, 2, 4] {
+                assert!(log.contains(&format!("->progress [INFO   ] Every: {n}\n")));
+            }
+
+            for n in [1, 3] {
+                assert!(!log.contains(&format!("Every: {n}")));
+            }
+
+            assert!(log.contains("->progress [WARNING] Hourly: 0\n"));
+            assert!(!log.contains("Hourly: 1"));
+        } else {
+            panic!("Missing the StringHandler!");
+        }
+    }
+}